@@ -12,7 +12,45 @@ pub trait IntrospectionConnector: Send + Sync + 'static {
 
     async fn get_metadata(&self) -> ConnectorResult<DatabaseMetadata>;
 
-    async fn introspect(&self) -> ConnectorResult<Datamodel>;
+    async fn introspect(&self) -> ConnectorResult<IntrospectionResult>;
+
+    /// Like `introspect`, with optional transformations of the introspected datamodel.
+    async fn introspect_with_options(&self, options: IntrospectionOptions) -> ConnectorResult<IntrospectionResult>;
+}
+
+/// An introspected datamodel, with warnings about what it does not represent.
+#[derive(Debug)]
+pub struct IntrospectionResult {
+    pub datamodel: Datamodel,
+    pub warnings: Vec<Warning>,
+}
+
+/// Something in the database the introspected datamodel does not represent, or not fully.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Warning {
+    /// Identifies the kind of warning. Codes are stable.
+    pub code: u32,
+    /// What is not represented and what happened to it.
+    pub message: String,
+    /// The affected table.
+    pub table: String,
+    /// The affected columns. Empty when the warning is about the table as a whole.
+    pub columns: Vec<String>,
+}
+
+/// Optional transformations of an introspected datamodel. All of them are off by default.
+#[derive(Debug, Default, Clone)]
+pub struct IntrospectionOptions {
+    /// Annotate fields with the native types of their columns.
+    pub native_types: bool,
+    /// Name models in singular PascalCase and fields in camelCase, mapped to the original table and column names.
+    pub naming_conventions: bool,
+    /// Glob patterns of the tables and views to introspect. Everything is introspected if empty.
+    pub include_tables: Vec<String>,
+    /// Glob patterns of the tables and views to leave out.
+    pub exclude_tables: Vec<String>,
+    /// Further schemas to introspect next to the one of the connection.
+    pub schemas: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
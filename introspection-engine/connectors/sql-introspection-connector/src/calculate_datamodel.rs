@@ -4,10 +4,14 @@ use datamodel::{
     dml, Datamodel, Field, FieldArity, FieldType, IdInfo, IdStrategy, IndexDefinition, Model, OnDeleteStrategy,
     RelationInfo, WithDatabaseName,
 };
+use datamodel_connector::Connector;
+use introspection_connector::Warning;
 use log::debug;
 use prisma_inflector;
 use regex::Regex;
+use rust_decimal::Decimal;
 use sql_schema_describer::*;
+use std::collections::HashMap;
 
 fn is_migration_table(table: &Table) -> bool {
     table.name == "_Migration"
@@ -36,15 +40,17 @@ fn is_prisma_join_table(table: &Table) -> bool {
 fn create_many_to_many_field(foreign_key: &ForeignKey, relation_name: String, is_self_relation: bool) -> Field {
     let inflector = prisma_inflector::default();
 
+    let (to, _) = sanitize_name(foreign_key.referenced_table.clone());
+    let basename = inflector.pluralize(&to).camel_case();
+
     let field_type = FieldType::Relation(RelationInfo {
         name: relation_name,
-        to: foreign_key.referenced_table.clone(),
+        to,
         to_fields: foreign_key.referenced_columns.clone(),
+        columns: vec![],
         on_delete: OnDeleteStrategy::None,
     });
 
-    let basename = inflector.pluralize(&foreign_key.referenced_table).camel_case();
-
     let name = match is_self_relation {
         true => format!("{}_{}", basename, foreign_key.columns[0]),
         false => basename,
@@ -61,24 +67,155 @@ fn create_many_to_many_field(foreign_key: &ForeignKey, relation_name: String, is
         documentation: None,
         is_generated: false,
         is_updated_at: false,
+        is_commented_out: false,
+    }
+}
+
+/// Views have columns like tables, but no keys or constraints. The column identifying the records of the
+/// view stands in for the primary key.
+fn view_as_table(view: &View) -> Table {
+    Table {
+        name: view.name.clone(),
+        columns: view.columns.clone(),
+        indices: vec![],
+        primary_key: view_id_column(view).map(|column| PrimaryKey {
+            columns: vec![column.name.clone()],
+            sequence: None,
+        }),
+        foreign_keys: vec![],
+        check_constraints: vec![],
+        comment: None,
+    }
+}
+
+/// The column marked with `@id` on the model of a view: the required column named `id`, or else the first
+/// required one. Views without required columns can not be queried and are introspected commented out.
+fn view_id_column(view: &View) -> Option<&Column> {
+    let candidates: Vec<&Column> = view
+        .columns
+        .iter()
+        .filter(|column| column.tpe.arity == ColumnArity::Required && is_supported_type(&column.tpe.family))
+        .collect();
+
+    candidates
+        .iter()
+        .find(|column| column.name == "id")
+        .or_else(|| candidates.first())
+        .cloned()
+}
+
+/// Columns of these types are introspected as commented out fields.
+fn is_supported_type(family: &ColumnTypeFamily) -> bool {
+    match family {
+        ColumnTypeFamily::Geometric | ColumnTypeFamily::TextSearch | ColumnTypeFamily::Unknown => false,
+        _ => true,
+    }
+}
+
+fn covers_unsupported_columns(index: &Index, table: &Table) -> bool {
+    index.columns.iter().any(|column_name| {
+        table
+            .columns
+            .iter()
+            .any(|column| &column.name == column_name && !is_supported_type(&column.tpe.family))
+    })
+}
+
+/// Columns of foreign keys over multiple columns are represented by a single relation field. Columns
+/// that are also part of the primary key, like a tenant id, additionally stay scalar fields.
+fn is_compound_foreign_key_column(column: &Column, table: &Table) -> bool {
+    !table.is_part_of_primary_key(&column.name)
+        && table
+            .foreign_keys
+            .iter()
+            .any(|fk| fk.columns.len() > 1 && fk.columns.contains(&column.name))
+}
+
+fn create_compound_relation_field(schema: &SqlSchema, foreign_key: &ForeignKey, table: &Table, model: &Model) -> Field {
+    let (to, _) = sanitize_name(foreign_key.referenced_table.clone());
+    let name = match model.find_field(&to) {
+        Some(_) => format!("{}_{}", to, foreign_key.columns.join("_")),
+        None => to.clone(),
+    };
+
+    let is_required = foreign_key.columns.iter().all(|column_name| {
+        table
+            .column(column_name)
+            .map(|column| column.tpe.arity == ColumnArity::Required)
+            .unwrap_or(false)
+    });
+
+    Field {
+        name,
+        arity: if is_required {
+            FieldArity::Required
+        } else {
+            FieldArity::Optional
+        },
+        field_type: FieldType::Relation(RelationInfo {
+            name: calculate_relation_name(schema, foreign_key, table),
+            to,
+            to_fields: foreign_key.referenced_columns.clone(),
+            columns: foreign_key.columns.clone(),
+            on_delete: OnDeleteStrategy::None,
+        }),
+        database_name: None,
+        default_value: None,
+        is_unique: false,
+        id_info: None,
+        documentation: None,
+        is_generated: false,
+        is_updated_at: false,
+        is_commented_out: false,
     }
 }
 
+/// The name of the field representing a column. Columns only represented by a compound relation field
+/// are represented by that field.
+fn field_name_for_column(model: &Model, column_name: &str) -> String {
+    let column_field = model.fields().find(|field| match &field.field_type {
+        FieldType::Relation(info) if !info.columns.is_empty() => false,
+        _ => field.database_name.as_ref().unwrap_or(&field.name) == column_name,
+    });
+
+    let relation_field = || {
+        model.fields().find(|field| match &field.field_type {
+            FieldType::Relation(info) => info.columns.iter().any(|column| column == column_name),
+            _ => false,
+        })
+    };
+
+    column_field
+        .or_else(relation_field)
+        .map(|field| field.name.clone())
+        .unwrap_or_else(|| column_name.to_owned())
+}
+
 /// Calculate a data model from a database schema.
 pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel> {
     debug!("Calculating data model");
 
     let mut data_model = Datamodel::new();
-    for table in schema
+    let view_tables: Vec<Table> = schema.views.iter().map(view_as_table).collect();
+    let tables = schema
         .tables
         .iter()
         .filter(|table| !is_migration_table(&table))
         .filter(|table| !is_prisma_join_table(&table))
-    {
+        .map(|table| (table, false));
+    let views = view_tables.iter().map(|table| (table, true));
+
+    for (table, is_view) in tables.chain(views) {
         let (name, database_name) = sanitize_name(table.name.clone());
         let mut model = Model::new(name, database_name);
-        //Todo: This needs to filter out composite Foreign Key columns, they are merged into one new field
-        for column in table.columns.iter() {
+        model.is_view = is_view;
+        model.is_commented_out = is_view && table.primary_key.is_none();
+        model.documentation = table.comment.clone();
+        for column in table
+            .columns
+            .iter()
+            .filter(|column| !is_compound_foreign_key_column(column, table))
+        {
             debug!("Handling column {:?}", column);
             let field_type = calculate_field_type(&schema, &column, &table);
             let arity = match column.tpe.arity {
@@ -90,10 +227,17 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
             let default_value = match field_type {
                 FieldType::Relation(_) => None,
                 _ if arity == FieldArity::List => None,
+                _ if column.auto_increment => match &id_info {
+                    Some(IdInfo {
+                        strategy: IdStrategy::Auto,
+                        ..
+                    }) => Some(autoincrement_default()),
+                    _ => None,
+                },
                 _ => column
                     .default
                     .as_ref()
-                    .and_then(|default| calculate_default(default, &column.tpe.family)),
+                    .and_then(|default| calculate_default(default, &column.tpe.family, &field_type)),
             };
 
             let is_unique = match field_type {
@@ -117,15 +261,35 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                 default_value,
                 is_unique,
                 id_info,
-                documentation: None,
+                documentation: column.comment.clone(),
                 is_generated: false,
                 is_updated_at: false,
+                is_commented_out: !is_supported_type(&column.tpe.family),
             };
 
             model.add_field(field);
         }
 
-        for index in table.indices.iter() {
+        for foreign_key in table.foreign_keys.iter().filter(|fk| fk.columns.len() > 1) {
+            let field = create_compound_relation_field(schema, foreign_key, table, &model);
+            model.add_field(field);
+        }
+
+        for index in table
+            .indices
+            .iter()
+            .filter(|index| !covers_unsupported_columns(index, table))
+        {
+            let mut fields: Vec<String> = Vec::new();
+
+            for column_name in index.columns.iter() {
+                let field_name = field_name_for_column(&model, column_name);
+
+                if !fields.contains(&field_name) {
+                    fields.push(field_name);
+                }
+            }
+
             if index.columns.len() > 1 {
                 let tpe = if index.tpe == IndexType::Unique {
                     datamodel::dml::IndexType::Unique
@@ -135,34 +299,47 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
 
                 let index_definition: IndexDefinition = IndexDefinition {
                     name: Some(index.name.clone()),
-                    fields: index.columns.clone(),
+                    fields: fields.clone(),
                     tpe,
+                    online: false,
                 };
                 model.add_index(index_definition)
             }
             if index.columns.len() == 1 && index.tpe != IndexType::Unique {
                 let index_definition: IndexDefinition = IndexDefinition {
                     name: Some(index.name.clone()),
-                    fields: index.columns.clone(),
+                    fields,
                     tpe: datamodel::dml::IndexType::Normal,
+                    online: false,
                 };
                 model.add_index(index_definition)
             }
         }
 
         if table.primary_key_columns().len() > 1 {
-            model.id_fields = table.primary_key_columns();
+            model.id_fields = table
+                .primary_key_columns()
+                .iter()
+                .map(|column_name| field_name_for_column(&model, column_name))
+                .collect();
         }
 
+        model.check_constraints = table
+            .check_constraints
+            .iter()
+            .map(|check| dml::CheckConstraint {
+                name: Some(check.name.clone()),
+                expression: check.expression.clone(),
+            })
+            .collect();
+
         data_model.add_model(model);
     }
 
     for e in schema.enums.iter() {
-        let mut values: Vec<String> = e.values.iter().cloned().collect();
-        values.sort_unstable();
         data_model.add_enum(dml::Enum {
             name: e.name.clone(),
-            values,
+            values: e.values.clone(),
             database_name: None,
             documentation: None,
         });
@@ -186,8 +363,13 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                     {
                         let other_model = data_model.find_model(&relation_info.to).unwrap();
 
-                        let table = schema.table_bang(model.name.as_str());
-                        let fk = table.foreign_key_for_column(relation_field.name.as_str());
+                        let table = schema.table_bang(model.database_name.as_ref().unwrap_or(&model.name));
+                        let fk = match relation_info.columns.as_slice() {
+                            [] => table.foreign_key_for_column(
+                                relation_field.database_name.as_ref().unwrap_or(&relation_field.name),
+                            ),
+                            columns => table.foreign_keys.iter().find(|fk| fk.columns.as_slice() == columns),
+                        };
                         let on_delete = match fk {
                             None => OnDeleteStrategy::None,
                             Some(fk) => match fk.on_delete_action {
@@ -200,12 +382,19 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                             name: relation_info.name.clone(),
                             to: model.name.clone(),
                             to_fields: vec![relation_field.name.clone()],
+                            columns: vec![],
                             on_delete,
                         });
 
                         let arity = match relation_field.arity {
                             FieldArity::Required | FieldArity::Optional
-                                if schema.table_bang(&model.name).is_column_unique(
+                                if !relation_info.columns.is_empty()
+                                    && table.are_columns_unique(&relation_info.columns) =>
+                            {
+                                FieldArity::Optional
+                            }
+                            FieldArity::Required | FieldArity::Optional
+                                if table.is_column_unique(
                                     &relation_field.database_name().as_ref().unwrap_or(&relation_field.name),
                                 ) =>
                             {
@@ -234,6 +423,7 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_commented_out: false,
                         };
 
                         fields_to_be_added.push((other_model.name.clone(), field));
@@ -254,11 +444,11 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
                 let is_self_relation = f.referenced_table == s.referenced_table;
 
                 fields_to_be_added.push((
-                    s.referenced_table.clone(),
+                    sanitize_name(s.referenced_table.clone()).0,
                     create_many_to_many_field(f, table.name[1..].to_string(), is_self_relation),
                 ));
                 fields_to_be_added.push((
-                    f.referenced_table.clone(),
+                    sanitize_name(f.referenced_table.clone()).0,
                     create_many_to_many_field(s, table.name[1..].to_string(), is_self_relation),
                 ));
             }
@@ -302,6 +492,522 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
     Ok(data_model)
 }
 
+/// The tables and views of other schemas merged into a described schema, and what got lost merging them.
+#[derive(Debug, Default)]
+pub struct MergedSchemas {
+    pub tables: Vec<MergedTable>,
+    pub warnings: Vec<Warning>,
+}
+
+/// A table or view of another schema, named with its schema-qualified name in the merged schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergedTable {
+    pub qualified_name: String,
+    pub schema: String,
+    pub name: String,
+}
+
+/// Adds the tables, views, enums and sequences of another database schema. Its tables and views are named
+/// with their schema-qualified names to keep them apart, `assign_schemas` moves their models to their schema.
+/// Enums can only be named once, the columns of an enum named like one of an earlier schema keep referring to
+/// that one. They are warned about when the values of the two enums differ.
+pub fn merge_schema(
+    schema: &mut SqlSchema,
+    other: SqlSchema,
+    other_schema_name: &str,
+    default_schema_name: &str,
+    merged: &mut MergedSchemas,
+) {
+    let default_prefix = format!("{}.", default_schema_name);
+    let qualified = |name: &str| format!("{}.{}", other_schema_name, name);
+
+    let conflicting_enums: Vec<&Enum> = other
+        .enums
+        .iter()
+        .filter(|other_enum| {
+            schema
+                .enums
+                .iter()
+                .any(|e| e.name == other_enum.name && e.values != other_enum.values)
+        })
+        .collect();
+
+    let other_columns = other
+        .tables
+        .iter()
+        .map(|table| (&table.name, &table.columns))
+        .chain(other.views.iter().map(|view| (&view.name, &view.columns)));
+
+    for (table_name, columns) in other_columns {
+        for column in columns {
+            if let ColumnTypeFamily::Enum(enum_name) = &column.tpe.family {
+                if conflicting_enums.iter().any(|e| &e.name == enum_name) {
+                    merged.warnings.push(Warning {
+                        code: 6,
+                        message: format!(
+                            "The enum `{}` of schema `{}` has other values than the enum of the same name the field refers to.",
+                            enum_name, other_schema_name
+                        ),
+                        table: qualified(table_name),
+                        columns: vec![column.name.clone()],
+                    });
+                }
+            }
+        }
+    }
+
+    for mut table in other.tables {
+        merged.tables.push(MergedTable {
+            qualified_name: qualified(&table.name),
+            schema: other_schema_name.to_owned(),
+            name: table.name.clone(),
+        });
+        table.name = qualified(&table.name);
+
+        // Foreign keys to tables of other schemas are schema-qualified already.
+        for foreign_key in table.foreign_keys.iter_mut() {
+            foreign_key.referenced_table = if foreign_key.referenced_table.starts_with(&default_prefix) {
+                foreign_key.referenced_table[default_prefix.len()..].to_owned()
+            } else if foreign_key.referenced_table.contains('.') {
+                foreign_key.referenced_table.clone()
+            } else {
+                qualified(&foreign_key.referenced_table)
+            };
+        }
+
+        schema.tables.push(table);
+    }
+
+    for mut view in other.views {
+        merged.tables.push(MergedTable {
+            qualified_name: qualified(&view.name),
+            schema: other_schema_name.to_owned(),
+            name: view.name.clone(),
+        });
+        view.name = qualified(&view.name);
+        schema.views.push(view);
+    }
+
+    for other_enum in other.enums {
+        if !schema.enums.iter().any(|e| e.name == other_enum.name) {
+            schema.enums.push(other_enum);
+        }
+    }
+
+    schema.sequences.extend(other.sequences);
+}
+
+/// Moves the models of the tables and views of other schemas to their schema with `@@schema`, mapped to the
+/// table name within it.
+pub fn assign_schemas(data_model: &mut Datamodel, tables: &[MergedTable]) {
+    for model in data_model.models_mut() {
+        let table_name = model.database_name.clone().unwrap_or_else(|| model.name.clone());
+
+        if let Some(table) = tables.iter().find(|table| table.qualified_name == table_name) {
+            model.schema = Some(table.schema.clone());
+            model.database_name = if model.name == table.name {
+                None
+            } else {
+                Some(table.name.clone())
+            };
+        }
+    }
+}
+
+/// Leaves out the tables and views not matching any of the `include` glob patterns (if there are any),
+/// or matching one of the `exclude` patterns. Tables of other schemas are matched by their
+/// schema-qualified names. Join tables of many to many relations are kept with the tables they join.
+/// Foreign keys to tables that are left out are dropped, their columns become scalar fields.
+pub fn filter_tables(schema: &mut SqlSchema, include: &[String], exclude: &[String]) {
+    if include.is_empty() && exclude.is_empty() {
+        return;
+    }
+
+    let is_excluded = |name: &str| exclude.iter().any(|pattern| matches_glob(pattern, name));
+    let is_selected = |name: &str| {
+        (include.is_empty() || include.iter().any(|pattern| matches_glob(pattern, name))) && !is_excluded(name)
+    };
+
+    let selected_tables: Vec<String> = schema
+        .tables
+        .iter()
+        .filter(|table| !is_prisma_join_table(table) && is_selected(&table.name))
+        .map(|table| table.name.clone())
+        .collect();
+
+    schema.tables.retain(|table| {
+        if is_prisma_join_table(table) {
+            !is_excluded(&table.name)
+                && table
+                    .foreign_keys
+                    .iter()
+                    .all(|fk| selected_tables.contains(&fk.referenced_table))
+        } else {
+            selected_tables.contains(&table.name)
+        }
+    });
+
+    for table in schema.tables.iter_mut() {
+        table
+            .foreign_keys
+            .retain(|fk| selected_tables.contains(&fk.referenced_table));
+    }
+
+    schema.views.retain(|view| is_selected(&view.name));
+
+    let used_enums: Vec<String> = schema
+        .tables
+        .iter()
+        .flat_map(|table| table.columns.iter())
+        .chain(schema.views.iter().flat_map(|view| view.columns.iter()))
+        .filter_map(|column| match &column.tpe.family {
+            ColumnTypeFamily::Enum(name) => Some(name.clone()),
+            _ => None,
+        })
+        .collect();
+
+    schema.enums.retain(|e| used_enums.contains(&e.name));
+}
+
+/// `*` matches any sequence of characters, `?` any single character.
+fn matches_glob(pattern: &str, name: &str) -> bool {
+    fn matches(pattern: &[char], name: &[char]) -> bool {
+        match (pattern.first(), name.first()) {
+            (None, None) => true,
+            (Some(&'*'), _) => matches(&pattern[1..], name) || (!name.is_empty() && matches(pattern, &name[1..])),
+            (Some(&'?'), Some(_)) => matches(&pattern[1..], &name[1..]),
+            (Some(p), Some(n)) if p == n => matches(&pattern[1..], &name[1..]),
+            _ => false,
+        }
+    }
+
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    matches(&pattern, &name)
+}
+
+/// Warnings about what `calculate_model` does not represent.
+pub fn calculate_warnings(schema: &SqlSchema) -> Vec<Warning> {
+    let mut warnings = Vec::new();
+
+    let tables = schema
+        .tables
+        .iter()
+        .filter(|table| !is_migration_table(&table))
+        .filter(|table| !is_prisma_join_table(&table));
+
+    for table in tables {
+        for column in table
+            .columns
+            .iter()
+            .filter(|column| !is_supported_type(&column.tpe.family))
+        {
+            warnings.push(Warning {
+                code: 1,
+                message: format!(
+                    "The type `{}` of the column is not supported. The field is commented out.",
+                    column.tpe.raw
+                ),
+                table: table.name.clone(),
+                columns: vec![column.name.clone()],
+            });
+        }
+
+        let has_unique_criteria =
+            table.primary_key.is_some() || table.indices.iter().any(|index| index.tpe == IndexType::Unique);
+
+        if !has_unique_criteria {
+            warnings.push(Warning {
+                code: 3,
+                message:
+                    "The table has neither a primary key nor a unique constraint, its records can not be identified."
+                        .to_owned(),
+                table: table.name.clone(),
+                columns: vec![],
+            });
+        }
+
+        for index in table
+            .indices
+            .iter()
+            .filter(|index| covers_unsupported_columns(index, table))
+        {
+            warnings.push(Warning {
+                code: 4,
+                message: format!(
+                    "The index `{}` covers columns of unsupported types. It is left out.",
+                    index.name
+                ),
+                table: table.name.clone(),
+                columns: index.columns.clone(),
+            });
+        }
+    }
+
+    for view in &schema.views {
+        let warning = match view_id_column(view) {
+            Some(column) => Warning {
+                code: 5,
+                message: format!(
+                    "The view has no primary key. The column `{}` is marked with `@id`, it must identify the records.",
+                    column.name
+                ),
+                table: view.name.clone(),
+                columns: vec![column.name.clone()],
+            },
+            None => Warning {
+                code: 5,
+                message: "The view has no required column to identify its records. The model is commented out."
+                    .to_owned(),
+                table: view.name.clone(),
+                columns: vec![],
+            },
+        };
+
+        warnings.push(warning);
+    }
+
+    warnings
+}
+
+/// Annotates the scalar fields of a calculated data model with the native types of their columns,
+/// as far as the connector knows them and they are compatible with the field type.
+pub fn add_native_types(data_model: &mut Datamodel, schema: &SqlSchema, connector: &dyn Connector) {
+    for model in data_model.models_mut() {
+        let table_name = model.database_name().clone().unwrap_or_else(|| model.name.clone());
+        let columns = match schema.get_table(&table_name) {
+            Some(table) => &table.columns,
+            None => match schema.get_view(&table_name) {
+                Some(view) => &view.columns,
+                None => continue,
+            },
+        };
+
+        for field in model.fields_mut() {
+            let scalar_type = match &field.field_type {
+                FieldType::Base(scalar_type) => *scalar_type,
+                _ => continue,
+            };
+
+            let column_name = field.database_name.clone().unwrap_or_else(|| field.name.clone());
+            let column = match columns.iter().find(|column| column.name == column_name) {
+                Some(column) => column,
+                None => continue,
+            };
+
+            // Postgres describes array types with a leading underscore.
+            let raw = match column.tpe.arity {
+                ColumnArity::List => column.tpe.raw.trim_start_matches('_'),
+                _ => column.tpe.raw.as_str(),
+            };
+
+            match connector.introspect_type(raw) {
+                Some(native_type) if native_type.prisma_type() == scalar_type => {
+                    field.field_type = FieldType::ConnectorSpecific(native_type)
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Renames models to singular PascalCase and fields to camelCase, mapped to the original table and
+/// column names. Relation fields holding a foreign key are named after the model they reference.
+pub fn apply_naming_conventions(data_model: &mut Datamodel, schema: &SqlSchema) {
+    let inflector = prisma_inflector::default();
+
+    // Calculated datamodels refer to models by table name and to fields by column name, so both are keys.
+    let mut model_names: HashMap<String, String> = HashMap::new();
+
+    for model in data_model.models() {
+        let conventional_name = |name: &str| pascal_case(&inflector.singularize(name));
+        let name = conventional_name(&model.name);
+        let is_taken = data_model
+            .models()
+            .any(|other| other.name != model.name && conventional_name(&other.name) == name);
+        let name = if is_taken { model.name.clone() } else { name };
+
+        model_names.insert(
+            model.database_name().clone().unwrap_or_else(|| model.name.clone()),
+            name.clone(),
+        );
+        model_names.insert(model.name.clone(), name);
+    }
+
+    let mut field_names: HashMap<String, HashMap<String, String>> = HashMap::new();
+    let mut new_fields: HashMap<String, Vec<(String, bool)>> = HashMap::new();
+
+    for model in data_model.models() {
+        let table_name = model.database_name().clone().unwrap_or_else(|| model.name.clone());
+        let (columns, foreign_keys): (&Vec<Column>, &[ForeignKey]) = match schema.get_table(&table_name) {
+            Some(table) => (&table.columns, table.foreign_keys.as_slice()),
+            None => match schema.get_view(&table_name) {
+                Some(view) => (&view.columns, &[][..]),
+                None => continue,
+            },
+        };
+
+        // Scalar fields get the first pick, then relation fields holding a foreign key, then back relation fields.
+        let mut candidates: Vec<(usize, usize, bool, Vec<String>)> = model
+            .fields()
+            .enumerate()
+            .map(|(index, field)| {
+                let column_name = field.database_name().clone().unwrap_or_else(|| field.name.clone());
+                let has_column = columns.iter().any(|column| column.name == column_name);
+                let is_foreign_key = foreign_keys.iter().any(|fk| fk.columns.contains(&column_name));
+
+                let (priority, mut names) = match &field.field_type {
+                    FieldType::Relation(info) => {
+                        let target = model_names.get(&info.to).unwrap_or(&info.to);
+
+                        if (has_column && is_foreign_key) || !info.columns.is_empty() {
+                            let names = vec![
+                                camel_case(target),
+                                camel_case(&strip_id_suffix(&field.name)),
+                                camel_case(&field.name),
+                            ];
+                            (1, names)
+                        } else if field.arity == FieldArity::List {
+                            (2, vec![camel_case(&inflector.pluralize(target))])
+                        } else {
+                            (2, vec![camel_case(target)])
+                        }
+                    }
+                    _ => (0, vec![camel_case(&field.name)]),
+                };
+
+                names.push(field.name.clone());
+
+                (priority, index, has_column, names)
+            })
+            .collect();
+
+        candidates.sort_by_key(|(priority, index, _, _)| (*priority, *index));
+
+        let mut taken: Vec<String> = Vec::new();
+        let mut chosen: Vec<(usize, String, bool)> = Vec::new();
+
+        for (_, index, has_column, names) in candidates {
+            let name = names
+                .iter()
+                .find(|name| !taken.contains(name))
+                .unwrap_or(&model.fields[index].name)
+                .clone();
+
+            taken.push(name.clone());
+            chosen.push((index, name, has_column));
+        }
+
+        chosen.sort_by_key(|(index, _, _)| *index);
+
+        let mut names = HashMap::new();
+
+        for (field, (_, name, has_column)) in model.fields().zip(chosen.iter()) {
+            if *has_column {
+                names.insert(
+                    field.database_name().clone().unwrap_or_else(|| field.name.clone()),
+                    name.clone(),
+                );
+            }
+
+            names.insert(field.name.clone(), name.clone());
+        }
+
+        field_names.insert(table_name, names.clone());
+        field_names.insert(model.name.clone(), names);
+        new_fields.insert(
+            model.name.clone(),
+            chosen
+                .into_iter()
+                .map(|(_, name, has_column)| (name, has_column))
+                .collect(),
+        );
+    }
+
+    for model in data_model.models_mut() {
+        let own_field_names = field_names.get(&model.name).cloned().unwrap_or_default();
+        let new_field_names = new_fields.remove(&model.name).unwrap_or_default();
+
+        for (field, (name, has_column)) in model.fields.iter_mut().zip(new_field_names.into_iter()) {
+            if let FieldType::Relation(info) = &mut field.field_type {
+                if let Some(target_field_names) = field_names.get(&info.to) {
+                    for to_field in info.to_fields.iter_mut() {
+                        rename(to_field, target_field_names);
+                    }
+                }
+
+                rename(&mut info.to, &model_names);
+            }
+
+            if has_column && field.database_name.is_none() && name != field.name {
+                field.database_name = Some(field.name.clone());
+            }
+
+            field.name = name;
+        }
+
+        for index in model.indexes.iter_mut() {
+            for field_name in index.fields.iter_mut() {
+                rename(field_name, &own_field_names);
+            }
+        }
+
+        for field_name in model.id_fields.iter_mut() {
+            rename(field_name, &own_field_names);
+        }
+
+        if let Some(name) = model_names.get(&model.name).cloned() {
+            if model.database_name.is_none() && name != model.name {
+                model.database_name = Some(model.name.clone());
+            }
+
+            model.name = name;
+        }
+    }
+}
+
+fn rename(name: &mut String, names: &HashMap<String, String>) {
+    if let Some(new_name) = names.get(name) {
+        *name = new_name.clone();
+    }
+}
+
+/// `user_accounts` and `USER_ACCOUNTS` become `UserAccounts`, names without underscores only get their
+/// first letter capitalized.
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().unwrap().to_uppercase().collect::<String>();
+
+            if word.chars().any(|c| c.is_lowercase()) {
+                first + chars.as_str()
+            } else {
+                first + &chars.as_str().to_lowercase()
+            }
+        })
+        .collect()
+}
+
+fn camel_case(name: &str) -> String {
+    pascal_case(name).camel_case()
+}
+
+/// `author_id` and `authorId` become `author`.
+fn strip_id_suffix(name: &str) -> String {
+    let lowercase = name.to_lowercase();
+
+    if name.len() > 3 && lowercase.ends_with("_id") {
+        name[..name.len() - 3].to_owned()
+    } else if name.len() > 2 && name.ends_with("Id") {
+        name[..name.len() - 2].to_owned()
+    } else {
+        name.to_owned()
+    }
+}
+
 fn parse_int(value: &str) -> Option<i32> {
     debug!("Parsing int '{}'", value);
     let re_num = Regex::new(r"^'?(\d+)'?$").expect("compile regex");
@@ -349,7 +1055,36 @@ fn parse_float(value: &str) -> Option<f32> {
     }
 }
 
-fn calculate_default(default: &str, tpe: &ColumnTypeFamily) -> Option<dml::DefaultValue> {
+/// Decimals are parsed exactly, a float would round them.
+fn parse_decimal(value: &str) -> Option<Decimal> {
+    debug!("Parsing decimal '{}'", value);
+    let re_num = Regex::new(r"^'?([^']+)'?$").expect("compile regex");
+    let captures = re_num.captures(value)?;
+    let num_str = captures.get(1).expect("get capture").as_str();
+
+    match num_str.parse::<Decimal>() {
+        Ok(num) => Some(num),
+        Err(_) => {
+            debug!("Couldn't parse decimal '{}'", num_str);
+            None
+        }
+    }
+}
+
+fn calculate_default(
+    default: &DefaultValue,
+    tpe: &ColumnTypeFamily,
+    field_type: &FieldType,
+) -> Option<dml::DefaultValue> {
+    let default = match default {
+        DefaultValue::Value(value) => value,
+        DefaultValue::DbGenerated(expression) => {
+            return field_type.scalar_type().map(|scalar_type| {
+                dml::DefaultValue::Expression(dml::ValueGenerator::new_db_generated(expression.clone(), scalar_type))
+            })
+        }
+    };
+
     let scalar = match tpe {
         ColumnTypeFamily::Boolean => match parse_int(default) {
             Some(x) => Some(ScalarValue::Boolean(x != 0)),
@@ -357,7 +1092,9 @@ fn calculate_default(default: &str, tpe: &ColumnTypeFamily) -> Option<dml::Defau
         },
         ColumnTypeFamily::Int => parse_int(default).map(|x| ScalarValue::Int(x)),
         ColumnTypeFamily::Float => parse_float(default).map(|x| ScalarValue::Float(x)),
+        ColumnTypeFamily::Decimal => parse_decimal(default).map(|x| ScalarValue::Decimal(x)),
         ColumnTypeFamily::String => Some(ScalarValue::String(default.to_string())),
+        ColumnTypeFamily::Enum(_) => Some(ScalarValue::ConstantLiteral(default.to_string())),
         _ => None,
     };
 
@@ -373,11 +1110,15 @@ fn calc_id_info(column: &Column, table: &Table) -> Option<IdInfo> {
             };
             Some(IdInfo {
                 strategy,
-                sequence: pk.sequence.as_ref().map(|sequence| dml::Sequence {
-                    name: sequence.name.clone(),
-                    allocation_size: sequence.allocation_size as i32,
-                    initial_value: sequence.initial_value as i32,
-                }),
+                sequence: pk
+                    .sequence
+                    .as_ref()
+                    .filter(|sequence| !is_default_sequence(sequence, column, table))
+                    .map(|sequence| dml::Sequence {
+                        name: sequence.name.clone(),
+                        allocation_size: sequence.allocation_size as i32,
+                        initial_value: sequence.initial_value as i32,
+                    }),
             })
         } else {
             None
@@ -385,11 +1126,25 @@ fn calc_id_info(column: &Column, table: &Table) -> Option<IdInfo> {
     })
 }
 
+/// The sequence Postgres creates for a `SERIAL` column. `@default(autoincrement())` alone stands for it,
+/// other sequences are rendered as `@sequence`.
+fn is_default_sequence(sequence: &Sequence, column: &Column, table: &Table) -> bool {
+    sequence.name == format!("{}_{}_seq", table.name, column.name)
+        && sequence.initial_value == 1
+        && sequence.allocation_size == 1
+}
+
+fn autoincrement_default() -> dml::DefaultValue {
+    dml::DefaultValue::Expression(
+        dml::ValueGenerator::new("autoincrement".to_string(), Vec::new()).expect("autoincrement is a builtin function"),
+    )
+}
+
 fn calculate_relation_name(schema: &SqlSchema, fk: &ForeignKey, table: &Table) -> String {
     //this is not called for prisma many to many relations. for them the name is just the name of the join table.
     let referenced_model = &fk.referenced_table;
     let model_with_fk = &table.name;
-    let fk_column_name = fk.columns.get(0).unwrap();
+    let fk_column_name = fk.columns.join("_");
 
     let fk_to_same_model: Vec<&ForeignKey> = table
         .foreign_keys
@@ -404,6 +1159,10 @@ fn calculate_relation_name(schema: &SqlSchema, fk: &ForeignKey, table: &Table) -
         .filter(|fk| fk.referenced_table == model_with_fk.clone())
         .collect();
 
+    // The names use the model names of tables that need sanitation, e.g. of other schemas.
+    let (referenced_model, _) = sanitize_name(referenced_model.clone());
+    let (model_with_fk, _) = sanitize_name(model_with_fk.clone());
+
     //unambiguous
     if fk_to_same_model.len() < 2 && fk_from_other_model_to_this.len() == 0 {
         if model_with_fk < referenced_model {
@@ -424,31 +1183,34 @@ fn calculate_relation_name(schema: &SqlSchema, fk: &ForeignKey, table: &Table) -
 fn calculate_field_type(schema: &SqlSchema, column: &Column, table: &Table) -> FieldType {
     debug!("Calculating field type for '{}'", column.name);
     // Look for a foreign key referencing this column
-    match table.foreign_keys.iter().find(|fk| fk.columns.contains(&column.name)) {
+    match table
+        .foreign_keys
+        .iter()
+        .find(|fk| fk.columns.len() == 1 && fk.columns.contains(&column.name))
+    {
         Some(fk) if calc_id_info(column, table).is_none() => {
             debug!("Found corresponding foreign key");
-            let idx = fk
-                .columns
-                .iter()
-                .position(|n| n == &column.name)
-                .expect("get column FK position");
-            let referenced_col = &fk.referenced_columns[idx];
-
             FieldType::Relation(RelationInfo {
                 name: calculate_relation_name(schema, fk, table),
-                to: fk.referenced_table.clone(),
-                to_fields: vec![referenced_col.clone()],
+                to: sanitize_name(fk.referenced_table.clone()).0,
+                to_fields: fk.referenced_columns.clone(),
+                columns: vec![],
                 on_delete: OnDeleteStrategy::None,
             })
         }
         _ => {
             debug!("Found no corresponding foreign key");
-            match column.tpe.family {
+            match &column.tpe.family {
+                ColumnTypeFamily::Binary => FieldType::Base(ScalarType::Bytes),
                 ColumnTypeFamily::Boolean => FieldType::Base(ScalarType::Boolean),
                 ColumnTypeFamily::DateTime => FieldType::Base(ScalarType::DateTime),
                 ColumnTypeFamily::Float => FieldType::Base(ScalarType::Float),
+                ColumnTypeFamily::Decimal => FieldType::Base(ScalarType::Decimal),
                 ColumnTypeFamily::Int => FieldType::Base(ScalarType::Int),
+                ColumnTypeFamily::Json => FieldType::Base(ScalarType::Json),
                 ColumnTypeFamily::String => FieldType::Base(ScalarType::String),
+                ColumnTypeFamily::Uuid => FieldType::Base(ScalarType::Uuid),
+                ColumnTypeFamily::Enum(enum_name) => FieldType::Enum(enum_name.clone()),
                 // XXX: We made a conscious decision to punt on mapping of ColumnTypeFamily
                 // variants that don't yet have corresponding PrismaType variants
                 _ => FieldType::Base(ScalarType::String),
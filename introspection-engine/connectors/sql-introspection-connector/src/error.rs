@@ -12,6 +12,8 @@ pub enum SqlIntrospectionError {
     Generic(Error),
     #[fail(display = "{}", _0)]
     Quaint(QuaintError),
+    #[fail(display = "Unknown provider `{}`, expected postgresql, mysql or sqlite.", provider)]
+    UnknownProvider { provider: String },
 }
 
 impl From<url::ParseError> for SqlIntrospectionError {
@@ -34,6 +36,12 @@ impl From<sql_schema_describer::SqlSchemaDescriberError> for SqlIntrospectionErr
     }
 }
 
+impl From<sql_schema_describer::ddl::DdlError> for SqlIntrospectionError {
+    fn from(e: sql_schema_describer::ddl::DdlError) -> Self {
+        SqlIntrospectionError::Generic(e.into())
+    }
+}
+
 impl SqlIntrospectionError {
     pub(crate) fn into_connector_error(self, connection_info: &ConnectionInfo) -> ConnectorError {
         let user_facing = match &self {
@@ -51,4 +59,17 @@ impl SqlIntrospectionError {
             kind: ErrorKind::Generic(self.into()),
         }
     }
+
+    /// Like `into_connector_error`, for introspection without a database connection.
+    pub(crate) fn into_offline_connector_error(self) -> ConnectorError {
+        let user_facing = KnownError::new(user_facing_errors::introspection_engine::IntrospectionFailed {
+            introspection_error: format!("{}", self),
+        })
+        .ok();
+
+        ConnectorError {
+            user_facing,
+            kind: ErrorKind::Generic(self.into()),
+        }
+    }
 }
@@ -3,9 +3,13 @@ pub mod calculate_datamodel; // only exported to be able to unit test it
 mod error;
 mod schema_describer_loading;
 
-use datamodel::Datamodel;
-use introspection_connector::{ConnectorError, ConnectorResult, DatabaseMetadata, IntrospectionConnector};
-use quaint::prelude::ConnectionInfo;
+use calculate_datamodel::MergedSchemas;
+use datamodel_connector::{DeclarativeConnector, ExampleConnector};
+use introspection_connector::{
+    ConnectorError, ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionOptions,
+    IntrospectionResult,
+};
+use quaint::prelude::{ConnectionInfo, SqlFamily};
 use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend};
 use std::future::Future;
 use tracing_futures::Instrument;
@@ -58,8 +62,19 @@ impl SqlIntrospectionConnector {
         Ok(db_metadate)
     }
 
-    async fn describe(&self) -> SqlIntrospectionResult<SqlSchema> {
-        Ok(self.describer.describe(self.connection_info.schema_name()).await?)
+    async fn describe(&self, options: &IntrospectionOptions) -> SqlIntrospectionResult<(SqlSchema, MergedSchemas)> {
+        let default_schema = self.connection_info.schema_name();
+        let mut sql_schema = self.describer.describe(default_schema).await?;
+        let mut merged = MergedSchemas::default();
+
+        for schema_name in options.schemas.iter().filter(|name| name.as_str() != default_schema) {
+            let other_schema = self.describer.describe(schema_name).await?;
+            calculate_datamodel::merge_schema(&mut sql_schema, other_schema, schema_name, default_schema, &mut merged);
+        }
+
+        calculate_datamodel::filter_tables(&mut sql_schema, &options.include_tables, &options.exclude_tables);
+
+        Ok((sql_schema, merged))
     }
 }
 
@@ -73,9 +88,102 @@ impl IntrospectionConnector for SqlIntrospectionConnector {
         Ok(self.catch(self.get_metadata_internal()).await?)
     }
 
-    async fn introspect(&self) -> ConnectorResult<Datamodel> {
-        let sql_schema = self.catch(self.describe()).await?;
-        let data_model = calculate_datamodel::calculate_model(&sql_schema).unwrap();
-        Ok(data_model)
+    async fn introspect(&self) -> ConnectorResult<IntrospectionResult> {
+        self.introspect_with_options(IntrospectionOptions::default()).await
+    }
+
+    async fn introspect_with_options(&self, options: IntrospectionOptions) -> ConnectorResult<IntrospectionResult> {
+        let (sql_schema, merged) = self.catch(self.describe(&options)).await?;
+
+        Ok(introspection_result(
+            &sql_schema,
+            merged,
+            self.connection_info.sql_family(),
+            &options,
+        ))
+    }
+}
+
+/// Introspects the schema a SQL DDL script defines, e.g. the output of `pg_dump --schema-only` or
+/// `mysqldump --no-data`, without connecting to a database. The provider is `postgresql`, `mysql`
+/// or `sqlite`. Unqualified names belong to `schema_name`, `public` by default on Postgres.
+pub fn introspect_ddl(
+    ddl: &str,
+    provider: &str,
+    schema_name: Option<&str>,
+    options: IntrospectionOptions,
+) -> ConnectorResult<IntrospectionResult> {
+    let sql_family = SqlFamily::from_scheme(provider)
+        .ok_or_else(|| SqlIntrospectionError::UnknownProvider {
+            provider: provider.to_string(),
+        })
+        .map_err(SqlIntrospectionError::into_offline_connector_error)?;
+    let schema_name = schema_name.unwrap_or_else(|| match sql_family {
+        SqlFamily::Postgres => "public",
+        SqlFamily::Mysql | SqlFamily::Sqlite => "",
+    });
+    let (sql_schema, merged) = describe_ddl(ddl, sql_family, schema_name, &options)
+        .map_err(SqlIntrospectionError::into_offline_connector_error)?;
+
+    Ok(introspection_result(&sql_schema, merged, sql_family, &options))
+}
+
+fn describe_ddl(
+    ddl: &str,
+    sql_family: SqlFamily,
+    default_schema: &str,
+    options: &IntrospectionOptions,
+) -> SqlIntrospectionResult<(SqlSchema, MergedSchemas)> {
+    let mut sql_schema = sql_schema_describer::ddl::describe(ddl, sql_family, default_schema)?;
+    let mut merged = MergedSchemas::default();
+
+    for schema_name in options.schemas.iter().filter(|name| name.as_str() != default_schema) {
+        let other_schema = sql_schema_describer::ddl::describe(ddl, sql_family, schema_name)?;
+        calculate_datamodel::merge_schema(&mut sql_schema, other_schema, schema_name, default_schema, &mut merged);
+    }
+
+    calculate_datamodel::filter_tables(&mut sql_schema, &options.include_tables, &options.exclude_tables);
+
+    Ok((sql_schema, merged))
+}
+
+/// Calculates the datamodel of a described schema and applies the introspection options to it.
+fn introspection_result(
+    sql_schema: &SqlSchema,
+    merged: MergedSchemas,
+    sql_family: SqlFamily,
+    options: &IntrospectionOptions,
+) -> IntrospectionResult {
+    let mut data_model = calculate_datamodel::calculate_model(sql_schema).unwrap();
+
+    if options.native_types {
+        calculate_datamodel::add_native_types(&mut data_model, sql_schema, &datamodel_connector(sql_family));
+    }
+
+    if options.naming_conventions {
+        calculate_datamodel::apply_naming_conventions(&mut data_model, sql_schema);
+    }
+
+    calculate_datamodel::assign_schemas(&mut data_model, &merged.tables);
+
+    // Warnings about tables that were left out are of no interest.
+    let mut warnings = calculate_datamodel::calculate_warnings(sql_schema);
+    warnings.extend(merged.warnings.into_iter().filter(|warning| {
+        sql_schema.tables.iter().any(|table| table.name == warning.table)
+            || sql_schema.views.iter().any(|view| view.name == warning.table)
+    }));
+
+    IntrospectionResult {
+        datamodel: data_model,
+        warnings,
+    }
+}
+
+/// The datamodel connector knowing the native types of the database.
+fn datamodel_connector(sql_family: SqlFamily) -> DeclarativeConnector {
+    match sql_family {
+        SqlFamily::Postgres => ExampleConnector::postgres(),
+        SqlFamily::Mysql => ExampleConnector::mysql(),
+        SqlFamily::Sqlite => ExampleConnector::sqlite(),
     }
 }
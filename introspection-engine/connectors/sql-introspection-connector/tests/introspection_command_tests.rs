@@ -1,11 +1,16 @@
 use pretty_assertions::assert_eq;
-use std::collections::HashSet;
 
 use datamodel::{
     common::{ScalarType, ScalarValue},
     dml, Datamodel, Field, FieldArity, FieldType, IdInfo, IdStrategy, Model, OnDeleteStrategy, RelationInfo,
 };
-use sql_introspection_connector::calculate_datamodel::calculate_model;
+use datamodel_connector::{ExampleConnector, ScalarFieldType};
+use introspection_connector::{IntrospectionOptions, Warning};
+use sql_introspection_connector::calculate_datamodel::{
+    add_native_types, apply_naming_conventions, assign_schemas, calculate_model, calculate_warnings, filter_tables,
+    merge_schema, MergedSchemas,
+};
+use sql_introspection_connector::introspect_ddl;
 use sql_schema_describer::*;
 
 #[test]
@@ -13,6 +18,7 @@ fn a_data_model_can_be_generated_from_a_schema() {
     let col_types = &[
         ColumnTypeFamily::Int,
         ColumnTypeFamily::Float,
+        ColumnTypeFamily::Decimal,
         ColumnTypeFamily::Boolean,
         ColumnTypeFamily::String,
         ColumnTypeFamily::DateTime,
@@ -31,18 +37,26 @@ fn a_data_model_can_be_generated_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            is_view: false,
+            is_commented_out: false,
+            schema: None,
             is_generated: false,
             indexes: vec![],
             id_fields: vec![],
+            check_constraints: vec![],
             fields: col_types
                 .iter()
                 .map(|col_type| {
                     let field_type = match col_type {
+                        ColumnTypeFamily::Binary => FieldType::Base(ScalarType::Bytes),
                         ColumnTypeFamily::Boolean => FieldType::Base(ScalarType::Boolean),
                         ColumnTypeFamily::DateTime => FieldType::Base(ScalarType::DateTime),
                         ColumnTypeFamily::Float => FieldType::Base(ScalarType::Float),
+                        ColumnTypeFamily::Decimal => FieldType::Base(ScalarType::Decimal),
                         ColumnTypeFamily::Int => FieldType::Base(ScalarType::Int),
+                        ColumnTypeFamily::Json => FieldType::Base(ScalarType::Json),
                         ColumnTypeFamily::String => FieldType::Base(ScalarType::String),
+                        ColumnTypeFamily::Uuid => FieldType::Base(ScalarType::Uuid),
                         // XXX: We made a conscious decision to punt on mapping of ColumnTypeFamily
                         // variants that don't yet have corresponding PrismaType variants
                         _ => FieldType::Base(ScalarType::String),
@@ -58,6 +72,10 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_commented_out: match col_type {
+                            ColumnTypeFamily::Geometric | ColumnTypeFamily::TextSearch => true,
+                            _ => false,
+                        },
                     }
                 })
                 .collect(),
@@ -79,6 +97,7 @@ fn a_data_model_can_be_generated_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 })
                 .collect(),
             indices: vec![],
@@ -87,15 +106,77 @@ fn a_data_model_can_be_generated_from_a_schema() {
                 sequence: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
     assert_eq!(data_model, ref_data_model);
 }
 
+#[test]
+fn native_types_are_added_to_a_data_model_calculated_from_a_schema() {
+    let columns = &[
+        ("title", "varchar(255)", ColumnTypeFamily::String),
+        ("counter", "int2", ColumnTypeFamily::Int),
+        ("unknown", "citext", ColumnTypeFamily::String),
+    ];
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Table1".to_string(),
+            columns: columns
+                .iter()
+                .map(|(name, raw, family)| Column {
+                    name: name.to_string(),
+                    tpe: ColumnType {
+                        raw: raw.to_string(),
+                        family: family.to_owned(),
+                        arity: ColumnArity::Required,
+                    },
+                    default: None,
+                    auto_increment: false,
+                    comment: None,
+                })
+                .collect(),
+            indices: vec![],
+            primary_key: None,
+            foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+    let mut data_model = calculate_model(&schema).expect("calculate data model");
+    add_native_types(&mut data_model, &schema, &ExampleConnector::postgres());
+
+    let model = data_model.find_model("Table1").unwrap();
+
+    assert_eq!(
+        model.find_field("title").unwrap().field_type,
+        FieldType::ConnectorSpecific(ScalarFieldType::with_args(
+            "VarChar",
+            vec![255],
+            ScalarType::String,
+            "varchar(255)"
+        ))
+    );
+    assert_eq!(
+        model.find_field("counter").unwrap().field_type,
+        FieldType::ConnectorSpecific(ScalarFieldType::new("SmallInt", ScalarType::Int, "int2"))
+    );
+    assert_eq!(
+        model.find_field("unknown").unwrap().field_type,
+        FieldType::Base(ScalarType::String)
+    );
+}
+
 #[test]
 fn arity_is_preserved_when_generating_data_model_from_a_schema() {
     let ref_data_model = Datamodel {
@@ -104,6 +185,9 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            is_view: false,
+            is_commented_out: false,
+            schema: None,
             fields: vec![
                 Field {
                     name: "optional".to_string(),
@@ -116,6 +200,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_commented_out: false,
                 },
                 Field {
                     name: "required".to_string(),
@@ -128,6 +213,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_commented_out: false,
                 },
                 Field {
                     name: "list".to_string(),
@@ -140,11 +226,13 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_commented_out: false,
                 },
             ],
             is_generated: false,
             indexes: vec![],
             id_fields: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
     };
@@ -162,6 +250,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "required".to_string(),
@@ -172,6 +261,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "list".to_string(),
@@ -182,6 +272,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -190,9 +281,12 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                 sequence: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -207,6 +301,9 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            is_view: false,
+            is_commented_out: false,
+            schema: None,
             fields: vec![
                 Field {
                     name: "no_default".to_string(),
@@ -219,6 +316,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_commented_out: false,
                 },
                 Field {
                     name: "int_default".to_string(),
@@ -231,6 +329,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_commented_out: false,
                 },
                 Field {
                     name: "bool_default".to_string(),
@@ -243,6 +342,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_commented_out: false,
                 },
                 Field {
                     name: "float_default".to_string(),
@@ -255,6 +355,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_commented_out: false,
                 },
                 Field {
                     name: "string_default".to_string(),
@@ -267,11 +368,29 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_commented_out: false,
+                },
+                Field {
+                    name: "db_generated_default".to_string(),
+                    arity: FieldArity::Optional,
+                    field_type: FieldType::Base(ScalarType::DateTime),
+                    database_name: None,
+                    default_value: Some(dml::DefaultValue::Expression(dml::ValueGenerator::new_db_generated(
+                        "now()".to_string(),
+                        ScalarType::DateTime,
+                    ))),
+                    is_unique: false,
+                    id_info: None,
+                    documentation: None,
+                    is_generated: false,
+                    is_updated_at: false,
+                    is_commented_out: false,
                 },
             ],
             is_generated: false,
             indexes: vec![],
             id_fields: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
     };
@@ -289,6 +408,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "int_default".to_string(),
@@ -297,8 +417,9 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
-                    default: Some("'1'".to_string()),
+                    default: Some(DefaultValue::Value("'1'".to_string())),
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "bool_default".to_string(),
@@ -307,8 +428,9 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                         family: ColumnTypeFamily::Boolean,
                         arity: ColumnArity::Nullable,
                     },
-                    default: Some("'1'".to_string()),
+                    default: Some(DefaultValue::Value("'1'".to_string())),
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "float_default".to_string(),
@@ -317,8 +439,9 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                         family: ColumnTypeFamily::Float,
                         arity: ColumnArity::Nullable,
                     },
-                    default: Some("'1.0'".to_string()),
+                    default: Some(DefaultValue::Value("'1.0'".to_string())),
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "string_default".to_string(),
@@ -327,16 +450,31 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                         family: ColumnTypeFamily::String,
                         arity: ColumnArity::Nullable,
                     },
-                    default: Some("default".to_string()),
+                    default: Some(DefaultValue::Value("default".to_string())),
                     auto_increment: false,
+                    comment: None,
+                },
+                Column {
+                    name: "db_generated_default".to_string(),
+                    tpe: ColumnType {
+                        raw: "raw".to_string(),
+                        family: ColumnTypeFamily::DateTime,
+                        arity: ColumnArity::Nullable,
+                    },
+                    default: Some(DefaultValue::DbGenerated("now()".to_string())),
+                    auto_increment: false,
+                    comment: None,
                 },
             ],
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -353,12 +491,17 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                is_commented_out: false,
+                schema: None,
                 fields: vec![Field {
                     name: "primary".to_string(),
                     arity: FieldArity::Required,
                     field_type: FieldType::Base(ScalarType::Int),
                     database_name: None,
-                    default_value: None,
+                    default_value: Some(dml::DefaultValue::Expression(
+                        dml::ValueGenerator::new("autoincrement".to_string(), Vec::new()).unwrap(),
+                    )),
                     is_unique: false,
                     id_info: Some(IdInfo {
                         strategy: IdStrategy::Auto,
@@ -367,10 +510,12 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_commented_out: false,
                 }],
                 is_generated: false,
                 indexes: vec![],
                 id_fields: vec![],
+                check_constraints: vec![],
             },
             // Model with non-auto-incrementing primary key
             Model {
@@ -378,6 +523,9 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table2".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                is_commented_out: false,
+                schema: None,
                 fields: vec![Field {
                     name: "primary".to_string(),
                     arity: FieldArity::Required,
@@ -392,10 +540,12 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_commented_out: false,
                 }],
                 is_generated: false,
                 indexes: vec![],
                 id_fields: vec![],
+                check_constraints: vec![],
             },
             // Model with primary key seeded by sequence
             Model {
@@ -403,12 +553,17 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table3".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                is_commented_out: false,
+                schema: None,
                 fields: vec![Field {
                     name: "primary".to_string(),
                     arity: FieldArity::Required,
                     field_type: FieldType::Base(ScalarType::Int),
                     database_name: None,
-                    default_value: None,
+                    default_value: Some(dml::DefaultValue::Expression(
+                        dml::ValueGenerator::new("autoincrement".to_string(), Vec::new()).unwrap(),
+                    )),
                     is_unique: false,
                     id_info: Some(IdInfo {
                         strategy: IdStrategy::Auto,
@@ -421,10 +576,12 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_commented_out: false,
                 }],
                 is_generated: false,
                 indexes: vec![],
                 id_fields: vec![],
+                check_constraints: vec![],
             },
         ],
         enums: vec![],
@@ -443,6 +600,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: true,
+                    comment: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -450,6 +608,8 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
             },
             Table {
                 name: "Table2".to_string(),
@@ -462,6 +622,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -469,6 +630,8 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
             },
             Table {
                 name: "Table3".to_string(),
@@ -481,6 +644,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: true,
+                    comment: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -492,10 +656,13 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     }),
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -510,6 +677,9 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            is_view: false,
+            is_commented_out: false,
+            schema: None,
             fields: vec![
                 Field {
                     name: "non_unique".to_string(),
@@ -522,6 +692,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_commented_out: false,
                 },
                 Field {
                     name: "unique".to_string(),
@@ -534,11 +705,13 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_commented_out: false,
                 },
             ],
             is_generated: false,
             indexes: vec![],
             id_fields: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
     };
@@ -556,6 +729,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "unique".to_string(),
@@ -566,6 +740,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ],
             indices: vec![Index {
@@ -575,9 +750,12 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
             }],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -585,7 +763,6 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
 }
 
 #[test]
-#[ignore]
 fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
     let ref_data_model = Datamodel {
         models: vec![
@@ -594,13 +771,18 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 name: "City".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                is_commented_out: false,
+                schema: None,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
                         arity: FieldArity::Required,
                         field_type: FieldType::Base(ScalarType::Int),
                         database_name: None,
-                        default_value: None,
+                        default_value: Some(dml::DefaultValue::Expression(
+                            dml::ValueGenerator::new("autoincrement".to_string(), Vec::new()).unwrap(),
+                        )),
                         is_unique: false,
                         id_info: Some(IdInfo {
                             strategy: IdStrategy::Auto,
@@ -609,6 +791,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_commented_out: false,
                     },
                     Field {
                         name: "name".to_string(),
@@ -621,17 +804,41 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_commented_out: false,
+                    },
+                    Field {
+                        name: "users".to_string(),
+                        arity: FieldArity::List,
+                        field_type: FieldType::Relation(RelationInfo {
+                            name: "CityToUser".to_string(),
+                            to: "User".to_string(),
+                            to_fields: vec!["City".to_string()],
+                            columns: vec![],
+                            on_delete: OnDeleteStrategy::None,
+                        }),
+                        database_name: None,
+                        default_value: None,
+                        is_unique: false,
+                        id_info: None,
+                        documentation: None,
+                        is_generated: false,
+                        is_updated_at: false,
+                        is_commented_out: false,
                     },
                 ],
                 is_generated: false,
                 indexes: vec![],
                 id_fields: vec![],
+                check_constraints: vec![],
             },
             Model {
                 database_name: None,
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                is_commented_out: false,
+                schema: None,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
@@ -644,31 +851,16 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_commented_out: false,
                     },
                     Field {
-                        name: "city-id".to_string(),
-                        arity: FieldArity::Required,
-                        field_type: FieldType::Relation(RelationInfo {
-                            name: "".to_string(),
-                            to: "City".to_string(),
-                            to_fields: vec!["id".to_string()],
-                            on_delete: OnDeleteStrategy::None,
-                        }),
-                        database_name: None,
-                        default_value: None,
-                        is_unique: false,
-                        id_info: None,
-                        documentation: None,
-                        is_generated: false,
-                        is_updated_at: false,
-                    },
-                    Field {
-                        name: "city-name".to_string(),
+                        name: "City".to_string(),
                         arity: FieldArity::Required,
                         field_type: FieldType::Relation(RelationInfo {
-                            name: "".to_string(),
+                            name: "CityToUser".to_string(),
                             to: "City".to_string(),
-                            to_fields: vec!["name".to_string()],
+                            to_fields: vec!["id".to_string(), "name".to_string()],
+                            columns: vec!["city-id".to_string(), "city-name".to_string()],
                             on_delete: OnDeleteStrategy::None,
                         }),
                         database_name: None,
@@ -678,11 +870,13 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_commented_out: false,
                     },
                 ],
                 is_generated: false,
                 indexes: vec![],
                 id_fields: vec![],
+                check_constraints: vec![],
             },
         ],
         enums: vec![],
@@ -702,6 +896,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         },
                         default: None,
                         auto_increment: true,
+                        comment: None,
                     },
                     Column {
                         name: "name".to_string(),
@@ -712,6 +907,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         },
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![],
@@ -720,6 +916,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
             },
             Table {
                 name: "User".to_string(),
@@ -733,6 +931,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         },
                         default: None,
                         auto_increment: true,
+                        comment: None,
                     },
                     Column {
                         name: "city-id".to_string(),
@@ -743,6 +942,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         },
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                     Column {
                         name: "city-name".to_string(),
@@ -753,6 +953,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         },
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![],
@@ -765,10 +966,13 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                     on_delete_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string(), "name".to_string()],
                 }],
+                check_constraints: vec![],
+                comment: None,
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -783,13 +987,18 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
             name: "User".to_string(),
             documentation: None,
             is_embedded: false,
+            is_view: false,
+            is_commented_out: false,
+            schema: None,
             fields: vec![
                 Field {
                     name: "id".to_string(),
                     arity: FieldArity::Required,
                     field_type: FieldType::Base(ScalarType::Int),
                     database_name: None,
-                    default_value: None,
+                    default_value: Some(dml::DefaultValue::Expression(
+                        dml::ValueGenerator::new("autoincrement".to_string(), Vec::new()).unwrap(),
+                    )),
                     is_unique: false,
                     id_info: Some(IdInfo {
                         strategy: IdStrategy::Auto,
@@ -798,6 +1007,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_commented_out: false,
                 },
                 Field {
                     name: "name".to_string(),
@@ -810,6 +1020,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_commented_out: false,
                 },
                 Field {
                     name: "lastname".to_string(),
@@ -822,6 +1033,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
+                    is_commented_out: false,
                 },
             ],
             is_generated: false,
@@ -829,8 +1041,10 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                 name: Some("name_last_name_unique".to_string()),
                 fields: vec!["name".to_string(), "lastname".to_string()],
                 tpe: datamodel::dml::IndexType::Unique,
+                online: false,
             }],
             id_fields: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
     };
@@ -848,6 +1062,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     },
                     default: None,
                     auto_increment: true,
+                    comment: None,
                 },
                 Column {
                     name: "name".to_string(),
@@ -858,6 +1073,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "lastname".to_string(),
@@ -868,6 +1084,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ],
             indices: vec![Index {
@@ -880,15 +1097,137 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                 sequence: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
     assert_eq!(data_model, ref_data_model);
 }
 
+#[test]
+fn compound_ids_and_indexes_refer_to_sanitized_field_names() {
+    let int_column = |name: &str| Column {
+        name: name.to_string(),
+        tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+        default: None,
+        auto_increment: false,
+        comment: None,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "Membership".to_string(),
+            columns: vec![int_column("group-id"), int_column("user-id"), int_column("role")],
+            indices: vec![
+                Index {
+                    name: "Membership_group_role_idx".to_string(),
+                    columns: vec!["group-id".to_string(), "role".to_string()],
+                    tpe: IndexType::Normal,
+                },
+                Index {
+                    name: "Membership.user_role".to_string(),
+                    columns: vec!["user-id".to_string(), "role".to_string()],
+                    tpe: IndexType::Unique,
+                },
+            ],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["group-id".to_string(), "user-id".to_string()],
+                sequence: None,
+            }),
+            foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+    let data_model = calculate_model(&schema).expect("calculate data model");
+
+    let membership = data_model.find_model("Membership").unwrap();
+    assert_eq!(
+        membership.id_fields,
+        vec!["group_id".to_string(), "user_id".to_string()]
+    );
+    assert_eq!(
+        membership.indexes,
+        vec![
+            dml::IndexDefinition {
+                name: Some("Membership_group_role_idx".to_string()),
+                fields: vec!["group_id".to_string(), "role".to_string()],
+                tpe: dml::IndexType::Normal,
+                online: false,
+            },
+            dml::IndexDefinition {
+                name: Some("Membership.user_role".to_string()),
+                fields: vec!["user_id".to_string(), "role".to_string()],
+                tpe: dml::IndexType::Unique,
+                online: false,
+            },
+        ]
+    );
+
+    let rendered = datamodel::render_datamodel_to_string(&data_model).unwrap();
+    assert!(rendered.contains(r#"@@id([group_id, user_id])"#));
+    assert!(rendered.contains(r#"@@index([group_id, role], name: "Membership_group_role_idx")"#));
+    assert!(rendered.contains(r#"@@unique([user_id, role], name: "Membership.user_role")"#));
+    datamodel::parse_datamodel(&rendered).unwrap();
+}
+
+#[test]
+fn table_and_column_comments_become_documentation() {
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "User".to_string(),
+            columns: vec![
+                Column {
+                    name: "id".to_string(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                    default: None,
+                    auto_increment: true,
+                    comment: None,
+                },
+                Column {
+                    name: "email".to_string(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
+                    default: None,
+                    auto_increment: false,
+                    comment: Some("Where we send the newsletter.".to_string()),
+                },
+            ],
+            indices: vec![],
+            primary_key: Some(PrimaryKey {
+                columns: vec!["id".to_string()],
+                sequence: None,
+            }),
+            foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: Some("A user of the blog.".to_string()),
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+    let data_model = calculate_model(&schema).expect("calculate data model");
+
+    let user = data_model.find_model("User").unwrap();
+    assert_eq!(user.documentation.as_ref().map(String::as_str), Some("A user of the blog."));
+    assert_eq!(
+        user.find_field("email").unwrap().documentation.as_ref().map(String::as_str),
+        Some("Where we send the newsletter.")
+    );
+    assert_eq!(user.find_field("id").unwrap().documentation, None);
+
+    let rendered = datamodel::render_datamodel_to_string(&data_model).unwrap();
+    assert!(rendered.contains("/// A user of the blog.\nmodel User {"));
+    assert!(rendered.contains("/// Where we send the newsletter."));
+}
+
 #[test]
 fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
     let ref_data_model = Datamodel {
@@ -898,13 +1237,18 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 name: "City".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                is_commented_out: false,
+                schema: None,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
                         arity: FieldArity::Required,
                         field_type: FieldType::Base(ScalarType::Int),
                         database_name: None,
-                        default_value: None,
+                        default_value: Some(dml::DefaultValue::Expression(
+                            dml::ValueGenerator::new("autoincrement".to_string(), Vec::new()).unwrap(),
+                        )),
                         is_unique: false,
                         id_info: Some(IdInfo {
                             strategy: IdStrategy::Auto,
@@ -913,6 +1257,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_commented_out: false,
                     },
                     Field {
                         name: "name".to_string(),
@@ -925,6 +1270,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_commented_out: false,
                     },
                     Field {
                         name: "users".to_string(),
@@ -932,6 +1278,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         field_type: FieldType::Relation(RelationInfo {
                             to: "User".to_string(),
                             to_fields: vec!["city_id".to_string()],
+                            columns: vec![],
                             name: "CityToUser".to_string(),
                             on_delete: OnDeleteStrategy::None,
                         }),
@@ -942,17 +1289,22 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_commented_out: false,
                     },
                 ],
                 is_generated: false,
                 indexes: vec![],
                 id_fields: vec![],
+                check_constraints: vec![],
             },
             Model {
                 database_name: None,
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                is_commented_out: false,
+                schema: None,
                 fields: vec![
                     Field {
                         name: "id".to_string(),
@@ -965,6 +1317,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_commented_out: false,
                     },
                     Field {
                         name: "city_id".to_string(),
@@ -973,6 +1326,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             name: "CityToUser".to_string(),
                             to: "City".to_string(),
                             to_fields: vec!["id".to_string()],
+                            columns: vec![],
                             on_delete: OnDeleteStrategy::None,
                         }),
                         database_name: None,
@@ -982,11 +1336,13 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_commented_out: false,
                     },
                 ],
                 is_generated: false,
                 indexes: vec![],
                 id_fields: vec![],
+                check_constraints: vec![],
             },
         ],
         enums: vec![],
@@ -1006,6 +1362,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         },
                         default: None,
                         auto_increment: true,
+                        comment: None,
                     },
                     Column {
                         name: "name".to_string(),
@@ -1016,6 +1373,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         },
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![],
@@ -1024,6 +1382,8 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
             },
             Table {
                 name: "User".to_string(),
@@ -1037,6 +1397,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         },
                         default: None,
                         auto_increment: true,
+                        comment: None,
                     },
                     Column {
                         name: "city_id".to_string(),
@@ -1047,6 +1408,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         },
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![],
@@ -1058,10 +1420,13 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                     on_delete_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string()],
                 }],
+                check_constraints: vec![],
+                comment: None,
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
@@ -1080,18 +1445,562 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
         }],
     };
 
-    let mut enum_values = HashSet::new();
-    enum_values.insert("a".to_string());
-    enum_values.insert("b".to_string());
     let schema = SqlSchema {
         tables: vec![],
         enums: vec![Enum {
             name: "Enum".to_string(),
-            values: enum_values,
+            values: vec!["a".to_string(), "b".to_string()],
         }],
         sequences: vec![],
+        views: vec![],
     };
     let data_model = calculate_model(&schema).expect("calculate data model");
 
     assert_eq!(data_model, ref_data_model);
 }
+
+#[test]
+fn views_are_introspected_as_view_models() {
+    let name_column = Column {
+        name: "name".to_string(),
+        tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Nullable),
+        default: None,
+        auto_increment: false,
+        comment: None,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![Table {
+            name: "User".to_string(),
+            columns: vec![name_column.clone()],
+            indices: vec![],
+            primary_key: None,
+            foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
+        }],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![View {
+            name: "UserName".to_string(),
+            columns: vec![name_column],
+            definition: Some("SELECT name FROM \"User\"".to_string()),
+        }],
+    };
+    let data_model = calculate_model(&schema).expect("calculate data model");
+
+    let table_model = data_model.find_model("User").expect("find User model");
+    let view_model = data_model.find_model("UserName").expect("find UserName model");
+
+    assert!(!table_model.is_view);
+    assert!(view_model.is_view);
+    assert!(view_model.is_commented_out);
+    assert_eq!(view_model.find_field("name").unwrap().arity, FieldArity::Optional);
+
+    let view_warnings: Vec<Warning> = calculate_warnings(&schema)
+        .into_iter()
+        .filter(|warning| warning.code == 5)
+        .collect();
+
+    assert_eq!(
+        view_warnings,
+        vec![Warning {
+            code: 5,
+            message: "The view has no required column to identify its records. The model is commented out."
+                .to_string(),
+            table: "UserName".to_string(),
+            columns: vec![],
+        }]
+    );
+}
+
+#[test]
+fn views_are_identified_by_a_required_column() {
+    let column = |name: &str, arity: ColumnArity| Column {
+        name: name.to_string(),
+        tpe: ColumnType::pure(ColumnTypeFamily::Int, arity),
+        default: None,
+        auto_increment: false,
+        comment: None,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![View {
+            name: "UserStats".to_string(),
+            columns: vec![
+                column("rank", ColumnArity::Nullable),
+                column("posts", ColumnArity::Required),
+                column("id", ColumnArity::Required),
+            ],
+            definition: None,
+        }],
+    };
+    let data_model = calculate_model(&schema).expect("calculate data model");
+    let view_model = data_model.find_model("UserStats").expect("find UserStats model");
+
+    assert!(!view_model.is_commented_out);
+    assert_eq!(
+        view_model.singular_id_fields().map(|field| field.name.as_str()).collect::<Vec<_>>(),
+        vec!["id"]
+    );
+
+    let view_warnings: Vec<Warning> = calculate_warnings(&schema)
+        .into_iter()
+        .filter(|warning| warning.code == 5)
+        .collect();
+
+    assert_eq!(
+        view_warnings,
+        vec![Warning {
+            code: 5,
+            message: "The view has no primary key. The column `id` is marked with `@id`, it must identify the records."
+                .to_string(),
+            table: "UserStats".to_string(),
+            columns: vec!["id".to_string()],
+        }]
+    );
+}
+
+#[test]
+fn naming_conventions_are_applied_when_requested() {
+    let int_column = |name: &str| Column {
+        name: name.to_string(),
+        tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+        default: None,
+        auto_increment: false,
+        comment: None,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![
+            Table {
+                name: "user_accounts".to_string(),
+                columns: vec![
+                    int_column("id"),
+                    Column {
+                        name: "created_at".to_string(),
+                        tpe: ColumnType::pure(ColumnTypeFamily::DateTime, ColumnArity::Required),
+                        default: None,
+                        auto_increment: false,
+                        comment: None,
+                    },
+                ],
+                indices: vec![],
+                primary_key: Some(PrimaryKey {
+                    columns: vec!["id".to_string()],
+                    sequence: None,
+                }),
+                foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
+            },
+            Table {
+                name: "blog_posts".to_string(),
+                columns: vec![int_column("id"), int_column("author_id")],
+                indices: vec![],
+                primary_key: Some(PrimaryKey {
+                    columns: vec!["id".to_string()],
+                    sequence: None,
+                }),
+                foreign_keys: vec![ForeignKey {
+                    constraint_name: None,
+                    columns: vec!["author_id".to_string()],
+                    referenced_table: "user_accounts".to_string(),
+                    on_delete_action: ForeignKeyAction::NoAction,
+                    referenced_columns: vec!["id".to_string()],
+                }],
+                check_constraints: vec![],
+                comment: None,
+            },
+        ],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+    let mut data_model = calculate_model(&schema).expect("calculate data model");
+    apply_naming_conventions(&mut data_model, &schema);
+
+    let user_account = data_model.find_model("UserAccount").expect("find UserAccount model");
+    assert_eq!(user_account.database_name, Some("user_accounts".to_string()));
+    assert_eq!(user_account.find_field("id").unwrap().database_name, None);
+    assert_eq!(
+        user_account.find_field("createdAt").unwrap().database_name,
+        Some("created_at".to_string())
+    );
+
+    let blog_posts = user_account.find_field("blogPosts").expect("find back relation field");
+    assert_eq!(blog_posts.database_name, None);
+    assert_eq!(
+        blog_posts.field_type,
+        FieldType::Relation(RelationInfo {
+            name: "blog_postsTouser_accounts".to_string(),
+            to: "BlogPost".to_string(),
+            to_fields: vec!["userAccount".to_string()],
+            columns: vec![],
+            on_delete: OnDeleteStrategy::None,
+        })
+    );
+
+    let blog_post = data_model.find_model("BlogPost").expect("find BlogPost model");
+    assert_eq!(blog_post.database_name, Some("blog_posts".to_string()));
+
+    let author = blog_post.find_field("userAccount").expect("find relation field");
+    assert_eq!(author.database_name, Some("author_id".to_string()));
+    assert_eq!(
+        author.field_type,
+        FieldType::Relation(RelationInfo {
+            name: "blog_postsTouser_accounts".to_string(),
+            to: "UserAccount".to_string(),
+            to_fields: vec!["id".to_string()],
+            columns: vec![],
+            on_delete: OnDeleteStrategy::None,
+        })
+    );
+}
+
+#[test]
+fn unsupported_constructs_are_commented_out_and_reported() {
+    let schema = SqlSchema {
+        tables: vec![
+            Table {
+                name: "Place".to_string(),
+                columns: vec![
+                    Column {
+                        name: "id".to_string(),
+                        tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                        default: None,
+                        auto_increment: false,
+                        comment: None,
+                    },
+                    Column {
+                        name: "location".to_string(),
+                        tpe: ColumnType {
+                            raw: "point".to_string(),
+                            family: ColumnTypeFamily::Geometric,
+                            arity: ColumnArity::Required,
+                        },
+                        default: None,
+                        auto_increment: false,
+                        comment: None,
+                    },
+                ],
+                indices: vec![Index {
+                    name: "Place_location_idx".to_string(),
+                    columns: vec!["location".to_string()],
+                    tpe: IndexType::Normal,
+                }],
+                primary_key: Some(PrimaryKey {
+                    columns: vec!["id".to_string()],
+                    sequence: None,
+                }),
+                foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
+            },
+            Table {
+                name: "Log".to_string(),
+                columns: vec![Column {
+                    name: "message".to_string(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
+                    default: None,
+                    auto_increment: false,
+                    comment: None,
+                }],
+                indices: vec![],
+                primary_key: None,
+                foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
+            },
+        ],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+    let data_model = calculate_model(&schema).expect("calculate data model");
+
+    let place = data_model.find_model("Place").unwrap();
+    assert!(!place.find_field("id").unwrap().is_commented_out);
+    assert!(place.find_field("location").unwrap().is_commented_out);
+    assert!(place.indexes.is_empty());
+
+    let warnings = calculate_warnings(&schema);
+
+    assert_eq!(
+        warnings,
+        vec![
+            Warning {
+                code: 1,
+                message: "The type `point` of the column is not supported. The field is commented out.".to_string(),
+                table: "Place".to_string(),
+                columns: vec!["location".to_string()],
+            },
+            Warning {
+                code: 4,
+                message: "The index `Place_location_idx` covers columns of unsupported types. It is left out."
+                    .to_string(),
+                table: "Place".to_string(),
+                columns: vec!["location".to_string()],
+            },
+            Warning {
+                code: 3,
+                message:
+                    "The table has neither a primary key nor a unique constraint, its records can not be identified."
+                        .to_string(),
+                table: "Log".to_string(),
+                columns: vec![],
+            },
+        ]
+    );
+}
+
+fn table_with_foreign_keys(name: &str, foreign_keys: Vec<(&str, &str)>) -> Table {
+    let int_column = |name: &str| Column {
+        name: name.to_string(),
+        tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+        default: None,
+        auto_increment: false,
+        comment: None,
+    };
+
+    Table {
+        name: name.to_string(),
+        columns: std::iter::once("id")
+            .chain(foreign_keys.iter().map(|(column, _)| *column))
+            .map(int_column)
+            .collect(),
+        indices: vec![],
+        primary_key: Some(PrimaryKey {
+            columns: vec!["id".to_string()],
+            sequence: None,
+        }),
+        foreign_keys: foreign_keys
+            .into_iter()
+            .map(|(column, referenced_table)| ForeignKey {
+                constraint_name: None,
+                columns: vec![column.to_string()],
+                referenced_table: referenced_table.to_string(),
+                referenced_columns: vec!["id".to_string()],
+                on_delete_action: ForeignKeyAction::NoAction,
+            })
+            .collect(),
+        check_constraints: vec![],
+        comment: None,
+    }
+}
+
+#[test]
+fn tables_can_be_filtered_with_glob_patterns() {
+    let mut join_table = table_with_foreign_keys("_PostToTag", vec![("A", "Post"), ("B", "Tag")]);
+    join_table.columns.retain(|column| column.name != "id");
+    join_table.primary_key = None;
+    join_table.indices = vec![Index {
+        name: "_PostToTag_AB_unique".to_string(),
+        columns: vec!["A".to_string(), "B".to_string()],
+        tpe: IndexType::Unique,
+    }];
+
+    let mut schema = SqlSchema {
+        tables: vec![
+            table_with_foreign_keys("User", vec![]),
+            table_with_foreign_keys("Post", vec![("author", "User")]),
+            table_with_foreign_keys("Tag", vec![]),
+            join_table,
+            table_with_foreign_keys("billing_invoice", vec![]),
+            table_with_foreign_keys("billing_payment", vec![("invoice", "billing_invoice")]),
+        ],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+
+    filter_tables(
+        &mut schema,
+        &["Post".to_string(), "Tag".to_string(), "billing_*".to_string()],
+        &["billing_pay?ent".to_string()],
+    );
+
+    let table_names: Vec<&str> = schema.tables.iter().map(|table| table.name.as_str()).collect();
+    assert_eq!(table_names, vec!["Post", "Tag", "_PostToTag", "billing_invoice"]);
+
+    // The foreign key to the left out `User` table is gone.
+    let data_model = calculate_model(&schema).expect("calculate data model");
+    let post = data_model.find_model("Post").unwrap();
+    assert_eq!(
+        post.find_field("author").unwrap().field_type,
+        FieldType::Base(ScalarType::Int)
+    );
+    assert!(post.find_field("tags").unwrap().field_type.is_relation());
+}
+
+#[test]
+fn tables_of_other_schemas_are_assigned_to_their_schema() {
+    let mut schema = SqlSchema {
+        tables: vec![table_with_foreign_keys("User", vec![])],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+    let billing = SqlSchema {
+        tables: vec![
+            table_with_foreign_keys("invoice", vec![("customer", "public.User")]),
+            table_with_foreign_keys("line", vec![("invoice", "invoice")]),
+        ],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![],
+    };
+
+    let mut merged = MergedSchemas::default();
+    merge_schema(&mut schema, billing, "billing", "public", &mut merged);
+
+    let referenced_tables: Vec<&str> = schema
+        .tables
+        .iter()
+        .flat_map(|table| table.foreign_keys.iter())
+        .map(|fk| fk.referenced_table.as_str())
+        .collect();
+    assert_eq!(referenced_tables, vec!["User", "billing.invoice"]);
+
+    let mut data_model = calculate_model(&schema).expect("calculate data model");
+    assign_schemas(&mut data_model, &merged.tables);
+
+    let user = data_model.find_model("User").unwrap();
+    assert_eq!(user.schema, None);
+    let invoice = data_model.find_model("billing_invoice").unwrap();
+    assert_eq!(invoice.schema.as_ref().unwrap(), "billing");
+    assert_eq!(invoice.database_name.as_ref().unwrap(), "invoice");
+
+    let rendered = datamodel::render_datamodel_to_string(&data_model).unwrap();
+    assert!(rendered.contains(r#"@@schema("billing")"#));
+    assert!(rendered.contains(r#"@@map("invoice")"#));
+    assert!(rendered.contains(r#"@@map("line")"#));
+    assert!(!rendered.contains("billing.invoice"));
+}
+
+#[test]
+fn enums_of_other_schemas_with_a_taken_name_are_warned_about() {
+    let status_column = |name: &str| Column {
+        name: name.to_string(),
+        tpe: ColumnType::pure(ColumnTypeFamily::Enum("status".to_string()), ColumnArity::Required),
+        default: None,
+        auto_increment: false,
+        comment: None,
+    };
+    let status = |values: &[&str]| Enum {
+        name: "status".to_string(),
+        values: values.iter().map(|value| value.to_string()).collect(),
+    };
+
+    let mut user = table_with_foreign_keys("User", vec![]);
+    user.columns.push(status_column("status"));
+    let mut schema = SqlSchema {
+        tables: vec![user],
+        enums: vec![status(&["active", "banned"])],
+        sequences: vec![],
+        views: vec![],
+    };
+
+    let mut invoice = table_with_foreign_keys("invoice", vec![]);
+    invoice.columns.push(status_column("status"));
+    let billing = SqlSchema {
+        tables: vec![invoice],
+        enums: vec![status(&["open", "paid"])],
+        sequences: vec![],
+        views: vec![],
+    };
+
+    let mut merged = MergedSchemas::default();
+    merge_schema(&mut schema, billing, "billing", "public", &mut merged);
+
+    assert_eq!(schema.enums, vec![status(&["active", "banned"])]);
+    assert_eq!(
+        merged.warnings,
+        vec![Warning {
+            code: 6,
+            message: "The enum `status` of schema `billing` has other values than the enum of the same name the field refers to.".to_string(),
+            table: "billing.invoice".to_string(),
+            columns: vec!["status".to_string()],
+        }]
+    );
+
+    // Enums with the same values are the same to the data model.
+    let mut schema = SqlSchema {
+        tables: vec![],
+        enums: vec![status(&["open", "paid"])],
+        sequences: vec![],
+        views: vec![],
+    };
+    let mut invoice = table_with_foreign_keys("invoice", vec![]);
+    invoice.columns.push(status_column("status"));
+    let billing = SqlSchema {
+        tables: vec![invoice],
+        enums: vec![status(&["open", "paid"])],
+        sequences: vec![],
+        views: vec![],
+    };
+
+    let mut merged = MergedSchemas::default();
+    merge_schema(&mut schema, billing, "billing", "public", &mut merged);
+
+    assert_eq!(schema.enums.len(), 1);
+    assert!(merged.warnings.is_empty());
+}
+
+#[test]
+fn ddl_scripts_can_be_introspected_without_a_database() {
+    let ddl = r#"
+        CREATE TABLE public."User" (
+            id integer NOT NULL,
+            email character varying(191) NOT NULL
+        );
+
+        CREATE TABLE public."Post" (
+            id serial PRIMARY KEY,
+            "authorId" integer NOT NULL REFERENCES public."User"(id) ON DELETE CASCADE
+        );
+
+        ALTER TABLE ONLY public."User" ADD CONSTRAINT "User_pkey" PRIMARY KEY (id);
+        CREATE UNIQUE INDEX "User.email" ON public."User" USING btree (email);
+    "#;
+
+    let result = introspect_ddl(ddl, "postgresql", None, IntrospectionOptions::default()).unwrap();
+    let data_model = result.datamodel;
+
+    let user = data_model.find_model("User").unwrap();
+    assert_eq!(
+        user.find_field("id").unwrap().id_info.as_ref().unwrap().strategy,
+        IdStrategy::None
+    );
+    assert!(user.find_field("email").unwrap().is_unique);
+
+    let post = data_model.find_model("Post").unwrap();
+    let post_id = post.find_field("id").unwrap();
+    assert_eq!(post_id.id_info.as_ref().unwrap().strategy, IdStrategy::Auto);
+    assert!(post_id.id_info.as_ref().unwrap().sequence.is_none());
+    assert_eq!(
+        post_id.default_value,
+        Some(dml::DefaultValue::Expression(
+            dml::ValueGenerator::new("autoincrement".to_string(), Vec::new()).unwrap()
+        ))
+    );
+
+    match &post.find_field("authorId").unwrap().field_type {
+        FieldType::Relation(info) => assert_eq!(info.to, "User"),
+        other => panic!("Expected a relation field, got {:?}", other),
+    }
+
+    match &user.find_field("posts").unwrap().field_type {
+        FieldType::Relation(info) => assert_eq!(info.on_delete, OnDeleteStrategy::Cascade),
+        other => panic!("Expected a relation field, got {:?}", other),
+    }
+
+    let error = introspect_ddl("CREATE TABLE t (id integer", "postgresql", None, IntrospectionOptions::default())
+        .unwrap_err();
+    assert!(error.user_facing.is_some());
+
+    assert!(introspect_ddl("", "oracle", None, IntrospectionOptions::default()).is_err());
+}
@@ -19,8 +19,8 @@ impl TestApi {
     }
 
     pub async fn introspect(&self) -> String {
-        let datamodel = self.introspection_connector.introspect().await.unwrap();
-        datamodel::render_datamodel_to_string(&datamodel).expect("Datamodel rendering failed")
+        let result = self.introspection_connector.introspect().await.unwrap();
+        datamodel::render_datamodel_to_string(&result.datamodel).expect("Datamodel rendering failed")
     }
 
     pub async fn get_metadata(&self) -> DatabaseMetadata {
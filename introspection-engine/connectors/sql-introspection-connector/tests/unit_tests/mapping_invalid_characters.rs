@@ -37,7 +37,7 @@ async fn fields_with_invalid_characters_should_be_mapped_for_postgres(api: &Test
                g_a    String @map("g a")
                h1     String
                h_a    String @map("h-a")
-               id     Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id     Int @id @default(autoincrement())
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -58,7 +58,7 @@ async fn fields_with_invalid_characters_should_be_mapped_for_mysql(api: &TestApi
                g_a    String @map("g a")
                h_a    String @map("h-a")
                h1     String
-               id     Int @id
+               id     Int @id @default(autoincrement())
                a      String @map("_a")
             }
         "#;
@@ -81,7 +81,7 @@ async fn fields_with_invalid_characters_should_be_mapped_for_sqlite(api: &TestAp
                g_a    String @map("g a")
                h_a    String @map("h-a")
                h1     String
-               id     Int @id
+               id     Int @id @default(autoincrement())
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -109,13 +109,13 @@ async fn tables_with_invalid_characters_should_be_mapped_for_postgres(api: &Test
 
     let dm = r#"
             model User {
-               id Int @id @sequence(name: "?User_id_seq", allocationSize: 1, initialValue: 1)
+               id Int @id @default(autoincrement())
 
                @@map("?User")
             }
 
             model User_with_Space {
-               id Int @id @sequence(name: "User with Space_id_seq", allocationSize: 1, initialValue: 1)
+               id Int @id @default(autoincrement())
 
                @@map("User with Space")
             }
@@ -130,13 +130,13 @@ async fn tables_with_invalid_characters_should_be_mapped_for_mysql(api: &TestApi
 
     let dm = r#"
             model User {
-               id      Int @id
+               id      Int @id @default(autoincrement())
 
                @@map("?User")
             }
 
             model User_with_Space {
-               id      Int @id
+               id      Int @id @default(autoincrement())
 
                @@map("User with Space")
             }
@@ -151,13 +151,13 @@ async fn tables_with_invalid_characters_should_be_mapped_for_sqlite(api: &TestAp
 
     let dm = r#"
             model User {
-               id      Int @id
+               id      Int @id @default(autoincrement())
 
                @@map("?User")
             }
 
             model User_with_Space {
-               id      Int @id
+               id      Int @id @default(autoincrement())
 
                @@map("User with Space")
             }
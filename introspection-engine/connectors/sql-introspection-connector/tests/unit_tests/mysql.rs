@@ -25,7 +25,7 @@ async fn introspecting_a_simple_table_with_gql_types_must_work(api: &TestApi) {
                 bool    Boolean
                 date    DateTime
                 float   Float
-                id      Int @id
+                id      Int @id @default(autoincrement())
                 int     Int 
                 string  String
             }
@@ -83,7 +83,7 @@ async fn introspecting_a_table_with_unique_index_must_work(api: &TestApi) {
     let dm = r#"
             model Blog {
                 authorId String @unique
-                id      Int @id
+                id      Int @id @default(autoincrement())
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -113,7 +113,7 @@ async fn introspecting_a_table_with_multi_column_unique_index_must_work(api: &Te
     let dm = r#"
             model User {
                 firstname String
-                id      Int @id
+                id      Int @id @default(autoincrement())
                 lastname String
                 @@unique([firstname, lastname], name: "test")
             }
@@ -140,7 +140,7 @@ async fn introspecting_a_table_with_required_and_optional_columns_must_work(api:
 
     let dm = r#"
             model User {
-                id      Int @id
+                id      Int @id @default(autoincrement())
                 optionalname String?
                 requiredname String
             }
@@ -163,7 +163,7 @@ async fn introspecting_a_table_with_required_and_optional_columns_must_work(api:
 //
 //    let dm = r#"
 //            model User {
-//                id      Int @id
+//                id      Int @id @default(autoincrement())
 //                joined DateTime? @default(now())
 //                name String
 //            }
@@ -198,7 +198,7 @@ async fn introspecting_a_table_with_default_values_should_work(api: &TestApi) {
                 bool Boolean @default(false)
                 bool2 Boolean @default(false)
                 float Float @default(5.3)
-                id      Int @id
+                id      Int @id @default(autoincrement())
                 int Int @default(5)
                 string String @default("Test")
             }
@@ -226,7 +226,7 @@ async fn introspecting_a_table_with_a_non_unique_index_should_work(api: &TestApi
     let dm = r#"
             model User {
                 a String
-                id      Int @id
+                id      Int @id @default(autoincrement())
                 @@index([a], name: "test")
             }
         "#;
@@ -255,7 +255,7 @@ async fn introspecting_a_table_with_a_multi_column_non_unique_index_should_work(
             model User {
                 a String
                 b String
-                id      Int @id
+                id      Int @id @default(autoincrement())
                 @@index([a,b], name: "test")
             }
         "#;
@@ -287,12 +287,12 @@ async fn introspecting_a_one_to_one_req_relation_should_work(api: &TestApi) {
 
     let dm = r#"
               model Post {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                user_id User
             }
           
             model User {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                post Post? 
             }
         "#;
@@ -331,13 +331,13 @@ async fn introspecting_two_one_to_one_relations_between_the_same_models_should_w
 
     let dm = r#"
             model Post {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                user_id User  @relation("Post_user_idToUser")
                user    User? @relation("PostToUser_post_id", references: [post_id])
             }
         
             model User {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                post_id Post  @relation("PostToUser_post_id")
                post Post?    @relation("Post_user_idToUser")
             }
@@ -369,12 +369,12 @@ async fn introspecting_a_one_to_one_relation_should_work(api: &TestApi) {
 
     let dm = r#"        
             model Post {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                user_id User?
             }
             
             model User {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                post Post? 
             }
         "#;
@@ -402,13 +402,13 @@ async fn introspecting_a_one_to_one_relation_referencing_non_id_should_work(api:
         .await;
     let dm = r#"        
             model Post {
-               id           Int     @id  
+               id           Int     @id @default(autoincrement())
                user_email   User?   @relation(references: [email])
             }
             
             model User {
                email        String? @unique 
-               id           Int     @id 
+               id           Int     @id @default(autoincrement())
                post         Post? 
             }
         "#;
@@ -439,12 +439,12 @@ async fn introspecting_a_one_to_many_relation_should_work(api: &TestApi) {
 
     let dm = r#"  
             model Post {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                user_id User?
             }
             
             model User {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                posts Post[] 
             }
         "#;
@@ -475,12 +475,12 @@ async fn introspecting_a_one_req_to_many_relation_should_work(api: &TestApi) {
 
     let dm = r#"
             model Post {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                user_id User
             }
             
             model User {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                posts Post[] 
             }
        "#;
@@ -519,12 +519,12 @@ async fn introspecting_a_prisma_many_to_many_relation_should_work(api: &TestApi)
 
     let dm = r#"
             model Post {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                users User[] 
             }
             
             model User {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                posts Post[] 
             }
         "#;
@@ -559,7 +559,7 @@ async fn introspecting_a_many_to_many_relation_should_work(api: &TestApi) {
 
     let dm = r#"
             model Post {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                postsToUserses PostsToUsers[] @relation(references: [post_id], onDelete: CASCADE)
             }
 
@@ -569,7 +569,7 @@ async fn introspecting_a_many_to_many_relation_should_work(api: &TestApi) {
             }
             
             model User {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                postsToUserses PostsToUsers[] @relation(onDelete: CASCADE)
             }
         "#;
@@ -605,7 +605,7 @@ async fn introspecting_a_many_to_many_relation_with_extra_fields_should_work(api
 
     let dm = r#"
             model Post {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                postsToUserses PostsToUsers[] @relation(references: [post_id])
             }
             
@@ -616,7 +616,7 @@ async fn introspecting_a_many_to_many_relation_with_extra_fields_should_work(api
             }
             
             model User {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                postsToUserses PostsToUsers[] 
             }
         "#;
@@ -646,7 +646,7 @@ async fn introspecting_a_self_relation_should_work(api: &TestApi) {
     let dm = r#"
             model User {
                 direct_report                  User?  @relation("UserToUser_direct_report")
-                id      Int @id
+                id      Int @id @default(autoincrement())
                 recruited_by                   User?  @relation("UserToUser_recruited_by")
                 users_UserToUser_direct_report User[] @relation("UserToUser_direct_report")
                 users_UserToUser_recruited_by  User[] @relation("UserToUser_recruited_by")
@@ -680,12 +680,12 @@ async fn introspecting_cascading_delete_behaviour_should_work(api: &TestApi) {
 
     let dm = r#"  
             model Post {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                user_id User?
             }
             
             model User {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                posts Post[] @relation(onDelete: CASCADE)
             }
         "#;
@@ -716,7 +716,7 @@ async fn introspecting_id_fields_with_foreign_key_should_ignore_the_relation(api
             }
 
             model User {
-               id      Int @id
+               id      Int @id @default(autoincrement())
             }
         "#;
     let result = dbg!(api.introspect().await);
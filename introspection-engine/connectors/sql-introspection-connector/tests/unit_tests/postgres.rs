@@ -23,7 +23,7 @@ async fn introspecting_a_simple_table_with_gql_types_must_work(api: &TestApi) {
                 bool    Boolean
                 date    DateTime
                 float   Float
-                id      Int @id @sequence(name: "Blog_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @default(autoincrement())
                 int     Int 
                 string  String
             }
@@ -32,6 +32,30 @@ async fn introspecting_a_simple_table_with_gql_types_must_work(api: &TestApi) {
     custom_assert(&result, dm);
 }
 
+#[test_one_connector(connector = "postgres")]
+async fn introspecting_numeric_columns_as_decimal_must_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Invoice", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("\"amount\" NUMERIC(12, 2) NOT NULL");
+                t.inject_custom("\"rate\" DOUBLE PRECISION NOT NULL");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model Invoice {
+                amount  Decimal
+                id      Int @id @default(autoincrement())
+                rate    Float
+            }
+        "#;
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
 #[test_one_connector(connector = "postgres")]
 async fn introspecting_a_table_with_compound_primary_keys_must_work(api: &TestApi) {
     let barrel = api.barrel();
@@ -74,7 +98,7 @@ async fn introspecting_a_table_with_unique_index_must_work(api: &TestApi) {
     let dm = r#"
             model Blog {
                 authorId String @unique
-                id      Int @id @sequence(name: "Blog_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @default(autoincrement())
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -100,7 +124,7 @@ async fn introspecting_a_table_with_multi_column_unique_index_must_work(api: &Te
     let dm = r#"
             model User {
                 firstname String
-                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @default(autoincrement())
                 lastname String
                 @@unique([firstname, lastname], name: "test")
             }
@@ -123,7 +147,7 @@ async fn introspecting_a_table_with_required_and_optional_columns_must_work(api:
         .await;
     let dm = r#"
             model User {
-                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @default(autoincrement())
                 optionalname String?
                 requiredname String
             }
@@ -145,7 +169,7 @@ async fn introspecting_a_table_with_required_and_optional_columns_must_work(api:
 //    }).await;
 //    let dm = r#"
 //            model User {
-//                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+//                id      Int @id @default(autoincrement())
 //                joined DateTime? @default(now())
 //                name String
 //            }
@@ -176,7 +200,7 @@ async fn introspecting_a_table_with_default_values_should_work(api: &TestApi) {
                 bool Boolean @default(false)
                 bool2 Boolean @default(false)
                 float Float @default(5.3)
-                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @default(autoincrement())
                 int Int @default(5)
                 string String @default("Test")
             }
@@ -204,7 +228,7 @@ async fn introspecting_a_table_with_a_non_unique_index_should_work(api: &TestApi
     let dm = r#"
             model User {
                 a String
-                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @default(autoincrement())
                 @@index([a], name: "test")
             }
         "#;
@@ -233,7 +257,7 @@ async fn introspecting_a_table_with_a_multi_column_non_unique_index_should_work(
             model User {
                 a String
                 b String
-                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @default(autoincrement())
                 @@index([a,b], name: "test")
             }
         "#;
@@ -259,12 +283,12 @@ async fn introspecting_a_one_to_one_req_relation_should_work(api: &TestApi) {
 
     let dm = r#"
               model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                user_id User
             }
           
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                post Post? 
             }
         "#;
@@ -288,13 +312,13 @@ async fn introspecting_two_one_to_one_relations_between_the_same_models_should_w
 
     let dm = r#"
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                user_id User  @relation("Post_user_idToUser")
                user    User? @relation("PostToUser_post_id", references: [post_id])
             }
         
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                post_id Post  @relation("PostToUser_post_id")
                post Post?    @relation("Post_user_idToUser")
             }
@@ -319,12 +343,12 @@ async fn introspecting_a_one_to_one_relation_should_work(api: &TestApi) {
         .await;
     let dm = r#"        
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                user_id User?
             }
             
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                post Post? 
             }
         "#;
@@ -349,13 +373,13 @@ async fn introspecting_a_one_to_one_relation_referencing_non_id_should_work(api:
         .await;
     let dm = r#"        
             model Post {
-               id           Int     @id  @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id           Int     @id  @default(autoincrement())
                user_email   User?   @relation(references: [email])
             }
             
             model User {
                email        String? @unique 
-               id           Int     @id  @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id           Int     @id  @default(autoincrement())
                post         Post? 
             }
         "#;
@@ -379,12 +403,12 @@ async fn introspecting_a_one_to_many_relation_should_work(api: &TestApi) {
         .await;
     let dm = r#"  
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                user_id User?
             }
             
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                posts Post[] 
             }
         "#;
@@ -408,12 +432,12 @@ async fn introspecting_a_one_req_to_many_relation_should_work(api: &TestApi) {
         .await;
     let dm = r#"
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                user_id User
             }
             
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                posts Post[] 
             }
        "#;
@@ -447,12 +471,12 @@ async fn introspecting_a_prisma_many_to_many_relation_should_work(api: &TestApi)
 
     let dm = r#"
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                users User[] 
             }
             
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                posts Post[] 
             }
         "#;
@@ -482,7 +506,7 @@ async fn introspecting_a_many_to_many_relation_should_work(api: &TestApi) {
 
     let dm = r#"
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                postsToUserses PostsToUsers[] @relation(references: [post_id], onDelete: CASCADE)
             }
 
@@ -492,7 +516,7 @@ async fn introspecting_a_many_to_many_relation_should_work(api: &TestApi) {
             }
             
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                postsToUserses PostsToUsers[] @relation( onDelete: CASCADE)
             }
         "#;
@@ -523,7 +547,7 @@ async fn introspecting_a_many_to_many_relation_with_extra_fields_should_work(api
 
     let dm = r#"
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                postsToUserses PostsToUsers[] @relation(references: [post_id])
             }
             
@@ -534,7 +558,7 @@ async fn introspecting_a_many_to_many_relation_with_extra_fields_should_work(api
             }
             
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                postsToUserses PostsToUsers[] 
             }
         "#;
@@ -558,7 +582,7 @@ async fn introspecting_a_self_relation_should_work(api: &TestApi) {
     let dm = r#"
             model User {
                 direct_report                  User?  @relation("UserToUser_direct_report")
-                id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+                id      Int @id @default(autoincrement())
                 recruited_by                   User?  @relation("UserToUser_recruited_by")
                 users_UserToUser_direct_report User[] @relation("UserToUser_direct_report")
                 users_UserToUser_recruited_by  User[] @relation("UserToUser_recruited_by")
@@ -587,12 +611,12 @@ async fn introspecting_cascading_delete_behaviour_should_work(api: &TestApi) {
 
     let dm = r#"  
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                user_id User?
             }
             
             model User {
-               id    Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id    Int @id @default(autoincrement())
                posts Post[] @relation(onDelete: CASCADE)
             }
         "#;
@@ -622,7 +646,7 @@ async fn introspecting_native_arrays_should_work(api: &TestApi) {
               url = "postgresql://localhost:5432"
             }
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                ints Int []
             }
         "#;
@@ -651,12 +675,12 @@ async fn introspecting_default_values_on_relations_should_be_ignored(api: &TestA
               url = "postgresql://localhost:5432"
             }
             model Post {
-               id      Int @id @sequence(name: "Post_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                user_id User?
             }
 
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                posts Post[]
             }
         "#;
@@ -685,7 +709,7 @@ async fn introspecting_default_values_on_lists_should_be_ignored(api: &TestApi)
             }
 
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
                ints    Int []
                ints2   Int []
             }
@@ -716,7 +740,7 @@ async fn introspecting_id_fields_with_foreign_key_should_ignore_the_relation(api
             }
 
             model User {
-               id      Int @id @sequence(name: "User_id_seq", allocationSize: 1, initialValue: 1)
+               id      Int @id @default(autoincrement())
             }
         "#;
     let result = dbg!(api.introspect().await);
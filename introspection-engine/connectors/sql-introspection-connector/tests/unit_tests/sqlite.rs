@@ -23,7 +23,7 @@ async fn introspecting_a_simple_table_with_gql_types_must_work(api: &TestApi) {
                 bool    Boolean
                 date    DateTime
                 float   Float
-                id      Int @id
+                id      Int @id @default(autoincrement())
                 int     Int 
                 string  String
             }
@@ -75,7 +75,7 @@ async fn introspecting_a_table_with_unique_index_must_work(api: &TestApi) {
     let dm = r#"
             model Blog {
                 authorId String @unique
-                id Int @id
+                id Int @id @default(autoincrement())
             }
         "#;
     let result = dbg!(api.introspect().await);
@@ -102,7 +102,7 @@ async fn introspecting_a_table_with_multi_column_unique_index_must_work(api: &Te
     let dm = r#"
             model User {
                 firstname String
-                id Int @id
+                id Int @id @default(autoincrement())
                 lastname String
                 @@unique([firstname, lastname], name: "test")
             }
@@ -126,7 +126,7 @@ async fn introspecting_a_table_with_required_and_optional_columns_must_work(api:
 
     let dm = r#"
             model User {
-                id Int @id
+                id Int @id @default(autoincrement())
                 optionalname String?
                 requiredname String
             }
@@ -149,7 +149,7 @@ async fn introspecting_a_table_with_required_and_optional_columns_must_work(api:
 //
 //    let dm = r#"
 //            model User {
-//                id Int @id
+//                id Int @id @default(autoincrement())
 //                joined DateTime? @default(now())
 //                name String
 //            }
@@ -181,7 +181,7 @@ async fn introspecting_a_table_with_default_values_should_work(api: &TestApi) {
                 bool Boolean @default(false)
                 bool2 Boolean @default(false)
                 float Float @default(5.3)
-                id Int @id
+                id Int @id @default(autoincrement())
                 int Int @default(5)
                 string String @default("Test")
             }
@@ -209,7 +209,7 @@ async fn introspecting_a_table_with_a_non_unique_index_should_work(api: &TestApi
     let dm = r#"
             model User {
                 a String
-                id Int @id
+                id Int @id @default(autoincrement())
                 @@index([a], name: "test")
             }
         "#;
@@ -238,7 +238,7 @@ async fn introspecting_a_table_with_a_multi_column_non_unique_index_should_work(
             model User {
                 a String
                 b String
-                id Int @id
+                id Int @id @default(autoincrement())
                 @@index([a,b], name: "test")
             }
         "#;
@@ -267,12 +267,12 @@ async fn introspecting_a_one_to_one_req_relation_should_work(api: &TestApi) {
 
     let dm = r#"
             model User {
-               id Int @id
+               id Int @id @default(autoincrement())
                post Post? 
             }
             
             model Post {
-               id Int @id
+               id Int @id @default(autoincrement())
                user_id User
             }
         "#;
@@ -304,13 +304,13 @@ async fn introspecting_two_one_to_one_relations_between_the_same_models_should_w
 
     let dm = r#"
             model User {
-               id Int @id
+               id Int @id @default(autoincrement())
                post_id Post  @relation("PostToUser_post_id")
                post Post?    @relation("Post_user_idToUser")
             }
             
             model Post {
-               id Int @id
+               id Int @id @default(autoincrement())
                user_id User  @relation("Post_user_idToUser")
                user    User? @relation("PostToUser_post_id", references: [post_id])
             }
@@ -339,12 +339,12 @@ async fn introspecting_a_one_to_one_relation_should_work(api: &TestApi) {
 
     let dm = r#"
             model User {
-               id Int @id
+               id Int @id @default(autoincrement())
                post Post? 
             }
             
             model Post {
-               id Int @id
+               id Int @id @default(autoincrement())
                user_id User?
             }
         "#;
@@ -373,12 +373,12 @@ async fn introspecting_a_one_to_one_relation_referencing_non_id_should_work(api:
     let dm = r#"
             model User {
                email        String? @unique 
-               id           Int     @id 
+               id           Int     @id @default(autoincrement())
                post         Post? 
             }
             
             model Post {
-               id           Int     @id  
+               id           Int     @id @default(autoincrement())
                user_email   User?   @relation(references: [email])
             }
         "#;
@@ -406,12 +406,12 @@ async fn introspecting_a_one_to_many_relation_should_work(api: &TestApi) {
 
     let dm = r#"
             model User {
-               id Int @id
+               id Int @id @default(autoincrement())
                posts Post[] 
             }
             
             model Post {
-               id Int @id
+               id Int @id @default(autoincrement())
                user_id User?
             }
         "#;
@@ -439,12 +439,12 @@ async fn introspecting_a_one_req_to_many_relation_should_work(api: &TestApi) {
 
     let dm = r#"
             model User {
-               id Int @id
+               id Int @id @default(autoincrement())
                posts Post[] 
             }
             
             model Post {
-               id Int @id
+               id Int @id @default(autoincrement())
                user_id User
             }
         "#;
@@ -480,12 +480,12 @@ async fn introspecting_a_prisma_many_to_many_relation_should_work(api: &TestApi)
 
     let dm = r#"
             model User {
-               id Int @id
+               id Int @id @default(autoincrement())
                posts Post[] 
             }
             
             model Post {
-               id Int @id
+               id Int @id @default(autoincrement())
                users User[] 
             }
         "#;
@@ -518,12 +518,12 @@ async fn introspecting_a_many_to_many_relation_should_work(api: &TestApi) {
 
     let dm = r#"
             model User {
-               id Int @id
+               id Int @id @default(autoincrement())
                postsToUserses PostsToUsers[] @relation(onDelete: CASCADE)
             }
             
             model Post {
-               id Int @id
+               id Int @id @default(autoincrement())
                postsToUserses PostsToUsers[] @relation(references: [post_id], onDelete: CASCADE)
             }
             
@@ -561,12 +561,12 @@ async fn introspecting_a_many_to_many_relation_with_extra_fields_should_work(api
 
     let dm = r#"
             model User {
-               id Int @id
+               id Int @id @default(autoincrement())
                postsToUserses PostsToUsers[] 
             }
             
             model Post {
-               id Int @id
+               id Int @id @default(autoincrement())
                postsToUserses PostsToUsers[] @relation(references: [post_id])
             }
             
@@ -600,7 +600,7 @@ async fn introspecting_a_self_relation_should_work(api: &TestApi) {
     let dm = r#"
             model User {
                 direct_report                  User?  @relation("UserToUser_direct_report")
-                id                             Int    @id
+                id                             Int    @id @default(autoincrement())
                 recruited_by                   User?  @relation("UserToUser_recruited_by")
                 users_UserToUser_direct_report User[] @relation("UserToUser_direct_report")
                 users_UserToUser_recruited_by  User[] @relation("UserToUser_recruited_by")
@@ -632,12 +632,12 @@ async fn introspecting_cascading_delete_behaviour_should_work(api: &TestApi) {
 
     let dm = r#"
             model User {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                posts Post[] @relation(onDelete: CASCADE)
             }
             
             model Post {
-               id      Int @id
+               id      Int @id @default(autoincrement())
                user_id User?
             }
         "#;
@@ -663,12 +663,12 @@ async fn introspecting_id_fields_with_foreign_key_should_ignore_the_relation(api
 
     let dm = r#"
             model User {
-               id      Int @id
+               id      Int @id @default(autoincrement())
             }
 
             model Post {
                test    String
-               user_id Int @id
+               user_id Int @id @default(autoincrement())
             }
         "#;
     let result = dbg!(api.introspect().await);
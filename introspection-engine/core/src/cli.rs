@@ -9,4 +9,28 @@ pub fn clap_app() -> clap::App<'static, 'static> {
                 .takes_value(false)
                 .required(false),
         )
+        .arg(
+            Arg::with_name("ddl")
+                .long("ddl")
+                .value_name("FILE")
+                .help("Introspects a SQL DDL script, e.g. from `pg_dump --schema-only`, and prints the datamodel")
+                .takes_value(true)
+                .required(false)
+                .requires("provider"),
+        )
+        .arg(
+            Arg::with_name("provider")
+                .long("provider")
+                .help("The SQL dialect of the DDL script")
+                .takes_value(true)
+                .possible_values(&["postgresql", "mysql", "sqlite"])
+                .required(false),
+        )
+        .arg(
+            Arg::with_name("schema_name")
+                .long("schema-name")
+                .help("The schema the tables of the DDL script belong to, `public` by default on Postgres")
+                .takes_value(true)
+                .required(false),
+        )
 }
@@ -10,6 +10,8 @@ pub enum CoreError {
     ConnectorError(#[fail(cause)] introspection_connector::ConnectorError),
     #[fail(display = "Datamodel rendering failed: {}", _0)]
     DatamodelRendering(datamodel::error::ErrorCollection),
+    #[fail(display = "The given schema is invalid: {}", _0)]
+    InvalidSchema(datamodel::error::ErrorCollection),
 }
 
 impl From<ConnectorError> for CoreError {
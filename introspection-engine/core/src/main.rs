@@ -1,6 +1,7 @@
 pub mod cli;
 mod connector_loader;
 mod error;
+mod reintrospection;
 mod rpc;
 
 #[cfg(test)]
@@ -15,6 +16,12 @@ fn main() {
 
     if matches.is_present("version") {
         println!(env!("GIT_HASH"));
+    } else if let Some(ddl_path) = matches.value_of("ddl") {
+        introspect_ddl_file(
+            ddl_path,
+            matches.value_of("provider").unwrap(),
+            matches.value_of("schema_name"),
+        );
     } else {
         let mut io_handler = IoHandler::new();
         io_handler.extend_with(RpcImpl::new().to_delegate());
@@ -29,6 +36,30 @@ fn main() {
     }
 }
 
+fn introspect_ddl_file(path: &str, provider: &str, schema_name: Option<&str>) {
+    let ddl = match std::fs::read_to_string(path) {
+        Ok(ddl) => ddl,
+        Err(err) => {
+            eprintln!("Could not read `{}`: {}", path, err);
+            std::process::exit(1);
+        }
+    };
+
+    match RpcImpl::introspect_ddl_internal(&ddl, provider, schema_name, Default::default(), None, false) {
+        Ok(output) => {
+            for warning in output.warnings {
+                eprintln!("warning: {}", warning.message);
+            }
+
+            print!("{}", output.datamodel);
+        }
+        Err(err) => {
+            eprintln!("{}", err.message);
+            std::process::exit(1);
+        }
+    }
+}
+
 fn init_logger() {
     use tracing_subscriber::{EnvFilter, FmtSubscriber};
 
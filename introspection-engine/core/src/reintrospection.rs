@@ -0,0 +1,337 @@
+use datamodel::{Datamodel, Field, FieldType, Model};
+use std::collections::HashMap;
+
+/// Merges a freshly introspected datamodel into the datamodel of the schema file the user already has.
+///
+/// Models, enums and fields are matched by their names in the database, back relation fields by the
+/// relation they belong to. For everything that still exists in the database, the names, `@map`s,
+/// relation names, `@updatedAt` and defaults the database does not know about (e.g. `@default(cuid())`)
+/// are kept from the previous datamodel, and so is documentation where the database has no comment.
+/// Everything else comes from the database, and things that are gone from the database are gone from
+/// the result.
+pub(crate) fn merge_datamodels(previous: &Datamodel, mut calculated: Datamodel) -> Datamodel {
+    let enum_names = merge_enums(previous, &mut calculated);
+    let model_matches = match_models(previous, &calculated);
+    let relation_names = relation_names(previous, &calculated, &model_matches);
+
+    // Calculated datamodels refer to models by table name and to fields by column name.
+    let mut model_names: HashMap<String, String> = HashMap::new();
+    let mut field_names: HashMap<String, HashMap<String, String>> = HashMap::new();
+
+    for (model, model_match) in calculated.models().zip(model_matches.iter()) {
+        let mut names = HashMap::new();
+
+        if let Some(model_match) = model_match {
+            let previous_model = &previous.models[model_match.previous];
+            model_names.insert(model.name.clone(), previous_model.name.clone());
+            model_names.insert(database_name(model), previous_model.name.clone());
+
+            for (field, previous_field) in model.fields().zip(model_match.fields.iter()) {
+                if let Some(previous_field) = previous_field {
+                    let previous_field = &previous_model.fields[*previous_field];
+                    names.insert(field.name.clone(), previous_field.name.clone());
+                    names.insert(column_name(field), previous_field.name.clone());
+                }
+            }
+        }
+
+        field_names.insert(database_name(model), names.clone());
+        field_names.insert(model.name.clone(), names);
+    }
+
+    for (model, model_match) in calculated.models.iter_mut().zip(model_matches.iter()) {
+        let own_field_names = field_names.get(&model.name).cloned().unwrap_or_default();
+
+        for field in model.fields_mut() {
+            match &mut field.field_type {
+                FieldType::Relation(info) => {
+                    if let Some(target_field_names) = field_names.get(&info.to) {
+                        for to_field in info.to_fields.iter_mut() {
+                            rename(to_field, target_field_names);
+                        }
+                    }
+
+                    rename(&mut info.to, &model_names);
+                    rename(&mut info.name, &relation_names);
+                }
+                FieldType::Enum(enum_name) => rename(enum_name, &enum_names),
+                _ => (),
+            }
+        }
+
+        for index in model.indexes.iter_mut() {
+            for field_name in index.fields.iter_mut() {
+                rename(field_name, &own_field_names);
+            }
+        }
+
+        for field_name in model.id_fields.iter_mut() {
+            rename(field_name, &own_field_names);
+        }
+
+        let model_match = match model_match {
+            Some(model_match) => model_match,
+            None => continue,
+        };
+
+        let previous_model = &previous.models[model_match.previous];
+        model.name = previous_model.name.clone();
+        model.database_name = previous_model.database_name.clone();
+
+        if model.documentation.is_none() {
+            model.documentation = previous_model.documentation.clone();
+        }
+
+        for (field, previous_field) in model.fields.iter_mut().zip(model_match.fields.iter()) {
+            if let Some(previous_field) = previous_field {
+                merge_field(field, &previous_model.fields[*previous_field]);
+            }
+        }
+
+        // Keep the order of the schema file, new fields go last.
+        let mut fields: Vec<(Option<usize>, Field)> =
+            model_match.fields.iter().cloned().zip(model.fields.drain(..)).collect();
+        fields.sort_by_key(|(previous_field, _)| previous_field.unwrap_or(std::usize::MAX));
+        model.fields = fields.into_iter().map(|(_, field)| field).collect();
+    }
+
+    let mut models: Vec<(Option<usize>, Model)> = model_matches
+        .iter()
+        .map(|model_match| model_match.as_ref().map(|model_match| model_match.previous))
+        .zip(calculated.models.drain(..))
+        .collect();
+    models.sort_by_key(|(previous_model, _)| previous_model.unwrap_or(std::usize::MAX));
+    calculated.models = models.into_iter().map(|(_, model)| model).collect();
+
+    calculated
+}
+
+/// A calculated model that already exists in the previous datamodel.
+struct ModelMatch {
+    /// The index of the model in the previous datamodel.
+    previous: usize,
+    /// For each field of the calculated model, the index of the matching field in the previous model.
+    fields: Vec<Option<usize>>,
+}
+
+fn match_models(previous: &Datamodel, calculated: &Datamodel) -> Vec<Option<ModelMatch>> {
+    let mut matches: Vec<Option<ModelMatch>> = calculated
+        .models()
+        .map(|model| {
+            let table_name = database_name(model);
+            let previous_index = previous
+                .models()
+                .position(|previous_model| database_name(previous_model) == table_name)?;
+            let previous_model = &previous.models[previous_index];
+
+            let fields = model
+                .fields()
+                .map(|field| {
+                    if !has_column(calculated, model, field) {
+                        return None;
+                    }
+
+                    let column = column_name(field);
+
+                    previous_model.fields().position(|previous_field| {
+                        has_column(previous, previous_model, previous_field)
+                            && column_name(previous_field) == column
+                            && previous_field.field_type.is_relation() == field.field_type.is_relation()
+                    })
+                })
+                .collect();
+
+            Some(ModelMatch {
+                previous: previous_index,
+                fields,
+            })
+        })
+        .collect();
+
+    let mut model_names: HashMap<String, String> = HashMap::new();
+
+    for (model, model_match) in calculated.models().zip(matches.iter()) {
+        if let Some(model_match) = model_match {
+            let previous_name = &previous.models[model_match.previous].name;
+            model_names.insert(model.name.clone(), previous_name.clone());
+            model_names.insert(database_name(model), previous_name.clone());
+        }
+    }
+
+    let inline_relation_names = relation_names(previous, calculated, &matches);
+
+    for (model, model_match) in calculated.models().zip(matches.iter_mut()) {
+        let model_match = match model_match {
+            Some(model_match) => model_match,
+            None => continue,
+        };
+        let previous_model = &previous.models[model_match.previous];
+
+        for (field_index, field) in model.fields().enumerate() {
+            let info = match &field.field_type {
+                FieldType::Relation(info) if model_match.fields[field_index].is_none() => info,
+                _ => continue,
+            };
+
+            if has_column(calculated, model, field) {
+                continue;
+            }
+
+            let target = match model_names.get(&info.to) {
+                Some(target) => target,
+                None => continue,
+            };
+
+            let relation_name = inline_relation_names.get(&info.name).unwrap_or(&info.name);
+
+            let candidates: Vec<usize> = previous_model
+                .fields()
+                .enumerate()
+                .filter(|(previous_index, previous_field)| {
+                    !model_match.fields.contains(&Some(*previous_index))
+                        && !has_column(previous, previous_model, previous_field)
+                        && match &previous_field.field_type {
+                            FieldType::Relation(previous_info) => &previous_info.to == target,
+                            _ => false,
+                        }
+                })
+                .map(|(previous_index, _)| previous_index)
+                .collect();
+
+            let same_relation =
+                candidates.iter().find(
+                    |previous_index| match &previous_model.fields[**previous_index].field_type {
+                        FieldType::Relation(previous_info) => &previous_info.name == relation_name,
+                        _ => false,
+                    },
+                );
+
+            let unmatched_in_calculated = model
+                .fields()
+                .enumerate()
+                .filter(|(index, other)| {
+                    model_match.fields[*index].is_none()
+                        && !has_column(calculated, model, other)
+                        && match &other.field_type {
+                            FieldType::Relation(other_info) => other_info.to == info.to,
+                            _ => false,
+                        }
+                })
+                .count();
+
+            model_match.fields[field_index] = match same_relation {
+                Some(previous_index) => Some(*previous_index),
+                None if candidates.len() == 1 && unmatched_in_calculated == 1 => Some(candidates[0]),
+                None => None,
+            };
+        }
+    }
+
+    matches
+}
+
+/// The relation names of the previous datamodel, by the relation names of the calculated datamodel.
+fn relation_names(
+    previous: &Datamodel,
+    calculated: &Datamodel,
+    model_matches: &[Option<ModelMatch>],
+) -> HashMap<String, String> {
+    let mut names = HashMap::new();
+
+    for (model, model_match) in calculated.models().zip(model_matches.iter()) {
+        let model_match = match model_match {
+            Some(model_match) => model_match,
+            None => continue,
+        };
+        let previous_model = &previous.models[model_match.previous];
+
+        for (field, previous_field) in model.fields().zip(model_match.fields.iter()) {
+            let previous_field = match previous_field {
+                Some(previous_field) => &previous_model.fields[*previous_field],
+                None => continue,
+            };
+
+            if let (FieldType::Relation(info), FieldType::Relation(previous_info)) =
+                (&field.field_type, &previous_field.field_type)
+            {
+                names
+                    .entry(info.name.clone())
+                    .or_insert_with(|| previous_info.name.clone());
+            }
+        }
+    }
+
+    names
+}
+
+/// Keeps the names and documentation of enums that still exist. Returns the enum renames.
+fn merge_enums(previous: &Datamodel, calculated: &mut Datamodel) -> HashMap<String, String> {
+    let mut names = HashMap::new();
+
+    for calculated_enum in calculated.enums_mut() {
+        let enum_name = calculated_enum
+            .database_name
+            .clone()
+            .unwrap_or_else(|| calculated_enum.name.clone());
+        let previous_enum = previous
+            .enums()
+            .find(|previous_enum| previous_enum.database_name.as_ref().unwrap_or(&previous_enum.name) == &enum_name);
+
+        if let Some(previous_enum) = previous_enum {
+            names.insert(calculated_enum.name.clone(), previous_enum.name.clone());
+            calculated_enum.name = previous_enum.name.clone();
+            calculated_enum.database_name = previous_enum.database_name.clone();
+            calculated_enum.documentation = previous_enum.documentation.clone();
+        }
+    }
+
+    names
+}
+
+fn merge_field(field: &mut Field, previous_field: &Field) {
+    field.name = previous_field.name.clone();
+    field.database_name = previous_field.database_name.clone();
+    field.is_updated_at = previous_field.is_updated_at;
+
+    if field.default_value.is_none() && !field.field_type.is_relation() {
+        field.default_value = previous_field.default_value.clone();
+    }
+
+    if field.documentation.is_none() {
+        field.documentation = previous_field.documentation.clone();
+    }
+}
+
+/// Back relation fields have no column. In calculated datamodels, their `to_fields` point at the field
+/// holding the foreign key instead of being empty.
+fn has_column(datamodel: &Datamodel, model: &Model, field: &Field) -> bool {
+    match &field.field_type {
+        FieldType::Relation(info) => {
+            field.arity.is_singular()
+                && !info.to_fields.is_empty()
+                && datamodel
+                    .related_field(&model.name, &info.to, &info.name, &field.name)
+                    .map(|related| info.to_fields.len() != 1 || info.to_fields[0] != related.name)
+                    .unwrap_or(true)
+        }
+        _ => true,
+    }
+}
+
+fn database_name(model: &Model) -> String {
+    model.database_name.clone().unwrap_or_else(|| model.name.clone())
+}
+
+/// Relation fields over compound foreign keys are identified by all of their columns.
+fn column_name(field: &Field) -> String {
+    match &field.field_type {
+        FieldType::Relation(info) if !info.columns.is_empty() => info.columns.join(","),
+        _ => field.database_name.clone().unwrap_or_else(|| field.name.clone()),
+    }
+}
+
+fn rename(name: &mut String, names: &HashMap<String, String>) {
+    if let Some(new_name) = names.get(name) {
+        *name = new_name.clone();
+    }
+}
@@ -1,6 +1,8 @@
 use crate::connector_loader::load_connector;
 use crate::error::CoreError;
-use introspection_connector::DatabaseMetadata;
+use crate::reintrospection::merge_datamodels;
+use datamodel::{Configuration, Datamodel};
+use introspection_connector::{DatabaseMetadata, IntrospectionOptions, IntrospectionResult, Warning};
 use jsonrpc_core::*;
 use jsonrpc_derive::rpc;
 use serde_derive::*;
@@ -17,7 +19,10 @@ pub trait Rpc {
     fn get_database_metadata(&self, url: UrlInput) -> Result<DatabaseMetadata>;
 
     #[rpc(name = "introspect")]
-    fn introspect(&self, url: UrlInput) -> Result<String>;
+    fn introspect(&self, input: IntrospectionInput) -> Result<IntrospectionOutput>;
+
+    #[rpc(name = "introspectDdl")]
+    fn introspect_ddl(&self, input: DdlIntrospectionInput) -> Result<IntrospectionOutput>;
 }
 
 pub(crate) struct RpcImpl {
@@ -33,8 +38,30 @@ impl Rpc for RpcImpl {
         self.block_on(Self::get_database_metadata_internal(&url.url))
     }
 
-    fn introspect(&self, url: UrlInput) -> Result<String> {
-        self.block_on(Self::introspect_internal(&url.url).instrument(tracing::info_span!("Introspect", ?url)))
+    fn introspect(&self, input: IntrospectionInput) -> Result<IntrospectionOutput> {
+        self.block_on(
+            Self::introspect_internal(
+                &input.url,
+                input.settings.options(),
+                input.settings.schema.as_ref().map(String::as_str),
+                input.settings.detailed,
+            )
+            .instrument(tracing::info_span!("Introspect", ?input)),
+        )
+    }
+
+    fn introspect_ddl(&self, input: DdlIntrospectionInput) -> Result<IntrospectionOutput> {
+        let span = tracing::info_span!("IntrospectDdl", provider = input.provider.as_str());
+        let _guard = span.enter();
+
+        Self::introspect_ddl_internal(
+            &input.ddl,
+            &input.provider,
+            input.schema_name.as_ref().map(String::as_str),
+            input.settings.options(),
+            input.settings.schema.as_ref().map(String::as_str),
+            input.settings.detailed,
+        )
     }
 }
 
@@ -45,10 +72,42 @@ impl RpcImpl {
         }
     }
 
-    pub(crate) async fn introspect_internal(connection_string: &str) -> Result<String> {
+    pub(crate) async fn introspect_internal(
+        connection_string: &str,
+        options: IntrospectionOptions,
+        schema: Option<&str>,
+        detailed: bool,
+    ) -> Result<IntrospectionOutput> {
+        let previous_data_model = parse_previous_schema(schema)?;
         let connector = load_connector(connection_string).await?;
-        let data_model = connector.introspect().await.map_err(CoreError::from)?;
-        Ok(datamodel::render_datamodel_to_string(&data_model).map_err(CoreError::from)?)
+        let result = connector
+            .introspect_with_options(options)
+            .await
+            .map_err(CoreError::from)?;
+
+        let mut output = introspection_output(previous_data_model, result, schema, detailed)?;
+
+        if let Some(details) = output.details.as_mut() {
+            details.metadata = Some(connector.get_metadata().await.map_err(CoreError::from)?);
+        }
+
+        Ok(output)
+    }
+
+    /// Introspects a SQL DDL script, e.g. the output of `pg_dump --schema-only`, instead of a live database.
+    pub(crate) fn introspect_ddl_internal(
+        ddl: &str,
+        provider: &str,
+        schema_name: Option<&str>,
+        options: IntrospectionOptions,
+        schema: Option<&str>,
+        detailed: bool,
+    ) -> Result<IntrospectionOutput> {
+        let previous_data_model = parse_previous_schema(schema)?;
+        let result = sql_introspection_connector::introspect_ddl(ddl, provider, schema_name, options)
+            .map_err(CoreError::from)?;
+
+        introspection_output(previous_data_model, result, schema, detailed)
     }
 
     pub(crate) async fn list_databases_internal(connection_string: &str) -> Result<Vec<String>> {
@@ -71,7 +130,142 @@ impl RpcImpl {
     }
 }
 
+fn parse_previous_schema(schema: Option<&str>) -> Result<Option<Datamodel>> {
+    match schema {
+        Some(schema) => Ok(Some(
+            datamodel::parse_datamodel(schema).map_err(CoreError::InvalidSchema)?,
+        )),
+        None => Ok(None),
+    }
+}
+
+fn introspection_output(
+    previous_data_model: Option<Datamodel>,
+    result: IntrospectionResult,
+    schema: Option<&str>,
+    detailed: bool,
+) -> Result<IntrospectionOutput> {
+    let data_model = match previous_data_model {
+        Some(previous_data_model) => merge_datamodels(&previous_data_model, result.datamodel),
+        None => result.datamodel,
+    };
+
+    let details = if detailed {
+        Some(introspection_details(&data_model, schema)?)
+    } else {
+        None
+    };
+
+    Ok(IntrospectionOutput {
+        datamodel: datamodel::render_datamodel_to_string(&data_model).map_err(CoreError::from)?,
+        warnings: result.warnings,
+        details,
+    })
+}
+
+/// The configuration blocks come from the current schema file, if there is one.
+fn introspection_details(data_model: &Datamodel, schema: Option<&str>) -> Result<IntrospectionDetails> {
+    let config = match schema {
+        Some(schema) => datamodel::parse_configuration(schema).map_err(CoreError::InvalidSchema)?,
+        None => Configuration {
+            datasources: Vec::new(),
+            generators: Vec::new(),
+        },
+    };
+
+    Ok(IntrospectionDetails {
+        schema: datamodel::render_datamodel_and_config_to_string(data_model, &config).map_err(CoreError::from)?,
+        config: datamodel::json::mcf::config_to_mcf_json_value(&config),
+        dmmf: datamodel::json::dmmf::render_to_dmmf_value(data_model),
+        metadata: None,
+    })
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UrlInput {
     pub(crate) url: String,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntrospectionInput {
+    pub(crate) url: String,
+    #[serde(flatten)]
+    pub(crate) settings: IntrospectionSettings,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DdlIntrospectionInput {
+    /// The DDL script, e.g. the output of `pg_dump --schema-only` or `mysqldump --no-data`.
+    pub(crate) ddl: String,
+    /// The dialect of the script: `postgresql`, `mysql` or `sqlite`.
+    pub(crate) provider: String,
+    /// The schema the unqualified tables of the script belong to. Defaults to `public` on Postgres.
+    #[serde(default)]
+    pub(crate) schema_name: Option<String>,
+    #[serde(flatten)]
+    pub(crate) settings: IntrospectionSettings,
+}
+
+/// The settings shared by all introspection methods.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntrospectionSettings {
+    /// Annotate fields with the native types of their columns, e.g. `@db.VarChar(255)`.
+    #[serde(default)]
+    pub(crate) native_types: bool,
+    /// Name models in singular PascalCase and fields in camelCase, with `@@map`/`@map` to the original names.
+    #[serde(default)]
+    pub(crate) naming_conventions: bool,
+    /// The current schema file. Names and annotations of things that still exist in the database are kept.
+    #[serde(default)]
+    pub(crate) schema: Option<String>,
+    /// Glob patterns (`*` and `?`) of the tables to introspect, e.g. `["billing_*"]`. All tables if empty.
+    #[serde(default)]
+    pub(crate) include_tables: Vec<String>,
+    /// Glob patterns of the tables to leave out.
+    #[serde(default)]
+    pub(crate) exclude_tables: Vec<String>,
+    /// Further Postgres schemas to introspect. Their models are assigned to them with e.g. `@@schema("billing")`.
+    #[serde(default)]
+    pub(crate) schemas: Vec<String>,
+    /// Also return the configuration, the DMMF and the database metadata, see `IntrospectionDetails`.
+    #[serde(default)]
+    pub(crate) detailed: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IntrospectionOutput {
+    /// The rendered datamodel.
+    pub(crate) datamodel: String,
+    /// What the datamodel does not represent.
+    pub(crate) warnings: Vec<Warning>,
+    /// Only when `detailed` is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) details: Option<IntrospectionDetails>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IntrospectionDetails {
+    /// The rendered datamodel together with the datasource and generator blocks of the current schema file.
+    pub(crate) schema: String,
+    /// The datasources and generators of the current schema file, in the format of `getConfig`.
+    pub(crate) config: serde_json::Value,
+    /// The DMMF of the datamodel.
+    pub(crate) dmmf: serde_json::Value,
+    /// The size of the introspected database. Not available for DDL scripts.
+    pub(crate) metadata: Option<DatabaseMetadata>,
+}
+
+impl IntrospectionSettings {
+    pub(crate) fn options(&self) -> IntrospectionOptions {
+        IntrospectionOptions {
+            native_types: self.native_types,
+            naming_conventions: self.naming_conventions,
+            include_tables: self.include_tables.clone(),
+            exclude_tables: self.exclude_tables.clone(),
+            schemas: self.schemas.clone(),
+        }
+    }
+}
@@ -1 +1,3 @@
 mod error_tests;
+mod introspection_details_tests;
+mod reintrospection_tests;
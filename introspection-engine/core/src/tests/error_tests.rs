@@ -1,4 +1,5 @@
 use crate::rpc::RpcImpl;
+use introspection_connector::IntrospectionOptions;
 use pretty_assertions::assert_eq;
 use serde_json::json;
 use test_setup::*;
@@ -10,7 +11,9 @@ async fn unreachable_database_must_return_a_proper_error_on_mysql() {
 
     url.set_port(Some(8787)).unwrap();
 
-    let error = RpcImpl::introspect_internal(url.as_str()).await.unwrap_err();
+    let error = RpcImpl::introspect_internal(url.as_str(), IntrospectionOptions::default(), None, false)
+        .await
+        .unwrap_err();
 
     let port = url.port().unwrap();
     let host = url.host().unwrap().to_string();
@@ -28,3 +31,28 @@ async fn unreachable_database_must_return_a_proper_error_on_mysql() {
 
     assert_eq!(json_error, expected);
 }
+
+#[test]
+fn unknown_ddl_providers_must_return_a_proper_error() {
+    let error = RpcImpl::introspect_ddl_internal(
+        "CREATE TABLE a (id integer);",
+        "oracle",
+        None,
+        IntrospectionOptions::default(),
+        None,
+        false,
+    )
+    .unwrap_err();
+
+    let json_error = serde_json::to_value(error.data.unwrap()).unwrap();
+    let expected = json!({
+        "is_panic": false,
+        "message": "Introspection operation failed to produce a schema file: Unknown provider `oracle`, expected postgresql, mysql or sqlite.",
+        "meta": {
+            "introspection_error": "Unknown provider `oracle`, expected postgresql, mysql or sqlite.",
+        },
+        "error_code": "P4000"
+    });
+
+    assert_eq!(json_error, expected);
+}
@@ -0,0 +1,59 @@
+use crate::rpc::RpcImpl;
+use introspection_connector::IntrospectionOptions;
+use pretty_assertions::assert_eq;
+
+const DDL: &str = r#"
+    CREATE TABLE "User" (
+        id serial PRIMARY KEY,
+        name text NOT NULL
+    );
+"#;
+
+const SCHEMA: &str = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://localhost:5432/blog"
+    }
+
+    generator client {
+        provider = "prisma-client-js"
+    }
+
+    model User {
+        id Int @id @default(autoincrement())
+    }
+"#;
+
+#[test]
+fn detailed_introspection_must_return_the_configuration_and_the_dmmf() {
+    let output = RpcImpl::introspect_ddl_internal(
+        DDL,
+        "postgresql",
+        None,
+        IntrospectionOptions::default(),
+        Some(SCHEMA),
+        true,
+    )
+    .unwrap();
+
+    let details = output.details.expect("details must be returned");
+
+    assert!(details.schema.contains("datasource db {"));
+    assert!(details.schema.contains("generator client {"));
+    assert!(details.schema.contains("model User {"));
+    assert_eq!(details.config["datasources"][0]["name"], "db");
+    assert_eq!(details.config["generators"][0]["name"], "client");
+    assert_eq!(details.dmmf["models"][0]["name"], "User");
+    assert_eq!(details.dmmf["models"][0]["fields"].as_array().unwrap().len(), 2);
+    assert!(details.metadata.is_none());
+}
+
+#[test]
+fn introspection_must_only_return_details_when_asked_for() {
+    let output =
+        RpcImpl::introspect_ddl_internal(DDL, "postgresql", None, IntrospectionOptions::default(), None, false)
+            .unwrap();
+
+    assert!(output.details.is_none());
+    assert!(output.datamodel.contains("model User {"));
+}
@@ -0,0 +1,133 @@
+use crate::reintrospection::merge_datamodels;
+use datamodel::{Datamodel, FieldType};
+use pretty_assertions::assert_eq;
+
+const PREVIOUS: &str = r#"
+    /// A user of the blog.
+    model User {
+        id           String   @id @default(cuid())
+        /// Where we send the newsletter.
+        emailAddress String   @unique @map("email")
+        articles     Post[]   @relation("Authorship")
+        updatedAt    DateTime @updatedAt
+        legacy       String
+        @@map("users")
+    }
+
+    model Post {
+        id     Int  @id
+        writer User @relation("Authorship") @map("author")
+        @@map("posts")
+    }
+
+    /// Posts can be tagged.
+    model Tag {
+        id Int @id
+    }
+"#;
+
+const CALCULATED: &str = r#"
+    model users {
+        id        String   @id
+        email     String   @unique
+        nickname  String?
+        updatedAt DateTime
+        posts     posts[]
+    }
+
+    model posts {
+        id     Int   @id
+        author users
+    }
+
+    model comments {
+        id Int @id
+    }
+"#;
+
+fn merge(previous: &str, calculated: &str) -> (Datamodel, Datamodel) {
+    let previous = datamodel::parse_datamodel(previous).unwrap();
+    let calculated = datamodel::parse_datamodel(calculated).unwrap();
+    let merged = merge_datamodels(&previous, calculated);
+
+    (previous, merged)
+}
+
+#[test]
+fn reintrospection_must_keep_model_and_field_names() {
+    let (_, merged) = merge(PREVIOUS, CALCULATED);
+
+    let user = merged.find_model("User").unwrap();
+    assert_eq!(user.database_name.as_ref().unwrap(), "users");
+    assert_eq!(user.documentation.as_ref().unwrap(), "A user of the blog.");
+
+    let email = user.find_field("emailAddress").unwrap();
+    assert_eq!(email.database_name.as_ref().unwrap(), "email");
+    assert_eq!(email.documentation.as_ref().unwrap(), "Where we send the newsletter.");
+
+    let post = merged.find_model("Post").unwrap();
+    assert_eq!(post.database_name.as_ref().unwrap(), "posts");
+    assert!(merged.find_model("users").is_none());
+    assert!(merged.find_model("posts").is_none());
+}
+
+#[test]
+fn reintrospection_must_keep_relation_field_names() {
+    let (_, merged) = merge(PREVIOUS, CALCULATED);
+
+    let user = merged.find_model("User").unwrap();
+    assert!(user.find_field("posts").is_none());
+
+    match &user.find_field("articles").unwrap().field_type {
+        FieldType::Relation(info) => {
+            assert_eq!(info.to, "Post");
+            assert_eq!(info.name, "Authorship");
+        }
+        other => panic!("Expected a relation field, got {:?}", other),
+    }
+
+    let writer = merged.find_model("Post").unwrap().find_field("writer").unwrap();
+    assert_eq!(writer.database_name.as_ref().unwrap(), "author");
+
+    match &writer.field_type {
+        FieldType::Relation(info) => {
+            assert_eq!(info.to, "User");
+            assert_eq!(info.name, "Authorship");
+            assert_eq!(info.to_fields, vec!["id".to_owned()]);
+        }
+        other => panic!("Expected a relation field, got {:?}", other),
+    }
+
+    // The merged datamodel must still be valid.
+    let rendered = datamodel::render_datamodel_to_string(&merged).unwrap();
+    datamodel::parse_datamodel(&rendered).unwrap();
+}
+
+#[test]
+fn reintrospection_must_keep_annotations_the_database_does_not_know_about() {
+    let (previous, merged) = merge(PREVIOUS, CALCULATED);
+
+    let user = merged.find_model("User").unwrap();
+    let previous_user = previous.find_model("User").unwrap();
+
+    let id = user.find_field("id").unwrap();
+    assert!(id.default_value.is_some());
+    assert_eq!(id.default_value, previous_user.find_field("id").unwrap().default_value);
+
+    assert!(user.find_field("updatedAt").unwrap().is_updated_at);
+}
+
+#[test]
+fn reintrospection_must_follow_the_database_for_added_and_removed_things() {
+    let (_, merged) = merge(PREVIOUS, CALCULATED);
+
+    let user = merged.find_model("User").unwrap();
+    let field_names: Vec<&str> = user.fields().map(|field| field.name.as_str()).collect();
+    assert_eq!(
+        field_names,
+        vec!["id", "emailAddress", "articles", "updatedAt", "nickname"]
+    );
+
+    assert!(merged.find_model("Tag").is_none());
+    assert!(merged.find_model("comments").is_some());
+}
@@ -1,4 +1,4 @@
-use super::{Connector, ScalarFieldType, ScalarType};
+use super::{error::ConnectorError, Connector, ScalarFieldType, ScalarType};
 
 #[derive(Debug)]
 pub struct DeclarativeConnector {
@@ -8,21 +8,52 @@ pub struct DeclarativeConnector {
 }
 
 impl Connector for DeclarativeConnector {
-    fn calculate_type(&self, name: &str, args: Vec<i32>) -> Option<ScalarFieldType> {
+    fn calculate_type(&self, name: &str, args: Vec<i32>) -> Result<ScalarFieldType, ConnectorError> {
         match self.get_type_alias(name) {
             Some(alias) => self.calculate_type(&alias.aliased_to, args),
-            None => self.get_field_type_constructor(&name).map(|constructor| {
-                let datasource_type = constructor.datasource_type(&args);
+            None => {
+                let constructor = self
+                    .get_field_type_constructor(&name)
+                    .ok_or_else(|| ConnectorError::UnknownNativeType { name: name.to_string() })?;
 
-                ScalarFieldType {
-                    name: name.to_string(),
-                    prisma_type: constructor.prisma_type,
-                    datasource_type,
+                if constructor.number_of_args != args.len() {
+                    return Err(ConnectorError::WrongNumberOfArguments {
+                        name: name.to_string(),
+                        expected: constructor.number_of_args,
+                        given: args.len(),
+                    });
                 }
-            }),
+
+                let datasource_type = constructor.datasource_type(&args);
+
+                Ok(ScalarFieldType::with_args(
+                    &constructor.name,
+                    args,
+                    constructor.prisma_type,
+                    &datasource_type,
+                ))
+            }
         }
     }
 
+    fn introspect_type(&self, datasource_type: &str) -> Option<ScalarFieldType> {
+        let (base_type, args) = parse_datasource_type(datasource_type)?;
+
+        self.field_type_constructors
+            .iter()
+            .find(|constructor| {
+                constructor.datasource_type.eq_ignore_ascii_case(&base_type) && constructor.number_of_args == args.len()
+            })
+            .map(|constructor| {
+                ScalarFieldType::with_args(
+                    &constructor.name,
+                    args.clone(),
+                    constructor.prisma_type,
+                    &constructor.datasource_type(&args),
+                )
+            })
+    }
+
     fn supports_scalar_lists(&self) -> bool {
         self.supports_scalar_lists
     }
@@ -38,6 +69,24 @@ impl DeclarativeConnector {
     }
 }
 
+/// Splits a type as reported by the database, e.g. `varchar(255)`, into its base type and arguments.
+fn parse_datasource_type(datasource_type: &str) -> Option<(String, Vec<i32>)> {
+    let datasource_type = datasource_type.trim();
+
+    match datasource_type.find('(') {
+        Some(start) => {
+            let end = datasource_type.rfind(')')?;
+            let args = datasource_type[start + 1..end]
+                .split(',')
+                .map(|arg| arg.trim().parse::<i32>().ok())
+                .collect::<Option<Vec<i32>>>()?;
+
+            Some((datasource_type[..start].trim().to_string(), args))
+        }
+        None => Some((datasource_type.to_string(), Vec::new())),
+    }
+}
+
 #[derive(Debug)]
 pub struct TypeAlias {
     name: String,
@@ -1,2 +1,29 @@
-#[allow(unused)]
-enum ConnectorError {}
+use std::fmt;
+
+/// Errors raised by a connector while resolving a native type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConnectorError {
+    /// The connector does not know a native type with the given name.
+    UnknownNativeType { name: String },
+    /// The native type was given the wrong number of arguments.
+    WrongNumberOfArguments {
+        name: String,
+        expected: usize,
+        given: usize,
+    },
+}
+
+impl fmt::Display for ConnectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConnectorError::UnknownNativeType { name } => write!(f, "Native type {} is not supported.", name),
+            ConnectorError::WrongNumberOfArguments { name, expected, given } => write!(
+                f,
+                "Native type {} takes {} argument(s), but received {}.",
+                name, expected, given
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConnectorError {}
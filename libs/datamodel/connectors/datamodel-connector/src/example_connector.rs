@@ -13,58 +13,53 @@ impl ExampleConnector {
 
     pub fn postgres() -> DeclarativeConnector {
         let type_aliases = vec![
-            // standard types
-            TypeAlias::new("String", "Text"),
-            //            TypeAlias::new("Boolean", "Boolean"),
-            TypeAlias::new("Int", "Integer"),
-            TypeAlias::new("Float", "Real"),
-            TypeAlias::new("DateTime", "Timestamp"),
-            // custom types
             TypeAlias::new("Int8", "BigInt"),
-            TypeAlias::new("Serial8", "BigSerial"),
             TypeAlias::new("Float8", "DoublePrecision"),
-            TypeAlias::new("Int", "Integer"),
             TypeAlias::new("Int4", "Integer"),
             TypeAlias::new("Decimal", "Numeric"),
             TypeAlias::new("Float4", "Real"),
             TypeAlias::new("Int2", "SmallInt"),
-            TypeAlias::new("Serial2", "SmallSerial"),
-            TypeAlias::new("Serial4", "Serial"),
-            TypeAlias::new("Char", "Character"),
-            TypeAlias::new("VarChar", "CharacterVarying"),
-            TypeAlias::new("TimestampTZ", "TimestampWithTimeZone"),
+            TypeAlias::new("Character", "Char"),
+            TypeAlias::new("CharacterVarying", "VarChar"),
+            TypeAlias::new("TimestampTZ", "Timestamptz"),
+            TypeAlias::new("TimestampWithTimeZone", "Timestamptz"),
             TypeAlias::new("Bool", "Boolean"),
-            TypeAlias::new("VarBit", "BitVarying"),
         ];
-        // missing because of interpolation:
-        // Numeric, Character, CharacterVarying, Timestamp, TimestampWithTimeZone, Time
-        // Bit, BitVarying
+        // The datasource types are the type names Postgres reports in `udt_name`, so
+        // that introspected columns can be mapped back to their native type.
+        //
+        // Serial types are left out, as they are described as their integer type and
+        // are covered by `@default(autoincrement())`.
         //
         // types for which photon types are unclear:
-        // ByteA, Date, TimeTZ
+        // TimeTZ, Interval, Bit, VarBit, Money, PGLSN
         // Point, Line, LSeg, Box, Path, Polygon, Circle
-        // CIDR, INet, Macaddr
+        // CIDR, Macaddr
         // TSVector, TSQuery
-        // UUID
-        // XML, JSON, JSONB
         // Int4Range, Int8Range, NumRange, TSRange, TSTZRange, DateRange
         // TXIDSnapshot
         let field_type_constructors = vec![
-            FieldTypeConstructor::without_args("BigInt", "bigint", ScalarType::Int),
-            FieldTypeConstructor::without_args("BigSerial", "bigserial", ScalarType::Int),
-            FieldTypeConstructor::without_args("DoublePrecision", "double precision", ScalarType::Float),
-            FieldTypeConstructor::without_args("Integer", "integer", ScalarType::Int),
-            FieldTypeConstructor::without_args("Real", "real", ScalarType::Float),
-            FieldTypeConstructor::without_args("SmallInt", "smallint", ScalarType::Int),
-            FieldTypeConstructor::without_args("SmallSerial", "smallserial", ScalarType::Int),
-            FieldTypeConstructor::without_args("Serial", "serial", ScalarType::Int),
-            FieldTypeConstructor::without_args("Money", "money", ScalarType::Float),
+            FieldTypeConstructor::without_args("SmallInt", "int2", ScalarType::Int),
+            FieldTypeConstructor::without_args("Integer", "int4", ScalarType::Int),
+            FieldTypeConstructor::without_args("BigInt", "int8", ScalarType::Int),
+            FieldTypeConstructor::without_args("Real", "float4", ScalarType::Float),
+            FieldTypeConstructor::without_args("DoublePrecision", "float8", ScalarType::Float),
+            FieldTypeConstructor::with_args("Numeric", "numeric", ScalarType::Decimal, 2),
             FieldTypeConstructor::without_args("Text", "text", ScalarType::String),
-            FieldTypeConstructor::without_args("Char", "char", ScalarType::String),
+            FieldTypeConstructor::with_args("VarChar", "varchar", ScalarType::String, 1),
+            FieldTypeConstructor::with_args("Char", "bpchar", ScalarType::String, 1),
             FieldTypeConstructor::without_args("Name", "name", ScalarType::String),
-            FieldTypeConstructor::without_args("Boolean", "boolean", ScalarType::Boolean),
-            FieldTypeConstructor::without_args("Boolean", "boolean", ScalarType::Boolean),
-            FieldTypeConstructor::without_args("PGLSN", "pg_lsn", ScalarType::Int),
+            FieldTypeConstructor::without_args("Uuid", "uuid", ScalarType::Uuid),
+            FieldTypeConstructor::without_args("Xml", "xml", ScalarType::String),
+            FieldTypeConstructor::without_args("Inet", "inet", ScalarType::String),
+            FieldTypeConstructor::without_args("Boolean", "bool", ScalarType::Boolean),
+            FieldTypeConstructor::without_args("Timestamp", "timestamp", ScalarType::DateTime),
+            FieldTypeConstructor::without_args("Timestamptz", "timestamptz", ScalarType::DateTime),
+            FieldTypeConstructor::without_args("Date", "date", ScalarType::DateTime),
+            FieldTypeConstructor::without_args("Time", "time", ScalarType::DateTime),
+            FieldTypeConstructor::without_args("Json", "json", ScalarType::Json),
+            FieldTypeConstructor::without_args("JsonB", "jsonb", ScalarType::Json),
+            FieldTypeConstructor::without_args("ByteA", "bytea", ScalarType::Bytes),
         ];
         DeclarativeConnector {
             type_aliases,
@@ -72,4 +67,68 @@ impl ExampleConnector {
             supports_scalar_lists: true,
         }
     }
+
+    pub fn mysql() -> DeclarativeConnector {
+        let type_aliases = vec![
+            TypeAlias::new("Integer", "Int"),
+            TypeAlias::new("Numeric", "Decimal"),
+            TypeAlias::new("Real", "Double"),
+        ];
+        // The datasource types are the type names MySQL reports in `data_type`.
+        let field_type_constructors = vec![
+            FieldTypeConstructor::without_args("TinyInt", "tinyint", ScalarType::Int),
+            FieldTypeConstructor::without_args("SmallInt", "smallint", ScalarType::Int),
+            FieldTypeConstructor::without_args("MediumInt", "mediumint", ScalarType::Int),
+            FieldTypeConstructor::without_args("Int", "int", ScalarType::Int),
+            FieldTypeConstructor::without_args("BigInt", "bigint", ScalarType::Int),
+            FieldTypeConstructor::without_args("Float", "float", ScalarType::Float),
+            FieldTypeConstructor::without_args("Double", "double", ScalarType::Float),
+            FieldTypeConstructor::with_args("Decimal", "decimal", ScalarType::Decimal, 2),
+            FieldTypeConstructor::with_args("Char", "char", ScalarType::String, 1),
+            FieldTypeConstructor::with_args("VarChar", "varchar", ScalarType::String, 1),
+            FieldTypeConstructor::without_args("TinyText", "tinytext", ScalarType::String),
+            FieldTypeConstructor::without_args("Text", "text", ScalarType::String),
+            FieldTypeConstructor::without_args("MediumText", "mediumtext", ScalarType::String),
+            FieldTypeConstructor::without_args("LongText", "longtext", ScalarType::String),
+            FieldTypeConstructor::without_args("Date", "date", ScalarType::DateTime),
+            FieldTypeConstructor::without_args("Time", "time", ScalarType::DateTime),
+            FieldTypeConstructor::without_args("DateTime", "datetime", ScalarType::DateTime),
+            FieldTypeConstructor::without_args("Timestamp", "timestamp", ScalarType::DateTime),
+            FieldTypeConstructor::without_args("Json", "json", ScalarType::Json),
+            FieldTypeConstructor::with_args("Binary", "binary", ScalarType::Bytes, 1),
+            FieldTypeConstructor::with_args("VarBinary", "varbinary", ScalarType::Bytes, 1),
+            FieldTypeConstructor::without_args("TinyBlob", "tinyblob", ScalarType::Bytes),
+            FieldTypeConstructor::without_args("Blob", "blob", ScalarType::Bytes),
+            FieldTypeConstructor::without_args("MediumBlob", "mediumblob", ScalarType::Bytes),
+            FieldTypeConstructor::without_args("LongBlob", "longblob", ScalarType::Bytes),
+            // Uuid fields are stored as CHAR(36) by default. They can be stored in 16 bytes instead.
+            FieldTypeConstructor::without_args("BinaryUuid", "binary(16)", ScalarType::Uuid),
+        ];
+        DeclarativeConnector {
+            type_aliases,
+            field_type_constructors,
+            supports_scalar_lists: false,
+        }
+    }
+
+    pub fn sqlite() -> DeclarativeConnector {
+        // SQLite accepts any declared type and reports it as written, so these are the spellings
+        // we render ourselves.
+        let field_type_constructors = vec![
+            FieldTypeConstructor::without_args("Integer", "INTEGER", ScalarType::Int),
+            FieldTypeConstructor::without_args("Real", "REAL", ScalarType::Float),
+            FieldTypeConstructor::with_args("Decimal", "DECIMAL", ScalarType::Decimal, 2),
+            FieldTypeConstructor::without_args("Text", "TEXT", ScalarType::String),
+            FieldTypeConstructor::with_args("VarChar", "VARCHAR", ScalarType::String, 1),
+            FieldTypeConstructor::without_args("Boolean", "BOOLEAN", ScalarType::Boolean),
+            FieldTypeConstructor::without_args("Date", "DATE", ScalarType::DateTime),
+            FieldTypeConstructor::without_args("DateTime", "DATETIME", ScalarType::DateTime),
+            FieldTypeConstructor::without_args("Blob", "BLOB", ScalarType::Bytes),
+        ];
+        DeclarativeConnector {
+            type_aliases: vec![],
+            field_type_constructors,
+            supports_scalar_lists: false,
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use crate::error::ConnectorError;
 use crate::scalars::ScalarType;
 
 pub mod error;
@@ -10,7 +11,11 @@ pub use declarative_connector::DeclarativeConnector;
 pub use example_connector::ExampleConnector;
 
 pub trait Connector {
-    fn calculate_type(&self, name: &str, args: Vec<i32>) -> Option<ScalarFieldType>;
+    /// Resolves a native type by its name, e.g. `VarChar` with the arguments `[255]`.
+    fn calculate_type(&self, name: &str, args: Vec<i32>) -> Result<ScalarFieldType, ConnectorError>;
+
+    /// Finds the native type for a type reported by the database, e.g. `varchar(255)`.
+    fn introspect_type(&self, datasource_type: &str) -> Option<ScalarFieldType>;
 
     fn supports_scalar_lists(&self) -> bool;
 }
@@ -18,6 +23,7 @@ pub trait Connector {
 #[derive(Debug, Clone, PartialEq)]
 pub struct ScalarFieldType {
     name: String,
+    args: Vec<i32>,
     prisma_type: scalars::ScalarType,
     datasource_type: String,
 }
@@ -26,15 +32,36 @@ impl ScalarFieldType {
     pub fn new(name: &str, prisma_type: scalars::ScalarType, datasource_type: &str) -> Self {
         ScalarFieldType {
             name: name.to_string(),
+            args: Vec::new(),
+            prisma_type,
+            datasource_type: datasource_type.to_string(),
+        }
+    }
+
+    pub fn with_args(name: &str, args: Vec<i32>, prisma_type: scalars::ScalarType, datasource_type: &str) -> Self {
+        ScalarFieldType {
+            name: name.to_string(),
+            args,
             prisma_type,
             datasource_type: datasource_type.to_string(),
         }
     }
 
+    /// The name of the native type, as written in the datamodel.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The arguments given to the native type.
+    pub fn args(&self) -> &[i32] {
+        &self.args
+    }
+
     pub fn prisma_type(&self) -> scalars::ScalarType {
         self.prisma_type
     }
 
+    /// The full type as rendered into the database, e.g. `varchar(255)`.
     pub fn datasource_type(&self) -> &str {
         &self.datasource_type
     }
@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Prisma's builtin scalar types.
@@ -10,6 +11,9 @@ pub enum ScalarType {
     Boolean,
     String,
     DateTime,
+    Json,
+    Bytes,
+    Uuid,
 }
 
 impl ScalarType {
@@ -21,6 +25,9 @@ impl ScalarType {
             "Boolean" => Ok(ScalarType::Boolean),
             "String" => Ok(ScalarType::String),
             "DateTime" => Ok(ScalarType::DateTime),
+            "Json" => Ok(ScalarType::Json),
+            "Bytes" => Ok(ScalarType::Bytes),
+            "Uuid" => Ok(ScalarType::Uuid),
             _ => Err(format!("type {} is not a known scalar type.", s)),
         }
     }
@@ -35,6 +42,9 @@ impl ToString for ScalarType {
             ScalarType::Boolean => String::from("Boolean"),
             ScalarType::String => String::from("String"),
             ScalarType::DateTime => String::from("DateTime"),
+            ScalarType::Json => String::from("Json"),
+            ScalarType::Bytes => String::from("Bytes"),
+            ScalarType::Uuid => String::from("Uuid"),
         }
     }
 }
@@ -44,7 +54,7 @@ impl ToString for ScalarType {
 pub enum ScalarValue {
     Int(i32),
     Float(f32),
-    Decimal(f32),
+    Decimal(Decimal),
     Boolean(bool),
     String(String),
     DateTime(DateTime<Utc>),
@@ -14,6 +14,8 @@ pub struct Field {
     pub directives: Vec<Directive>,
     /// The comments for this field.
     pub documentation: Option<Comment>,
+    /// If set, the field is rendered as a comment.
+    pub is_commented_out: bool,
     /// The location of this field in the text representation.
     pub span: Span,
 }
@@ -11,6 +11,8 @@ pub struct Model {
     pub directives: Vec<Directive>,
     /// The documentation for this model.
     pub documentation: Option<Comment>,
+    /// If set, the model is rendered as a comment.
+    pub is_commented_out: bool,
     /// The location of this model in the text representation.
     pub span: Span,
 }
@@ -223,6 +223,7 @@ fn parse_field(token: &pest::iterators::Pair<'_, Rule>) -> Result<Field, Datamod
             default_value: None,
             directives,
             documentation: doc_comments_to_string(&comments),
+            is_commented_out: false,
             span: Span::from_pest(token.as_span()),
         }),
         _ => panic!(
@@ -266,6 +267,7 @@ fn parse_model(token: &pest::iterators::Pair<'_, Rule>) -> Result<Model, ErrorCo
             fields,
             directives,
             documentation: doc_comments_to_string(&comments),
+            is_commented_out: false,
             span: Span::from_pest(token.as_span()),
         }),
         _ => panic!(
@@ -414,6 +416,7 @@ fn parse_type(token: &pest::iterators::Pair<'_, Rule>) -> Field {
             default_value: None,
             directives,
             documentation: doc_comments_to_string(&comments),
+            is_commented_out: false,
             span: Span::from_pest(token.as_span()),
         },
         _ => panic!(
@@ -160,6 +160,10 @@ impl<'a> Renderer<'a> {
     }
 
     fn render_model(&mut self, model: &ast::Model) {
+        if model.is_commented_out {
+            return self.render_commented_out_model(model);
+        }
+
         Self::render_documentation(self, model);
 
         self.write("model ");
@@ -188,6 +192,29 @@ impl<'a> Renderer<'a> {
         self.end_line();
     }
 
+    /// Renders the model as usual, with every line commented out.
+    fn render_commented_out_model(&mut self, model: &ast::Model) {
+        let mut buffer = Vec::new();
+
+        {
+            let mut renderer = Renderer::new(&mut buffer, self.indent_width);
+            renderer.render_model(&ast::Model {
+                is_commented_out: false,
+                ..model.clone()
+            });
+        }
+
+        for line in String::from_utf8(buffer).expect("Rendered non-UTF-8 model.").lines() {
+            if line.is_empty() {
+                self.write("//");
+            } else {
+                self.write(&format!("// {}", line));
+            }
+
+            self.end_line();
+        }
+    }
+
     fn render_enum(&mut self, enm: &ast::Enum) {
         Self::render_documentation(self, enm);
 
@@ -218,7 +245,11 @@ impl<'a> Renderer<'a> {
     fn render_field(target: &mut TableFormat, field: &ast::Field) {
         Self::render_documentation(&mut target.interleave_writer(), field);
 
-        target.write(&field.name.name);
+        if field.is_commented_out {
+            target.write(&format!("// {}", field.name.name));
+        } else {
+            target.write(&field.name.name);
+        }
 
         // Type
         {
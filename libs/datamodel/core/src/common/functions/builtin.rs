@@ -62,3 +62,28 @@ impl Functional for ServerSideTrivialFunctional {
         ))
     }
 }
+
+/// Database generated default (`dbgenerated("...")`). The SQL expression is rendered into the
+/// column definition as is, and its return type is the type of the field it is used on.
+pub struct DbGeneratedFunctional {}
+
+impl Functional for DbGeneratedFunctional {
+    fn name(&self) -> &str {
+        crate::dml::DB_GENERATED_FUNCTION
+    }
+
+    fn apply(&self, values: &[ValueValidator], span: ast::Span) -> Result<MaybeExpression, DatamodelError> {
+        self.check_arg_count(values, 1, span)?;
+
+        let expression = values[0].as_str()?;
+
+        Ok(MaybeExpression::Expression(
+            ScalarValue::Expression(
+                String::from(self.name()),
+                ScalarType::String,
+                vec![ScalarValue::String(expression)],
+            ),
+            span,
+        ))
+    }
+}
@@ -7,6 +7,7 @@ use super::interpolation::StringInterpolator;
 use super::FromStrAndSpan;
 use super::{ScalarType, ScalarValue};
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use std::error;
 
 #[derive(Debug, Clone)]
@@ -79,7 +80,16 @@ impl ValueValidator {
                 ScalarType::Boolean => self.as_bool().map(dml::ScalarValue::Boolean),
                 ScalarType::DateTime => self.as_date_time().map(dml::ScalarValue::DateTime),
                 ScalarType::String => self.as_str().map(dml::ScalarValue::String),
+                ScalarType::Json | ScalarType::Bytes | ScalarType::Uuid => {
+                    Err(self.construct_error(&scalar_type.to_string()))
+                }
             },
+            // Database generated expressions take the type of the field they are used on.
+            MaybeExpression::Expression(dml::ScalarValue::Expression(name, _, args), _)
+                if name == dml::DB_GENERATED_FUNCTION =>
+            {
+                Ok(dml::ScalarValue::Expression(name.clone(), scalar_type, args.clone()))
+            }
             MaybeExpression::Expression(expr, _) => {
                 if expr.get_type() == scalar_type {
                     Ok(expr.clone())
@@ -170,15 +180,15 @@ impl ValueValidator {
         }
     }
 
-    // TODO: Ask which decimal type to take.
-    /// Tries to convert the wrapped value to a Prisma Decimal.
-    pub fn as_decimal(&self) -> Result<f32, DatamodelError> {
+    /// Tries to convert the wrapped value to a Prisma Decimal. The literal is parsed exactly, without
+    /// going through a floating point type.
+    pub fn as_decimal(&self) -> Result<Decimal, DatamodelError> {
         match &self.value {
             MaybeExpression::Value(_, ast::Expression::NumericValue(value, _)) => {
-                self.wrap_error_from_result(value.parse::<f32>(), "numeric")
+                self.wrap_error_from_result(value.parse::<Decimal>(), "numeric")
             }
             MaybeExpression::Value(_, ast::Expression::Any(value, _)) => {
-                self.wrap_error_from_result(value.parse::<f32>(), "numeric")
+                self.wrap_error_from_result(value.parse::<Decimal>(), "numeric")
             }
             _ => Err(self.construct_error("numeric")),
         }
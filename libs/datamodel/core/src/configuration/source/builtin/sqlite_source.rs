@@ -33,6 +33,6 @@ impl Source for SqliteSource {
     }
 
     fn connector(&self) -> Box<dyn Connector> {
-        Box::new(ExampleConnector::empty())
+        Box::new(ExampleConnector::sqlite())
     }
 }
@@ -129,4 +129,47 @@ impl Datamodel {
             })
         })
     }
+
+    /// The columns holding the foreign key of a relation field, in the order of the referenced fields.
+    /// A relation field referencing a single field has one column, named like the field. A relation
+    /// field referencing multiple fields has the columns given with `@map([...])`, by default named
+    /// after the field and the referenced columns.
+    pub fn relation_columns(&self, field: &Field) -> Vec<String> {
+        let relation_info = match &field.field_type {
+            FieldType::Relation(relation_info) => relation_info,
+            _ => return Vec::new(),
+        };
+
+        if !relation_info.columns.is_empty() {
+            return relation_info.columns.clone();
+        }
+
+        let column = field.database_name.clone().unwrap_or_else(|| field.name.clone());
+        let related_model = match self.find_model(&relation_info.to) {
+            Some(related_model) => related_model,
+            None => return vec![column],
+        };
+
+        let referenced_fields = if relation_info.to_fields.is_empty() {
+            related_model.id_field_names()
+        } else {
+            relation_info.to_fields.clone()
+        };
+
+        if referenced_fields.len() < 2 {
+            return vec![column];
+        }
+
+        referenced_fields
+            .iter()
+            .map(|referenced_field| {
+                let referenced_column = related_model
+                    .find_field(referenced_field)
+                    .and_then(|referenced_field| referenced_field.database_name.clone())
+                    .unwrap_or_else(|| referenced_field.clone());
+
+                format!("{}_{}", column, referenced_column)
+            })
+            .collect()
+    }
 }
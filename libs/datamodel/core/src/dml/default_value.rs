@@ -29,8 +29,23 @@ impl DefaultValue {
             Self::Expression(vg) => vg.return_type(),
         }
     }
+
+    /// The SQL expression of a `dbgenerated("...")` default, which the database evaluates itself.
+    pub fn db_generated_expression(&self) -> Option<&str> {
+        match self {
+            Self::Expression(vg) => vg.db_generated_expression(),
+            Self::Single(_) => None,
+        }
+    }
+
+    pub fn is_db_generated(&self) -> bool {
+        self.db_generated_expression().is_some()
+    }
 }
 
+/// Name of the function declaring a default that is rendered verbatim into the column DDL.
+pub const DB_GENERATED_FUNCTION: &str = "dbgenerated";
+
 #[derive(Clone)]
 pub struct ValueGenerator {
     pub name: String,
@@ -46,6 +61,15 @@ impl ValueGenerator {
         Ok(ValueGenerator { name, args, generator })
     }
 
+    /// The return type of a `dbgenerated("...")` expression can not be known, it takes the type of its field.
+    pub fn new_db_generated(expression: String, return_type: ScalarType) -> Self {
+        ValueGenerator {
+            name: String::from(DB_GENERATED_FUNCTION),
+            args: vec![ScalarValue::String(expression)],
+            generator: ValueGeneratorFn::DbGenerated(return_type),
+        }
+    }
+
     pub fn return_type(&self) -> ScalarType {
         self.generator.return_type()
     }
@@ -54,6 +78,13 @@ impl ValueGenerator {
         self.generator.invoke()
     }
 
+    pub fn db_generated_expression(&self) -> Option<&str> {
+        match (&self.generator, self.args.as_slice()) {
+            (ValueGeneratorFn::DbGenerated(_), [ScalarValue::String(expression)]) => Some(expression.as_str()),
+            _ => None,
+        }
+    }
+
     fn name(&self) -> &str {
         &self.name
     }
@@ -69,6 +100,7 @@ pub enum ValueGeneratorFn {
     CUID,
     Now,
     Autoincrement,
+    DbGenerated(ScalarType),
 }
 
 impl ValueGeneratorFn {
@@ -78,6 +110,7 @@ impl ValueGeneratorFn {
             Self::CUID => ScalarType::String,
             Self::Now => ScalarType::DateTime,
             Self::Autoincrement => ScalarType::Int,
+            Self::DbGenerated(return_type) => *return_type,
         }
     }
 
@@ -87,6 +120,7 @@ impl ValueGeneratorFn {
             Self::CUID => Self::generate_cuid(),
             Self::Now => Self::generate_now(),
             Self::Autoincrement => None,
+            Self::DbGenerated(_) => None,
         }
     }
 
@@ -140,6 +174,19 @@ impl TryFrom<ScalarValue> for DefaultValue {
 
     fn try_from(sv: ScalarValue) -> std::result::Result<Self, DatamodelError> {
         Ok(match sv {
+            ScalarValue::Expression(ref name, return_type, ref args) if name == DB_GENERATED_FUNCTION => {
+                match args.as_slice() {
+                    [ScalarValue::String(expression)] => {
+                        Self::Expression(ValueGenerator::new_db_generated(expression.clone(), return_type))
+                    }
+                    _ => {
+                        return Err(DatamodelError::new_functional_evaluation_error(
+                            &format!("The function {} expects a single string argument.", name),
+                            ast::Span::empty(),
+                        ))
+                    }
+                }
+            }
             ScalarValue::Expression(name, _, args) => Self::Expression(ValueGenerator::new(name, args)?),
             other => Self::Single(other),
         })
@@ -35,6 +35,15 @@ impl FieldType {
             _ => false,
         }
     }
+
+    /// The builtin scalar type of this field type. For native types, this is the scalar type they map to.
+    pub fn scalar_type(&self) -> Option<ScalarType> {
+        match self {
+            Self::Base(scalar_type) => Some(*scalar_type),
+            Self::ConnectorSpecific(sft) => Some(sft.prisma_type()),
+            _ => None,
+        }
+    }
 }
 
 /// Holds information about an id, or priamry key.
@@ -72,6 +81,8 @@ pub struct Field {
     /// If set, signals that this field is updated_at and will be updated to now()
     /// automatically.
     pub is_updated_at: bool,
+    /// If set, the field is rendered commented out, because it can not be represented in the datamodel.
+    pub is_commented_out: bool,
 }
 
 impl WithName for Field {
@@ -90,6 +101,23 @@ impl WithDatabaseName for Field {
     fn set_database_name(&mut self, database_name: &Option<String>) {
         self.database_name = database_name.clone()
     }
+    fn database_names(&self) -> &[String] {
+        match &self.field_type {
+            FieldType::Relation(relation_info) => &relation_info.columns,
+            _ => &[],
+        }
+    }
+    fn set_database_names(&mut self, database_names: Vec<String>) -> Result<(), String> {
+        match &mut self.field_type {
+            FieldType::Relation(relation_info) if self.arity.is_singular() => {
+                relation_info.columns = database_names;
+                Ok(())
+            }
+            _ => Err(String::from(
+                "Only relation fields holding a foreign key can be mapped to multiple columns.",
+            )),
+        }
+    }
 }
 
 impl Field {
@@ -106,6 +134,7 @@ impl Field {
             documentation: None,
             is_generated: false,
             is_updated_at: false,
+            is_commented_out: false,
         }
     }
     /// Creates a new field with the given name and type, marked as generated and optional.
@@ -121,6 +150,7 @@ impl Field {
             documentation: None,
             is_generated: true,
             is_updated_at: false,
+            is_commented_out: false,
         }
     }
 }
@@ -11,14 +11,22 @@ pub struct Model {
     pub documentation: Option<String>,
     /// The database internal name of this model.
     pub database_name: Option<String>,
+    /// The database schema of the table of this model. The schema of the connection if not set.
+    pub schema: Option<String>,
     /// Indicates if this model is embedded or not.
     pub is_embedded: bool,
+    /// Indicates if this model is backed by a database view. Views are read-only.
+    pub is_view: bool,
     /// Describes Composite Indexes
     pub indexes: Vec<IndexDefinition>,
     /// Describes Composite Primary Keys
     pub id_fields: Vec<String>,
+    /// Describes `CHECK` constraints on the table of the model.
+    pub check_constraints: Vec<CheckConstraint>,
     /// Indicates if this model is generated.
     pub is_generated: bool,
+    /// If set, the model is rendered commented out, because it can not be represented in the datamodel.
+    pub is_commented_out: bool,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -26,6 +34,15 @@ pub struct IndexDefinition {
     pub name: Option<String>,
     pub fields: Vec<String>,
     pub tpe: IndexType,
+    /// Whether the index should be created without locking writes on the table.
+    pub online: bool,
+}
+
+/// A SQL `CHECK` constraint. The expression is SQL, rendered as is.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CheckConstraint {
+    pub name: Option<String>,
+    pub expression: String,
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -42,10 +59,14 @@ impl Model {
             fields: vec![],
             indexes: vec![],
             id_fields: vec![],
+            check_constraints: vec![],
             documentation: None,
             database_name,
+            schema: None,
             is_embedded: false,
+            is_view: false,
             is_generated: false,
+            is_commented_out: false,
         }
     }
 
@@ -7,6 +7,9 @@ pub struct RelationInfo {
     pub to: String,
     /// The target field of the relation.
     pub to_fields: Vec<String>,
+    /// The columns holding the foreign key of a relation over multiple fields, set with `@map([...])`.
+    /// Empty if the column names are derived from the field name.
+    pub columns: Vec<String>,
     /// The name of the relation. Internally, an empty string signals no name.
     pub name: String,
     /// A strategy indicating what happens when
@@ -21,6 +24,7 @@ impl RelationInfo {
         RelationInfo {
             to: String::from(to),
             to_fields: Vec::new(),
+            columns: Vec::new(),
             name: String::new(),
             on_delete: OnDeleteStrategy::None,
         }
@@ -29,6 +33,7 @@ impl RelationInfo {
         RelationInfo {
             to: String::from(to),
             to_fields: vec![String::from(to_field)],
+            columns: Vec::new(),
             name: String::new(),
             on_delete: OnDeleteStrategy::None,
         }
@@ -38,6 +43,7 @@ impl RelationInfo {
         RelationInfo {
             to: String::from(to),
             to_fields,
+            columns: Vec::new(),
             name: String::new(),
             on_delete: OnDeleteStrategy::None,
         }
@@ -15,6 +15,15 @@ pub trait WithDatabaseName {
     fn database_name(&self) -> &Option<String>;
     /// Sets the internal database name.
     fn set_database_name(&mut self, database_name: &Option<String>);
+    /// Gets the internal database names of an object stored in multiple columns. Empty otherwise.
+    fn database_names(&self) -> &[String] {
+        &[]
+    }
+    /// Sets the internal database names of an object stored in multiple columns. Returns an error
+    /// message if the object is not stored in multiple columns.
+    fn set_database_names(&mut self, _database_names: Vec<String>) -> Result<(), String> {
+        Err(String::from("Only relation fields can be mapped to multiple columns."))
+    }
 }
 
 pub trait Parsable: Sized {
@@ -32,12 +32,16 @@ fn model_from_dmmf(model: &Model) -> dml::Model {
     dml::Model {
         name: model.name.clone(),
         database_name: model.db_name.clone(),
+        schema: model.schema.clone(),
         is_embedded: model.is_embedded,
+        is_view: model.is_view,
         fields: model.fields.iter().map(&field_from_dmmf).collect(),
         indexes: vec![],
         id_fields: model.id_fields.clone(),
+        check_constraints: vec![],
         documentation: model.documentation.clone(),
         is_generated: model.is_generated.unwrap_or(false),
+        is_commented_out: false,
     }
 }
 
@@ -64,6 +68,7 @@ fn field_from_dmmf(field: &Field) -> dml::Field {
         is_unique: field.is_unique,
         is_generated: field.is_generated.unwrap_or(false),
         is_updated_at: field.is_updated_at.unwrap_or(false),
+        is_commented_out: false,
         documentation: field.documentation.clone(),
     }
 }
@@ -83,8 +88,11 @@ fn default_value_from_serde(
                 dml::ScalarValue::Float(val.as_f64().unwrap() as f32)
             }
             (serde_json::Value::Number(val), ScalarType::Int) => dml::ScalarValue::Int(val.as_i64().unwrap() as i32),
+            (serde_json::Value::String(val), ScalarType::Decimal) => {
+                dml::ScalarValue::Decimal(val.parse().expect("Invalid Decimal default value."))
+            }
             (serde_json::Value::Number(val), ScalarType::Decimal) => {
-                dml::ScalarValue::Decimal(val.as_f64().unwrap() as f32)
+                dml::ScalarValue::Decimal(val.to_string().parse().expect("Invalid Decimal default value."))
             }
             (serde_json::Value::String(val), ScalarType::DateTime) => {
                 dml::ScalarValue::DateTime(String::from(val.as_str()).parse::<DateTime<Utc>>().unwrap())
@@ -112,10 +120,30 @@ fn type_from_string(scalar: &str) -> ScalarType {
 }
 
 fn function_from_dmmf(func: &Function, expected_type: ScalarType) -> dml::ScalarValue {
+    // The only argument of `dbgenerated()` is the SQL expression, which is always a string.
+    if func.name == dml::DB_GENERATED_FUNCTION {
+        let expression = match func.args.as_slice() {
+            [serde_json::Value::String(expression)] => expression.clone(),
+            _ => panic!("Function {} expects a single string argument.", func.name),
+        };
+
+        return dml::ScalarValue::Expression(
+            func.name.clone(),
+            expected_type,
+            vec![dml::ScalarValue::String(expression)],
+        );
+    }
+
     if !func.args.is_empty() {
         panic!("Function argument deserialization is not supported with DMMF. There are no type annotations yet, so it's not clear which is meant.");
     }
 
+    // `uuid()` generates strings, which are also the default values of Uuid fields.
+    let expected_type = match expected_type {
+        ScalarType::Uuid => ScalarType::String,
+        expected_type => expected_type,
+    };
+
     if func.return_type != expected_type.to_string() {
         panic!(
             "Type missmatch during deserialization. Expected: {}, but got: {}.",
@@ -139,6 +167,7 @@ fn get_field_type(field: &Field) -> dml::FieldType {
         "object" => dml::FieldType::Relation(dml::RelationInfo {
             to: field.field_type.clone(),
             to_fields: field.relation_to_fields.clone().unwrap_or_default(),
+            columns: Vec::new(),
             name: field.relation_name.clone().unwrap_or(String::new()),
             on_delete: get_on_delete_strategy(&field.relation_on_delete),
         }),
@@ -52,7 +52,11 @@ pub struct Function {
 pub struct Model {
     pub name: String,
     pub is_embedded: bool,
+    #[serde(default)]
+    pub is_view: bool,
     pub db_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     pub fields: Vec<Field>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_generated: Option<bool>,
@@ -44,7 +44,9 @@ fn model_to_dmmf(model: &dml::Model) -> Model {
     Model {
         name: model.name.clone(),
         db_name: model.database_name.clone(),
+        schema: model.schema.clone(),
         is_embedded: model.is_embedded,
+        is_view: model.is_view,
         fields: model.fields().map(&field_to_dmmf).collect(),
         is_generated: Some(model.is_generated),
         documentation: model.documentation.clone(),
@@ -76,8 +78,7 @@ fn get_field_kind(field: &dml::Field) -> String {
     match field.field_type {
         dml::FieldType::Relation(_) => String::from("object"),
         dml::FieldType::Enum(_) => String::from("enum"),
-        dml::FieldType::Base(_) => String::from("scalar"),
-        _ => unimplemented!("DMMF does not support field type {:?}", field.field_type),
+        dml::FieldType::Base(_) | dml::FieldType::ConnectorSpecific(_) => String::from("scalar"),
     }
 }
 
@@ -108,7 +109,8 @@ fn value_to_serde(value: &dml::ScalarValue) -> serde_json::Value {
         dml::ScalarValue::ConstantLiteral(val) => serde_json::Value::String(val.clone()),
         dml::ScalarValue::Float(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
         dml::ScalarValue::Int(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
-        dml::ScalarValue::Decimal(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
+        // Decimals are rendered as strings, JSON numbers can't hold them without loss of precision.
+        dml::ScalarValue::Decimal(val) => serde_json::Value::String(val.to_string()),
         dml::ScalarValue::DateTime(val) => serde_json::Value::String(val.to_rfc3339()),
         dml::ScalarValue::Expression(name, return_type, args) => function_to_serde(&name, *return_type, &args),
     }
@@ -17,7 +17,38 @@ impl DirectiveValidator<dml::Field> for DefaultDirectiveValidator {
             return self.error("Cannot set a default value on list field.", args.span());
         }
 
-        if let dml::FieldType::Base(scalar_type) = field.field_type {
+        if let Some(scalar_type @ dml::ScalarType::Json) | Some(scalar_type @ dml::ScalarType::Bytes) =
+            field.field_type.scalar_type()
+        {
+            return self.error(
+                &format!("Cannot set a default value on a {} field.", scalar_type.to_string()),
+                args.span(),
+            );
+        }
+
+        // Generated UUIDs are strings, which Uuid fields take as well. Other strings are not known to be UUIDs.
+        if let Some(dml::ScalarType::Uuid) = field.field_type.scalar_type() {
+            let value = match args.default_arg("value")?.as_type(dml::ScalarType::String) {
+                Ok(value) => value,
+                Err(err) => return Err(self.parser_error(&err)),
+            };
+
+            match value {
+                dml::ScalarValue::Expression(ref name, _, _) if name == "uuid" => {
+                    field.default_value = Some(value.clone().try_into()?)
+                }
+                dml::ScalarValue::Expression(name, _, expression) if name == dml::DB_GENERATED_FUNCTION => {
+                    field.default_value =
+                        Some(dml::ScalarValue::Expression(name, dml::ScalarType::Uuid, expression).try_into()?)
+                }
+                _ => {
+                    return self.error(
+                        "Uuid fields can only use `uuid()` or `dbgenerated()` as default value.",
+                        args.span(),
+                    )
+                }
+            }
+        } else if let Some(scalar_type) = field.field_type.scalar_type() {
             match args.default_arg("value")?.as_type(scalar_type) {
                 // TODO: Here, a default value directive can override the default value syntax sugar.
                 Ok(value) => {
@@ -1,3 +1,4 @@
+use crate::common::value::ValueListValidator;
 use crate::error::DatamodelError;
 use crate::validator::directive::{Args, DirectiveValidator};
 use crate::{ast, dml};
@@ -10,8 +11,20 @@ impl<T: dml::WithDatabaseName> DirectiveValidator<T> for MapDirectiveValidator {
         &"map"
     }
     fn validate_and_apply(&self, args: &mut Args, obj: &mut T) -> Result<(), DatamodelError> {
-        match args.default_arg("name")?.as_str() {
-            Ok(value) => obj.set_database_name(&Some(value)),
+        let name_arg = args.default_arg("name")?;
+
+        // Relation fields over multiple fields are mapped to one column per referenced field.
+        match name_arg.as_array()?.to_str_vec() {
+            Ok(ref values) if values.len() > 1 => {
+                if let Err(message) = obj.set_database_names(values.clone()) {
+                    return Err(DatamodelError::new_directive_validation_error(
+                        &message,
+                        "map",
+                        name_arg.span(),
+                    ));
+                }
+            }
+            Ok(mut values) => obj.set_database_name(&values.pop()),
             // self.parser_error would be better here, but we cannot call it due to rust limitations.
             Err(err) => {
                 return Err(DatamodelError::new_directive_validation_error(
@@ -26,6 +39,19 @@ impl<T: dml::WithDatabaseName> DirectiveValidator<T> for MapDirectiveValidator {
     }
 
     fn serialize(&self, obj: &T, _atamodel: &dml::Datamodel) -> Result<Vec<ast::Directive>, DatamodelError> {
+        if !obj.database_names().is_empty() {
+            let names = obj
+                .database_names()
+                .iter()
+                .map(|name| ast::Expression::StringValue(name.clone(), ast::Span::empty()))
+                .collect();
+
+            return Ok(vec![ast::Directive::new(
+                DirectiveValidator::<T>::directive_name(self),
+                vec![ast::Argument::new_array("", names)],
+            )]);
+        }
+
         if let Some(db_name) = obj.database_name() {
             return Ok(vec![ast::Directive::new(
                 DirectiveValidator::<T>::directive_name(self),
@@ -1,15 +1,18 @@
 use crate::dml;
 use crate::validator::directive::DirectiveListValidator;
 
+mod check;
 mod default;
 mod embedded;
 mod id;
 mod map;
 mod relation;
+mod schema;
 mod sequence;
 mod unique_and_index;
 mod updated_at;
 mod utils;
+mod view;
 
 /// Returns a directive list validator containing all builtin field directives.
 pub fn new_builtin_field_directives() -> DirectiveListValidator<dml::Field> {
@@ -32,9 +35,12 @@ pub fn new_builtin_model_directives() -> DirectiveListValidator<dml::Model> {
 
     validator.add(Box::new(map::MapDirectiveValidator {}));
     validator.add(Box::new(embedded::EmbeddedDirectiveValidator {}));
+    validator.add(Box::new(view::ViewDirectiveValidator {}));
+    validator.add(Box::new(schema::SchemaDirectiveValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelUniqueDirectiveValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelIndexDirectiveValidator {}));
     validator.add(Box::new(id::ModelLevelIdDirectiveValidator {}));
+    validator.add(Box::new(check::ModelLevelCheckDirectiveValidator {}));
 
     validator
 }
@@ -24,7 +24,21 @@ impl DirectiveValidator<dml::Field> for RelationDirectiveValidator {
             }
 
             if let Ok(related_fields) = args.arg("references") {
-                relation_info.to_fields = related_fields.as_array()?.to_literal_vec()?;
+                let to_fields = related_fields.as_array()?.to_literal_vec()?;
+
+                if let Some(duplicate) = to_fields
+                    .iter()
+                    .enumerate()
+                    .find(|(index, to_field)| to_fields[..*index].contains(to_field))
+                    .map(|(_, to_field)| to_field)
+                {
+                    return self.error(
+                        &format!("The field `{}` is referenced more than once.", duplicate),
+                        related_fields.span(),
+                    );
+                }
+
+                relation_info.to_fields = to_fields;
             }
 
             if let Ok(on_delete) = args.arg("onDelete") {
@@ -106,6 +106,7 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
             name: None,
             fields: vec![],
             tpe: index_type,
+            online: false,
         };
         let name = match args.optional_arg("name") {
             Some(name_arg) => Some(name_arg?.as_str()?),
@@ -113,6 +114,10 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
         };
         index_def.name = name;
 
+        if let Some(online_arg) = args.optional_arg("online") {
+            index_def.online = online_arg?.as_bool()?;
+        }
+
         match args.default_arg("fields")?.as_array() {
             Ok(fields) => {
                 let fields = fields.iter().map(|f| f.as_constant_literal().unwrap()).collect();
@@ -171,6 +176,12 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
                 if let Some(name) = &index_def.name {
                     args.push(ast::Argument::new_string("name", &name));
                 }
+                if index_def.online {
+                    args.push(ast::Argument::new(
+                        "online",
+                        ast::Expression::BooleanValue("true".to_string(), ast::Span::empty()),
+                    ));
+                }
 
                 ast::Directive::new(self.directive_name(), args)
             })
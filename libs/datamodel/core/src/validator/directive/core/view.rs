@@ -0,0 +1,29 @@
+use crate::error::DatamodelError;
+use crate::validator::directive::{Args, DirectiveValidator};
+use crate::{ast, dml};
+
+/// Prismas builtin `@@view` directive.
+pub struct ViewDirectiveValidator {}
+
+impl DirectiveValidator<dml::Model> for ViewDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        &"view"
+    }
+
+    fn validate_and_apply(&self, _args: &mut Args, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        obj.is_view = true;
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        if model.is_view {
+            return Ok(vec![ast::Directive::new(self.directive_name(), vec![])]);
+        }
+
+        Ok(vec![])
+    }
+}
@@ -6,7 +6,7 @@ use crate::{
     configuration, dml,
     error::{DatamodelError, ErrorCollection},
 };
-use datamodel_connector::{Connector, ExampleConnector};
+use datamodel_connector::Connector;
 use std::convert::TryInto;
 
 /// Helper for lifting a datamodel.
@@ -16,18 +16,22 @@ use std::convert::TryInto;
 /// additional semantics are attached.
 pub struct LiftAstToDml {
     directives: DirectiveBox,
+    /// The name and connector of the first source, used to resolve native types.
+    source: Option<(String, Box<dyn Connector>)>,
 }
 
-const USE_CONNECTORS_FOR_CUSTOM_TYPES: bool = false; // FEATURE FLAG
-
 impl LiftAstToDml {
     /// Creates a new instance, with all builtin directives and
     /// the directives defined by the given sources registered.
     ///
     /// The directives defined by the given sources will be namespaced.
+    /// Native types are namespaced with the name of the first source, e.g. `@pg.VarChar(255)`.
     pub fn with_sources(sources: &[Box<dyn configuration::Source + Send + Sync>]) -> LiftAstToDml {
         LiftAstToDml {
             directives: DirectiveBox::with_sources(sources),
+            source: sources
+                .first()
+                .map(|source| (source.name().clone(), source.connector())),
         }
     }
 
@@ -111,6 +115,20 @@ impl LiftAstToDml {
         // If we cannot parse the field type, we exit right away.
         let (field_type, extra_attributes) = self.lift_field_type(&ast_field, ast_schema, &mut Vec::new())?;
 
+        // We merge arttributes so we can fail on duplicates.
+        let attributes = [&extra_attributes[..], &ast_field.directives[..]].concat();
+        let (native_type_attributes, attributes): (Vec<ast::Directive>, Vec<ast::Directive>) = attributes
+            .into_iter()
+            .partition(|directive| self.is_native_type_directive(directive));
+
+        let field_type = match self.lift_native_type(field_type.clone(), &native_type_attributes) {
+            Ok(field_type) => field_type,
+            Err(err) => {
+                errors.push(err);
+                field_type
+            }
+        };
+
         let mut field = dml::Field::new(&ast_field.name.name, field_type.clone());
         field.documentation = ast_field.documentation.clone().map(|comment| comment.text);
         field.arity = self.lift_field_arity(&ast_field.arity);
@@ -118,8 +136,8 @@ impl LiftAstToDml {
         if let Some(value) = &ast_field.default_value {
             let validator = ValueValidator::new(value)?;
 
-            if let dml::FieldType::Base(base_type) = &field_type {
-                match validator.as_type(*base_type) {
+            if let Some(base_type) = field_type.scalar_type() {
+                match validator.as_type(base_type) {
                     Ok(val) => field.default_value = Some(val.try_into()?),
                     Err(err) => errors.push(err),
                 };
@@ -131,9 +149,6 @@ impl LiftAstToDml {
             }
         }
 
-        // We merge arttributes so we can fail on duplicates.
-        let attributes = [&extra_attributes[..], &ast_field.directives[..]].concat();
-
         if let Err(mut err) = self.directives.field.validate_and_apply(&attributes, &mut field) {
             errors.append(&mut err);
         }
@@ -165,24 +180,7 @@ impl LiftAstToDml {
         let type_name = &ast_field.field_type.name;
 
         if let Ok(scalar_type) = ScalarType::from_str(type_name) {
-            if USE_CONNECTORS_FOR_CUSTOM_TYPES {
-                let pg_connector = ExampleConnector::postgres();
-                let args = vec![]; // TODO: figure out args
-                let pg_type_specification = ast_field
-                    .directives
-                    .iter()
-                    .find(|dir| dir.name.name.starts_with("pg.")) // we use find because there should be at max 1.
-                    .map(|dir| dir.name.name.trim_start_matches("pg."));
-
-                if let Some(x) = pg_type_specification.and_then(|ts| pg_connector.calculate_type(&ts, args)) {
-                    let field_type = dml::FieldType::ConnectorSpecific(x);
-                    Ok((field_type, vec![]))
-                } else {
-                    Ok((dml::FieldType::Base(scalar_type), vec![]))
-                }
-            } else {
-                Ok((dml::FieldType::Base(scalar_type), vec![]))
-            }
+            Ok((dml::FieldType::Base(scalar_type), vec![]))
         } else if ast_schema.find_model(type_name).is_some() {
             Ok((dml::FieldType::Relation(dml::RelationInfo::new(type_name)), vec![]))
         } else if ast_schema.find_enum(type_name).is_some() {
@@ -225,19 +223,6 @@ impl LiftAstToDml {
 
             attrs.append(&mut custom_type.directives.clone());
             Ok((field_type, attrs))
-        } else if USE_CONNECTORS_FOR_CUSTOM_TYPES {
-            let pg_connector = ExampleConnector::postgres();
-            let args = vec![]; // TODO: figure out args
-
-            if let Some(x) = pg_connector.calculate_type(&ast_field.field_type.name, args) {
-                let field_type = dml::FieldType::ConnectorSpecific(x);
-                Ok((field_type, vec![]))
-            } else {
-                Err(DatamodelError::new_type_not_found_error(
-                    type_name,
-                    ast_field.field_type.span,
-                ))
-            }
         } else {
             Err(DatamodelError::new_type_not_found_error(
                 type_name,
@@ -245,4 +230,78 @@ impl LiftAstToDml {
             ))
         }
     }
+
+    /// Internal: Checks if a directive specifies a native type, e.g. `@pg.VarChar(255)`.
+    fn is_native_type_directive(&self, directive: &ast::Directive) -> bool {
+        match &self.source {
+            Some((source_name, _)) => directive.name.name.starts_with(&format!("{}.", source_name)),
+            None => false,
+        }
+    }
+
+    /// Internal: Resolves the native type directives of a field against the connector of the first source.
+    fn lift_native_type(
+        &self,
+        field_type: dml::FieldType,
+        directives: &[ast::Directive],
+    ) -> Result<dml::FieldType, DatamodelError> {
+        let (source_name, connector) = match (&self.source, directives.first()) {
+            (Some(source), Some(_)) => source,
+            _ => return Ok(field_type),
+        };
+
+        if let Some(duplicate) = directives.get(1) {
+            return Err(DatamodelError::new_validation_error(
+                "A field can only have one native type.",
+                duplicate.span,
+            ));
+        }
+
+        let directive = &directives[0];
+        let type_name = directive.name.name.trim_start_matches(&format!("{}.", source_name));
+
+        let scalar_type = match field_type {
+            dml::FieldType::Base(scalar_type) => scalar_type,
+            _ => {
+                return Err(DatamodelError::new_directive_validation_error(
+                    "Native types can only be used on scalar fields.",
+                    &directive.name.name,
+                    directive.span,
+                ))
+            }
+        };
+
+        let mut args = Vec::with_capacity(directive.arguments.len());
+
+        for arg in &directive.arguments {
+            if !arg.name.name.is_empty() {
+                return Err(DatamodelError::new_directive_validation_error(
+                    "Native type arguments can not be named.",
+                    &directive.name.name,
+                    arg.span,
+                ));
+            }
+
+            args.push(ValueValidator::new(&arg.value)?.as_int()?);
+        }
+
+        let native_type = connector.calculate_type(type_name, args).map_err(|err| {
+            DatamodelError::new_directive_validation_error(&err.to_string(), &directive.name.name, directive.span)
+        })?;
+
+        if native_type.prisma_type() != scalar_type {
+            return Err(DatamodelError::new_directive_validation_error(
+                &format!(
+                    "Native type {} can only be used on fields of type {}, but the field is of type {}.",
+                    native_type.name(),
+                    native_type.prisma_type().to_string(),
+                    scalar_type.to_string()
+                ),
+                &directive.name.name,
+                directive.span,
+            ));
+        }
+
+        Ok(dml::FieldType::ConnectorSpecific(native_type))
+    }
 }
@@ -2,9 +2,15 @@ use super::{common::*, DirectiveBox};
 use crate::configuration;
 use crate::error::ErrorCollection;
 use crate::{ast, dml};
+use datamodel_connector::ScalarFieldType;
+
+/// The namespace of native type directives when no source is given.
+const DEFAULT_NATIVE_TYPE_NAMESPACE: &str = "db";
 
 pub struct LowerDmlToAst {
     directives: DirectiveBox,
+    /// The namespace native types are rendered with, e.g. `@db.VarChar(255)`.
+    native_type_namespace: String,
 }
 
 impl LowerDmlToAst {
@@ -12,6 +18,7 @@ impl LowerDmlToAst {
     pub fn new() -> Self {
         Self {
             directives: DirectiveBox::new(),
+            native_type_namespace: String::from(DEFAULT_NATIVE_TYPE_NAMESPACE),
         }
     }
 
@@ -19,10 +26,14 @@ impl LowerDmlToAst {
     /// the directives defined by the given sources registered.
     ///
     /// The directives defined by the given sources will be namespaced.
-    #[allow(unused)]
-    fn with_sources(sources: &[Box<dyn configuration::Source + Send + Sync>]) -> LowerDmlToAst {
+    /// Native types are namespaced with the name of the first source.
+    pub fn with_sources(sources: &[Box<dyn configuration::Source + Send + Sync>]) -> LowerDmlToAst {
         LowerDmlToAst {
             directives: DirectiveBox::with_sources(sources),
+            native_type_namespace: sources
+                .first()
+                .map(|source| source.name().clone())
+                .unwrap_or_else(|| String::from(DEFAULT_NATIVE_TYPE_NAMESPACE)),
         }
     }
 
@@ -71,6 +82,7 @@ impl LowerDmlToAst {
             fields,
             directives: self.directives.model.serialize(model, datamodel)?,
             documentation: model.documentation.clone().map(|text| ast::Comment { text }),
+            is_commented_out: model.is_commented_out,
             span: ast::Span::empty(),
         })
     }
@@ -98,13 +110,20 @@ impl LowerDmlToAst {
         model: &dml::Model,
         datamodel: &dml::Datamodel,
     ) -> Result<ast::Field, ErrorCollection> {
+        let mut directives = self.directives.field.serialize(field, datamodel)?;
+
+        if let dml::FieldType::ConnectorSpecific(sft) = &field.field_type {
+            directives.push(self.lower_native_type(sft));
+        }
+
         Ok(ast::Field {
             name: ast::Identifier::new(&field.name),
             arity: self.lower_field_arity(field.arity),
             default_value: field.default_value.clone().map(|v| v.into()),
-            directives: self.directives.field.serialize(field, datamodel)?,
+            directives,
             field_type: self.lower_type(&field.field_type, field, model, &datamodel),
             documentation: field.documentation.clone().map(|text| ast::Comment { text }),
+            is_commented_out: field.is_commented_out,
             span: ast::Span::empty(),
         })
     }
@@ -118,6 +137,17 @@ impl LowerDmlToAst {
         }
     }
 
+    /// Internal: Lowers a native type to its directive, e.g. `@db.VarChar(255)`.
+    fn lower_native_type(&self, sft: &ScalarFieldType) -> ast::Directive {
+        let arguments = sft
+            .args()
+            .iter()
+            .map(|arg| ast::Argument::new("", ast::Expression::NumericValue(arg.to_string(), ast::Span::empty())))
+            .collect();
+
+        ast::Directive::new(&format!("{}.{}", self.native_type_namespace, sft.name()), arguments)
+    }
+
     /// Internal: Lowers a field's arity.
     fn lower_type(
         &self,
@@ -154,7 +184,7 @@ impl LowerDmlToAst {
                     ast::Identifier::new(&rel.to)
                 }
             }
-            _ => unimplemented!("Connector specific types are not supported atm."),
+            dml::FieldType::ConnectorSpecific(sft) => ast::Identifier::new(&sft.prisma_type().to_string()),
         }
     }
 }
@@ -144,11 +144,15 @@ impl Standardiser {
             documentation: None,
             name: relation_name,
             database_name: None,
+            schema: None,
             is_embedded: false,
+            is_view: false,
             fields: vec![a_related_field, b_related_field],
             indexes: vec![],
             id_fields: vec![],
+            check_constraints: vec![],
             is_generated: true,
+            is_commented_out: false,
         }
     }
 
@@ -158,6 +162,7 @@ impl Standardiser {
             dml::FieldType::Relation(dml::RelationInfo {
                 to: model.name.clone(),
                 to_fields: model.id_field_names(),
+                columns: Vec::new(),
                 name: String::from(relation_name), // Will be corrected in later step
                 on_delete: dml::OnDeleteStrategy::None,
             }),
@@ -287,6 +292,7 @@ impl Standardiser {
                     let relation_info = dml::RelationInfo {
                         to: model.name.clone(),
                         to_fields: vec![],
+                        columns: vec![],
                         name: rel.name.clone(),
                         on_delete: OnDeleteStrategy::None,
                     };
@@ -35,6 +35,9 @@ impl<'a> Validator<'a> {
             if let Err(err) = self.validate_embedded_types_have_no_back_relation(ast_schema, schema, model) {
                 errors.push(err);
             }
+            if let Err(err) = self.validate_relation_references(ast_schema, schema, model) {
+                errors.push(err);
+            }
 
             if let Err(ref mut the_errors) =
                 self.validate_field_arities(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
@@ -73,6 +76,27 @@ impl<'a> Validator<'a> {
                     ast_field.span,
                 ));
             }
+
+            // Binary values are written as literals, which the query engine does not render for lists.
+            let is_bytes = match &field.field_type {
+                dml::FieldType::Base(scalar_type) => *scalar_type == dml::ScalarType::Bytes,
+                dml::FieldType::ConnectorSpecific(sft) => sft.prisma_type() == dml::ScalarType::Bytes,
+                _ => false,
+            };
+
+            if field.arity == FieldArity::List && is_bytes {
+                let ast_field = ast_model
+                    .fields
+                    .iter()
+                    .find(|ast_field| ast_field.name.name == field.name)
+                    .unwrap();
+
+                errors.push(DatamodelError::new_model_validation_error(
+                    &format!("The field `{}` is a list of Bytes, which is not supported.", field.name),
+                    &model.name,
+                    ast_field.span,
+                ));
+            }
         }
 
         if errors.has_errors() {
@@ -100,6 +124,15 @@ impl<'a> Validator<'a> {
             ast_model.span,
         ));
 
+        // Views have no primary key, the query engine identifies their records by a single id field.
+        if model.is_view && (model.singular_id_fields().count() != 1 || !model.id_fields.is_empty()) {
+            return Err(DatamodelError::new_model_validation_error(
+                "View models must mark the single field identifying their records with `@id`.",
+                &model.name,
+                ast_model.span,
+            ));
+        }
+
         match (model.singular_id_fields().count(), model.id_fields.is_empty()) {
             (c, _) if c > 1 => multiple_single_field_id_error,
             (0, true) => multiple_id_criteria_error,
@@ -141,6 +174,63 @@ impl<'a> Validator<'a> {
         Ok(())
     }
 
+    /// Ensures that relation fields reference existing fields, and that relation fields mapped
+    /// to multiple columns have one column per referenced field.
+    fn validate_relation_references(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), DatamodelError> {
+        for field in model.fields() {
+            let rel = match &field.field_type {
+                dml::FieldType::Relation(rel) => rel,
+                _ => continue,
+            };
+
+            let related_model = match datamodel.find_model(&rel.to) {
+                Some(related_model) => related_model,
+                None => continue,
+            };
+
+            let field_span = ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR).span;
+
+            for to_field in rel.to_fields.iter() {
+                if related_model.find_field(to_field).is_none() {
+                    return Err(DatamodelError::new_model_validation_error(
+                        &format!(
+                            "The relation field `{}` references `{}`, which is not a field of model `{}`.",
+                            &field.name, to_field, &related_model.name
+                        ),
+                        &model.name,
+                        field_span,
+                    ));
+                }
+            }
+
+            let referenced_fields_count = if rel.to_fields.is_empty() {
+                related_model.id_field_names().len()
+            } else {
+                rel.to_fields.len()
+            };
+
+            if !rel.columns.is_empty() && rel.columns.len() != referenced_fields_count {
+                return Err(DatamodelError::new_model_validation_error(
+                    &format!(
+                        "The relation field `{}` is mapped to {} columns, but references {} fields.",
+                        &field.name,
+                        rel.columns.len(),
+                        referenced_fields_count
+                    ),
+                    &model.name,
+                    field_span,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Elegantly checks if any relations in the model are ambigious.
     fn validate_relations_not_ambiguous(
         &self,
@@ -13,6 +13,9 @@ fn parse_scalar_types() {
         isPro Boolean
         balance Decimal
         averageGrade Float
+        preferences Json
+        avatar Bytes
+        token Uuid
     }
     "#;
 
@@ -31,6 +34,13 @@ fn parse_scalar_types() {
     user_model
         .assert_has_field("averageGrade")
         .assert_base_type(&ScalarType::Float);
+    user_model
+        .assert_has_field("preferences")
+        .assert_base_type(&ScalarType::Json);
+    user_model
+        .assert_has_field("avatar")
+        .assert_base_type(&ScalarType::Bytes);
+    user_model.assert_has_field("token").assert_base_type(&ScalarType::Uuid);
 }
 
 #[test]
@@ -153,3 +163,41 @@ fn scalar_list_types_are_not_supported_by_mysql() {
         DatamodelError::new_scalar_list_fields_are_not_supported("Post", "enums", Span::new(204, 219)),
     );
 }
+
+#[test]
+fn scalar_list_types_are_not_supported_by_sqlite() {
+    let dml = r#"
+    datasource sqlite {
+        provider = "sqlite"
+        url = "file:dev.db"
+    }
+    
+    model Post {
+        id Int @id
+        text String
+        photo String?
+        comments String[]
+        enums    Enum[]
+    }
+    
+    enum Enum {
+        A
+        B
+        C
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_length(2);
+
+    errors.assert_is_at(
+        0,
+        DatamodelError::new_scalar_list_fields_are_not_supported("Post", "comments", Span::new(178, 195)),
+    );
+
+    errors.assert_is_at(
+        1,
+        DatamodelError::new_scalar_list_fields_are_not_supported("Post", "enums", Span::new(204, 219)),
+    );
+}
@@ -12,6 +12,7 @@ pub trait FieldAsserts {
     fn assert_relation_to(&self, t: &str) -> &Self;
     fn assert_relation_delete_strategy(&self, t: dml::OnDeleteStrategy) -> &Self;
     fn assert_relation_to_fields(&self, t: &[&str]) -> &Self;
+    fn assert_relation_columns(&self, t: &[&str]) -> &Self;
     fn assert_arity(&self, arity: &dml::FieldArity) -> &Self;
     fn assert_with_db_name(&self, t: &str) -> &Self;
     fn assert_with_documentation(&self, t: &str) -> &Self;
@@ -27,6 +28,7 @@ pub trait FieldAsserts {
 pub trait ModelAsserts {
     fn assert_has_field(&self, t: &str) -> &dml::Field;
     fn assert_is_embedded(&self, t: bool) -> &Self;
+    fn assert_is_view(&self, t: bool) -> &Self;
     fn assert_with_db_name(&self, t: &str) -> &Self;
     fn assert_with_documentation(&self, t: &str) -> &Self;
     fn assert_has_index(&self, def: IndexDefinition) -> &Self;
@@ -119,6 +121,16 @@ impl FieldAsserts for dml::Field {
         self
     }
 
+    fn assert_relation_columns(&self, t: &[&str]) -> &Self {
+        if let dml::FieldType::Relation(info) = &self.field_type {
+            assert_eq!(info.columns, t);
+        } else {
+            panic!("Relation expected, but found {:?}", self.field_type);
+        }
+
+        self
+    }
+
     fn assert_arity(&self, arity: &dml::FieldArity) -> &Self {
         assert_eq!(self.arity, *arity);
 
@@ -211,6 +223,12 @@ impl ModelAsserts for dml::Model {
         self
     }
 
+    fn assert_is_view(&self, t: bool) -> &Self {
+        assert_eq!(self.is_view, t);
+
+        self
+    }
+
     fn assert_with_db_name(&self, t: &str) -> &Self {
         assert_eq!(self.database_name, Some(String::from(t)));
 
@@ -0,0 +1,98 @@
+use datamodel::{ast::Span, error::*, render_datamodel_to_string, CheckConstraint};
+
+use crate::common::*;
+
+#[test]
+fn basic_check_must_work() {
+    let dml = r#"
+    model Product {
+        id    Int @id
+        price Int
+
+        @@check("price >= 0")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let product_model = schema.assert_has_model("Product");
+    assert_eq!(
+        product_model.check_constraints,
+        vec![CheckConstraint {
+            name: None,
+            expression: "price >= 0".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn multiple_named_checks_must_work() {
+    let dml = r#"
+    model Product {
+        id       Int @id
+        price    Int
+        discount Int
+
+        @@check("price >= 0", name: "price_is_positive")
+        @@check("discount <= price", name: "discount_is_lower_than_price")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let product_model = schema.assert_has_model("Product");
+    assert_eq!(
+        product_model.check_constraints,
+        vec![
+            CheckConstraint {
+                name: Some("price_is_positive".to_string()),
+                expression: "price >= 0".to_string(),
+            },
+            CheckConstraint {
+                name: Some("discount_is_lower_than_price".to_string()),
+                expression: "discount <= price".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn must_error_when_a_check_name_is_used_twice() {
+    let dml = r#"
+    model Product {
+        id    Int @id
+        price Int
+
+        @@check("price >= 0", name: "price_check")
+        @@check("price < 1000", name: "price_check")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The check constraint name `price_check` is used more than once.",
+        "check",
+        Span::new(123, 165),
+    ));
+}
+
+#[test]
+fn check_directives_must_round_trip() {
+    let dml = r#"
+    model Product {
+        id    Int @id
+        price Int
+
+        @@check("price >= 0")
+        @@check("price < 1000", name: "price_is_sane")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+
+    let reparsed = datamodel::parse_datamodel(&rendered).unwrap();
+    assert_eq!(
+        reparsed.assert_has_model("Product").check_constraints,
+        schema.assert_has_model("Product").check_constraints
+    );
+}
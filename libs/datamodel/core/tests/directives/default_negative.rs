@@ -41,6 +41,24 @@ fn should_error_if_default_value_for_list() {
     ));
 }
 
+#[test]
+fn should_error_if_default_value_for_json() {
+    let dml = r#"
+    model Model {
+        id Int @id
+        meta Json @default("{}")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "Cannot set a default value on a Json field.",
+        "default",
+        Span::new(57, 70),
+    ));
+}
+
 #[test]
 fn should_error_if_default_value_type_missmatch() {
     let dml = r#"
@@ -76,3 +94,20 @@ fn should_error_if_default_value_parser_error() {
         Span::new(68, 74),
     ));
 }
+
+#[test]
+fn should_error_if_uuid_field_has_another_default_value_than_uuid() {
+    let dml = r#"
+    model Model {
+        id Uuid @id @default(cuid())
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "Uuid fields can only use `uuid()` or `dbgenerated()` as default value.",
+        "default",
+        Span::new(40, 55),
+    ));
+}
@@ -32,7 +32,7 @@ fn should_set_default_for_all_scalar_types() {
     user_model
         .assert_has_field("decimal")
         .assert_base_type(&ScalarType::Decimal)
-        .assert_default_value(DefaultValue::Single(ScalarValue::Decimal(3.15)));
+        .assert_default_value(DefaultValue::Single(ScalarValue::Decimal("3.15".parse().unwrap())));
     user_model
         .assert_has_field("string")
         .assert_base_type(&ScalarType::String)
@@ -49,6 +49,24 @@ fn should_set_default_for_all_scalar_types() {
         )));
 }
 
+#[test]
+fn decimal_defaults_must_keep_their_precision() {
+    let dml = r#"
+    model Model {
+        id Int @id
+        price Decimal @default(12345678.123456789)
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_field("price")
+        .assert_default_value(DefaultValue::Single(ScalarValue::Decimal(
+            "12345678.123456789".parse().unwrap(),
+        )));
+}
+
 #[test]
 fn should_set_default_an_enum_type() {
     let dml = r#"
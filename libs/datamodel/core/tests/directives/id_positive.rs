@@ -94,6 +94,26 @@ fn should_allow_string_ids_with_uuid() {
         ));
 }
 
+#[test]
+fn should_allow_uuid_ids_with_uuid() {
+    let dml = r#"
+    model Model {
+        id Uuid @id @default(uuid())
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_field("id")
+        .assert_is_id(true)
+        .assert_id_strategy(IdStrategy::Auto)
+        .assert_base_type(&ScalarType::Uuid)
+        .assert_default_value(DefaultValue::Expression(
+            ValueGenerator::new("uuid".to_owned(), Vec::new()).unwrap(),
+        ));
+}
+
 #[test]
 fn should_allow_string_ids_without_default() {
     let dml = r#"
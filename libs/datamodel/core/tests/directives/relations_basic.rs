@@ -85,6 +85,41 @@ fn resolve_related_fields() {
         .assert_relation_to_fields(&["firstName", "lastName"]);
 }
 
+#[test]
+fn resolve_related_fields_mapped_to_multiple_columns() {
+    let dml = r#"
+    model User {
+        tenant String
+        id     Int
+        posts  Post[]
+
+        @@id([tenant, id])
+    }
+
+    model Post {
+        id   Int  @id
+        user User @relation(references: [tenant, id]) @map(["user_tenant", "user_id"])
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    let post_model = schema.assert_has_model("Post");
+    post_model
+        .assert_has_field("user")
+        .assert_relation_to("User")
+        .assert_relation_to_fields(&["tenant", "id"])
+        .assert_relation_columns(&["user_tenant", "user_id"]);
+
+    let rendered = datamodel::render_datamodel_to_string(&schema).unwrap();
+    assert!(rendered.contains(r#"@map(["user_tenant", "user_id"])"#));
+
+    parse(&rendered)
+        .assert_has_model("Post")
+        .assert_has_field("user")
+        .assert_relation_columns(&["user_tenant", "user_id"]);
+}
+
 #[test]
 fn resolve_enum_field() {
     let dml = r#"
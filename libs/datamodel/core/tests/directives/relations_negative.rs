@@ -205,3 +205,93 @@ fn should_fail_on_conflicting_generated_back_relation_fields() {
         Span::new(98, 152),
     ));
 }
+
+#[test]
+fn should_fail_on_references_to_unknown_fields() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+        user User @relation(references: [name])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The relation field `user` references `name`, which is not a field of model `User`.",
+        "Post",
+        Span::new(109, 148),
+    ));
+}
+
+#[test]
+fn should_fail_on_duplicate_references() {
+    let dml = r#"
+    model User {
+        id    Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id   Int  @id
+        user User @relation(references: [id, id])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The field `id` is referenced more than once.",
+        "relation",
+        Span::new(147, 155),
+    ));
+}
+
+#[test]
+fn should_fail_if_a_relation_field_is_mapped_to_the_wrong_number_of_columns() {
+    let dml = r#"
+    model User {
+        tenant String
+        id     Int
+        posts  Post[]
+
+        @@id([tenant, id])
+    }
+
+    model Post {
+        id   Int  @id
+        user User @map(["user_tenant", "user_id", "user_name"])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The relation field `user` is mapped to 3 columns, but references 2 fields.",
+        "Post",
+        Span::new(163, 218),
+    ));
+}
+
+#[test]
+fn should_fail_if_a_scalar_field_is_mapped_to_multiple_columns() {
+    let dml = r#"
+    model User {
+        id   Int    @id
+        name String @map(["first_name", "last_name"])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "Only relation fields holding a foreign key can be mapped to multiple columns.",
+        "map",
+        Span::new(67, 94),
+    ));
+}
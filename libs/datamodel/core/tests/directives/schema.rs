@@ -0,0 +1,62 @@
+use datamodel::{ast::Span, error::DatamodelError, render_datamodel_to_string};
+
+use crate::common::*;
+
+#[test]
+fn schema_directive_must_work() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+
+    model Invoice {
+        id Int @id
+
+        @@schema("billing")
+        @@map("invoices")
+    }
+    "#;
+
+    let schema = parse(dml);
+    assert_eq!(schema.assert_has_model("User").schema, None);
+
+    let invoice = schema.assert_has_model("Invoice");
+    invoice.assert_with_db_name("invoices");
+    assert_eq!(invoice.schema, Some("billing".to_owned()));
+}
+
+#[test]
+fn schema_directive_must_be_rendered() {
+    let dml = r#"
+    model Invoice {
+        id Int @id
+
+        @@schema("billing")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+
+    let reparsed = datamodel::parse_datamodel(&rendered).unwrap();
+    assert_eq!(reparsed.assert_has_model("Invoice").schema, Some("billing".to_owned()));
+}
+
+#[test]
+fn must_error_when_the_schema_name_is_empty() {
+    let dml = r#"
+    model Invoice {
+        id Int @id
+
+        @@schema("")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The schema name must not be empty.",
+        "schema",
+        Span::new(51, 61),
+    ));
+}
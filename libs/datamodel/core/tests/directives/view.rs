@@ -0,0 +1,83 @@
+use datamodel::{ast::Span, error::DatamodelError, render_datamodel_to_string};
+
+use crate::common::*;
+
+#[test]
+fn view_directive_must_work() {
+    let dml = r#"
+    model User {
+        id   Int    @id
+        name String
+    }
+
+    model UserName {
+        id   Int    @id
+        name String
+
+        @@view
+    }
+    "#;
+
+    let schema = parse(dml);
+    schema.assert_has_model("User").assert_is_view(false);
+    schema.assert_has_model("UserName").assert_is_view(true);
+}
+
+#[test]
+fn view_directive_must_be_rendered() {
+    let dml = r#"
+    model UserName {
+        id   Int    @id
+        name String
+
+        @@view
+    }
+    "#;
+
+    let schema = parse(dml);
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+
+    let reparsed = datamodel::parse_datamodel(&rendered).unwrap();
+    reparsed.assert_has_model("UserName").assert_is_view(true);
+}
+
+#[test]
+fn view_models_must_have_an_id_field() {
+    let dml = r#"
+    model UserName {
+        id   Int
+        name String
+
+        @@view
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "View models must mark the single field identifying their records with `@id`.",
+        "UserName",
+        Span::new(5, 80),
+    ));
+}
+
+#[test]
+fn view_models_must_not_have_multi_field_ids() {
+    let dml = r#"
+    model UserName {
+        a    Int
+        b    Int
+
+        @@id([a, b])
+        @@view
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "View models must mark the single field identifying their records with `@id`.",
+        "UserName",
+        Span::new(5, 98),
+    ));
+}
@@ -133,3 +133,40 @@ fn test_parser_renderer_model_with_comments_via_dml() {
 
     assert_eq!(rendered, DATAMODEL_STRING_WITH_COMMENTS);
 }
+
+const DATAMODEL_STRING_WITH_NATIVE_TYPES: &str = r#"datasource pg {
+  provider = "postgresql"
+  url      = "postgresql://localhost/postgres"
+}
+
+model Product {
+  id    Int     @id @pg.BigInt
+  name  String  @pg.VarChar(255)
+  price Decimal @pg.Numeric(10, 2)
+}"#;
+
+#[test]
+fn test_parser_renderer_native_types_via_dml() {
+    let dml = datamodel::parse_datamodel(DATAMODEL_STRING_WITH_NATIVE_TYPES).unwrap();
+    let config = datamodel::parse_configuration(DATAMODEL_STRING_WITH_NATIVE_TYPES).unwrap();
+    let rendered = datamodel::render_datamodel_and_config_to_string(&dml, &config).unwrap();
+
+    print!("{}", rendered);
+
+    assert_eq!(rendered, DATAMODEL_STRING_WITH_NATIVE_TYPES);
+}
+
+const DATAMODEL_STRING_WITH_DB_GENERATED_DEFAULTS: &str = r#"model Post {
+  id        Uuid     @id @default(dbgenerated("gen_random_uuid()"))
+  createdAt DateTime @default(dbgenerated("now() at time zone 'utc'"))
+}"#;
+
+#[test]
+fn test_parser_renderer_db_generated_defaults_via_dml() {
+    let dml = datamodel::parse_datamodel(DATAMODEL_STRING_WITH_DB_GENERATED_DEFAULTS).unwrap();
+    let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
+
+    print!("{}", rendered);
+
+    assert_eq!(rendered, DATAMODEL_STRING_WITH_DB_GENERATED_DEFAULTS);
+}
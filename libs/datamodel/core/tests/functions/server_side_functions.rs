@@ -66,3 +66,33 @@ fn correctly_handle_server_side_uuid_function() {
             ValueGenerator::new("uuid".to_owned(), Vec::new()).unwrap(),
         ));
 }
+
+#[test]
+fn correctly_handle_server_side_dbgenerated_function() {
+    let dml = r#"
+    model User {
+        id Uuid @id @default(dbgenerated("gen_random_uuid()"))
+        signupDate DateTime @default(dbgenerated("now() at time zone 'utc'"))
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model
+        .assert_has_field("id")
+        .assert_base_type(&ScalarType::Uuid)
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_db_generated(
+            "gen_random_uuid()".to_owned(),
+            ScalarType::Uuid,
+        )));
+
+    let signup_date = user_model.assert_has_field("signupDate");
+    signup_date.assert_base_type(&ScalarType::DateTime);
+
+    let default_value = signup_date.default_value.as_ref().unwrap();
+    assert_eq!(default_value.get_type(), ScalarType::DateTime);
+    assert_eq!(
+        default_value.db_generated_expression(),
+        Some("now() at time zone 'utc'")
+    );
+}
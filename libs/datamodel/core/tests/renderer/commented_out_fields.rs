@@ -0,0 +1,53 @@
+#[test]
+fn commented_out_fields_are_rendered_as_comments() {
+    let input = r#"
+        model Place {
+            id       Int    @id
+            location String @map("geom")
+        }
+    "#;
+
+    let mut dml = datamodel::parse_datamodel(input).unwrap();
+    dml.find_model_mut("Place")
+        .unwrap()
+        .find_field_mut("location")
+        .unwrap()
+        .is_commented_out = true;
+
+    let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
+
+    assert!(rendered.contains("// location String @map(\"geom\")"));
+
+    let reparsed = datamodel::parse_datamodel(&rendered).unwrap();
+    let place = reparsed.find_model("Place").unwrap();
+
+    assert!(place.find_field("id").is_some());
+    assert!(place.find_field("location").is_none());
+}
+
+#[test]
+fn commented_out_models_are_rendered_as_comments() {
+    let input = r#"
+        model User {
+            id Int @id
+        }
+
+        model Post {
+            id    Int     @id
+            title String?
+        }
+    "#;
+
+    let mut dml = datamodel::parse_datamodel(input).unwrap();
+    dml.find_model_mut("Post").unwrap().is_commented_out = true;
+
+    let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
+
+    assert!(rendered.contains("// model Post {\n"));
+    assert!(rendered.contains("\n// }"));
+
+    let reparsed = datamodel::parse_datamodel(&rendered).unwrap();
+
+    assert!(reparsed.find_model("User").is_some());
+    assert!(reparsed.find_model("Post").is_none());
+}
@@ -1 +1,2 @@
+pub mod commented_out_fields;
 pub mod simplification;
@@ -108,3 +108,95 @@ fn should_fail_on_custom_related_types() {
         ast::Span::new(25, 29),
     ));
 }
+
+#[test]
+fn should_fail_on_native_type_with_wrong_prisma_type() {
+    let dml = r#"
+    datasource pg {
+        provider = "postgresql"
+        url = "postgresql://"
+    }
+
+    model Blog {
+        id    Int @id
+        title Int @pg.VarChar(255)
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native type VarChar can only be used on fields of type String, but the field is of type Int.",
+        "pg.VarChar",
+        ast::Span::new(148, 163),
+    ));
+}
+
+#[test]
+fn should_fail_on_native_type_with_wrong_number_of_arguments() {
+    let dml = r#"
+    datasource pg {
+        provider = "postgresql"
+        url = "postgresql://"
+    }
+
+    model Blog {
+        id    Int @id
+        title String @pg.VarChar
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native type VarChar takes 1 argument(s), but received 0.",
+        "pg.VarChar",
+        ast::Span::new(151, 161),
+    ));
+}
+
+#[test]
+fn should_fail_on_unknown_native_type() {
+    let dml = r#"
+    datasource pg {
+        provider = "postgresql"
+        url = "postgresql://"
+    }
+
+    model Blog {
+        id    Int @id
+        title String @pg.Text2
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native type Text2 is not supported.",
+        "pg.Text2",
+        ast::Span::new(151, 159),
+    ));
+}
+
+#[test]
+fn should_fail_on_bytes_lists() {
+    let dml = r#"
+    datasource pg {
+        provider = "postgresql"
+        url = "postgresql://"
+    }
+
+    model Blog {
+        id    Int @id
+        blobs Bytes[]
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_model_validation_error(
+        "The field `blobs` is a list of Bytes, which is not supported.",
+        "Blog",
+        ast::Span::new(137, 150),
+    ));
+}
@@ -134,17 +134,18 @@ fn should_handle_type_mappings() {
 }
 
 #[test]
-#[ignore]
 fn should_handle_type_specifications() {
     let dml = r#"
         datasource pg {
-          provider = "postgres"
+          provider = "postgresql"
           url = "postgresql://"
         }
 
         model Blog {
-            id     Int @id
-            bigInt Int @pg.BigInt
+            id     Int     @id
+            bigInt Int     @pg.BigInt
+            title  String  @pg.VarChar(255)
+            price  Decimal @pg.Numeric(10, 2)
         }
     "#;
 
@@ -154,5 +155,102 @@ fn should_handle_type_specifications() {
 
     user_model
         .assert_has_field("bigInt")
-        .assert_connector_type(&ScalarFieldType::new("BigInt", ScalarType::Int, "bigint"));
+        .assert_connector_type(&ScalarFieldType::new("BigInt", ScalarType::Int, "int8"));
+    user_model
+        .assert_has_field("title")
+        .assert_connector_type(&ScalarFieldType::with_args(
+            "VarChar",
+            vec![255],
+            ScalarType::String,
+            "varchar(255)",
+        ));
+    user_model
+        .assert_has_field("price")
+        .assert_connector_type(&ScalarFieldType::with_args(
+            "Numeric",
+            vec![10, 2],
+            ScalarType::Decimal,
+            "numeric(10,2)",
+        ));
+}
+
+#[test]
+fn should_resolve_native_type_aliases() {
+    let dml = r#"
+        datasource pg {
+          provider = "postgresql"
+          url = "postgresql://"
+        }
+
+        model Blog {
+            id   Int    @id
+            name String @pg.CharacterVarying(100)
+        }
+    "#;
+
+    let datamodel = parse(dml);
+
+    datamodel
+        .assert_has_model("Blog")
+        .assert_has_field("name")
+        .assert_connector_type(&ScalarFieldType::with_args(
+            "VarChar",
+            vec![100],
+            ScalarType::String,
+            "varchar(100)",
+        ));
+}
+
+#[test]
+fn should_handle_binary_uuid_storage_on_mysql() {
+    let dml = r#"
+        datasource db {
+          provider = "mysql"
+          url = "mysql://"
+        }
+
+        model Blog {
+            id         Int  @id
+            externalId Uuid @db.BinaryUuid
+        }
+    "#;
+
+    let datamodel = parse(dml);
+
+    datamodel
+        .assert_has_model("Blog")
+        .assert_has_field("externalId")
+        .assert_connector_type(&ScalarFieldType::new("BinaryUuid", ScalarType::Uuid, "binary(16)"));
+}
+
+#[test]
+fn should_allow_binary_uuid_ids_on_mysql() {
+    let dml = r#"
+        datasource db {
+          provider = "mysql"
+          url = "mysql://"
+        }
+
+        model Blog {
+            id    Uuid   @id @db.BinaryUuid
+            posts Post[]
+        }
+
+        model Post {
+            id   Uuid @id @db.BinaryUuid
+            blog Blog @relation(references: id)
+        }
+    "#;
+
+    let datamodel = parse(dml);
+
+    datamodel
+        .assert_has_model("Blog")
+        .assert_has_field("id")
+        .assert_is_id(true)
+        .assert_connector_type(&ScalarFieldType::new("BinaryUuid", ScalarType::Uuid, "binary(16)"));
+    datamodel
+        .assert_has_model("Post")
+        .assert_has_field("blog")
+        .assert_relation_to("Blog");
 }
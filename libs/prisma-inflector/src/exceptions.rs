@@ -79,4 +79,38 @@ lazy_static! {
         ("y$", "ies"),
     ];
 
+    // Plural to singular, most specific first.
+    pub static ref SINGULAR_SUFFIX_INFLECTIONS: Vec<(&'static str, &'static str)> = vec![
+        ("(database)s$", "${1}"),
+        ("(quiz)zes$", "${1}"),
+        ("(matr)ices$", "${1}ix"),
+        ("(vert|ind)ices$", "${1}ex"),
+        ("^(ox)en$", "${1}"),
+        ("(alias|status)(es)?$", "${1}"),
+        ("(octop|vir)(us|i)$", "${1}us"),
+        ("^(a)x[ie]s$", "${1}xis"),
+        ("(cris|test)(is|es)$", "${1}is"),
+        ("(shoe)s$", "${1}"),
+        ("(o)es$", "${1}"),
+        ("(bus)(es)?$", "${1}"),
+        ("^([lm])ice$", "${1}ouse"),
+        ("(x|ch|ss|sh)es$", "${1}"),
+        ("(m)ovies$", "${1}ovie"),
+        ("(s)eries$", "${1}eries"),
+        ("([^aeiouy]|qu)ies$", "${1}y"),
+        ("([lr])ves$", "${1}f"),
+        ("(tive)s$", "${1}"),
+        ("(hive)s$", "${1}"),
+        ("([^f])ves$", "${1}fe"),
+        ("(analy|ba|diagno|parenthe|progno|synop|the)(sis|ses)$", "${1}sis"),
+        ("([ti])a$", "${1}um"),
+        ("(n)ews$", "${1}ews"),
+        ("(wo|^)men$", "${1}man"),
+        ("teeth$", "tooth"),
+        ("geese$", "goose"),
+        ("feet$", "foot"),
+        ("(ss|us|is)$", "${1}"),
+        ("s$", ""),
+    ];
+
 }
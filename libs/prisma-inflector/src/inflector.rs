@@ -14,6 +14,7 @@ pub enum Mode {
 pub struct Inflector {
     pub mode: Mode,
     rules: Vec<Rule>,
+    singular_rules: Vec<Rule>,
     _inhibit: (),
 }
 
@@ -28,6 +29,18 @@ impl Inflector {
         panic!("Invariant violation: Inflector should always fall back to catch-all case -s.")
     }
 
+    /// The inverse of `pluralize`, as far as it can be inverted. Words that do not look like plurals
+    /// are returned unchanged.
+    pub fn singularize(&self, s: &str) -> String {
+        for rule in &self.singular_rules {
+            if let Some(s) = rule.inflect(s) {
+                return s;
+            }
+        }
+
+        s.to_owned()
+    }
+
     pub fn new(mode: Mode) -> Inflector {
         let mut rules = vec![];
 
@@ -113,13 +126,46 @@ impl Inflector {
         // Global fallback, just assume that the plural adds -s
         rules.push(Self::regex_rule("$", "s"));
 
+        let singular_rules = Self::singular_rules(&mode);
+
         Inflector {
             mode,
             rules,
+            singular_rules,
             _inhibit: (),
         }
     }
 
+    fn singular_rules(mode: &Mode) -> Vec<Rule> {
+        let mut rules = vec![];
+
+        rules.push(Self::category_rule("", "", &exceptions::UNCOUNTABLE));
+
+        // Irregular plurals, the other way around.
+        let additional_irregulars = match mode {
+            Mode::Anglicized => exceptions::IRREGULAR_ANGLICIZED.to_vec(),
+            Mode::Classical => exceptions::IRREGULAR_CLASSICAL.to_vec(),
+        };
+
+        exceptions::STANDARD_IRREGULAR
+            .iter()
+            .chain(additional_irregulars.iter())
+            .filter(|(singular, plural)| singular != plural)
+            .for_each(|(singular, plural)| {
+                Self::irregular(*plural, *singular)
+                    .into_iter()
+                    .for_each(|r| rules.push(r));
+            });
+
+        exceptions::SINGULAR_SUFFIX_INFLECTIONS
+            .iter()
+            .for_each(|(plural, singular)| {
+                rules.push(Self::regex_rule(plural, singular));
+            });
+
+        rules
+    }
+
     fn irregular(singular: &'static str, plural: &'static str) -> Vec<Rule> {
         let first_singular = singular.chars().next().unwrap();
         let first_plural = plural.chars().next().unwrap();
@@ -217,4 +263,40 @@ mod test {
             assert_eq!(inflector.pluralize(singular), expected_plural);
         });
     }
+
+    #[test]
+    fn test_singularize_example_word_list() {
+        let examples = vec![
+            ("users", "user"),
+            ("user_accounts", "user_account"),
+            ("User", "User"),
+            ("categories", "category"),
+            ("Boxes", "Box"),
+            ("churches", "church"),
+            ("houses", "house"),
+            ("courses", "course"),
+            ("statuses", "status"),
+            ("status", "status"),
+            ("addresses", "address"),
+            ("address", "address"),
+            ("wolves", "wolf"),
+            ("lives", "life"),
+            ("children", "child"),
+            ("Women", "Woman"),
+            ("mice", "mouse"),
+            ("matrices", "matrix"),
+            ("indices", "index"),
+            ("series", "series"),
+            ("news", "news"),
+            ("quizzes", "quiz"),
+            ("analyses", "analysis"),
+            ("heroes", "hero"),
+        ];
+
+        let inflector = Inflector::new(Mode::Anglicized);
+
+        examples.into_iter().for_each(|(plural, expected_singular)| {
+            assert_eq!(inflector.singularize(plural), expected_singular);
+        });
+    }
 }
@@ -23,6 +23,15 @@ impl Rule {
     pub fn regex(singular: Regex, plural: String) -> Rule {
         Rule::Regex(RegexRule { singular, plural })
     }
+
+    /// Like `pluralize`, but a matching rule also applies if it leaves the word as it is.
+    pub fn inflect(&self, s: &str) -> Option<String> {
+        match self {
+            Rule::Category(c) => c.pluralize(s),
+            Rule::Regex(r) if r.singular.is_match(s) => Some(r.singular.replace(s, &r.plural as &str).to_string()),
+            Rule::Regex(_) => None,
+        }
+    }
 }
 
 impl Pluralize for Rule {
@@ -49,8 +49,10 @@ impl<'a> DatamodelConverter<'a> {
             .map(|model| ModelTemplate {
                 name: model.name.clone(),
                 is_embedded: model.is_embedded,
+                is_view: model.is_view,
                 fields: self.convert_fields(model),
                 manifestation: model.database_name.clone(),
+                schema: model.schema.clone(),
                 indexes: self.convert_indexes(model),
             })
             .collect()
@@ -97,6 +99,7 @@ impl<'a> DatamodelConverter<'a> {
                     behaviour: field.behaviour(),
                     default_value: field.default_value(),
                     internal_enum: field.internal_enum(self.datamodel),
+                    native_type: field.native_type(),
                 }),
             })
             .collect()
@@ -116,10 +119,22 @@ impl<'a> DatamodelConverter<'a> {
             .collect()
     }
 
+    /// Compound ids become unique indexes. Unique indexes covering relation fields are left out, they can
+    /// not be expressed over scalar fields.
     fn convert_indexes(&self, model: &dml::Model) -> Vec<IndexTemplate> {
-        model
+        let covers_only_scalar_fields = |fields: &[String]| {
+            fields.iter().all(|name| {
+                model
+                    .find_field(name)
+                    .map(|field| !field.field_type.is_relation())
+                    .unwrap_or(false)
+            })
+        };
+
+        let mut indexes: Vec<IndexTemplate> = model
             .indexes
             .iter()
+            .filter(|i| i.tpe == dml::IndexType::Normal || covers_only_scalar_fields(&i.fields))
             .map(|i| IndexTemplate {
                 name: i.name.clone(),
                 fields: i.fields.clone(),
@@ -128,7 +143,21 @@ impl<'a> DatamodelConverter<'a> {
                     dml::IndexType::Normal => IndexType::Normal,
                 },
             })
-            .collect()
+            .collect();
+
+        let id_is_unique_index = indexes
+            .iter()
+            .any(|i| i.typ == IndexType::Unique && i.fields == model.id_fields);
+
+        if model.id_fields.len() > 1 && !id_is_unique_index && covers_only_scalar_fields(&model.id_fields) {
+            indexes.push(IndexTemplate {
+                name: None,
+                fields: model.id_fields.clone(),
+                typ: IndexType::Unique,
+            });
+        }
+
+        indexes
     }
 
     pub fn calculate_relations(datamodel: &dml::Datamodel) -> Vec<TempRelationHolder> {
@@ -207,22 +236,22 @@ impl<'a> DatamodelConverter<'a> {
                     };
                     let inline_on_model_a = TempManifestationHolder::Inline {
                         in_table_of_model: model_a.name.clone(),
-                        column: field_a.final_db_name(),
+                        columns: datamodel.relation_columns(&field_a),
                         referenced_fields: referenced_fields_a.clone(),
                     };
                     let inline_on_model_b = TempManifestationHolder::Inline {
                         in_table_of_model: model_b.name.clone(),
-                        column: field_b.final_db_name(),
+                        columns: datamodel.relation_columns(&field_b),
                         referenced_fields: referenced_fields_b.clone(),
                     };
                     let inline_on_this_model = TempManifestationHolder::Inline {
                         in_table_of_model: model.name.clone(),
-                        column: field.final_db_name(),
+                        columns: datamodel.relation_columns(field),
                         referenced_fields: to_fields.clone(),
                     };
                     let inline_on_related_model = TempManifestationHolder::Inline {
                         in_table_of_model: related_model.name.clone(),
-                        column: related_field.final_db_name(),
+                        columns: datamodel.relation_columns(related_field),
                         referenced_fields: related_field_info.to_fields.clone(),
                     };
 
@@ -276,8 +305,8 @@ pub struct TempRelationHolder {
 pub enum TempManifestationHolder {
     Inline {
         in_table_of_model: String,
-        /// The name of the foreign key columns.
-        column: String,
+        /// The names of the foreign key columns. Relations over multiple fields have one per field.
+        columns: Vec<String>,
         /// The name of the (dml) fields referenced by the relation.
         referenced_fields: Vec<String>,
     },
@@ -339,12 +368,35 @@ impl TempRelationHolder {
             }),
             TempManifestationHolder::Inline {
                 in_table_of_model,
-                column,
-                ..
-            } => RelationLinkManifestation::Inline(InlineRelation {
-                in_table_of_model_name: in_table_of_model.to_string(),
-                referencing_column: column.to_string(),
-            }),
+                columns,
+                referenced_fields,
+            } => {
+                let referenced_model = if in_table_of_model == &self.model_a.name {
+                    &self.model_b
+                } else {
+                    &self.model_a
+                };
+
+                let referenced_fields = if referenced_fields.is_empty() {
+                    referenced_model.id_field_names()
+                } else {
+                    referenced_fields.clone()
+                };
+
+                RelationLinkManifestation::Inline(InlineRelation {
+                    in_table_of_model_name: in_table_of_model.to_string(),
+                    referencing_columns: columns.clone(),
+                    referenced_columns: referenced_fields
+                        .iter()
+                        .map(|name| {
+                            referenced_model
+                                .find_field(name)
+                                .map(|field| field.final_db_name())
+                                .unwrap_or_else(|| name.clone())
+                        })
+                        .collect(),
+                })
+            }
         }
     }
 }
@@ -360,6 +412,7 @@ trait DatamodelFieldExtensions {
     fn final_db_name(&self) -> String;
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum>;
     fn default_value(&self) -> Option<dml::DefaultValue>;
+    fn native_type(&self) -> Option<String>;
 }
 
 impl DatamodelFieldExtensions for dml::Field {
@@ -367,16 +420,18 @@ impl DatamodelFieldExtensions for dml::Field {
         match self.field_type {
             dml::FieldType::Enum(_) => TypeIdentifier::Enum,
             dml::FieldType::Relation(_) => TypeIdentifier::Relation,
-            dml::FieldType::Base(scalar) => match scalar {
-                dml::ScalarType::Boolean => TypeIdentifier::Boolean,
-                dml::ScalarType::DateTime => TypeIdentifier::DateTime,
-                dml::ScalarType::Decimal => TypeIdentifier::Float,
-                dml::ScalarType::Float => TypeIdentifier::Float,
-                dml::ScalarType::Int => TypeIdentifier::Int,
-                dml::ScalarType::String => TypeIdentifier::String,
-            },
-            dml::FieldType::ConnectorSpecific { .. } => {
-                unimplemented!("Connector Specific types are not supported here yet")
+            dml::FieldType::Base(_) | dml::FieldType::ConnectorSpecific(_) => {
+                match self.field_type.scalar_type().unwrap() {
+                    dml::ScalarType::Boolean => TypeIdentifier::Boolean,
+                    dml::ScalarType::DateTime => TypeIdentifier::DateTime,
+                    dml::ScalarType::Json => TypeIdentifier::Json,
+                    dml::ScalarType::Bytes => TypeIdentifier::Bytes,
+                    dml::ScalarType::Decimal => TypeIdentifier::Decimal,
+                    dml::ScalarType::Float => TypeIdentifier::Float,
+                    dml::ScalarType::Int => TypeIdentifier::Int,
+                    dml::ScalarType::String => TypeIdentifier::String,
+                    dml::ScalarType::Uuid => TypeIdentifier::UUID,
+                }
             }
         }
     }
@@ -454,4 +509,11 @@ impl DatamodelFieldExtensions for dml::Field {
     fn default_value(&self) -> Option<dml::DefaultValue> {
         self.default_value.clone()
     }
+
+    fn native_type(&self) -> Option<String> {
+        match &self.field_type {
+            dml::FieldType::ConnectorSpecific(sft) => Some(sft.datasource_type().to_owned()),
+            _ => None,
+        }
+    }
 }
@@ -29,9 +29,11 @@ pub struct FieldManifestation {
 pub enum TypeIdentifier {
     String,
     Float,
+    Decimal,
     Boolean,
     Enum,
     Json,
+    Bytes,
     DateTime,
     GraphQLID,
     UUID,
@@ -116,6 +118,7 @@ impl FieldTemplate {
                     behaviour: st.behaviour,
                     model,
                     default_value: st.default_value,
+                    native_type: st.native_type,
                 };
 
                 Field::Scalar(Arc::new(scalar))
@@ -124,6 +124,8 @@ impl RelationField {
             .unwrap()
     }
 
+    /// The column of the relation field. Foreign keys over multiple columns are named after their first
+    /// column here, reads and writes go through all of them.
     pub fn db_name(&self) -> String {
         let relation = self.relation();
 
@@ -134,11 +136,11 @@ impl RelationField {
                 if is_self_rel && self.is_hidden {
                     self.name.clone()
                 } else if is_self_rel && (self.relation_side == RelationSide::B || self.related_field().is_hidden) {
-                    m.referencing_column.clone()
+                    m.referencing_columns[0].clone()
                 } else if is_self_rel && self.relation_side == RelationSide::A {
                     self.name.clone()
                 } else if m.in_table_of_model_name == self.model().name {
-                    m.referencing_column.clone()
+                    m.referencing_columns[0].clone()
                 } else {
                     self.name.clone()
                 }
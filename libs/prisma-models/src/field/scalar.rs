@@ -27,6 +27,7 @@ pub struct ScalarFieldTemplate {
     pub behaviour: Option<FieldBehaviour>,
     pub default_value: Option<DefaultValue>,
     pub internal_enum: Option<InternalEnum>,
+    pub native_type: Option<String>,
 }
 
 #[derive(DebugStub)]
@@ -41,6 +42,8 @@ pub struct ScalarField {
     pub internal_enum: Option<InternalEnum>,
     pub behaviour: Option<FieldBehaviour>,
     pub default_value: Option<DefaultValue>,
+    /// The datasource type of fields with a native type, e.g. `binary(16)`.
+    pub native_type: Option<String>,
 
     #[debug_stub = "#ModelWeakRef#"]
     pub model: ModelWeakRef,
@@ -60,6 +63,7 @@ impl Hash for ScalarField {
         self.is_auto_generated_int_id.hash(state);
         self.manifestation.hash(state);
         self.internal_enum.hash(state);
+        self.native_type.hash(state);
         self.behaviour.hash(state);
         self.is_unique.hash(state);
         self.model().hash(state);
@@ -76,6 +80,7 @@ impl PartialEq for ScalarField {
             && self.is_auto_generated_int_id == other.is_auto_generated_int_id
             && self.manifestation == other.manifestation
             && self.internal_enum == other.internal_enum
+            && self.native_type == other.native_type
             && self.behaviour == other.behaviour
             && self.default_value == other.default_value
             && self.is_unique == other.is_unique
@@ -13,8 +13,10 @@ pub type ModelWeakRef = Weak<Model>;
 pub struct ModelTemplate {
     pub name: String,
     pub is_embedded: bool,
+    pub is_view: bool,
     pub fields: Vec<FieldTemplate>,
     pub manifestation: Option<String>,
+    pub schema: Option<String>,
     pub indexes: Vec<IndexTemplate>,
 }
 
@@ -22,6 +24,10 @@ pub struct ModelTemplate {
 pub struct Model {
     pub name: String,
     pub is_embedded: bool,
+    /// Views can be read, but not written to.
+    pub is_view: bool,
+    /// The database schema of the table, if it is not the one of the connection.
+    pub schema: Option<String>,
 
     manifestation: Option<String>,
     fields: OnceCell<Fields>,
@@ -36,6 +42,8 @@ impl ModelTemplate {
         let model = Arc::new(Model {
             name: self.name,
             is_embedded: self.is_embedded,
+            is_view: self.is_view,
+            schema: self.schema,
             fields: OnceCell::new(),
             indexes: OnceCell::new(),
             manifestation: self.manifestation,
@@ -5,17 +5,22 @@ use std::collections::{btree_map::Keys, BTreeMap};
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct PrismaArgs {
     pub args: BTreeMap<String, PrismaValue>,
+    /// Values to append to scalar list fields, by field name.
+    pub list_pushes: BTreeMap<String, PrismaValue>,
 }
 
 impl From<BTreeMap<String, PrismaValue>> for PrismaArgs {
     fn from(args: BTreeMap<String, PrismaValue>) -> Self {
-        Self { args }
+        Self {
+            args,
+            list_pushes: BTreeMap::new(),
+        }
     }
 }
 
 impl PrismaArgs {
     pub fn new() -> Self {
-        Self { args: BTreeMap::new() }
+        Self::default()
     }
 
     pub fn insert<T, V>(&mut self, key: T, arg: V)
@@ -26,6 +31,14 @@ impl PrismaArgs {
         self.args.insert(key.into(), arg.into());
     }
 
+    pub fn push_to_list<T, V>(&mut self, key: T, values: V)
+    where
+        T: Into<String>,
+        V: Into<PrismaValue>,
+    {
+        self.list_pushes.insert(key.into(), values.into());
+    }
+
     pub fn has_arg_for(&self, field: &str) -> bool {
         self.args.contains_key(field)
     }
@@ -61,7 +74,7 @@ impl PrismaArgs {
     }
 
     pub fn update_datetimes(&mut self, model: ModelRef) {
-        if !self.args.is_empty() {
+        if !self.args.is_empty() || !self.list_pushes.is_empty() {
             if let Some(field) = model.fields().updated_at() {
                 if let None = self.args.get(&field.name) {
                     self.args.insert(field.name.clone(), PrismaValue::DateTime(Utc::now()));
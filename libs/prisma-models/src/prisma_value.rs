@@ -28,6 +28,8 @@ pub enum PrismaValue {
     String(String),
     #[serde(serialize_with = "serialize_decimal")]
     Float(Decimal),
+    #[serde(serialize_with = "serialize_decimal_string")]
+    Decimal(Decimal),
     Boolean(bool),
     #[serde(serialize_with = "serialize_date")]
     DateTime(DateTime<Utc>),
@@ -37,6 +39,10 @@ pub enum PrismaValue {
     Uuid(Uuid),
     GraphqlId(GraphqlId),
     List(PrismaListValue),
+    /// A JSON document, kept in its serialized form.
+    Json(String),
+    #[serde(serialize_with = "serialize_bytes")]
+    Bytes(Vec<u8>),
 }
 
 fn serialize_date<S>(date: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
@@ -53,6 +59,22 @@ where
     decimal.to_f64().expect("Decimal is not a f64.").serialize(serializer)
 }
 
+/// Binary values are encoded in base64.
+fn serialize_bytes<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    base64::encode(bytes).serialize(serializer)
+}
+
+/// Decimals are encoded as strings, as JSON numbers can't hold them without loss of precision.
+fn serialize_decimal_string<S>(decimal: &Decimal, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    decimal.to_string().serialize(serializer)
+}
+
 impl PrismaValue {
     pub fn is_null(&self) -> bool {
         match self {
@@ -67,12 +89,15 @@ impl fmt::Display for PrismaValue {
         match self {
             PrismaValue::String(x) => x.fmt(f),
             PrismaValue::Float(x) => x.fmt(f),
+            PrismaValue::Decimal(x) => x.fmt(f),
             PrismaValue::Boolean(x) => x.fmt(f),
             PrismaValue::DateTime(x) => x.fmt(f),
             PrismaValue::Enum(x) => x.as_string().fmt(f),
             PrismaValue::Int(x) => x.fmt(f),
             PrismaValue::Null => "null".fmt(f),
             PrismaValue::Uuid(x) => x.fmt(f),
+            PrismaValue::Json(x) => x.fmt(f),
+            PrismaValue::Bytes(x) => base64::encode(x).fmt(f),
             PrismaValue::GraphqlId(x) => match x {
                 GraphqlId::String(x) => x.fmt(f),
                 GraphqlId::Int(x) => x.fmt(f),
@@ -269,7 +294,7 @@ impl From<Option<dml::ScalarValue>> for PrismaValue {
             dml::ScalarValue::Float(x) => x.try_into().expect("Can't convert float to decimal"),
             dml::ScalarValue::String(x) => PrismaValue::String(x.clone()),
             dml::ScalarValue::DateTime(x) => PrismaValue::DateTime(x),
-            dml::ScalarValue::Decimal(x) => x.try_into().expect("Can't convert float to decimal"),
+            dml::ScalarValue::Decimal(x) => PrismaValue::Decimal(x),
             dml::ScalarValue::ConstantLiteral(x) => PrismaValue::Enum(EnumValue::string(x.clone(), x.clone())),
             dml::ScalarValue::Expression(_, _, _) => unreachable!(),
         })
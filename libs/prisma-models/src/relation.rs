@@ -30,7 +30,10 @@ impl OnDelete {
 #[derive(Debug, Clone, PartialEq)]
 pub struct InlineRelation {
     pub in_table_of_model_name: String,
-    pub referencing_column: String,
+    /// The foreign key columns. Relations over multiple fields have more than one.
+    pub referencing_columns: Vec<String>,
+    /// The columns referenced by the foreign key columns, in the same order.
+    pub referenced_columns: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -232,6 +235,32 @@ impl Relation {
         }
     }
 
+    /// Whether the relation is inlined with foreign key columns that reference other fields than the id of
+    /// the related model, e.g. several fields. Such relations can not be resolved by comparing ids, they are
+    /// joined over the referenced columns.
+    pub fn references_non_id_fields(&self) -> bool {
+        let manifestation = match self.inline_manifestation() {
+            Some(manifestation) => manifestation,
+            None => return false,
+        };
+
+        let referenced_model = if manifestation.in_table_of_model_name == self.model_a().name {
+            self.model_b()
+        } else {
+            self.model_a()
+        };
+        let id_field = referenced_model
+            .fields()
+            .scalar()
+            .into_iter()
+            .find(|field| field.is_id());
+
+        match (manifestation.referenced_columns.as_slice(), id_field) {
+            ([column], Some(id_field)) => column != id_field.db_name(),
+            _ => true,
+        }
+    }
+
     pub fn internal_data_model(&self) -> InternalDataModelRef {
         self.internal_data_model
             .upgrade()
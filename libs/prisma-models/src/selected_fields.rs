@@ -106,6 +106,8 @@ impl SelectedFields {
             .expect("Expected at least one field to be present.")
     }
 
+    /// The relation fields whose column holds the id of the related record. Foreign keys referencing other
+    /// fields are left out, their related records are always read with a join.
     pub(super) fn relation_inlined(&self) -> impl Iterator<Item = &RelationFieldRef> {
         self.relation
             .iter()
@@ -121,9 +123,12 @@ impl SelectedFields {
                     .map(|mf| mf.in_table_of_model_name == rf.model().name)
                     .unwrap_or(false);
 
-                (!rf.is_hidden && is_inline && is_self && rf.relation_side.is_b())
-                    || (related.is_hidden && is_inline && is_self && rf.relation_side.is_a())
-                    || (is_inline && !is_self && is_intable)
+                let holds_id = !relation.references_non_id_fields();
+
+                holds_id
+                    && ((!rf.is_hidden && is_inline && is_self && rf.relation_side.is_b())
+                        || (related.is_hidden && is_inline && is_self && rf.relation_side.is_a())
+                        || (is_inline && !is_self && is_intable))
             })
     }
 
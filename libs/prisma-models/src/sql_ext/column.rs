@@ -1,3 +1,4 @@
+use super::table::schema_and_table;
 use crate::{Field, RelationField, ScalarField};
 use quaint::ast::Column;
 
@@ -17,12 +18,8 @@ impl AsColumn for Field {
 impl AsColumn for RelationField {
     fn as_column(&self) -> Column<'static> {
         let model = self.model();
-        let internal_data_model = model.internal_data_model();
         let db_name = self.db_name();
-        let parts = (
-            (internal_data_model.db_name.clone(), model.db_name().to_string()),
-            db_name.clone(),
-        );
+        let parts = (schema_and_table(&model), db_name.clone());
 
         parts.into()
     }
@@ -30,10 +27,8 @@ impl AsColumn for RelationField {
 
 impl AsColumn for ScalarField {
     fn as_column(&self) -> Column<'static> {
-        let db = self.internal_data_model().db_name.clone();
-        let table = self.model().db_name().to_string();
         let col = self.db_name().to_string();
 
-        Column::from(((db, table), col))
+        Column::from((schema_and_table(&self.model()), col))
     }
 }
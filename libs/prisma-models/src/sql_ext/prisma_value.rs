@@ -36,7 +36,7 @@ impl<'a> From<ParameterizedValue<'a>> for PrismaValue {
             ParameterizedValue::Text(s) => PrismaValue::String(s.into_owned()),
             ParameterizedValue::Boolean(b) => PrismaValue::Boolean(b),
             ParameterizedValue::Array(v) => PrismaValue::List(Some(v.into_iter().map(PrismaValue::from).collect())),
-            ParameterizedValue::Json(val) => PrismaValue::String(val.to_string()),
+            ParameterizedValue::Json(val) => PrismaValue::Json(val.to_string()),
             ParameterizedValue::Uuid(uuid) => PrismaValue::Uuid(uuid),
             ParameterizedValue::DateTime(dt) => PrismaValue::DateTime(dt),
             ParameterizedValue::Char(c) => PrismaValue::String(c.to_string()),
@@ -49,15 +49,21 @@ impl<'a> From<PrismaValue> for ParameterizedValue<'a> {
         match pv {
             PrismaValue::String(s) => s.into(),
             PrismaValue::Float(f) => f.into(),
+            PrismaValue::Decimal(d) => d.into(),
             PrismaValue::Boolean(b) => b.into(),
             PrismaValue::DateTime(d) => d.into(),
             PrismaValue::Enum(e) => e.as_string().into_owned().into(),
             PrismaValue::Int(i) => (i as i64).into(),
             PrismaValue::Null => ParameterizedValue::Null,
-            PrismaValue::Uuid(u) => u.to_string().into(),
+            PrismaValue::Uuid(u) => ParameterizedValue::Uuid(u),
             PrismaValue::GraphqlId(id) => id.into(),
             PrismaValue::List(Some(l)) => ParameterizedValue::Array(l.into_iter().map(|x| x.into()).collect()),
             PrismaValue::List(None) => unreachable!(),
+            // quaint has no binary parameters, the connectors render binary values as literals.
+            PrismaValue::Bytes(_) => unreachable!("Binary values can not be bound as parameters."),
+            PrismaValue::Json(s) => {
+                ParameterizedValue::Json(serde_json::from_str(&s).expect("JSON values are validated when parsed."))
+            }
         }
     }
 }
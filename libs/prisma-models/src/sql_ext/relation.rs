@@ -1,5 +1,5 @@
 use crate::{AsColumn, AsTable, InlineRelation, Relation, RelationField, RelationSide};
-use quaint::ast::{Column, Table};
+use quaint::ast::{Column, Comparable, ConditionTree, Conjuctive, DatabaseValue, Row, Table};
 
 pub trait RelationExt {
     /// A helper function to decide actions based on the `Relation` type. Inline
@@ -16,6 +16,24 @@ pub trait RelationExt {
 pub trait RelationFieldExt {
     fn opposite_column(&self, alias: bool) -> Column<'static>;
     fn relation_column(&self, alias: bool) -> Column<'static>;
+
+    /// The columns of the relation table referring to the records of this field's model, each paired with
+    /// the column of the model it holds the values of. Usually the single column holding the id, a foreign
+    /// key referencing other fields of the model has its own columns.
+    fn relation_columns(&self) -> Vec<(Column<'static>, Column<'static>)>;
+
+    /// The columns of the relation table referring to the related records, each paired with the column of
+    /// the related model it holds the values of.
+    fn opposite_columns(&self) -> Vec<(Column<'static>, Column<'static>)>;
+
+    /// The condition joining the related model to the relation table, optionally under the given
+    /// aliases. Relations inlined in this model over a foreign key referencing other fields than the id are
+    /// joined on all its columns, all other relations on the id of the related model.
+    fn related_join_condition(
+        &self,
+        related_alias: Option<String>,
+        relation_alias: Option<String>,
+    ) -> ConditionTree<'static>;
 }
 
 pub trait InlineRelationExt {
@@ -24,13 +42,19 @@ pub trait InlineRelationExt {
 
 impl InlineRelationExt for InlineRelation {
     fn referencing_column(&self, table: Table<'static>) -> Column<'static> {
-        let column = Column::from(self.referencing_column.clone());
+        let column = Column::from(self.referencing_columns[0].clone());
         column.table(table)
     }
 }
 
 impl RelationFieldExt for RelationField {
+    /// Relations over a foreign key referencing other fields than the id are read with a join to the related
+    /// model, which gives the id of the related records.
     fn opposite_column(&self, alias: bool) -> Column<'static> {
+        if self.relation().references_non_id_fields() {
+            return self.related_model().fields().id().as_column();
+        }
+
         let col = match self.relation_side {
             RelationSide::A => self.relation().model_b_column(),
             RelationSide::B => self.relation().model_a_column(),
@@ -43,7 +67,15 @@ impl RelationFieldExt for RelationField {
         }
     }
 
+    /// Relations over a foreign key referencing other fields than the id are read with a join to the table
+    /// of this field's model under `Relation::TABLE_ALIAS`, which gives the id of the parent records.
     fn relation_column(&self, alias: bool) -> Column<'static> {
+        if self.relation().references_non_id_fields() {
+            let col = self.model().fields().id().as_column();
+
+            return if alias { col.table(Relation::TABLE_ALIAS) } else { col };
+        }
+
         let col = match self.relation_side {
             RelationSide::A => self.relation().model_a_column(),
             RelationSide::B => self.relation().model_b_column(),
@@ -55,6 +87,103 @@ impl RelationFieldExt for RelationField {
             col
         }
     }
+
+    fn relation_columns(&self) -> Vec<(Column<'static>, Column<'static>)> {
+        let relation = self.relation();
+        let model = self.model();
+
+        match relation.inline_manifestation() {
+            Some(m) if relation.references_non_id_fields() && self.relation_is_inlined_in_child() => {
+                foreign_key_columns(m, relation.as_table(), model.as_table())
+            }
+            Some(_) if relation.references_non_id_fields() => {
+                vec![(model.fields().id().as_column(), model.fields().id().as_column())]
+            }
+            _ => vec![(self.relation_column(false), model.fields().id().as_column())],
+        }
+    }
+
+    fn opposite_columns(&self) -> Vec<(Column<'static>, Column<'static>)> {
+        let relation = self.relation();
+        let related_model = self.related_model();
+
+        match relation.inline_manifestation() {
+            Some(m) if relation.references_non_id_fields() && self.relation_is_inlined_in_parent() => {
+                foreign_key_columns(m, relation.as_table(), related_model.as_table())
+            }
+            Some(_) if relation.references_non_id_fields() => vec![(
+                related_model.fields().id().as_column(),
+                related_model.fields().id().as_column(),
+            )],
+            _ => vec![(self.opposite_column(false), related_model.fields().id().as_column())],
+        }
+    }
+
+    fn related_join_condition(
+        &self,
+        related_alias: Option<String>,
+        relation_alias: Option<String>,
+    ) -> ConditionTree<'static> {
+        let related_table: Table<'static> = match related_alias {
+            Some(alias) => alias.into(),
+            None => self.related_model().as_table(),
+        };
+        let relation_table: Table<'static> = match relation_alias {
+            Some(alias) => alias.into(),
+            None => self.relation().as_table(),
+        };
+
+        join_condition(self.opposite_columns(), relation_table, related_table)
+    }
+}
+
+/// The foreign key columns of an inline relation in the given relation table, each paired with the column
+/// it references in the given table of the referenced model.
+fn foreign_key_columns(
+    manifestation: &InlineRelation,
+    relation_table: Table<'static>,
+    referenced_table: Table<'static>,
+) -> Vec<(Column<'static>, Column<'static>)> {
+    manifestation
+        .referencing_columns
+        .iter()
+        .zip(manifestation.referenced_columns.iter())
+        .map(|(referencing, referenced)| {
+            (
+                Column::from(referencing.clone()).table(relation_table.clone()),
+                Column::from(referenced.clone()).table(referenced_table.clone()),
+            )
+        })
+        .collect()
+}
+
+/// The condition matching each column of the left table to its paired column of the right table.
+pub fn join_condition(
+    column_pairs: Vec<(Column<'static>, Column<'static>)>,
+    left_table: Table<'static>,
+    right_table: Table<'static>,
+) -> ConditionTree<'static> {
+    column_pairs
+        .into_iter()
+        .fold(ConditionTree::NoCondition, |acc, (left_column, right_column)| {
+            let comparison = right_column
+                .table(right_table.clone())
+                .equals(left_column.table(left_table.clone()));
+
+            match acc {
+                ConditionTree::NoCondition => comparison.into(),
+                acc => acc.and(comparison),
+            }
+        })
+}
+
+/// A single column as is, several columns as a row, e.g. `(a, b)`, to compare them at once.
+pub fn columns_value(columns: Vec<Column<'static>>) -> DatabaseValue<'static> {
+    if columns.len() == 1 {
+        columns.into_iter().next().unwrap().into()
+    } else {
+        Row::from(columns).into()
+    }
 }
 
 impl AsTable for Relation {
@@ -108,7 +237,7 @@ impl RelationExt for Relation {
 
     fn inline_relation_column(&self) -> Option<Column<'static>> {
         if let Some(mani) = self.inline_manifestation() {
-            Some(Column::from(mani.referencing_column.clone()).table(self.as_table()))
+            Some(mani.referencing_column(self.as_table()))
         } else {
             None
         }
@@ -7,6 +7,16 @@ pub trait AsTable {
 
 impl AsTable for Model {
     fn as_table(&self) -> Table<'static> {
-        (self.internal_data_model().db_name.clone(), self.db_name().to_string()).into()
+        schema_and_table(self).into()
     }
 }
+
+/// Models with a `@@schema` live in that schema instead of the one of the connection.
+pub fn schema_and_table(model: &Model) -> (String, String) {
+    let schema = model
+        .schema
+        .clone()
+        .unwrap_or_else(|| model.internal_data_model().db_name.clone());
+
+    (schema, model.db_name().to_owned())
+}
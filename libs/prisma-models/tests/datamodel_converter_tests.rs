@@ -54,6 +54,7 @@ fn models_with_only_scalar_fields() {
                 id Int @id @default(autoincrement())
                 int Int
                 float Float
+                decimal Decimal
                 boolean Boolean
                 dateTime DateTime
                 stringOpt String?
@@ -79,6 +80,10 @@ fn models_with_only_scalar_fields() {
         .assert_scalar_field("float")
         .assert_type_identifier(TypeIdentifier::Float)
         .assert_no_behaviour();
+    model
+        .assert_scalar_field("decimal")
+        .assert_type_identifier(TypeIdentifier::Decimal)
+        .assert_no_behaviour();
     model
         .assert_scalar_field("boolean")
         .assert_type_identifier(TypeIdentifier::Boolean)
@@ -119,6 +124,30 @@ fn db_names_work() {
     )
 }
 
+#[test]
+fn schemas_work() {
+    let datamodel = convert(
+        r#"
+            model Test {
+                id String @id @default(cuid())
+            }
+
+            model Invoice {
+                id String @id @default(cuid())
+
+                @@schema("billing")
+                @@map("invoices")
+            }
+        "#,
+    );
+
+    assert_eq!(datamodel.assert_model("Test").schema, None);
+
+    let model = datamodel.assert_model("Invoice");
+    assert_eq!(model.schema, Some("billing".to_string()));
+    assert_eq!(model.db_name(), "invoices");
+}
+
 #[test]
 #[ignore]
 fn scalar_lists_work() {
@@ -158,6 +187,50 @@ fn unique_works() {
         .assert_unique();
 }
 
+#[test]
+fn compound_ids_and_unique_indexes_work() {
+    let datamodel = convert(
+        r#"
+            model User {
+                id    Int    @id
+                posts Post[]
+            }
+
+            model Post {
+                authorId Int
+                slug     String
+                title    String
+                author   User
+
+                @@id([authorId, slug])
+                @@unique([authorId, title], name: "title_per_author")
+                @@unique([author, title])
+            }
+        "#,
+    );
+
+    let model = datamodel.assert_model("Post");
+    let unique_indexes: Vec<(Option<String>, Vec<String>)> = model
+        .unique_indexes()
+        .into_iter()
+        .map(|index| {
+            let fields = index.fields().iter().map(|field| field.name.clone()).collect();
+            (index.name.clone(), fields)
+        })
+        .collect();
+
+    assert_eq!(
+        unique_indexes,
+        vec![
+            (
+                Some("title_per_author".to_string()),
+                vec!["authorId".to_string(), "title".to_string()]
+            ),
+            (None, vec!["authorId".to_string(), "slug".to_string()]),
+        ]
+    );
+}
+
 #[test]
 fn uuid_fields_must_work() {
     let datamodel = convert(
@@ -263,8 +336,11 @@ fn explicit_relation_fields() {
         .assert_model_b("Post")
         .assert_manifestation(RelationLinkManifestation::Inline(InlineRelation {
             in_table_of_model_name: "Post".to_string(),
-            referencing_column: "blog_id".to_string(),
+            referencing_columns: vec!["blog_id".to_string()],
+            referenced_columns: vec!["id".to_string()],
         }));
+
+    assert!(!relation.references_non_id_fields());
 }
 
 #[test]
@@ -339,10 +415,73 @@ fn implicit_relation_fields() {
         .assert_model_b("Post")
         .assert_manifestation(RelationLinkManifestation::Inline(InlineRelation {
             in_table_of_model_name: "Post".to_string(),
-            referencing_column: "blog".to_string(),
+            referencing_columns: vec!["blog".to_string()],
+            referenced_columns: vec!["id".to_string()],
         }));
 }
 
+#[test]
+fn relation_fields_over_multiple_columns() {
+    let datamodel = convert(
+        r#"
+            model User {
+                tenant String
+                id     Int
+                posts  Post[]
+
+                @@id([tenant, id])
+            }
+
+            model Post {
+                id   Int  @id
+                user User @map(["user_tenant", "user_id"])
+            }
+        "#,
+    );
+
+    let relation_name = "PostToUser";
+    let relation = datamodel.assert_relation(relation_name);
+
+    relation
+        .assert_name(relation_name)
+        .assert_model_a("Post")
+        .assert_model_b("User")
+        .assert_manifestation(RelationLinkManifestation::Inline(InlineRelation {
+            in_table_of_model_name: "Post".to_string(),
+            referencing_columns: vec!["user_tenant".to_string(), "user_id".to_string()],
+            referenced_columns: vec!["tenant".to_string(), "id".to_string()],
+        }));
+
+    assert!(relation.references_non_id_fields());
+}
+
+#[test]
+fn relation_fields_referencing_other_fields_than_the_id() {
+    let datamodel = convert(
+        r#"
+            model User {
+                id        Int    @id
+                firstName String @unique
+                posts     Post[]
+            }
+
+            model Post {
+                id   Int  @id
+                user User @relation(references: [firstName])
+            }
+        "#,
+    );
+
+    let relation = datamodel.assert_relation("PostToUser");
+
+    relation.assert_manifestation(RelationLinkManifestation::Inline(InlineRelation {
+        in_table_of_model_name: "Post".to_string(),
+        referencing_columns: vec!["user".to_string()],
+        referenced_columns: vec!["firstName".to_string()],
+    }));
+    assert!(relation.references_non_id_fields());
+}
+
 #[test]
 fn explicit_relation_names() {
     let datamodel = convert(
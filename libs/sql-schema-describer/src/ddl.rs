@@ -0,0 +1,1520 @@
+//! Description of SQL DDL scripts, e.g. the output of `pg_dump --schema-only` or
+//! `mysqldump --no-data`, without a database connection.
+//!
+//! The script is described the way the describer of its SQL family would describe the database it
+//! creates. Parsing is tolerant: statements that do not define tables, enums, sequences, indexes
+//! or comments are skipped, and so are views, since their columns cannot be known without a
+//! database.
+
+use super::*;
+use quaint::prelude::SqlFamily;
+
+/// A statement of a DDL script could not be parsed.
+#[derive(Debug, Fail)]
+#[fail(display = "Could not parse `{}`: {}", statement, message)]
+pub struct DdlError {
+    /// The statement that could not be parsed.
+    pub statement: String,
+    /// What went wrong.
+    pub message: String,
+}
+
+type DdlResult<T> = Result<T, DdlError>;
+
+/// Describes the schema `schema` of a DDL script. On Postgres, the objects of other schemas are
+/// skipped. MySQL and SQLite scripts are taken to define a single schema.
+pub fn describe(ddl: &str, sql_family: SqlFamily, schema: &str) -> DdlResult<SqlSchema> {
+    let tokens = tokenize(ddl, sql_family);
+    let mut describer = DdlDescriber {
+        sql_family,
+        schema,
+        tables: Vec::new(),
+        enums: Vec::new(),
+        sequences: Vec::new(),
+        owned_sequences: Vec::new(),
+    };
+
+    for statement in tokens.split(|token| token.is_symbol(";")) {
+        if statement.is_empty() {
+            continue;
+        }
+
+        let mut parser = StatementParser {
+            source: ddl,
+            tokens: statement,
+            position: 0,
+            sql_family,
+        };
+
+        describer.statement(&mut parser)?;
+    }
+
+    Ok(describer.finish())
+}
+
+struct DdlDescriber<'a> {
+    sql_family: SqlFamily,
+    schema: &'a str,
+    tables: Vec<Table>,
+    enums: Vec<Enum>,
+    sequences: Vec<Sequence>,
+    /// Sequences owned by a column, as (table, column, sequence). This is what
+    /// `pg_get_serial_sequence` looks at, so these are the sequences of primary keys.
+    owned_sequences: Vec<(String, String, String)>,
+}
+
+impl<'a> DdlDescriber<'a> {
+    fn statement(&mut self, parser: &mut StatementParser<'_>) -> DdlResult<()> {
+        if parser.eat_keyword("CREATE") {
+            parser.eat_keywords(&["OR", "REPLACE"]);
+
+            while ["GLOBAL", "LOCAL", "TEMPORARY", "TEMP", "UNLOGGED"]
+                .iter()
+                .any(|keyword| parser.eat_keyword(keyword))
+            {}
+
+            let unique = parser.eat_keyword("UNIQUE");
+
+            if parser.eat_keyword("TABLE") {
+                self.create_table(parser)
+            } else if parser.eat_keyword("INDEX") {
+                self.create_index(parser, unique)
+            } else if parser.eat_keyword("TYPE") {
+                self.create_type(parser)
+            } else if parser.eat_keyword("SEQUENCE") {
+                self.create_sequence(parser)
+            } else {
+                Ok(())
+            }
+        } else if parser.eat_keywords(&["ALTER", "TABLE"]) {
+            self.alter_table(parser)
+        } else if parser.eat_keywords(&["ALTER", "SEQUENCE"]) {
+            self.alter_sequence(parser)
+        } else if parser.eat_keywords(&["COMMENT", "ON"]) {
+            self.comment_on(parser)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn create_table(&mut self, parser: &mut StatementParser<'_>) -> DdlResult<()> {
+        parser.eat_keywords(&["IF", "NOT", "EXISTS"]);
+        let name = self.local_name(parser.qualified_name()?);
+
+        // `CREATE TABLE ... AS SELECT`, `PARTITION OF` and the like have no column definitions.
+        let name = match name {
+            Some(name) if parser.eat_symbol("(") => name,
+            _ => return Ok(()),
+        };
+
+        let mut table = Table {
+            name,
+            columns: Vec::new(),
+            indices: Vec::new(),
+            primary_key: None,
+            foreign_keys: Vec::new(),
+            check_constraints: Vec::new(),
+            comment: None,
+        };
+
+        if !parser.eat_symbol(")") {
+            loop {
+                self.table_element(parser, &mut table)?;
+
+                if !parser.eat_symbol(",") {
+                    break;
+                }
+            }
+
+            parser.expect_symbol(")")?;
+        }
+
+        // Of the table options, only the comment is described.
+        while let Some(token) = parser.next() {
+            if token.is_keyword("COMMENT") {
+                parser.eat_symbol("=");
+                table.comment = parser.string();
+            }
+        }
+
+        if self.sql_family == SqlFamily::Sqlite {
+            let create_table = parser.text(0, parser.tokens.len());
+            let enums = sqlite::get_enums(&table.name, create_table, &mut table.columns);
+
+            self.enums.extend(enums);
+            table.check_constraints = sqlite::get_check_constraints(create_table);
+        }
+
+        self.tables.push(table);
+
+        Ok(())
+    }
+
+    fn table_element(&mut self, parser: &mut StatementParser<'_>, table: &mut Table) -> DdlResult<()> {
+        if parser.is_keyword("CONSTRAINT") || self.at_table_constraint(parser) {
+            self.table_constraint(parser, table)
+        } else if parser.is_keyword("LIKE") || parser.is_keyword("EXCLUDE") {
+            parser.skip_expression();
+            Ok(())
+        } else {
+            let column = self.column_definition(parser, table)?;
+            table.columns.push(column);
+            Ok(())
+        }
+    }
+
+    fn at_table_constraint(&self, parser: &StatementParser<'_>) -> bool {
+        let keywords: &[&str] = match self.sql_family {
+            SqlFamily::Mysql => &[
+                "PRIMARY", "UNIQUE", "FOREIGN", "CHECK", "KEY", "INDEX", "FULLTEXT", "SPATIAL",
+            ],
+            _ => &["PRIMARY", "UNIQUE", "FOREIGN", "CHECK"],
+        };
+
+        keywords.iter().any(|keyword| parser.is_keyword(keyword))
+    }
+
+    fn table_constraint(&mut self, parser: &mut StatementParser<'_>, table: &mut Table) -> DdlResult<()> {
+        let constraint_name = if parser.eat_keyword("CONSTRAINT") {
+            Some(parser.identifier()?)
+        } else {
+            None
+        };
+
+        if parser.eat_keywords(&["PRIMARY", "KEY"]) {
+            parser.index_name()?;
+            let columns = parser.column_list()?;
+
+            table.primary_key = Some(PrimaryKey {
+                columns,
+                sequence: None,
+            });
+        } else if parser.eat_keyword("UNIQUE") {
+            let _ = parser.eat_keyword("KEY") || parser.eat_keyword("INDEX");
+            let index_name = parser.index_name()?;
+            let columns = parser.column_list()?;
+            let name = constraint_name
+                .or(index_name)
+                .unwrap_or_else(|| self.unique_index_name(table, &columns));
+
+            table.indices.push(Index {
+                name,
+                columns,
+                tpe: IndexType::Unique,
+            });
+        } else if parser.eat_keyword("KEY") || parser.eat_keyword("INDEX") {
+            let index_name = parser.index_name()?;
+            let columns = parser.column_list()?;
+            let name = index_name.unwrap_or_else(|| columns[0].clone());
+
+            table.indices.push(Index {
+                name,
+                columns,
+                tpe: IndexType::Normal,
+            });
+        } else if parser.eat_keywords(&["FOREIGN", "KEY"]) {
+            parser.index_name()?;
+            let columns = parser.column_list()?;
+            let foreign_key = self.references(parser, table, constraint_name, columns)?;
+
+            table.foreign_keys.push(foreign_key);
+        } else if parser.eat_keyword("CHECK") {
+            let expression = parser.parenthesized()?;
+
+            self.add_check_constraint(table, constraint_name, expression, None);
+        }
+
+        // Constraint options, e.g. `DEFERRABLE`, and constraints that are not described, e.g.
+        // `FULLTEXT` indexes.
+        parser.skip_expression();
+
+        Ok(())
+    }
+
+    fn column_definition(&mut self, parser: &mut StatementParser<'_>, table: &mut Table) -> DdlResult<Column> {
+        let name = parser.identifier()?;
+        let tokens = parser.tokens;
+
+        let type_start = parser.position;
+        let mut depth = 0;
+
+        while let Some(token) = parser.peek() {
+            if depth == 0 && (token.is_symbol(",") || token.is_symbol(")") || parser.at_column_option()) {
+                break;
+            }
+
+            if token.is_symbol("(") {
+                depth += 1;
+            } else if token.is_symbol(")") {
+                depth -= 1;
+            }
+
+            parser.position += 1;
+        }
+
+        let type_end = parser.position;
+
+        let mut required = false;
+        let mut default = None;
+        let mut auto_increment = false;
+        let mut comment = None;
+        let mut constraint_name = None;
+
+        while !parser.is_done() && !parser.is_symbol(",") && !parser.is_symbol(")") {
+            if parser.eat_keyword("CONSTRAINT") {
+                constraint_name = Some(parser.identifier()?);
+            } else if parser.eat_keywords(&["NOT", "NULL"]) {
+                required = true;
+            } else if parser.eat_keyword("NULL") {
+                required = false;
+            } else if parser.eat_keyword("DEFAULT") {
+                default = Some(parser.default_expression());
+            } else if parser.eat_keywords(&["PRIMARY", "KEY"]) {
+                let _ = parser.eat_keyword("ASC") || parser.eat_keyword("DESC");
+                auto_increment |= parser.eat_keyword("AUTOINCREMENT");
+
+                table.primary_key = Some(PrimaryKey {
+                    columns: vec![name.clone()],
+                    sequence: None,
+                });
+            } else if parser.eat_keyword("UNIQUE") {
+                parser.eat_keyword("KEY");
+                let columns = vec![name.clone()];
+                let index_name = constraint_name
+                    .take()
+                    .unwrap_or_else(|| self.unique_index_name(table, &columns));
+
+                table.indices.push(Index {
+                    name: index_name,
+                    columns,
+                    tpe: IndexType::Unique,
+                });
+            } else if parser.is_keyword("REFERENCES") {
+                let foreign_key = self.references(parser, table, constraint_name.take(), vec![name.clone()])?;
+
+                table.foreign_keys.push(foreign_key);
+            } else if parser.eat_keyword("CHECK") {
+                let expression = parser.parenthesized()?;
+
+                self.add_check_constraint(table, constraint_name.take(), expression, Some(&name));
+            } else if parser.eat_keyword("AUTO_INCREMENT") || parser.eat_keyword("AUTOINCREMENT") {
+                auto_increment = true;
+            } else if parser.eat_keyword("COMMENT") {
+                comment = parser.string();
+            } else if parser.eat_keyword("GENERATED") {
+                // Identity columns are `GENERATED {ALWAYS | BY DEFAULT} AS IDENTITY [(...)]`, other
+                // generated columns are described like regular ones.
+                let _ = parser.eat_keyword("ALWAYS") || parser.eat_keywords(&["BY", "DEFAULT"]);
+                parser.eat_keyword("AS");
+
+                if parser.eat_keyword("IDENTITY") {
+                    auto_increment = true;
+                    self.identity_sequence(parser, &table.name, &name)?;
+                } else if parser.is_symbol("(") {
+                    parser.parenthesized()?;
+                }
+            } else if parser.eat_keywords(&["ON", "UPDATE"]) {
+                parser.default_expression();
+            } else if parser.eat_keyword("COLLATE") {
+                parser.qualified_name()?;
+            } else if parser.eat_keyword("CHARSET") || parser.eat_keywords(&["CHARACTER", "SET"]) {
+                parser.next();
+            } else if parser.is_symbol("(") {
+                // e.g. the expression of a MySQL generated column, after `AS`.
+                parser.parenthesized()?;
+            } else {
+                parser.next();
+            }
+        }
+
+        let arity = if required {
+            ColumnArity::Required
+        } else {
+            ColumnArity::Nullable
+        };
+        let (tpe, is_serial) = self.column_type(
+            &table.name,
+            &name,
+            &tokens[type_start..type_end],
+            parser.text(type_start, type_end),
+            arity,
+        );
+        let (default, default_is_auto_increment) = match default {
+            Some((start, end)) if start < end => self.column_default(&tokens[start..end], parser.text(start, end)),
+            _ => (None, false),
+        };
+
+        let mut column = Column {
+            name,
+            tpe,
+            default,
+            auto_increment: auto_increment || default_is_auto_increment,
+            comment,
+        };
+
+        // `serial` columns come with a sequence.
+        if is_serial {
+            let sequence_name = format!("{}_{}_seq", table.name, column.name);
+
+            column.default = Some(DefaultValue::Value(format!("nextval(\"{}\"::regclass)", sequence_name)));
+            column.auto_increment = true;
+            self.owned_sequences
+                .push((table.name.clone(), column.name.clone(), sequence_name.clone()));
+            self.sequences.push(Sequence {
+                name: sequence_name,
+                initial_value: 1,
+                allocation_size: 1,
+            });
+        }
+
+        Ok(column)
+    }
+
+    /// The type of a column, given its tokens and their text, and whether it is one of the
+    /// Postgres `serial` types.
+    fn column_type(
+        &mut self,
+        table: &str,
+        column: &str,
+        tokens: &[Token],
+        type_text: &str,
+        arity: ColumnArity,
+    ) -> (ColumnType, bool) {
+        match self.sql_family {
+            SqlFamily::Postgres => postgres_column_type(tokens, arity),
+            SqlFamily::Mysql => {
+                let (data_type, full_data_type) = mysql_data_type(tokens);
+                let mut tpe = mysql::get_column_type(&data_type, &full_data_type, arity);
+
+                if data_type == "enum" {
+                    let enum_name = inline_enum_name(table, column);
+                    tpe.family = ColumnTypeFamily::Enum(enum_name.clone());
+                    self.enums.push(Enum {
+                        name: enum_name,
+                        values: parse_quoted_values(&full_data_type),
+                    });
+                }
+
+                (tpe, false)
+            }
+            SqlFamily::Sqlite => (sqlite::get_column_type(type_text, arity), false),
+        }
+    }
+
+    /// The default of a column as the describer would find it in the database, and whether the
+    /// default makes the column auto-incrementing.
+    fn column_default(&self, tokens: &[Token], text: &str) -> (Option<String>, bool) {
+        if tokens[0].is_keyword("NULL") {
+            return (None, false);
+        }
+
+        match self.sql_family {
+            SqlFamily::Postgres => {
+                // Names of the described schema are not qualified in the database, e.g. in casts or
+                // `nextval('"User_id_seq"'::regclass)`.
+                let text = text
+                    .replace(&format!("::{}.", self.schema), "::")
+                    .replace(&format!("::\"{}\".", self.schema), "::")
+                    .replace(&format!("'{}.", self.schema), "'")
+                    .replace(&format!("'\"{}\".", self.schema), "'");
+
+                let value = || DefaultValue::Value(text.replace("\'", "").replace("::text", ""));
+
+                if text.starts_with("nextval(") {
+                    (Some(value()), true)
+                } else if postgres::is_literal_default(&text) {
+                    (Some(value()), false)
+                } else {
+                    (Some(DefaultValue::DbGenerated(text.clone())), false)
+                }
+            }
+            SqlFamily::Mysql => {
+                let default = match tokens {
+                    [token] if token.kind == TokenKind::String => DefaultValue::Value(token.value.clone()),
+                    [token] if token.is_keyword("TRUE") => DefaultValue::Value("1".to_string()),
+                    [token] if token.is_keyword("FALSE") => DefaultValue::Value("0".to_string()),
+                    _ if tokens
+                        .iter()
+                        .all(|token| token.kind == TokenKind::Number || token.is_symbol("-")) =>
+                    {
+                        DefaultValue::Value(text.replace(' ', ""))
+                    }
+                    _ => DefaultValue::DbGenerated(expression_in_parentheses(text).unwrap_or(text).to_string()),
+                };
+
+                (Some(default), false)
+            }
+            SqlFamily::Sqlite => (Some(sqlite::parse_default(text)), false),
+        }
+    }
+
+    fn references(
+        &self,
+        parser: &mut StatementParser<'_>,
+        table: &Table,
+        constraint_name: Option<String>,
+        columns: Vec<String>,
+    ) -> DdlResult<ForeignKey> {
+        parser.expect_keyword("REFERENCES")?;
+        let referenced_table = self.referenced_table_name(parser.qualified_name()?);
+
+        // Without columns, the primary key of the referenced table is referenced. It is filled in
+        // once all tables are known.
+        let referenced_columns = if parser.is_symbol("(") {
+            parser.column_list()?
+        } else {
+            Vec::new()
+        };
+
+        let mut on_delete_action = match self.sql_family {
+            SqlFamily::Mysql => ForeignKeyAction::Restrict,
+            _ => ForeignKeyAction::NoAction,
+        };
+
+        if parser.eat_keyword("MATCH") {
+            parser.next();
+        }
+
+        while parser.eat_keyword("ON") {
+            let is_delete = parser.eat_keyword("DELETE");
+
+            if !is_delete {
+                parser.expect_keyword("UPDATE")?;
+            }
+
+            let action = parser.foreign_key_action()?;
+
+            if is_delete {
+                on_delete_action = action;
+            }
+        }
+
+        let constraint_name = match self.sql_family {
+            SqlFamily::Postgres => {
+                Some(constraint_name.unwrap_or_else(|| format!("{}_{}_fkey", table.name, columns.join("_"))))
+            }
+            SqlFamily::Mysql => {
+                Some(constraint_name.unwrap_or_else(|| format!("{}_ibfk_{}", table.name, table.foreign_keys.len() + 1)))
+            }
+            SqlFamily::Sqlite => None,
+        };
+
+        Ok(ForeignKey {
+            constraint_name,
+            columns,
+            referenced_table,
+            referenced_columns,
+            on_delete_action,
+        })
+    }
+
+    fn add_check_constraint(&self, table: &mut Table, name: Option<String>, expression: &str, column: Option<&str>) {
+        let name = match (self.sql_family, name) {
+            // SQLite check constraints are described from the whole statement, like in the
+            // describer.
+            (SqlFamily::Sqlite, _) => return,
+            (_, Some(name)) => name,
+            (SqlFamily::Postgres, None) => match column {
+                Some(column) => format!("{}_{}_check", table.name, column),
+                None => format!("{}_check", table.name),
+            },
+            (SqlFamily::Mysql, None) => format!("{}_chk_{}", table.name, table.check_constraints.len() + 1),
+        };
+
+        table.check_constraints.push(CheckConstraint {
+            name,
+            expression: expression.trim().to_string(),
+        });
+    }
+
+    /// The name the database gives to unnamed unique constraints.
+    fn unique_index_name(&self, table: &Table, columns: &[String]) -> String {
+        match self.sql_family {
+            SqlFamily::Postgres => format!("{}_{}_key", table.name, columns.join("_")),
+            SqlFamily::Mysql => columns[0].clone(),
+            SqlFamily::Sqlite => format!("sqlite_autoindex_{}_{}", table.name, table.indices.len() + 1),
+        }
+    }
+
+    /// Registers the sequence of an identity column, given the sequence options after `AS IDENTITY`.
+    fn identity_sequence(&mut self, parser: &mut StatementParser<'_>, table: &str, column: &str) -> DdlResult<()> {
+        let mut sequence = Sequence {
+            name: format!("{}_{}_seq", table, column),
+            initial_value: 1,
+            allocation_size: 1,
+        };
+
+        if parser.eat_symbol("(") {
+            while !parser.is_done() && !parser.is_symbol(")") {
+                if parser.eat_keywords(&["SEQUENCE", "NAME"]) {
+                    if let Some(name) = parser.qualified_name()?.pop() {
+                        sequence.name = name;
+                    }
+                } else if parser.eat_keyword("START") {
+                    parser.eat_keyword("WITH");
+
+                    if let Some(initial_value) = parser.next().and_then(|token| token.value.parse().ok()) {
+                        sequence.initial_value = initial_value;
+                    }
+                } else if parser.eat_keyword("INCREMENT") {
+                    parser.eat_keyword("BY");
+
+                    if let Some(allocation_size) = parser.next().and_then(|token| token.value.parse().ok()) {
+                        sequence.allocation_size = allocation_size;
+                    }
+                } else {
+                    parser.next();
+                }
+            }
+
+            parser.expect_symbol(")")?;
+        }
+
+        if self.sql_family == SqlFamily::Postgres {
+            self.owned_sequences
+                .push((table.to_string(), column.to_string(), sequence.name.clone()));
+            self.sequences.push(sequence);
+        }
+
+        Ok(())
+    }
+
+    fn alter_table(&mut self, parser: &mut StatementParser<'_>) -> DdlResult<()> {
+        parser.eat_keywords(&["IF", "EXISTS"]);
+        parser.eat_keyword("ONLY");
+        let name = self.local_name(parser.qualified_name()?);
+
+        let position = name.and_then(|name| self.tables.iter().position(|table| table.name == name));
+        let mut table = match position {
+            Some(position) => self.tables.remove(position),
+            None => return Ok(()),
+        };
+
+        let result = self.alter_table_actions(parser, &mut table);
+        self.tables.push(table);
+
+        result
+    }
+
+    fn alter_table_actions(&mut self, parser: &mut StatementParser<'_>, table: &mut Table) -> DdlResult<()> {
+        loop {
+            if parser.eat_keyword("ADD") {
+                if parser.is_keyword("CONSTRAINT") || self.at_table_constraint(parser) {
+                    self.table_constraint(parser, table)?;
+                } else {
+                    parser.eat_keyword("COLUMN");
+                    parser.eat_keywords(&["IF", "NOT", "EXISTS"]);
+                    let column = self.column_definition(parser, table)?;
+                    table.columns.push(column);
+                }
+            } else if parser.eat_keyword("MODIFY") {
+                parser.eat_keyword("COLUMN");
+                let column = self.column_definition(parser, table)?;
+                table.columns.retain(|existing| existing.name != column.name);
+                table.columns.push(column);
+            } else if parser.eat_keyword("ALTER") {
+                parser.eat_keyword("COLUMN");
+                let column_name = parser.identifier()?;
+                self.alter_column(parser, table, &column_name)?;
+            }
+
+            // Actions that are not described, e.g. `OWNER TO`.
+            parser.skip_expression();
+
+            if !parser.eat_symbol(",") {
+                return Ok(());
+            }
+        }
+    }
+
+    fn alter_column(
+        &mut self,
+        parser: &mut StatementParser<'_>,
+        table: &mut Table,
+        column_name: &str,
+    ) -> DdlResult<()> {
+        let column_position = match table.columns.iter().position(|column| column.name == column_name) {
+            Some(position) => position,
+            None => return Ok(()),
+        };
+
+        if parser.eat_keywords(&["SET", "DEFAULT"]) {
+            let (start, end) = parser.default_expression();
+
+            if start < end {
+                let (default, is_auto_increment) =
+                    self.column_default(&parser.tokens[start..end], parser.text(start, end));
+                let column = &mut table.columns[column_position];
+
+                column.default = default;
+                column.auto_increment |= is_auto_increment;
+            }
+        } else if parser.eat_keywords(&["DROP", "DEFAULT"]) {
+            table.columns[column_position].default = None;
+        } else if parser.eat_keywords(&["SET", "NOT", "NULL"]) {
+            set_arity(&mut table.columns[column_position], ColumnArity::Required);
+        } else if parser.eat_keywords(&["DROP", "NOT", "NULL"]) {
+            set_arity(&mut table.columns[column_position], ColumnArity::Nullable);
+        } else if parser.eat_keywords(&["ADD", "GENERATED"]) {
+            let _ = parser.eat_keyword("ALWAYS") || parser.eat_keywords(&["BY", "DEFAULT"]);
+            parser.expect_keyword("AS")?;
+            parser.expect_keyword("IDENTITY")?;
+
+            table.columns[column_position].auto_increment = true;
+            self.identity_sequence(parser, &table.name, column_name)?;
+        }
+
+        Ok(())
+    }
+
+    fn create_index(&mut self, parser: &mut StatementParser<'_>, unique: bool) -> DdlResult<()> {
+        parser.eat_keyword("CONCURRENTLY");
+        parser.eat_keywords(&["IF", "NOT", "EXISTS"]);
+
+        let index_name = if parser.is_keyword("ON") {
+            None
+        } else {
+            parser.qualified_name()?.pop()
+        };
+
+        parser.expect_keyword("ON")?;
+        parser.eat_keyword("ONLY");
+        let table_name = self.local_name(parser.qualified_name()?);
+        parser.index_name()?;
+
+        // Indexes on expressions are not described.
+        let columns = match parser.column_list() {
+            Ok(columns) => columns,
+            Err(_) => return Ok(()),
+        };
+
+        let table = match table_name.and_then(|name| self.tables.iter_mut().find(|table| table.name == name)) {
+            Some(table) => table,
+            None => return Ok(()),
+        };
+
+        table.indices.push(Index {
+            name: index_name.unwrap_or_else(|| format!("{}_{}_idx", table.name, columns.join("_"))),
+            columns,
+            tpe: if unique { IndexType::Unique } else { IndexType::Normal },
+        });
+
+        Ok(())
+    }
+
+    fn create_type(&mut self, parser: &mut StatementParser<'_>) -> DdlResult<()> {
+        let name = self.local_name(parser.qualified_name()?);
+
+        if !parser.eat_keywords(&["AS", "ENUM"]) {
+            return Ok(());
+        }
+
+        parser.expect_symbol("(")?;
+        let mut values = Vec::new();
+
+        while let Some(value) = parser.string() {
+            values.push(value);
+
+            if !parser.eat_symbol(",") {
+                break;
+            }
+        }
+
+        parser.expect_symbol(")")?;
+
+        if let Some(name) = name {
+            self.enums.push(Enum { name, values });
+        }
+
+        Ok(())
+    }
+
+    fn create_sequence(&mut self, parser: &mut StatementParser<'_>) -> DdlResult<()> {
+        parser.eat_keywords(&["IF", "NOT", "EXISTS"]);
+
+        let name = match self.local_name(parser.qualified_name()?) {
+            Some(name) => name,
+            None => return Ok(()),
+        };
+
+        let mut initial_value = 1;
+        let mut allocation_size = 1;
+
+        while let Some(token) = parser.next() {
+            if token.is_keyword("START") {
+                parser.eat_keyword("WITH");
+
+                if let Some(value) = parser.next().and_then(|token| token.value.parse().ok()) {
+                    initial_value = value;
+                }
+            } else if token.is_keyword("INCREMENT") {
+                parser.eat_keyword("BY");
+
+                if let Some(value) = parser.next().and_then(|token| token.value.parse().ok()) {
+                    allocation_size = value;
+                }
+            }
+        }
+
+        self.sequences.push(Sequence {
+            name,
+            initial_value,
+            allocation_size,
+        });
+
+        Ok(())
+    }
+
+    fn alter_sequence(&mut self, parser: &mut StatementParser<'_>) -> DdlResult<()> {
+        parser.eat_keywords(&["IF", "EXISTS"]);
+        let sequence = self.local_name(parser.qualified_name()?);
+
+        while !parser.is_done() {
+            if parser.eat_keywords(&["OWNED", "BY"]) {
+                let mut column = parser.qualified_name()?;
+                let column_name = column.pop();
+
+                if let (Some(sequence), Some(table), Some(column)) =
+                    (sequence.clone(), self.local_name(column), column_name)
+                {
+                    self.owned_sequences.push((table, column, sequence));
+                }
+            } else {
+                parser.next();
+            }
+        }
+
+        Ok(())
+    }
+
+    fn comment_on(&mut self, parser: &mut StatementParser<'_>) -> DdlResult<()> {
+        let (table_name, column_name) = if parser.eat_keyword("TABLE") {
+            (self.local_name(parser.qualified_name()?), None)
+        } else if parser.eat_keyword("COLUMN") {
+            let mut name = parser.qualified_name()?;
+            let column_name = name.pop();
+
+            (self.local_name(name), column_name)
+        } else {
+            return Ok(());
+        };
+
+        parser.expect_keyword("IS")?;
+        let comment = parser.string();
+
+        let table = match table_name.and_then(|name| self.tables.iter_mut().find(|table| table.name == name)) {
+            Some(table) => table,
+            None => return Ok(()),
+        };
+
+        match column_name {
+            Some(column_name) => {
+                if let Some(column) = table.columns.iter_mut().find(|column| column.name == column_name) {
+                    column.comment = comment;
+                }
+            }
+            None => table.comment = comment,
+        }
+
+        Ok(())
+    }
+
+    /// The unqualified name of an object of the described schema, or `None` for objects of other
+    /// schemas.
+    fn local_name(&self, mut name: Vec<String>) -> Option<String> {
+        let local_name = name.pop()?;
+
+        match name.pop() {
+            Some(schema) if self.sql_family == SqlFamily::Postgres && schema != self.schema => None,
+            _ => Some(local_name),
+        }
+    }
+
+    /// Tables of other schemas are referenced with their schema, like in `postgres::SqlSchemaDescriber`.
+    fn referenced_table_name(&self, mut name: Vec<String>) -> String {
+        let table = name.pop().unwrap_or_default();
+
+        match name.pop() {
+            Some(schema) if self.sql_family == SqlFamily::Postgres && schema != self.schema => {
+                format!("{}.{}", schema, table)
+            }
+            _ => table,
+        }
+    }
+
+    fn finish(mut self) -> SqlSchema {
+        let sql_family = self.sql_family;
+
+        if sql_family == SqlFamily::Postgres {
+            let columns = self.tables.iter_mut().flat_map(|table| table.columns.iter_mut());
+            postgres::resolve_enum_columns(columns, &self.enums);
+        }
+
+        let primary_keys: Vec<(String, Vec<String>)> = self
+            .tables
+            .iter()
+            .filter_map(|table| {
+                table
+                    .primary_key
+                    .as_ref()
+                    .map(|primary_key| (table.name.clone(), primary_key.columns.clone()))
+            })
+            .collect();
+
+        for table in self.tables.iter_mut() {
+            for foreign_key in table.foreign_keys.iter_mut() {
+                if foreign_key.referenced_columns.is_empty() {
+                    if let Some((_, columns)) = primary_keys
+                        .iter()
+                        .find(|(table_name, _)| table_name == &foreign_key.referenced_table)
+                    {
+                        foreign_key.referenced_columns = columns.clone();
+                    }
+                }
+            }
+
+            if let Some(primary_key) = table.primary_key.as_mut() {
+                let columns = &mut table.columns;
+
+                match sql_family {
+                    SqlFamily::Sqlite => {
+                        // Integer id columns are always implemented with either row id or autoincrement.
+                        if let [primary_key_column] = primary_key.columns.as_slice() {
+                            for column in columns.iter_mut() {
+                                if &column.name == primary_key_column && column.tpe.raw.to_lowercase() == "integer" {
+                                    column.auto_increment = true;
+                                }
+                            }
+                        }
+                    }
+                    _ => {
+                        for column in columns
+                            .iter_mut()
+                            .filter(|column| primary_key.columns.contains(&column.name))
+                        {
+                            set_arity(column, ColumnArity::Required);
+                        }
+                    }
+                }
+
+                if let [primary_key_column] = primary_key.columns.as_slice() {
+                    let table_name = &table.name;
+                    let sequences = &self.sequences;
+
+                    primary_key.sequence = self
+                        .owned_sequences
+                        .iter()
+                        .find(|(table, column, _)| table == table_name && column == primary_key_column)
+                        .and_then(|(_, _, sequence_name)| {
+                            sequences.iter().find(|sequence| &sequence.name == sequence_name)
+                        })
+                        .cloned();
+                }
+            }
+
+            // Indexes backing foreign keys are described as part of the foreign keys, but unique
+            // ones are needed to identify 1:1 relations.
+            if sql_family == SqlFamily::Mysql {
+                let foreign_keys = &table.foreign_keys;
+
+                table.indices.retain(|index| {
+                    index.tpe == IndexType::Unique
+                        || !foreign_keys
+                            .iter()
+                            .any(|foreign_key| foreign_key.columns == index.columns)
+                });
+            }
+
+            table.columns.sort_by(|a, b| a.name.cmp(&b.name));
+            table.foreign_keys.sort_by(|a, b| a.columns.cmp(&b.columns));
+        }
+
+        self.tables.sort_by(|a, b| a.name.cmp(&b.name));
+
+        SqlSchema {
+            tables: self.tables,
+            enums: self.enums,
+            sequences: self.sequences,
+            views: Vec::new(),
+        }
+    }
+}
+
+/// Changes the arity of a column, unless it is a list.
+fn set_arity(column: &mut Column, arity: ColumnArity) {
+    if column.tpe.arity != ColumnArity::List {
+        column.tpe.arity = arity;
+    }
+}
+
+/// Postgres types are described by their `udt_name`, e.g. `int4` for `integer` and `_int4` for
+/// `integer[]`. Also returns whether the type is one of the `serial` types.
+fn postgres_column_type(tokens: &[Token], arity: ColumnArity) -> (ColumnType, bool) {
+    let mut words: Vec<String> = Vec::new();
+    let mut arguments: Vec<i64> = Vec::new();
+    let mut is_array = false;
+    let mut in_brackets = false;
+
+    for token in tokens {
+        match token.kind {
+            TokenKind::Word if token.is_keyword("ARRAY") => is_array = true,
+            TokenKind::Word => words.push(token.value.to_lowercase()),
+            TokenKind::QuotedIdentifier => words.push(token.value.clone()),
+            TokenKind::Number if !in_brackets => arguments.push(token.value.parse().unwrap_or(0)),
+            // Types of other schemas, e.g. `public.citext`.
+            TokenKind::Symbol if token.value == "." => words.clear(),
+            TokenKind::Symbol if token.value == "[" => {
+                is_array = true;
+                in_brackets = true;
+            }
+            TokenKind::Symbol if token.value == "]" => in_brackets = false,
+            _ => (),
+        }
+    }
+
+    let type_name = words.join(" ");
+    let mut is_serial = false;
+
+    let udt_name = match type_name.as_str() {
+        "integer" | "int" => "int4",
+        "smallint" => "int2",
+        "bigint" => "int8",
+        "serial" | "serial4" => {
+            is_serial = true;
+            "int4"
+        }
+        "smallserial" | "serial2" => {
+            is_serial = true;
+            "int2"
+        }
+        "bigserial" | "serial8" => {
+            is_serial = true;
+            "int8"
+        }
+        "real" => "float4",
+        "double precision" => "float8",
+        "float" if arguments.first().map(|precision| *precision <= 24).unwrap_or(false) => "float4",
+        "float" => "float8",
+        "boolean" => "bool",
+        "character varying" | "char varying" => "varchar",
+        "character" | "char" => "bpchar",
+        "decimal" => "numeric",
+        "timestamp without time zone" => "timestamp",
+        "timestamp with time zone" => "timestamptz",
+        "time without time zone" => "time",
+        "time with time zone" => "timetz",
+        "bit varying" => "varbit",
+        name if name.starts_with("interval") => "interval",
+        name => name,
+    };
+
+    let (character_maximum_length, numeric_precision, numeric_scale) = match udt_name {
+        "varchar" => (arguments.first().cloned(), None, None),
+        // `char` without a length is `char(1)`.
+        "bpchar" => (Some(arguments.first().cloned().unwrap_or(1)), None, None),
+        "numeric" if !arguments.is_empty() => (None, arguments.first().cloned(), arguments.get(1).cloned().or(Some(0))),
+        _ => (None, None, None),
+    };
+
+    let (udt_name, arity) = if is_array {
+        (format!("_{}", udt_name), ColumnArity::List)
+    } else {
+        (udt_name.to_string(), arity)
+    };
+
+    let mut tpe = postgres::get_column_type("", &udt_name, arity);
+    tpe.raw = postgres::raw_type_with_arguments(&udt_name, character_maximum_length, numeric_precision, numeric_scale);
+
+    (tpe, is_serial)
+}
+
+/// The `data_type` and `column_type` MySQL has in its information schema for a column type, e.g.
+/// `int` and `int(11) unsigned`.
+fn mysql_data_type(tokens: &[Token]) -> (String, String) {
+    let mut full_data_type = String::new();
+
+    for (index, token) in tokens.iter().enumerate() {
+        let follows_word = index > 0 && !tokens[index - 1].is_symbol("(") && !tokens[index - 1].is_symbol(",");
+
+        match token.kind {
+            TokenKind::Word if follows_word => {
+                full_data_type.push(' ');
+                full_data_type.push_str(&token.value.to_lowercase());
+            }
+            TokenKind::Word => full_data_type.push_str(&token.value.to_lowercase()),
+            TokenKind::String => full_data_type.push_str(&format!("'{}'", token.value.replace('\'', "''"))),
+            _ => full_data_type.push_str(&token.value),
+        }
+    }
+
+    let data_type = full_data_type.split(&['(', ' '][..]).next().unwrap_or("").to_string();
+
+    match data_type.as_str() {
+        "integer" => ("int".to_string(), full_data_type.replacen("integer", "int", 1)),
+        "bool" | "boolean" => ("tinyint".to_string(), "tinyint(1)".to_string()),
+        _ => (data_type, full_data_type),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TokenKind {
+    /// Keywords and unquoted identifiers.
+    Word,
+    QuotedIdentifier,
+    String,
+    Number,
+    Symbol,
+}
+
+#[derive(Debug)]
+struct Token {
+    kind: TokenKind,
+    /// The text of the token, without quotes and escapes for quoted identifiers and strings.
+    value: String,
+    start: usize,
+    end: usize,
+}
+
+impl Token {
+    fn is_keyword(&self, keyword: &str) -> bool {
+        self.kind == TokenKind::Word && self.value.eq_ignore_ascii_case(keyword)
+    }
+
+    fn is_symbol(&self, symbol: &str) -> bool {
+        self.kind == TokenKind::Symbol && self.value == symbol
+    }
+}
+
+fn tokenize(ddl: &str, sql_family: SqlFamily) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut position = 0;
+
+    while let Some(c) = ddl[position..].chars().next() {
+        let start = position;
+        let rest = &ddl[start..];
+
+        if c.is_whitespace() {
+            position += c.len_utf8();
+            continue;
+        }
+
+        if rest.starts_with("--") || (c == '#' && sql_family == SqlFamily::Mysql) {
+            position = rest.find('\n').map(|end| start + end).unwrap_or_else(|| ddl.len());
+            continue;
+        }
+
+        // This also skips MySQL's conditional comments (`/*!40101 SET ... */`), which only set
+        // session variables in dumps.
+        if rest.starts_with("/*") {
+            position = rest[2..]
+                .find("*/")
+                .map(|end| start + 2 + end + 2)
+                .unwrap_or_else(|| ddl.len());
+            continue;
+        }
+
+        let (kind, value, end) = match c {
+            '\'' => {
+                let (value, end) = quoted(ddl, start, sql_family == SqlFamily::Mysql);
+                (TokenKind::String, value, end)
+            }
+            '"' if sql_family == SqlFamily::Mysql => {
+                let (value, end) = quoted(ddl, start, true);
+                (TokenKind::String, value, end)
+            }
+            '"' | '`' => {
+                let (value, end) = quoted(ddl, start, false);
+                (TokenKind::QuotedIdentifier, value, end)
+            }
+            '$' if sql_family == SqlFamily::Postgres => match dollar_quoted(ddl, start) {
+                Some((value, end)) => (TokenKind::String, value, end),
+                None => (TokenKind::Symbol, "$".to_string(), start + 1),
+            },
+            c if c.is_ascii_digit() => {
+                let end = start + token_length(rest, |c| c.is_ascii_alphanumeric() || c == '.');
+                (TokenKind::Number, ddl[start..end].to_string(), end)
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let end = start + token_length(rest, |c| c.is_alphanumeric() || c == '_' || c == '$');
+                (TokenKind::Word, ddl[start..end].to_string(), end)
+            }
+            ':' if rest.starts_with("::") => (TokenKind::Symbol, "::".to_string(), start + 2),
+            c => (TokenKind::Symbol, c.to_string(), start + c.len_utf8()),
+        };
+
+        tokens.push(Token {
+            kind,
+            value,
+            start,
+            end,
+        });
+        position = end;
+    }
+
+    tokens
+}
+
+fn token_length(rest: &str, continues: impl Fn(char) -> bool) -> usize {
+    rest.char_indices()
+        .find(|(_, c)| !continues(*c))
+        .map(|(length, _)| length)
+        .unwrap_or_else(|| rest.len())
+}
+
+/// Reads the quoted string or identifier starting at `start`. Quotes are escaped by doubling
+/// them, and on MySQL also with backslashes.
+fn quoted(ddl: &str, start: usize, backslash_escapes: bool) -> (String, usize) {
+    let quote = ddl[start..].chars().next().expect("opening quote");
+    let mut value = String::new();
+    let mut chars = ddl[start + 1..].char_indices().peekable();
+
+    while let Some((offset, c)) = chars.next() {
+        if c == quote {
+            if chars.peek().map(|(_, next)| *next == quote).unwrap_or(false) {
+                chars.next();
+                value.push(quote);
+                continue;
+            }
+
+            return (value, start + 1 + offset + 1);
+        }
+
+        if backslash_escapes && c == '\\' {
+            if let Some((_, escaped)) = chars.next() {
+                value.push(match escaped {
+                    'n' => '\n',
+                    'r' => '\r',
+                    't' => '\t',
+                    '0' => '\0',
+                    escaped => escaped,
+                });
+            }
+
+            continue;
+        }
+
+        value.push(c);
+    }
+
+    (value, ddl.len())
+}
+
+/// Reads a Postgres dollar-quoted string, e.g. `$body$...$body$`, starting at `start`.
+fn dollar_quoted(ddl: &str, start: usize) -> Option<(String, usize)> {
+    let rest = &ddl[start + 1..];
+    let tag_length = rest.find('$')?;
+    let tag = &rest[..tag_length];
+
+    if tag.starts_with(|c: char| c.is_ascii_digit()) || !tag.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return None;
+    }
+
+    let delimiter = format!("${}$", tag);
+    let body_start = start + 1 + tag_length + 1;
+    let body_end = body_start + ddl[body_start..].find(&delimiter)?;
+
+    Some((ddl[body_start..body_end].to_string(), body_end + delimiter.len()))
+}
+
+/// The keywords that end the type of a column definition, and the default expression of a column.
+const COLUMN_OPTIONS: &[&str] = &[
+    "CONSTRAINT",
+    "NOT",
+    "NULL",
+    "DEFAULT",
+    "PRIMARY",
+    "UNIQUE",
+    "REFERENCES",
+    "CHECK",
+    "AUTO_INCREMENT",
+    "AUTOINCREMENT",
+    "COMMENT",
+    "GENERATED",
+    "AS",
+    "ON",
+    "COLLATE",
+    "CHARSET",
+];
+
+/// Parses the tokens of a single statement.
+struct StatementParser<'a> {
+    source: &'a str,
+    tokens: &'a [Token],
+    position: usize,
+    sql_family: SqlFamily,
+}
+
+impl<'a> StatementParser<'a> {
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<&'a Token> {
+        let token = self.tokens.get(self.position);
+
+        if token.is_some() {
+            self.position += 1;
+        }
+
+        token
+    }
+
+    fn is_done(&self) -> bool {
+        self.position >= self.tokens.len()
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        self.peek().map(|token| token.is_keyword(keyword)).unwrap_or(false)
+    }
+
+    fn is_symbol(&self, symbol: &str) -> bool {
+        self.peek().map(|token| token.is_symbol(symbol)).unwrap_or(false)
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let is_keyword = self.is_keyword(keyword);
+
+        if is_keyword {
+            self.position += 1;
+        }
+
+        is_keyword
+    }
+
+    /// Consumes the keywords only if they all come next, in order.
+    fn eat_keywords(&mut self, keywords: &[&str]) -> bool {
+        let tokens = self.tokens;
+        let position = self.position;
+        let matches = keywords.iter().enumerate().all(|(offset, keyword)| {
+            tokens
+                .get(position + offset)
+                .map(|token| token.is_keyword(keyword))
+                .unwrap_or(false)
+        });
+
+        if matches {
+            self.position += keywords.len();
+        }
+
+        matches
+    }
+
+    fn eat_symbol(&mut self, symbol: &str) -> bool {
+        let is_symbol = self.is_symbol(symbol);
+
+        if is_symbol {
+            self.position += 1;
+        }
+
+        is_symbol
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> DdlResult<()> {
+        if self.eat_keyword(keyword) {
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("`{}`", keyword)))
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: &str) -> DdlResult<()> {
+        if self.eat_symbol(symbol) {
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("`{}`", symbol)))
+        }
+    }
+
+    fn at_column_option(&self) -> bool {
+        match self.peek() {
+            // `CHARACTER SET utf8`, but also the `character varying` type.
+            Some(token) if token.is_keyword("CHARACTER") => self
+                .tokens
+                .get(self.position + 1)
+                .map(|next| next.is_keyword("SET"))
+                .unwrap_or(false),
+            Some(token) => COLUMN_OPTIONS.iter().any(|option| token.is_keyword(option)),
+            None => false,
+        }
+    }
+
+    fn identifier(&mut self) -> DdlResult<String> {
+        let identifier = match self.peek() {
+            Some(token) if token.kind == TokenKind::QuotedIdentifier => token.value.clone(),
+            // Postgres folds unquoted identifiers to lower case.
+            Some(token) if token.kind == TokenKind::Word && self.sql_family == SqlFamily::Postgres => {
+                token.value.to_lowercase()
+            }
+            Some(token) if token.kind == TokenKind::Word => token.value.clone(),
+            _ => return Err(self.unexpected("an identifier")),
+        };
+
+        self.position += 1;
+
+        Ok(identifier)
+    }
+
+    /// A possibly schema-qualified name, e.g. `"public"."User"`, as its parts.
+    fn qualified_name(&mut self) -> DdlResult<Vec<String>> {
+        let mut parts = vec![self.identifier()?];
+
+        while self.eat_symbol(".") {
+            parts.push(self.identifier()?);
+        }
+
+        Ok(parts)
+    }
+
+    /// A parenthesized list of column names. Lengths, sort orders, operator classes and the like
+    /// are skipped.
+    fn column_list(&mut self) -> DdlResult<Vec<String>> {
+        self.expect_symbol("(")?;
+        let mut columns = Vec::new();
+
+        loop {
+            columns.push(self.identifier()?);
+
+            // MySQL index prefix lengths, e.g. `name(10)`, look like function calls.
+            if self.sql_family != SqlFamily::Mysql && self.is_symbol("(") {
+                return Err(self.unexpected("a column name"));
+            }
+
+            self.skip_expression();
+
+            if !self.eat_symbol(",") {
+                break;
+            }
+        }
+
+        self.expect_symbol(")")?;
+
+        Ok(columns)
+    }
+
+    /// The optional name and index method of a MySQL index, e.g. `idx USING BTREE`, or the
+    /// index method of a Postgres index, e.g. `USING btree`.
+    fn index_name(&mut self) -> DdlResult<Option<String>> {
+        let name = if self.is_symbol("(") || self.is_keyword("USING") {
+            None
+        } else {
+            Some(self.identifier()?)
+        };
+
+        if self.eat_keyword("USING") {
+            self.next();
+        }
+
+        Ok(name)
+    }
+
+    fn foreign_key_action(&mut self) -> DdlResult<ForeignKeyAction> {
+        if self.eat_keyword("CASCADE") {
+            Ok(ForeignKeyAction::Cascade)
+        } else if self.eat_keyword("RESTRICT") {
+            Ok(ForeignKeyAction::Restrict)
+        } else if self.eat_keywords(&["SET", "NULL"]) {
+            Ok(ForeignKeyAction::SetNull)
+        } else if self.eat_keywords(&["SET", "DEFAULT"]) {
+            Ok(ForeignKeyAction::SetDefault)
+        } else if self.eat_keywords(&["NO", "ACTION"]) {
+            Ok(ForeignKeyAction::NoAction)
+        } else {
+            Err(self.unexpected("a referential action"))
+        }
+    }
+
+    /// Skips tokens up to the next `,` or `)` outside of parentheses.
+    fn skip_expression(&mut self) {
+        let mut depth = 0;
+
+        while let Some(token) = self.peek() {
+            if depth == 0 && (token.is_symbol(",") || token.is_symbol(")")) {
+                break;
+            }
+
+            if token.is_symbol("(") {
+                depth += 1;
+            } else if token.is_symbol(")") {
+                depth -= 1;
+            }
+
+            self.position += 1;
+        }
+    }
+
+    /// Skips a column default, which ends at the next column option. Returns the range of its tokens.
+    fn default_expression(&mut self) -> (usize, usize) {
+        let start = self.position;
+        let mut depth = 0;
+
+        while let Some(token) = self.peek() {
+            if depth == 0
+                && (token.is_symbol(",") || token.is_symbol(")") || (self.position > start && self.at_column_option()))
+            {
+                break;
+            }
+
+            if token.is_symbol("(") {
+                depth += 1;
+            } else if token.is_symbol(")") {
+                depth -= 1;
+            }
+
+            self.position += 1;
+        }
+
+        (start, self.position)
+    }
+
+    /// Consumes a parenthesized expression, returning its source text without the parentheses.
+    fn parenthesized(&mut self) -> DdlResult<&'a str> {
+        self.expect_symbol("(")?;
+        let start = self.position;
+        let mut depth = 1;
+
+        while let Some(token) = self.next() {
+            if token.is_symbol("(") {
+                depth += 1;
+            } else if token.is_symbol(")") {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Ok(self.text(start, self.position - 1));
+                }
+            }
+        }
+
+        Err(self.unexpected("`)`"))
+    }
+
+    fn string(&mut self) -> Option<String> {
+        match self.peek() {
+            Some(token) if token.kind == TokenKind::String => {
+                self.position += 1;
+                Some(token.value.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// The source text of the tokens in `start..end`.
+    fn text(&self, start: usize, end: usize) -> &'a str {
+        if start >= end {
+            return "";
+        }
+
+        &self.source[self.tokens[start].start..self.tokens[end - 1].end]
+    }
+
+    fn unexpected(&self, expected: &str) -> DdlError {
+        let found = match self.peek() {
+            Some(_) => format!("`{}`", self.text(self.position, self.position + 1)),
+            None => "the end of the statement".to_string(),
+        };
+
+        DdlError {
+            statement: self.text(0, self.tokens.len()).to_string(),
+            message: format!("Expected {}, found {}.", expected, found),
+        }
+    }
+}
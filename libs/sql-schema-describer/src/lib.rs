@@ -1,10 +1,12 @@
 //! Database description.
 
 use failure::Fail;
+use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
 use std::fmt;
 
+pub mod ddl;
 pub mod mysql;
 pub mod postgres;
 pub mod sqlite;
@@ -47,6 +49,9 @@ pub struct SqlSchema {
     pub enums: Vec<Enum>,
     /// The schema's sequences, unique to Postgres.
     pub sequences: Vec<Sequence>,
+    /// The schema's views.
+    #[serde(default)]
+    pub views: Vec<View>,
 }
 
 impl SqlSchema {
@@ -64,6 +69,14 @@ impl SqlSchema {
         self.enums.iter().find(|x| x.name == name)
     }
 
+    /// Get the enum used by an enum column.
+    pub fn column_enum(&self, column: &Column) -> Option<&Enum> {
+        match &column.tpe.family {
+            ColumnTypeFamily::Enum(name) => self.get_enum(name),
+            _ => None,
+        }
+    }
+
     pub fn table(&self, name: &str) -> core::result::Result<&Table, String> {
         match self.tables.iter().find(|t| t.name == name) {
             Some(t) => Ok(t),
@@ -75,6 +88,11 @@ impl SqlSchema {
         self.table(&name).unwrap()
     }
 
+    /// Get a view.
+    pub fn get_view(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|x| x.name == name)
+    }
+
     /// Get a sequence.
     pub fn get_sequence(&self, name: &str) -> Option<&Sequence> {
         self.sequences.iter().find(|x| x.name == name)
@@ -85,6 +103,7 @@ impl SqlSchema {
             tables: Vec::new(),
             enums: Vec::new(),
             sequences: Vec::new(),
+            views: Vec::new(),
         }
     }
 }
@@ -103,6 +122,12 @@ pub struct Table {
     pub primary_key: Option<PrimaryKey>,
     /// The table's foreign keys.
     pub foreign_keys: Vec<ForeignKey>,
+    /// The table's `CHECK` constraints.
+    #[serde(default)]
+    pub check_constraints: Vec<CheckConstraint>,
+    /// The table's comment, if any.
+    #[serde(default)]
+    pub comment: Option<String>,
 }
 
 impl Table {
@@ -148,7 +173,56 @@ impl Table {
             index.tpe == IndexType::Unique && index.columns.len() == 1 && index.columns.contains(column_name)
         })
     }
+
+    /// Whether a unique index covers exactly the given columns, in any order.
+    pub fn are_columns_unique(&self, column_names: &[String]) -> bool {
+        self.indices.iter().any(|index| {
+            index.tpe == IndexType::Unique
+                && index.columns.len() == column_names.len()
+                && column_names
+                    .iter()
+                    .all(|column_name| index.columns.contains(column_name))
+        })
+    }
+
+    pub fn check_constraint(&self, name: &str) -> Option<&CheckConstraint> {
+        self.check_constraints.iter().find(|check| check.name == name)
+    }
+
+    pub fn has_check_constraint(&self, name: &str) -> bool {
+        self.check_constraint(name).is_some()
+    }
 }
+
+/// A `CHECK` constraint of a table.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckConstraint {
+    /// Constraint name.
+    pub name: String,
+    /// The SQL expression of the constraint, without the surrounding `CHECK (...)`.
+    pub expression: String,
+}
+
+/// A view found in a schema. Views are described so they can be introspected, but the migration
+/// engine never touches them.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    /// The view's name.
+    pub name: String,
+    /// The view's columns.
+    pub columns: Vec<Column>,
+    /// The SQL query defining the view, if the database exposes it.
+    pub definition: Option<String>,
+}
+
+impl View {
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+}
+
 /// The type of an index.
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -197,15 +271,60 @@ pub struct Column {
     pub tpe: ColumnType,
     /// Column default.
     // Does this field need to be richer? E.g. to easier detect the usages of sequences here
-    pub default: Option<String>,
+    pub default: Option<DefaultValue>,
     /// Is the column auto-incrementing?
     pub auto_increment: bool,
+    /// The column's comment, if any.
+    #[serde(default)]
+    pub comment: Option<String>,
 }
 
 impl Column {
     pub fn is_required(&self) -> bool {
         self.tpe.arity == ColumnArity::Required
     }
+
+    /// The SQL expression of the default, if the database computes it.
+    pub fn db_generated_default(&self) -> Option<&str> {
+        self.default.as_ref().and_then(DefaultValue::as_db_generated)
+    }
+}
+
+/// The default of a column.
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DefaultValue {
+    /// A value, as the database reports it.
+    Value(String),
+    /// A SQL expression computed by the database, e.g. `gen_random_uuid()`.
+    DbGenerated(String),
+}
+
+impl DefaultValue {
+    /// The value of the default, if it is not computed by the database.
+    pub fn as_value(&self) -> Option<&str> {
+        match self {
+            DefaultValue::Value(value) => Some(value),
+            DefaultValue::DbGenerated(_) => None,
+        }
+    }
+
+    /// The SQL expression of the default, if it is computed by the database.
+    pub fn as_db_generated(&self) -> Option<&str> {
+        match self {
+            DefaultValue::Value(_) => None,
+            DefaultValue::DbGenerated(expression) => Some(expression),
+        }
+    }
+}
+
+/// SQLite and MySQL report expression defaults in parentheses, e.g. `(lower(hex(randomblob(16))))`.
+pub(crate) fn expression_in_parentheses(text: &str) -> Option<&str> {
+    if text.starts_with('(') && text.ends_with(')') {
+        Some(&text[1..text.len() - 1])
+    } else {
+        None
+    }
 }
 
 /// The type of a column.
@@ -239,6 +358,8 @@ pub enum ColumnTypeFamily {
     Int,
     /// Floating point types.
     Float,
+    /// Fixed-point decimal types.
+    Decimal,
     /// Boolean types.
     Boolean,
     /// String types.
@@ -259,6 +380,8 @@ pub enum ColumnTypeFamily {
     TextSearch,
     /// Transaction ID types.
     TransactionId,
+    /// Enum types, referencing the enum by name.
+    Enum(String),
     /// Unknown
     Unknown,
 }
@@ -268,6 +391,7 @@ impl fmt::Display for ColumnTypeFamily {
         let str = match self {
             Self::Int => "int",
             Self::Float => "float",
+            Self::Decimal => "decimal",
             Self::Boolean => "boolean",
             Self::String => "string",
             Self::DateTime => "dateTime",
@@ -278,6 +402,7 @@ impl fmt::Display for ColumnTypeFamily {
             Self::LogSequenceNumber => "logSequenceNumber",
             Self::TextSearch => "textSearch",
             Self::TransactionId => "transactionId",
+            Self::Enum(name) => return write!(f, "enum({})", name),
             Self::Unknown => "unknown",
         };
         write!(f, "{}", str)
@@ -340,8 +465,24 @@ pub struct ForeignKey {
 pub struct Enum {
     /// Enum name.
     pub name: String,
-    /// Possible enum values.
-    pub values: HashSet<String>,
+    /// Possible enum values, in their defined order.
+    pub values: Vec<String>,
+}
+
+/// The name of the enum of a column on databases where enums are declared inline on the column
+/// (`ENUM` columns on MySQL and `CHECK` constraints on SQLite), since they are not named there.
+pub fn inline_enum_name(table: &str, column: &str) -> String {
+    format!("{}_{}", table, column)
+}
+
+static QUOTED_VALUE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"'((?:[^']|'')*)'"#).expect("compile regex"));
+
+/// Parses a list of single-quoted SQL string literals, e.g. `'A','B'`.
+pub(crate) fn parse_quoted_values(list: &str) -> Vec<String> {
+    QUOTED_VALUE_RE
+        .captures_iter(list)
+        .map(|captures| captures[1].replace("''", "'"))
+        .collect()
 }
 
 /// A SQL sequence.
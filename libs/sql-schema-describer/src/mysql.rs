@@ -30,15 +30,22 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let table_names = self.get_table_names(schema).await;
 
         let mut tables = Vec::with_capacity(table_names.len());
+        let mut enums = Vec::new();
 
         for table_name in &table_names {
-            tables.push(self.get_table(schema, table_name).await);
+            let (table, table_enums) = self.get_table(schema, table_name).await;
+            tables.push(table);
+            enums.extend(table_enums);
         }
 
+        let (views, view_enums) = self.get_views(schema).await;
+        enums.extend(view_enums);
+
         Ok(SqlSchema {
             tables,
-            enums: vec![],
+            enums,
             sequences: vec![],
+            views,
         })
     }
 }
@@ -91,6 +98,35 @@ impl SqlSchemaDescriber {
         names
     }
 
+    /// Returns the views of the schema, and the enums of their `ENUM` columns.
+    async fn get_views(&self, schema: &str) -> (Vec<View>, Vec<Enum>) {
+        debug!("Getting views");
+        let sql = "SELECT table_name as view_name, view_definition as view_definition
+            FROM information_schema.views
+            WHERE table_schema = ?
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await.expect("get views");
+
+        let mut views = Vec::new();
+        let mut enums = Vec::new();
+
+        for row in rows.into_iter() {
+            let name = row.get("view_name").and_then(|x| x.to_string()).expect("get view name");
+            let definition = row.get("view_definition").and_then(|x| x.to_string());
+            let (columns, view_enums) = self.get_columns(schema, &name).await;
+
+            enums.extend(view_enums);
+            views.push(View {
+                name,
+                columns,
+                definition,
+            });
+        }
+
+        debug!("Found views: {:?}", views);
+        (views, enums)
+    }
+
     async fn get_size(&self, schema: &str) -> usize {
         debug!("Getting db size");
         let sql = "SELECT 
@@ -107,26 +143,50 @@ impl SqlSchemaDescriber {
         size.parse().unwrap()
     }
 
-    async fn get_table(&self, schema: &str, name: &str) -> Table {
+    async fn get_table(&self, schema: &str, name: &str) -> (Table, Vec<Enum>) {
         debug!("Getting table '{}'", name);
-        let columns = self.get_columns(schema, name).await;
+        let (columns, enums) = self.get_columns(schema, name).await;
         let foreign_keys = self.get_foreign_keys(schema, name).await;
         let (indices, primary_key) = self.get_indices(schema, name, &foreign_keys).await;
-        Table {
+        let check_constraints = self.get_check_constraints(schema, name).await;
+        let comment = self.get_table_comment(schema, name).await;
+        let table = Table {
             name: name.to_string(),
             columns,
             foreign_keys,
             indices,
             primary_key,
-        }
+            check_constraints,
+            comment,
+        };
+
+        (table, enums)
     }
 
-    async fn get_columns(&self, schema: &str, table: &str) -> Vec<Column> {
+    async fn get_table_comment(&self, schema: &str, table: &str) -> Option<String> {
+        let sql = "SELECT table_comment table_comment
+            FROM information_schema.tables
+            WHERE table_schema = ? AND table_name = ?";
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into(), table.into()])
+            .await
+            .expect("querying for table comment");
+
+        rows.into_iter()
+            .next()
+            .and_then(|row| row.get("table_comment").and_then(|x| x.to_string()))
+            .filter(|comment| !comment.is_empty())
+    }
+
+    /// Returns the columns of the table, and the enums of its `ENUM` columns.
+    async fn get_columns(&self, schema: &str, table: &str) -> (Vec<Column>, Vec<Enum>) {
         // We alias all the columns because MySQL column names are case-insensitive in queries, but the
         // information schema column names became upper-case in MySQL 8, causing the code fetching
         // the result values by column name below to fail.
         let sql = "
-            SELECT column_name column_name, data_type data_type, column_type full_data_type, column_default column_default, is_nullable is_nullable, extra extra
+            SELECT column_name column_name, data_type data_type, column_type full_data_type, column_default column_default, is_nullable is_nullable, extra extra,
+                column_comment column_comment
             FROM information_schema.columns
             WHERE table_schema = ? AND table_name = ?
             ORDER BY column_name";
@@ -136,11 +196,16 @@ impl SqlSchemaDescriber {
             .query_raw(sql, &[schema.into(), table.into()])
             .await
             .expect("querying for columns");
+        let mut enums = Vec::new();
         let cols = rows
             .into_iter()
             .map(|col| {
                 debug!("Got column: {:?}", col);
 
+                let name = col
+                    .get("column_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get column name");
                 let data_type = col.get("data_type").and_then(|x| x.to_string()).expect("get data_type");
                 let full_data_type = col
                     .get("full_data_type")
@@ -161,7 +226,17 @@ impl SqlSchemaDescriber {
                 } else {
                     ColumnArity::Nullable
                 };
-                let tpe = get_column_type(&data_type, &full_data_type, arity);
+                let mut tpe = get_column_type(&data_type, &full_data_type, arity);
+
+                if data_type == "enum" {
+                    let enum_name = inline_enum_name(table, &name);
+                    tpe.family = ColumnTypeFamily::Enum(enum_name.clone());
+                    enums.push(Enum {
+                        name: enum_name,
+                        values: parse_quoted_values(&full_data_type),
+                    });
+                }
+
                 let extra = col
                     .get("extra")
                     .and_then(|x| x.to_string())
@@ -171,20 +246,32 @@ impl SqlSchemaDescriber {
                     "auto_increment" => true,
                     _ => false,
                 };
+                // MySQL 8 marks expression defaults, older versions only have `CURRENT_TIMESTAMP`.
+                let default = col.get("column_default").and_then(|x| x.to_string()).map(|default| {
+                    if extra.contains("default_generated") || default.eq_ignore_ascii_case("CURRENT_TIMESTAMP") {
+                        DefaultValue::DbGenerated(default)
+                    } else {
+                        DefaultValue::Value(default)
+                    }
+                });
+                // MySQL has no null comments, columns without a comment have an empty one.
+                let comment = col
+                    .get("column_comment")
+                    .and_then(|x| x.to_string())
+                    .filter(|comment| !comment.is_empty());
+
                 Column {
-                    name: col
-                        .get("column_name")
-                        .and_then(|x| x.to_string())
-                        .expect("get column name"),
+                    name,
                     tpe,
-                    default: col.get("column_default").and_then(|x| x.to_string()),
+                    default,
                     auto_increment: auto_increment,
+                    comment,
                 }
             })
             .collect();
 
         debug!("Found table columns: {:?}", cols);
-        cols
+        (cols, enums)
     }
 
     async fn get_foreign_keys(&self, schema: &str, table: &str) -> Vec<ForeignKey> {
@@ -303,6 +390,53 @@ impl SqlSchemaDescriber {
         fks
     }
 
+    async fn get_check_constraints(&self, schema: &str, table: &str) -> Vec<CheckConstraint> {
+        // `information_schema.check_constraints` only exists on MySQL 8.0.16 and later (and
+        // MariaDB). Older versions parse but do not enforce check constraints, so there is
+        // nothing to describe there.
+        let sql = "
+            SELECT
+                cc.constraint_name constraint_name,
+                cc.check_clause check_clause
+            FROM information_schema.check_constraints AS cc
+            INNER JOIN information_schema.table_constraints AS tc ON
+                cc.constraint_schema = tc.constraint_schema
+                AND cc.constraint_name = tc.constraint_name
+            WHERE
+                tc.table_schema = ?
+                AND tc.table_name = ?
+                AND tc.constraint_type = 'CHECK'
+            ORDER BY constraint_name
+        ";
+
+        debug!("describing table check constraints, SQL: '{}'", sql);
+
+        let result_set = match self.conn.query_raw(sql, &[schema.into(), table.into()]).await {
+            Ok(result_set) => result_set,
+            Err(err) => {
+                debug!("Could not describe check constraints: {:?}", err);
+                return Vec::new();
+            }
+        };
+
+        result_set
+            .into_iter()
+            .map(|row| {
+                debug!("Got check constraint row {:?}", row);
+                let name = row
+                    .get("constraint_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get constraint_name");
+                let expression = row
+                    .get("check_clause")
+                    .and_then(|x| x.to_string())
+                    .expect("get check_clause");
+
+                CheckConstraint { name, expression }
+            })
+            .collect()
+    }
+
     async fn get_indices(
         &self,
         schema: &str,
@@ -399,7 +533,7 @@ impl SqlSchemaDescriber {
     }
 }
 
-fn get_column_type(data_type: &str, full_data_type: &str, arity: ColumnArity) -> ColumnType {
+pub(crate) fn get_column_type(data_type: &str, full_data_type: &str, arity: ColumnArity) -> ColumnType {
     let family = match (data_type, full_data_type) {
         ("int", _) => ColumnTypeFamily::Int,
         ("smallint", _) => ColumnTypeFamily::Int,
@@ -407,8 +541,8 @@ fn get_column_type(data_type: &str, full_data_type: &str, arity: ColumnArity) ->
         ("tinyint", _) => ColumnTypeFamily::Int,
         ("mediumint", _) => ColumnTypeFamily::Int,
         ("bigint", _) => ColumnTypeFamily::Int,
-        ("decimal", _) => ColumnTypeFamily::Float,
-        ("numeric", _) => ColumnTypeFamily::Float,
+        ("decimal", _) => ColumnTypeFamily::Decimal,
+        ("numeric", _) => ColumnTypeFamily::Decimal,
         ("float", _) => ColumnTypeFamily::Float,
         ("double", _) => ColumnTypeFamily::Float,
         ("date", _) => ColumnTypeFamily::DateTime,
@@ -422,8 +556,6 @@ fn get_column_type(data_type: &str, full_data_type: &str, arity: ColumnArity) ->
         ("tinytext", _) => ColumnTypeFamily::String,
         ("mediumtext", _) => ColumnTypeFamily::String,
         ("longtext", _) => ColumnTypeFamily::String,
-        // XXX: Is this correct?
-        ("enum", _) => ColumnTypeFamily::String,
         ("set", _) => ColumnTypeFamily::String,
         ("binary", _) => ColumnTypeFamily::Binary,
         ("varbinary", _) => ColumnTypeFamily::Binary,
@@ -442,8 +574,17 @@ fn get_column_type(data_type: &str, full_data_type: &str, arity: ColumnArity) ->
         ("json", _) => ColumnTypeFamily::Json,
         _ => ColumnTypeFamily::Unknown,
     };
+    // Keep the length or precision of the types that take them, so native types can be matched
+    // with their arguments, e.g. `varchar(255)` or `decimal(10,2)`.
+    let raw = match data_type {
+        "char" | "varchar" | "binary" | "varbinary" | "decimal" => {
+            full_data_type.split(' ').next().unwrap_or(data_type).to_string()
+        }
+        _ => data_type.to_string(),
+    };
+
     ColumnType {
-        raw: data_type.to_string(),
+        raw,
         family: family,
         arity,
     }
@@ -4,7 +4,7 @@ use log::debug;
 use once_cell::sync::Lazy;
 use quaint::prelude::Queryable;
 use regex::Regex;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::sync::Arc;
 
@@ -39,11 +39,22 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables.push(self.get_table(schema, &table_name, &sequences).await);
         }
 
+        let mut views = self.get_views(schema).await;
+
         let enums = self.get_enums(schema).await?;
+        resolve_enum_columns(
+            tables
+                .iter_mut()
+                .flat_map(|table| table.columns.iter_mut())
+                .chain(views.iter_mut().flat_map(|view| view.columns.iter_mut())),
+            &enums,
+        );
+
         Ok(SqlSchema {
             enums,
             sequences,
             tables,
+            views,
         })
     }
 }
@@ -96,6 +107,32 @@ impl SqlSchemaDescriber {
         names
     }
 
+    async fn get_views(&self, schema: &str) -> Vec<View> {
+        debug!("Getting views");
+        let sql = "SELECT table_name as view_name, view_definition as view_definition
+            FROM information_schema.views
+            WHERE table_schema = $1
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await.expect("get views");
+
+        let mut views = Vec::new();
+
+        for row in rows.into_iter() {
+            let name = row.get("view_name").and_then(|x| x.to_string()).expect("get view name");
+            let definition = row.get("view_definition").and_then(|x| x.to_string());
+            let columns = self.get_columns(schema, &name).await;
+
+            views.push(View {
+                name,
+                columns,
+                definition,
+            });
+        }
+
+        debug!("Found views: {:?}", views);
+        views
+    }
+
     async fn get_size(&self, schema: &str) -> usize {
         debug!("Getting db size");
         let sql =
@@ -114,20 +151,40 @@ impl SqlSchemaDescriber {
 
     async fn get_table(&self, schema: &str, name: &str, sequences: &Vec<Sequence>) -> Table {
         debug!("Getting table '{}'", name);
-        let columns = self.get_columns(schema, name).await;
+        let mut columns = self.get_columns(schema, name).await;
         let (indices, primary_key) = self.get_indices(schema, name, sequences).await;
+        mark_sequence_seeded_column(&mut columns, primary_key.as_ref());
         let foreign_keys = self.get_foreign_keys(schema, name).await;
+        let check_constraints = self.get_check_constraints(schema, name).await;
+        let comment = self.get_table_comment(schema, name).await;
         Table {
             name: name.to_string(),
             columns,
             foreign_keys,
             indices,
             primary_key,
+            check_constraints,
+            comment,
         }
     }
 
+    async fn get_table_comment(&self, schema: &str, table: &str) -> Option<String> {
+        let sql = "SELECT obj_description((quote_ident($1::text) || '.' || quote_ident($2::text))::regclass, 'pg_class') as comment";
+        let rows = self
+            .conn
+            .query_raw(sql, &[schema.into(), table.into()])
+            .await
+            .expect("querying for table comment");
+
+        rows.into_iter()
+            .next()
+            .and_then(|row| row.get("comment").and_then(|x| x.to_string()))
+    }
+
     async fn get_columns(&self, schema: &str, table: &str) -> Vec<Column> {
-        let sql = "SELECT column_name, data_type, udt_name as full_column_type, column_default, is_nullable, is_identity, data_type
+        let sql = "SELECT column_name, data_type, udt_name as full_column_type, column_default, is_nullable, is_identity, data_type,
+                character_maximum_length, numeric_precision, numeric_scale,
+                col_description((quote_ident(table_schema::text) || '.' || quote_ident(table_name::text))::regclass, ordinal_position::int) as column_comment
             FROM information_schema.columns
             WHERE table_schema = $1 AND table_name = $2
             ORDER BY column_name";
@@ -177,23 +234,39 @@ impl SqlSchemaDescriber {
                 } else {
                     ColumnArity::Nullable
                 };
-                let tpe = get_column_type(data_type.as_ref(), &full_data_type, arity);
-
-                let default = col.get("column_default").and_then(|param_value| {
-                    param_value
-                        .to_string()
-                        .map(|x| x.replace("\'", "").replace("::text", ""))
-                });
+                let mut tpe = get_column_type(data_type.as_ref(), &full_data_type, arity);
+                tpe.raw = raw_type_with_arguments(
+                    &full_data_type,
+                    col.get("character_maximum_length").and_then(|x| x.as_i64()),
+                    col.get("numeric_precision").and_then(|x| x.as_i64()),
+                    col.get("numeric_scale").and_then(|x| x.as_i64()),
+                );
+
+                let raw_default = col
+                    .get("column_default")
+                    .and_then(|param_value| param_value.to_string());
+                let mut default = raw_default
+                    .as_ref()
+                    .map(|x| DefaultValue::Value(x.replace("\'", "").replace("::text", "")));
                 let is_auto_increment = is_identity
-                    || match default {
-                        Some(ref val) => is_autoincrement(val, schema, table, &col_name),
+                    || match default.as_ref().and_then(DefaultValue::as_value) {
+                        Some(val) => is_autoincrement(val, schema, table, &col_name),
                         _ => false,
                     };
+
+                if !is_auto_increment {
+                    if let Some(expression) = raw_default.filter(|raw| !is_literal_default(raw)) {
+                        default = Some(DefaultValue::DbGenerated(expression));
+                    }
+                }
+                let comment = col.get("column_comment").and_then(|x| x.to_string());
+
                 Column {
                     name: col_name,
                     tpe,
                     default,
                     auto_increment: is_auto_increment,
+                    comment,
                 }
             })
             .collect();
@@ -209,6 +282,7 @@ impl SqlSchemaDescriber {
                 con.oid as "con_id",
                 att2.attname as "child_column",
                 cl.relname as "parent_table",
+                parent_ns.nspname as "parent_schema",
                 att.attname as "parent_column",
                 con.confdeltype,
                 conname as constraint_name,
@@ -238,6 +312,8 @@ impl SqlSchemaDescriber {
                 att.attrelid = con.confrelid and att.attnum = con.child
             JOIN pg_class cl on
                 cl.oid = con.confrelid
+            JOIN pg_namespace parent_ns on
+                parent_ns.oid = cl.relnamespace
             JOIN pg_attribute att2 on
                 att2.attrelid = con.conrelid and att2.attnum = con.parent
             ORDER BY con_id, con.colidx"#;
@@ -263,6 +339,16 @@ impl SqlSchemaDescriber {
                 .get("parent_table")
                 .and_then(|x| x.to_string())
                 .expect("get parent_table");
+            let referenced_schema = row
+                .get("parent_schema")
+                .and_then(|x| x.to_string())
+                .expect("get parent_schema");
+            // Tables of other schemas are referenced by their schema-qualified name.
+            let referenced_table = if referenced_schema == schema {
+                referenced_table
+            } else {
+                format!("{}.{}", referenced_schema, referenced_table)
+            };
             let referenced_column = row
                 .get("parent_column")
                 .and_then(|x| x.to_string())
@@ -317,6 +403,49 @@ impl SqlSchemaDescriber {
         fks
     }
 
+    async fn get_check_constraints(&self, schema: &str, table: &str) -> Vec<CheckConstraint> {
+        let sql = r#"
+            SELECT
+                con.conname AS constraint_name,
+                pg_get_constraintdef(con.oid) AS definition
+            FROM
+                pg_constraint con
+                JOIN pg_class cl on con.conrelid = cl.oid
+                JOIN pg_namespace ns on cl.relnamespace = ns.oid
+            WHERE
+                cl.relname = $1
+                AND ns.nspname = $2
+                AND con.contype = 'c'
+            ORDER BY con.conname"#;
+        debug!("describing table check constraints, SQL: '{}'", sql);
+
+        let result_set = self
+            .conn
+            .query_raw(&sql, &[table.into(), schema.into()])
+            .await
+            .expect("querying for check constraints");
+
+        result_set
+            .into_iter()
+            .map(|row| {
+                debug!("Got check constraint row {:?}", row);
+                let name = row
+                    .get("constraint_name")
+                    .and_then(|x| x.to_string())
+                    .expect("get constraint_name");
+                let definition = row
+                    .get("definition")
+                    .and_then(|x| x.to_string())
+                    .expect("get definition");
+
+                CheckConstraint {
+                    name,
+                    expression: check_expression(&definition),
+                }
+            })
+            .collect()
+    }
+
     async fn get_indices(
         &self,
         schema: &str,
@@ -449,7 +578,7 @@ impl SqlSchemaDescriber {
 
     async fn get_sequences(&self, schema: &str) -> SqlSchemaDescriberResult<Vec<Sequence>> {
         debug!("Getting sequences");
-        let sql = "SELECT start_value, sequence_name
+        let sql = "SELECT start_value, increment, sequence_name
                   FROM information_schema.sequences
                   WHERE sequence_schema = $1";
         let rows = self
@@ -466,10 +595,14 @@ impl SqlSchemaDescriber {
                     .and_then(|x| x.to_string())
                     .and_then(|x| x.parse::<u32>().ok())
                     .expect("get start_value");
+                // Descending sequences can not be represented.
+                let allocation_size = seq
+                    .get("increment")
+                    .and_then(|x| x.to_string())
+                    .and_then(|x| x.parse::<u32>().ok())
+                    .unwrap_or(1);
                 Sequence {
-                    // Not sure what allocation size refers to, but the TypeScript implementation
-                    // hardcodes this as 1
-                    allocation_size: 1,
+                    allocation_size,
                     initial_value,
                     name: seq
                         .get("sequence_name")
@@ -489,34 +622,69 @@ impl SqlSchemaDescriber {
             FROM pg_type t
             JOIN pg_enum e ON t.oid = e.enumtypid
             JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
-            WHERE n.nspname = $1";
+            WHERE n.nspname = $1
+            ORDER BY t.typname, e.enumsortorder";
         let rows = self
             .conn
             .query_raw(&sql, &[schema.into()])
             .await
             .expect("querying for enums");
-        let mut enum_values: HashMap<String, HashSet<String>> = HashMap::new();
+        let mut enums: Vec<Enum> = Vec::new();
         for row in rows.into_iter() {
             debug!("Got enum row: {:?}", row);
             let name = row.get("name").and_then(|x| x.to_string()).expect("get name");
             let value = row.get("value").and_then(|x| x.to_string()).expect("get value");
-            if !enum_values.contains_key(&name) {
-                enum_values.insert(name.clone(), HashSet::new());
+            match enums.last_mut() {
+                Some(last) if last.name == name => last.values.push(value),
+                _ => enums.push(Enum {
+                    name,
+                    values: vec![value],
+                }),
             }
-            let vals = enum_values.get_mut(&name).expect("get enum values");
-            vals.insert(value);
         }
 
-        let enums: Vec<Enum> = enum_values
-            .into_iter()
-            .map(|(k, v)| Enum { name: k, values: v })
-            .collect();
         debug!("Found enums: {:?}", enums);
         Ok(enums)
     }
 }
 
-fn get_column_type(_data_type: &str, full_data_type: &str, arity: ColumnArity) -> ColumnType {
+/// Enum columns are described as user-defined types, named after the enum (with a leading underscore
+/// for arrays). Their defaults are cast to the enum type, e.g. `A::"Color"`, so the cast is removed.
+pub(crate) fn resolve_enum_columns<'a>(columns: impl Iterator<Item = &'a mut Column>, enums: &[Enum]) {
+    for column in columns {
+        let type_name = column.tpe.raw.trim_start_matches('_');
+
+        if let Some(inum) = enums.iter().find(|inum| inum.name == type_name) {
+            column.tpe.family = ColumnTypeFamily::Enum(inum.name.clone());
+            column.default = column.default.take().map(|default| match default {
+                DefaultValue::Value(value) => DefaultValue::Value(
+                    value
+                        .trim_end_matches(&format!("::\"{}\"", inum.name))
+                        .trim_end_matches(&format!("::{}", inum.name))
+                        .to_string(),
+                ),
+                db_generated => db_generated,
+            });
+        }
+    }
+}
+
+/// Appends the length or precision of a column to its type, e.g. `varchar(255)` or `numeric(10,2)`,
+/// so native types can be matched with their arguments.
+pub(crate) fn raw_type_with_arguments(
+    udt_name: &str,
+    character_maximum_length: Option<i64>,
+    numeric_precision: Option<i64>,
+    numeric_scale: Option<i64>,
+) -> String {
+    match (udt_name, character_maximum_length, numeric_precision, numeric_scale) {
+        ("varchar", Some(length), _, _) | ("bpchar", Some(length), _, _) => format!("{}({})", udt_name, length),
+        ("numeric", _, Some(precision), Some(scale)) => format!("{}({},{})", udt_name, precision, scale),
+        _ => udt_name.to_string(),
+    }
+}
+
+pub(crate) fn get_column_type(_data_type: &str, full_data_type: &str, arity: ColumnArity) -> ColumnType {
     let family = match full_data_type {
         "int2" => ColumnTypeFamily::Int,
         "int4" => ColumnTypeFamily::Int,
@@ -541,7 +709,7 @@ fn get_column_type(_data_type: &str, full_data_type: &str, arity: ColumnArity) -
         "polygon" => ColumnTypeFamily::Geometric,
         "bpchar" => ColumnTypeFamily::String,
         "interval" => ColumnTypeFamily::DateTime,
-        "numeric" => ColumnTypeFamily::Float,
+        "numeric" => ColumnTypeFamily::Decimal,
         "pg_lsn" => ColumnTypeFamily::LogSequenceNumber,
         "time" => ColumnTypeFamily::DateTime,
         "timetz" => ColumnTypeFamily::DateTime,
@@ -557,6 +725,7 @@ fn get_column_type(_data_type: &str, full_data_type: &str, arity: ColumnArity) -
         "_float8" => ColumnTypeFamily::Float,
         "_float4" => ColumnTypeFamily::Float,
         "_int4" => ColumnTypeFamily::Int,
+        "_numeric" => ColumnTypeFamily::Decimal,
         "_text" => ColumnTypeFamily::String,
         "_varchar" => ColumnTypeFamily::String,
         _ => ColumnTypeFamily::Unknown,
@@ -575,6 +744,55 @@ static AUTOINCREMENT_REGEX: Lazy<Regex> = Lazy::new(|| {
         .unwrap()
 });
 
+static LITERAL_DEFAULT_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"^(?:'(?:[^']|'')*'|-?[0-9]+(?:\.[0-9]+)?|true|false|NULL)(?:::[\w" \[\]]+)?$"#).unwrap()
+});
+
+/// Literal defaults are constants, possibly cast, e.g. `'A'::text` or `42`. Everything else is
+/// an expression the database evaluates, e.g. `now()`.
+pub(crate) fn is_literal_default(value: &str) -> bool {
+    LITERAL_DEFAULT_REGEX.is_match(value)
+}
+
+/// Postgres renders check constraints as `CHECK (<expression>)`, possibly followed by options
+/// like `NOT VALID`. We only keep the expression.
+fn check_expression(definition: &str) -> String {
+    let definition = definition.trim();
+
+    if !definition.starts_with("CHECK (") {
+        return definition.to_string();
+    }
+
+    let body = &definition["CHECK (".len()..];
+    let end = body.rfind(')').unwrap_or_else(|| body.len());
+
+    body[..end].trim().to_string()
+}
+
+/// A primary key column defaulting to the next value of the sequence it owns is auto incrementing,
+/// also when the sequence is not named `{table}_{column}_seq`.
+fn mark_sequence_seeded_column(columns: &mut [Column], primary_key: Option<&PrimaryKey>) {
+    let (column_name, sequence) = match primary_key {
+        Some(PrimaryKey {
+            columns: pk_columns,
+            sequence: Some(sequence),
+        }) if pk_columns.len() == 1 => (&pk_columns[0], sequence),
+        _ => return,
+    };
+
+    for column in columns.iter_mut().filter(|column| &column.name == column_name) {
+        let default = match column.db_generated_default() {
+            Some(expression) if expression.starts_with("nextval(") && expression.contains(&sequence.name) => {
+                expression.replace('\'', "").replace("::text", "")
+            }
+            _ => continue,
+        };
+
+        column.default = Some(DefaultValue::Value(default));
+        column.auto_increment = true;
+    }
+}
+
 /// Returns whether a particular sequence (`value`) matches the provided column info.
 fn is_autoincrement(value: &str, schema_name: &str, table_name: &str, column_name: &str) -> bool {
     AUTOINCREMENT_REGEX
@@ -665,4 +883,14 @@ mod tests {
             "compound_column_name",
         ));
     }
+
+    #[test]
+    fn postgres_check_expression_works() {
+        assert_eq!(check_expression("CHECK ((price >= 0))"), "(price >= 0)");
+        assert_eq!(
+            check_expression("CHECK (((a > 0) AND (b > 0)))"),
+            "((a > 0) AND (b > 0))"
+        );
+        assert_eq!(check_expression("CHECK ((price >= 0)) NOT VALID"), "(price >= 0)");
+    }
 }
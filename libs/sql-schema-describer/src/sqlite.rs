@@ -2,7 +2,9 @@
 use super::*;
 use failure::_core::convert::TryInto;
 use log::debug;
+use once_cell::sync::Lazy;
 use quaint::{ast::ParameterizedValue, prelude::Queryable};
+use regex::Regex;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -31,17 +33,23 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let table_names: Vec<String> = self.get_table_names(schema).await;
 
         let mut tables = Vec::with_capacity(table_names.len());
+        let mut enums = Vec::new();
 
         for table_name in table_names.iter().filter(|table| !is_system_table(&table)) {
-            tables.push(self.get_table(schema, table_name).await)
+            let (table, table_enums) = self.get_table(schema, table_name).await;
+            tables.push(table);
+            enums.extend(table_enums);
         }
 
+        let views = self.get_views(schema).await;
+
         Ok(SqlSchema {
-            // There's no enum type in SQLite.
-            enums: vec![],
+            // There's no enum type in SQLite, enums are columns with a `CHECK` constraint.
+            enums,
             // There are no sequences in SQLite.
             sequences: vec![],
             tables: tables,
+            views,
         })
     }
 }
@@ -83,6 +91,30 @@ impl SqlSchemaDescriber {
         names
     }
 
+    async fn get_views(&self, schema: &str) -> Vec<View> {
+        let sql = format!(r#"SELECT name, sql FROM "{}".sqlite_master WHERE type='view'"#, schema);
+        debug!("describing views with query: '{}'", sql);
+        let result_set = self.conn.query_raw(&sql, &[]).await.expect("get views");
+
+        let mut views = Vec::new();
+
+        for row in result_set.into_iter() {
+            let name = row.get("name").and_then(|x| x.to_string()).expect("get view name");
+            let definition = row.get("sql").and_then(|x| x.to_string());
+            // Views have no primary key.
+            let (columns, _) = self.get_columns(schema, &name).await;
+
+            views.push(View {
+                name,
+                columns,
+                definition,
+            });
+        }
+
+        debug!("Found views: {:?}", views);
+        views
+    }
+
     async fn get_size(&self, _schema: &str) -> usize {
         debug!("Getting db size");
         let sql = format!(r#"SELECT page_count * page_size as size FROM pragma_page_count(), pragma_page_size();"#);
@@ -95,18 +127,39 @@ impl SqlSchemaDescriber {
         size.try_into().unwrap()
     }
 
-    async fn get_table(&self, schema: &str, name: &str) -> Table {
+    async fn get_table(&self, schema: &str, name: &str) -> (Table, Vec<Enum>) {
         debug!("describing table '{}' in schema '{}", name, schema);
-        let (columns, primary_key) = self.get_columns(schema, name).await;
+        let (mut columns, primary_key) = self.get_columns(schema, name).await;
         let foreign_keys = self.get_foreign_keys(schema, name).await;
         let indices = self.get_indices(schema, name).await;
-        Table {
+        let create_table = self.get_create_table(schema, name).await.unwrap_or_default();
+        let enums = get_enums(name, &create_table, &mut columns);
+        let check_constraints = get_check_constraints(&create_table);
+        let table = Table {
             name: name.to_string(),
             columns,
             indices,
             primary_key,
             foreign_keys,
-        }
+            check_constraints,
+            comment: None,
+        };
+
+        (table, enums)
+    }
+
+    /// Enums and check constraints can only be read from the `CREATE TABLE` statement of the table.
+    async fn get_create_table(&self, schema: &str, table: &str) -> Option<String> {
+        let sql = format!(
+            r#"SELECT sql FROM "{}".sqlite_master WHERE type='table' AND name = ?"#,
+            schema
+        );
+        debug!("describing table definition, query: '{}'", sql);
+        let result_set = self.conn.query_raw(&sql, &[table.into()]).await.expect("get table sql");
+
+        result_set
+            .first()
+            .and_then(|row| row.get("sql").and_then(|x| x.to_string()))
     }
 
     async fn get_columns(&self, schema: &str, table: &str) -> (Vec<Column>, Option<PrimaryKey>) {
@@ -119,7 +172,7 @@ impl SqlSchemaDescriber {
             .map(|row| {
                 debug!("Got column row {:?}", row);
                 let default_value = match row.get("dflt_value") {
-                    Some(ParameterizedValue::Text(v)) => Some(v.to_string().replace("\"", "").replace("'", "")),
+                    Some(ParameterizedValue::Text(v)) => Some(parse_default(&v)),
                     Some(ParameterizedValue::Null) => None,
                     Some(p) => panic!("expected a string value but got {:?}", p),
                     None => panic!("couldn't get dflt_value column"),
@@ -138,6 +191,7 @@ impl SqlSchemaDescriber {
                     tpe,
                     default: default_value.clone(),
                     auto_increment: false,
+                    comment: None,
                 };
                 if pk_col > 0 {
                     pk_cols.insert(pk_col, col.name.clone());
@@ -336,7 +390,7 @@ impl SqlSchemaDescriber {
     }
 }
 
-fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
+pub(crate) fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
     let tpe_lower = tpe.to_lowercase();
     let family = match tpe_lower.as_ref() {
         // SQLite only has a few native data types: https://www.sqlite.org/datatype3.html
@@ -348,10 +402,12 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         "boolean" => ColumnTypeFamily::Boolean,
         "text" => ColumnTypeFamily::String,
         s if s.contains("char") => ColumnTypeFamily::String,
-        s if s.contains("numeric") => ColumnTypeFamily::Float,
+        s if s.contains("numeric") => ColumnTypeFamily::Decimal,
+        s if s.starts_with("decimal") => ColumnTypeFamily::Decimal,
         "date" => ColumnTypeFamily::DateTime,
         "datetime" => ColumnTypeFamily::DateTime,
         "binary" => ColumnTypeFamily::Binary,
+        "blob" => ColumnTypeFamily::Binary,
         "double" => ColumnTypeFamily::Float,
         "binary[]" => ColumnTypeFamily::Binary,
         "boolean[]" => ColumnTypeFamily::Boolean,
@@ -370,6 +426,107 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
     }
 }
 
+/// Enums are `CHECK ("column" IN ('A', 'B'))` constraints on their column.
+pub(crate) fn get_enums(table: &str, create_table: &str, columns: &mut [Column]) -> Vec<Enum> {
+    let mut enums = Vec::new();
+
+    for captures in ENUM_CHECK_RE.captures_iter(create_table) {
+        let column = match columns.iter_mut().find(|column| column.name == &captures["column"]) {
+            Some(column) => column,
+            None => continue,
+        };
+        let enum_name = inline_enum_name(table, &column.name);
+
+        column.tpe.family = ColumnTypeFamily::Enum(enum_name.clone());
+        enums.push(Enum {
+            name: enum_name,
+            values: parse_quoted_values(&captures["values"]),
+        });
+    }
+
+    debug!("Found table enums: {:?}", enums);
+    enums
+}
+
+/// Only named check constraints (`CONSTRAINT "name" CHECK (...)`) are described. Unnamed ones
+/// cannot be told apart, and are used for enums.
+pub(crate) fn get_check_constraints(create_table: &str) -> Vec<CheckConstraint> {
+    let mut check_constraints = Vec::new();
+
+    for captures in NAMED_CHECK_RE.captures_iter(create_table) {
+        let name = captures
+            .name("quoted")
+            .or_else(|| captures.name("bare"))
+            .expect("check constraint name")
+            .as_str();
+        let body_start = captures.get(0).expect("check constraint match").end();
+
+        if let Some(expression) = balanced_parenthesized(&create_table[body_start..]) {
+            check_constraints.push(CheckConstraint {
+                name: name.to_string(),
+                expression: expression.trim().to_string(),
+            });
+        }
+    }
+
+    debug!("Found table check constraints: {:?}", check_constraints);
+    check_constraints
+}
+
+/// Returns the contents of the parenthesized expression `input` continues, given that the opening
+/// parenthesis was already consumed.
+fn balanced_parenthesized(input: &str) -> Option<&str> {
+    let mut depth = 1;
+    let mut in_string: Option<char> = None;
+
+    for (idx, c) in input.char_indices() {
+        match (in_string, c) {
+            (Some(quote), c) if c == quote => in_string = None,
+            (Some(_), _) => (),
+            (None, '\'') | (None, '"') | (None, '`') => in_string = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(&input[..idx]);
+                }
+            }
+            (None, _) => (),
+        }
+    }
+
+    None
+}
+
+static NAMED_CHECK_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)CONSTRAINT\s+(?:"(?P<quoted>[^"]+)"|(?P<bare>\w+))\s+CHECK\s*\("#).expect("compile regex")
+});
+
+static ENUM_CHECK_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)CHECK\s*\(\s*"(?P<column>[^"]+)"\s+IN\s*\((?P<values>(?:\s*'(?:[^']|'')*'\s*,?)*)\s*\)\s*\)"#)
+        .expect("compile regex")
+});
+
+/// Parses a column default as SQLite reports it. Expressions are in parentheses, except the current
+/// time keywords, and string values are quoted.
+pub(crate) fn parse_default(default: &str) -> DefaultValue {
+    if let Some(expression) = expression_in_parentheses(default) {
+        DefaultValue::DbGenerated(expression.to_owned())
+    } else if is_current_time_keyword(default) {
+        DefaultValue::DbGenerated(default.to_owned())
+    } else {
+        DefaultValue::Value(default.replace("\"", "").replace("'", ""))
+    }
+}
+
+/// The only expressions SQLite allows as defaults without parentheses.
+fn is_current_time_keyword(default: &str) -> bool {
+    ["CURRENT_TIME", "CURRENT_DATE", "CURRENT_TIMESTAMP"]
+        .iter()
+        .any(|keyword| default.eq_ignore_ascii_case(keyword))
+}
+
 /// Returns whether a table is one of the SQLite system tables.
 fn is_system_table(table_name: &str) -> bool {
     SQLITE_SYSTEM_TABLES
@@ -0,0 +1,367 @@
+use pretty_assertions::assert_eq;
+use quaint::prelude::SqlFamily;
+use sql_schema_describer::*;
+
+const PG_DUMP: &str = r#"
+--
+-- PostgreSQL database dump
+--
+
+SET statement_timeout = 0;
+SELECT pg_catalog.set_config('search_path', '', false);
+
+CREATE TYPE public."Role" AS ENUM (
+    'USER',
+    'ADMIN'
+);
+
+CREATE FUNCTION public.touch() RETURNS trigger
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    NEW."updatedAt" = now();
+    RETURN NEW;
+END;
+$$;
+
+CREATE TABLE public."User" (
+    id integer NOT NULL,
+    email character varying(191) NOT NULL,
+    name text,
+    role public."Role" DEFAULT 'USER'::public."Role" NOT NULL,
+    tags text[],
+    "createdAt" timestamp(3) without time zone DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    balance numeric(65,30) DEFAULT 0 NOT NULL
+);
+
+COMMENT ON TABLE public."User" IS 'Registered users';
+COMMENT ON COLUMN public."User".email IS 'Where we send the newsletter';
+
+CREATE SEQUENCE public."User_id_seq"
+    AS integer
+    START WITH 1
+    INCREMENT BY 1
+    NO MINVALUE
+    NO MAXVALUE
+    CACHE 1;
+
+ALTER SEQUENCE public."User_id_seq" OWNED BY public."User".id;
+
+CREATE TABLE public."Post" (
+    id integer GENERATED BY DEFAULT AS IDENTITY (SEQUENCE NAME public."Post_id_seq" START WITH 10 INCREMENT BY 5),
+    title text NOT NULL,
+    "authorId" integer,
+    CONSTRAINT "Post_title_check" CHECK ((length(title) > 0))
+);
+
+CREATE TABLE other."Audit" (
+    id integer NOT NULL
+);
+
+CREATE VIEW public."Authors" AS
+ SELECT DISTINCT "authorId" FROM public."Post";
+
+ALTER TABLE ONLY public."User" ALTER COLUMN id SET DEFAULT nextval('public."User_id_seq"'::regclass);
+
+ALTER TABLE ONLY public."Post"
+    ADD CONSTRAINT "Post_pkey" PRIMARY KEY (id);
+
+ALTER TABLE ONLY public."User"
+    ADD CONSTRAINT "User_pkey" PRIMARY KEY (id);
+
+CREATE UNIQUE INDEX "User.email" ON public."User" USING btree (email);
+
+CREATE INDEX "User_lower_name" ON public."User" USING btree (lower(name));
+
+ALTER TABLE ONLY public."Post"
+    ADD CONSTRAINT "Post_authorId_fkey" FOREIGN KEY ("authorId") REFERENCES public."User"(id) ON UPDATE CASCADE ON DELETE SET NULL;
+"#;
+
+#[test]
+fn pg_dump_output_must_be_described() {
+    let schema = ddl::describe(PG_DUMP, SqlFamily::Postgres, "public").unwrap();
+
+    let table_names: Vec<&str> = schema.tables.iter().map(|table| table.name.as_str()).collect();
+    assert_eq!(table_names, vec!["Post", "User"]);
+    assert!(schema.views.is_empty());
+
+    assert_eq!(
+        schema.enums,
+        vec![Enum {
+            name: "Role".to_string(),
+            values: vec!["USER".to_string(), "ADMIN".to_string()],
+        }]
+    );
+
+    let user = schema.get_table("User").unwrap();
+    let column_names: Vec<&str> = user.columns.iter().map(|column| column.name.as_str()).collect();
+    assert_eq!(
+        column_names,
+        vec!["balance", "createdAt", "email", "id", "name", "role", "tags"]
+    );
+
+    let id = user.column_bang("id");
+    assert_eq!(id.tpe.raw, "int4");
+    assert_eq!(id.tpe.family, ColumnTypeFamily::Int);
+    assert_eq!(id.tpe.arity, ColumnArity::Required);
+    assert!(id.auto_increment);
+
+    let email = user.column_bang("email");
+    assert_eq!(email.tpe.raw, "varchar(191)");
+    assert_eq!(email.tpe.family, ColumnTypeFamily::String);
+    assert_eq!(email.comment, Some("Where we send the newsletter".to_string()));
+
+    assert_eq!(user.column_bang("name").tpe.arity, ColumnArity::Nullable);
+    assert_eq!(user.column_bang("tags").tpe.raw, "_text");
+    assert_eq!(user.column_bang("tags").tpe.arity, ColumnArity::List);
+
+    let role = user.column_bang("role");
+    assert_eq!(role.tpe.family, ColumnTypeFamily::Enum("Role".to_string()));
+    assert_eq!(role.default, Some(DefaultValue::Value("USER".to_string())));
+
+    let created_at = user.column_bang("createdAt");
+    assert_eq!(created_at.tpe.raw, "timestamp");
+    assert_eq!(
+        created_at.default,
+        Some(DefaultValue::DbGenerated("CURRENT_TIMESTAMP".to_string()))
+    );
+
+    let balance = user.column_bang("balance");
+    assert_eq!(balance.tpe.raw, "numeric(65,30)");
+    assert_eq!(balance.default, Some(DefaultValue::Value("0".to_string())));
+
+    assert_eq!(user.comment, Some("Registered users".to_string()));
+    assert_eq!(
+        user.primary_key,
+        Some(PrimaryKey {
+            columns: vec!["id".to_string()],
+            sequence: Some(Sequence {
+                name: "User_id_seq".to_string(),
+                initial_value: 1,
+                allocation_size: 1,
+            }),
+        })
+    );
+    assert_eq!(
+        user.indices,
+        vec![Index {
+            name: "User.email".to_string(),
+            columns: vec!["email".to_string()],
+            tpe: IndexType::Unique,
+        }]
+    );
+
+    let post = schema.get_table("Post").unwrap();
+    assert!(post.column_bang("id").auto_increment);
+    assert_eq!(
+        post.primary_key.as_ref().and_then(|pk| pk.sequence.as_ref()),
+        Some(&Sequence {
+            name: "Post_id_seq".to_string(),
+            initial_value: 10,
+            allocation_size: 5,
+        })
+    );
+    assert_eq!(
+        post.foreign_keys,
+        vec![ForeignKey {
+            constraint_name: Some("Post_authorId_fkey".to_string()),
+            columns: vec!["authorId".to_string()],
+            referenced_table: "User".to_string(),
+            referenced_columns: vec!["id".to_string()],
+            on_delete_action: ForeignKeyAction::SetNull,
+        }]
+    );
+    assert_eq!(
+        post.check_constraints,
+        vec![CheckConstraint {
+            name: "Post_title_check".to_string(),
+            expression: "(length(title) > 0)".to_string(),
+        }]
+    );
+}
+
+const MYSQLDUMP: &str = r#"
+-- MySQL dump 10.13  Distrib 8.0.19, for Linux (x86_64)
+
+/*!40101 SET @OLD_CHARACTER_SET_CLIENT=@@CHARACTER_SET_CLIENT */;
+/*!40101 SET NAMES utf8mb4 */;
+
+DROP TABLE IF EXISTS `Post`;
+/*!40101 SET @saved_cs_client     = @@character_set_client */;
+/*!50503 SET character_set_client = utf8mb4 */;
+CREATE TABLE `Post` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `title` varchar(191) COLLATE utf8mb4_unicode_ci NOT NULL COMMENT 'The title, it\'s shown in lists',
+  `status` enum('DRAFT','PUBLISHED') COLLATE utf8mb4_unicode_ci NOT NULL DEFAULT 'DRAFT',
+  `published` tinyint(1) NOT NULL DEFAULT '0',
+  `authorId` int DEFAULT NULL,
+  `updatedAt` datetime(3) NOT NULL DEFAULT CURRENT_TIMESTAMP(3) ON UPDATE CURRENT_TIMESTAMP(3),
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `Post.title_unique` (`title`),
+  KEY `authorId` (`authorId`),
+  CONSTRAINT `Post_ibfk_1` FOREIGN KEY (`authorId`) REFERENCES `User` (`id`) ON DELETE SET NULL ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='Blog posts';
+/*!40101 SET character_set_client = @saved_cs_client */;
+
+DROP TABLE IF EXISTS `User`;
+CREATE TABLE `User` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `name` varchar(191) COLLATE utf8mb4_unicode_ci DEFAULT NULL,
+  PRIMARY KEY (`id`)
+) ENGINE=InnoDB AUTO_INCREMENT=3 DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+
+DELIMITER ;;
+/*!50003 CREATE*/ /*!50017 DEFINER=`root`@`%`*/ /*!50003 TRIGGER `touch` BEFORE UPDATE ON `User` FOR EACH ROW BEGIN
+  SET NEW.name = TRIM(NEW.name);
+END */;;
+DELIMITER ;
+"#;
+
+#[test]
+fn mysqldump_output_must_be_described() {
+    let schema = ddl::describe(MYSQLDUMP, SqlFamily::Mysql, "blog").unwrap();
+
+    let table_names: Vec<&str> = schema.tables.iter().map(|table| table.name.as_str()).collect();
+    assert_eq!(table_names, vec!["Post", "User"]);
+
+    assert_eq!(
+        schema.enums,
+        vec![Enum {
+            name: "Post_status".to_string(),
+            values: vec!["DRAFT".to_string(), "PUBLISHED".to_string()],
+        }]
+    );
+
+    let post = schema.get_table("Post").unwrap();
+    assert_eq!(post.comment, Some("Blog posts".to_string()));
+
+    let id = post.column_bang("id");
+    assert_eq!(id.tpe.raw, "int");
+    assert_eq!(id.tpe.family, ColumnTypeFamily::Int);
+    assert!(id.auto_increment);
+
+    let title = post.column_bang("title");
+    assert_eq!(title.tpe.raw, "varchar(191)");
+    assert_eq!(title.tpe.arity, ColumnArity::Required);
+    assert_eq!(title.comment, Some("The title, it's shown in lists".to_string()));
+
+    let status = post.column_bang("status");
+    assert_eq!(status.tpe.family, ColumnTypeFamily::Enum("Post_status".to_string()));
+    assert_eq!(status.default, Some(DefaultValue::Value("DRAFT".to_string())));
+
+    let published = post.column_bang("published");
+    assert_eq!(published.tpe.family, ColumnTypeFamily::Boolean);
+    assert_eq!(published.default, Some(DefaultValue::Value("0".to_string())));
+
+    let author_id = post.column_bang("authorId");
+    assert_eq!(author_id.tpe.arity, ColumnArity::Nullable);
+    assert_eq!(author_id.default, None);
+
+    assert_eq!(
+        post.column_bang("updatedAt").default,
+        Some(DefaultValue::DbGenerated("CURRENT_TIMESTAMP(3)".to_string()))
+    );
+
+    assert_eq!(
+        post.primary_key,
+        Some(PrimaryKey {
+            columns: vec!["id".to_string()],
+            sequence: None,
+        })
+    );
+    // The index backing the foreign key is described as part of the foreign key.
+    assert_eq!(
+        post.indices,
+        vec![Index {
+            name: "Post.title_unique".to_string(),
+            columns: vec!["title".to_string()],
+            tpe: IndexType::Unique,
+        }]
+    );
+    assert_eq!(
+        post.foreign_keys,
+        vec![ForeignKey {
+            constraint_name: Some("Post_ibfk_1".to_string()),
+            columns: vec!["authorId".to_string()],
+            referenced_table: "User".to_string(),
+            referenced_columns: vec!["id".to_string()],
+            on_delete_action: ForeignKeyAction::SetNull,
+        }]
+    );
+}
+
+#[test]
+fn sqlite_schema_must_be_described() {
+    let ddl = r#"
+        CREATE TABLE "User" (
+            "id" INTEGER NOT NULL PRIMARY KEY,
+            "email" TEXT NOT NULL,
+            "role" TEXT NOT NULL DEFAULT 'USER' CHECK ("role" IN ('USER', 'ADMIN')),
+            "createdAt" DATE NOT NULL DEFAULT CURRENT_TIMESTAMP
+        );
+        CREATE TABLE "Post" (
+            "id" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+            "authorId" INTEGER REFERENCES "User" ON DELETE CASCADE,
+            CONSTRAINT "positive_id" CHECK ("id" > 0)
+        );
+        CREATE UNIQUE INDEX "main"."User.email" ON "User"("email");
+    "#;
+
+    let schema = ddl::describe(ddl, SqlFamily::Sqlite, "main").unwrap();
+
+    let user = schema.get_table("User").unwrap();
+    assert!(user.column_bang("id").auto_increment);
+    assert_eq!(user.column_bang("email").tpe.family, ColumnTypeFamily::String);
+    assert_eq!(
+        user.column_bang("role").tpe.family,
+        ColumnTypeFamily::Enum("User_role".to_string())
+    );
+    assert_eq!(
+        user.column_bang("role").default,
+        Some(DefaultValue::Value("USER".to_string()))
+    );
+    assert_eq!(
+        user.column_bang("createdAt").default,
+        Some(DefaultValue::DbGenerated("CURRENT_TIMESTAMP".to_string()))
+    );
+    assert_eq!(
+        user.indices,
+        vec![Index {
+            name: "User.email".to_string(),
+            columns: vec!["email".to_string()],
+            tpe: IndexType::Unique,
+        }]
+    );
+
+    let post = schema.get_table("Post").unwrap();
+    assert_eq!(
+        post.foreign_keys,
+        vec![ForeignKey {
+            constraint_name: None,
+            columns: vec!["authorId".to_string()],
+            referenced_table: "User".to_string(),
+            referenced_columns: vec!["id".to_string()],
+            on_delete_action: ForeignKeyAction::Cascade,
+        }]
+    );
+    assert_eq!(
+        post.check_constraints,
+        vec![CheckConstraint {
+            name: "positive_id".to_string(),
+            expression: "\"id\" > 0".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn unparseable_table_definitions_must_be_reported() {
+    let ddl = "CREATE TABLE ok (id integer); CREATE TABLE broken (id integer REFERENCES other ON DELETE EXPLODE);";
+
+    let error = ddl::describe(ddl, SqlFamily::Postgres, "public").unwrap_err();
+
+    assert_eq!(
+        error.statement,
+        "CREATE TABLE broken (id integer REFERENCES other ON DELETE EXPLODE)"
+    );
+    assert_eq!(error.message, "Expected a referential action, found `EXPLODE`.");
+}
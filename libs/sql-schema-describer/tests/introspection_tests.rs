@@ -30,8 +30,8 @@ fn int_type(db_type: DbType) -> String {
 
 fn varchar_type(db_type: DbType, length: u64) -> String {
     match db_type {
-        DbType::Postgres => "varchar".to_string(),
-        DbType::MySql => "varchar".to_string(),
+        DbType::Postgres => format!("varchar({})", length),
+        DbType::MySql => format!("varchar({})", length),
         DbType::Sqlite => format!("VARCHAR({})", length),
     }
 }
@@ -62,6 +62,7 @@ fn is_required_must_work() {
                         },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "column2".to_string(),
@@ -72,6 +73,7 @@ fn is_required_must_work() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ];
 
@@ -116,6 +118,7 @@ fn foreign_keys_must_work() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 ];
 
@@ -142,6 +145,8 @@ fn foreign_keys_must_work() {
                             referenced_table: "City".to_string(),
                             on_delete_action,
                         }],
+                        check_constraints: vec![],
+                        comment: None,
                     }
                 );
             }.boxed()
@@ -195,6 +200,7 @@ fn multi_column_foreign_keys_must_work() {
                         },
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                     Column {
                         name: "city_name".to_string(),
@@ -205,6 +211,7 @@ fn multi_column_foreign_keys_must_work() {
                         },
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                 ];
 
@@ -231,6 +238,8 @@ fn multi_column_foreign_keys_must_work() {
                             referenced_table: "City".to_string(),
                             on_delete_action,
                         },],
+                        check_constraints: vec![],
+                        comment: None,
                     }
                 );
             }
@@ -263,6 +272,7 @@ fn names_with_hyphens_must_work() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 }];
                 assert_eq!(user_table.columns, expected_columns);
             }
@@ -304,8 +314,8 @@ fn composite_primary_keys_must_work() {
                 let table = schema.get_table("User").expect("couldn't get User table");
                 let (exp_int, exp_varchar) = match db_type {
                     DbType::Sqlite => ("INTEGER", "VARCHAR(255)"),
-                    DbType::MySql => ("int", "varchar"),
-                    DbType::Postgres => ("int4", "varchar"),
+                    DbType::MySql => ("int", "varchar(255)"),
+                    DbType::Postgres => ("int4", "varchar(255)"),
                 };
                 let mut expected_columns = vec![
                     Column {
@@ -318,6 +328,7 @@ fn composite_primary_keys_must_work() {
                     default: None,
                     auto_increment: false,
 
+                    comment: None,
                 },
                 Column {
                     name: "name".to_string(),
@@ -328,6 +339,7 @@ fn composite_primary_keys_must_work() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ];
             expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -344,6 +356,8 @@ fn composite_primary_keys_must_work() {
                             sequence: None,
                         }),
                         foreign_keys: vec![],
+                        check_constraints: vec![],
+                        comment: None,
                     }
                 );
             }
@@ -384,6 +398,7 @@ fn indices_must_work() {
                     default: None,
                     auto_increment: false,
 
+                    comment: None,
                 },
                 Column {
                     name: "id".to_string(),
@@ -395,6 +410,7 @@ fn indices_must_work() {
 
                     default,
                     auto_increment: true,
+                    comment: None,
                 },
             ];
             let pk_sequence = match db_type {
@@ -420,6 +436,8 @@ fn indices_must_work() {
                             sequence: pk_sequence,
                         }),
                         foreign_keys: vec![],
+                        check_constraints: vec![],
+                        comment: None,
                     }
                 );
             }
@@ -461,6 +479,7 @@ fn column_uniqueness_must_be_detected() {
                     default: None,
                     auto_increment: false,
 
+                    comment: None,
                 },
                 Column {
                     name: "uniq2".to_string(),
@@ -472,6 +491,7 @@ fn column_uniqueness_must_be_detected() {
 
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ];
             let mut expected_indices = vec![Index {
@@ -507,6 +527,8 @@ fn column_uniqueness_must_be_detected() {
                         indices: expected_indices,
                         primary_key: None,
                         foreign_keys: vec![],
+                        check_constraints: vec![],
+                        comment: None,
                     }
                 );
                 assert!(
@@ -547,8 +569,9 @@ fn defaults_must_work() {
                         arity: ColumnArity::Nullable,
                     },
 
-                    default: Some(default),
+                    default: Some(DefaultValue::Value(default)),
                     auto_increment: false,
+                    comment: None,
                 }];
                 assert_eq!(
                     user_table,
@@ -558,6 +581,8 @@ fn defaults_must_work() {
                         indices: vec![],
                         primary_key: None,
                         foreign_keys: vec![],
+                        check_constraints: vec![],
+                        comment: None,
                     }
                 );
             }.boxed()
@@ -51,6 +51,7 @@ async fn all_mysql_column_types_must_work() {
         t.add_column("multipolygon_col", types::custom("multipolygon"));
         t.add_column("geometrycollection_col", types::custom("geometrycollection"));
         t.add_column("json_col", types::custom("json"));
+        t.add_column("uuid_col", types::custom("char(36)"));
     });
 
     let full_sql = migration.make::<barrel::backend::MySql>();
@@ -70,6 +71,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: true,
+            comment: None,
         },
         Column {
             name: "int_col".to_string(),
@@ -81,6 +83,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -92,6 +95,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "tinyint4_col".to_string(),
@@ -102,6 +106,7 @@ async fn all_mysql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "tinyint1_col".to_string(),
@@ -113,6 +118,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "mediumint_col".to_string(),
@@ -124,6 +130,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "bigint_col".to_string(),
@@ -135,28 +142,31 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "decimal_col".to_string(),
             tpe: ColumnType {
-                raw: "decimal".to_string(),
-                family: ColumnTypeFamily::Float,
+                raw: "decimal(10,0)".to_string(),
+                family: ColumnTypeFamily::Decimal,
                 arity: ColumnArity::Required,
             },
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "numeric_col".to_string(),
             tpe: ColumnType {
-                raw: "decimal".to_string(),
-                family: ColumnTypeFamily::Float,
+                raw: "decimal(10,0)".to_string(),
+                family: ColumnTypeFamily::Decimal,
                 arity: ColumnArity::Required,
             },
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "float_col".to_string(),
@@ -168,6 +178,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "double_col".to_string(),
@@ -179,6 +190,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "date_col".to_string(),
@@ -190,6 +202,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "time_col".to_string(),
@@ -201,6 +214,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -212,6 +226,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "timestamp_col".to_string(),
@@ -221,8 +236,9 @@ async fn all_mysql_column_types_must_work() {
                 arity: ColumnArity::Required,
             },
 
-            default: Some("CURRENT_TIMESTAMP".to_string()),
+            default: Some(DefaultValue::DbGenerated("CURRENT_TIMESTAMP".to_string())),
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "year_col".to_string(),
@@ -234,28 +250,31 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "char_col".to_string(),
             tpe: ColumnType {
-                raw: "char".to_string(),
+                raw: "char(1)".to_string(),
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "varchar_col".to_string(),
             tpe: ColumnType {
-                raw: "varchar".to_string(),
+                raw: "varchar(255)".to_string(),
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -267,6 +286,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "tinytext_col".to_string(),
@@ -278,6 +298,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "mediumtext_col".to_string(),
@@ -289,6 +310,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "longtext_col".to_string(),
@@ -300,17 +322,19 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "enum_col".to_string(),
             tpe: ColumnType {
                 raw: "enum".to_string(),
-                family: ColumnTypeFamily::String,
+                family: ColumnTypeFamily::Enum("User_enum_col".to_string()),
                 arity: ColumnArity::Required,
             },
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "set_col".to_string(),
@@ -322,28 +346,31 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "binary_col".to_string(),
             tpe: ColumnType {
-                raw: "binary".to_string(),
+                raw: "binary(1)".to_string(),
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
             },
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "varbinary_col".to_string(),
             tpe: ColumnType {
-                raw: "varbinary".to_string(),
+                raw: "varbinary(255)".to_string(),
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
             },
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "blob_col".to_string(),
@@ -355,6 +382,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "tinyblob_col".to_string(),
@@ -366,6 +394,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "mediumblob_col".to_string(),
@@ -377,6 +406,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "longblob_col".to_string(),
@@ -388,6 +418,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "geometry_col".to_string(),
@@ -399,6 +430,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "point_col".to_string(),
@@ -410,6 +442,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "linestring_col".to_string(),
@@ -421,6 +454,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "polygon_col".to_string(),
@@ -432,6 +466,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "multipoint_col".to_string(),
@@ -443,6 +478,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "multilinestring_col".to_string(),
@@ -454,6 +490,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "multipolygon_col".to_string(),
@@ -465,6 +502,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "geometrycollection_col".to_string(),
@@ -476,6 +514,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "json_col".to_string(),
@@ -487,6 +526,19 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
+        },
+        Column {
+            name: "uuid_col".to_string(),
+            tpe: ColumnType {
+                raw: "char(36)".to_string(),
+                family: ColumnTypeFamily::String,
+                arity: ColumnArity::Required,
+            },
+
+            default: None,
+            auto_increment: false,
+            comment: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -502,6 +554,8 @@ async fn all_mysql_column_types_must_work() {
                 sequence: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -543,6 +597,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -553,6 +608,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -563,6 +619,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -573,6 +630,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "id".to_string(),
@@ -584,6 +642,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: true,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -621,6 +680,8 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -759,3 +820,57 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
         }]
     );
 }
+
+#[tokio::test]
+async fn mysql_enum_columns_must_be_described_as_enums() {
+    let db_name = "mysql_enum_columns_must_be_described_as_enums";
+
+    let sql = format!(
+        "CREATE TABLE `{0}`.User (
+            id INTEGER NOT NULL AUTO_INCREMENT PRIMARY KEY,
+            mood ENUM('sad', 'ok', 'happy') NOT NULL DEFAULT 'ok'
+        )",
+        db_name
+    );
+    let inspector = get_mysql_describer_for_schema(&sql, db_name).await;
+
+    let schema = inspector.describe(db_name).await.expect("describing");
+    let column = schema
+        .get_table("User")
+        .and_then(|table| table.column("mood"))
+        .expect("get mood column");
+
+    assert_eq!(column.tpe.family, ColumnTypeFamily::Enum("User_mood".to_owned()));
+    assert_eq!(column.default.as_ref().and_then(DefaultValue::as_value), Some("ok"));
+    assert_eq!(
+        schema.get_enum("User_mood"),
+        Some(&Enum {
+            name: "User_mood".to_owned(),
+            values: vec!["sad".to_owned(), "ok".to_owned(), "happy".to_owned()],
+        })
+    );
+}
+
+#[tokio::test]
+async fn mysql_comments_must_be_described() {
+    let db_name = "mysql_comments_must_be_described";
+
+    let sql = format!(
+        "CREATE TABLE `{0}`.User (
+            id INTEGER NOT NULL AUTO_INCREMENT PRIMARY KEY,
+            name VARCHAR(255) NOT NULL COMMENT 'The user''s full name.'
+        ) COMMENT = 'The users of the app.'",
+        db_name
+    );
+    let inspector = get_mysql_describer_for_schema(&sql, db_name).await;
+
+    let schema = inspector.describe(db_name).await.expect("describing");
+    let table = schema.get_table("User").expect("get User table");
+
+    assert_eq!(table.comment.as_ref().map(String::as_str), Some("The users of the app."));
+    assert_eq!(
+        table.column_bang("name").comment.as_ref().map(String::as_str),
+        Some("The user's full name.")
+    );
+    assert_eq!(table.column_bang("id").comment, None);
+}
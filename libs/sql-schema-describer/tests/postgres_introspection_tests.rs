@@ -1,7 +1,6 @@
 use barrel::{types, Migration};
 use pretty_assertions::assert_eq;
 use sql_schema_describer::*;
-use std::collections::HashSet;
 
 mod common;
 mod postgres;
@@ -77,6 +76,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_bool_col".into(),
@@ -87,6 +87,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_date_col".into(),
@@ -97,6 +98,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_double_col".into(),
@@ -107,6 +109,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_float_col".into(),
@@ -117,6 +120,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_int_col".into(),
@@ -127,6 +131,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_text_col".into(),
@@ -137,6 +142,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "array_varchar_col".into(),
@@ -147,6 +153,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "binary_col".into(),
@@ -157,6 +164,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "boolean_col".into(),
@@ -167,6 +175,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "date_time_col".into(),
@@ -178,6 +187,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "double_col".into(),
@@ -189,6 +199,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "float_col".into(),
@@ -200,6 +211,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "int_col".into(),
@@ -211,6 +223,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "primary_col".into(),
@@ -220,8 +233,12 @@ async fn all_postgres_column_types_must_work() {
                 arity: ColumnArity::Required,
             },
 
-            default: Some(format!("nextval(\"{}\".\"User_primary_col_seq\"::regclass)", SCHEMA)),
+            default: Some(DefaultValue::Value(format!(
+                "nextval(\"{}\".\"User_primary_col_seq\"::regclass)",
+                SCHEMA
+            ))),
             auto_increment: true,
+            comment: None,
         },
         Column {
             name: "string1_col".into(),
@@ -233,17 +250,19 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "string2_col".into(),
             tpe: ColumnType {
-                raw: "varchar".into(),
+                raw: "varchar(1)".into(),
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "bigint_col".into(),
@@ -255,6 +274,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "bigserial_col".into(),
@@ -264,8 +284,12 @@ async fn all_postgres_column_types_must_work() {
                 arity: ColumnArity::Required,
             },
 
-            default: Some(format!("nextval(\"{}\".\"User_bigserial_col_seq\"::regclass)", SCHEMA)),
+            default: Some(DefaultValue::Value(format!(
+                "nextval(\"{}\".\"User_bigserial_col_seq\"::regclass)",
+                SCHEMA
+            ))),
             auto_increment: true,
+            comment: None,
         },
         Column {
             name: "bit_col".into(),
@@ -277,6 +301,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "bit_varying_col".into(),
@@ -288,6 +313,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "box_col".into(),
@@ -299,17 +325,19 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "char_col".into(),
             tpe: ColumnType {
-                raw: "bpchar".into(),
+                raw: "bpchar(1)".into(),
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "circle_col".into(),
@@ -321,6 +349,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "interval_col".into(),
@@ -332,6 +361,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "line_col".into(),
@@ -343,6 +373,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "lseg_col".into(),
@@ -354,17 +385,19 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "numeric_col".into(),
             tpe: ColumnType {
                 raw: "numeric".into(),
-                family: ColumnTypeFamily::Float,
+                family: ColumnTypeFamily::Decimal,
                 arity: ColumnArity::Required,
             },
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "path_col".into(),
@@ -376,6 +409,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "pg_lsn_col".into(),
@@ -387,6 +421,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "polygon_col".into(),
@@ -398,6 +433,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "smallint_col".into(),
@@ -409,6 +445,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "smallserial_col".into(),
@@ -418,11 +455,12 @@ async fn all_postgres_column_types_must_work() {
                 arity: ColumnArity::Required,
             },
 
-            default: Some(format!(
+            default: Some(DefaultValue::Value(format!(
                 "nextval(\"{}\".\"User_smallserial_col_seq\"::regclass)",
                 SCHEMA
-            )),
+            ))),
             auto_increment: true,
+            comment: None,
         },
         Column {
             name: "serial_col".into(),
@@ -432,8 +470,12 @@ async fn all_postgres_column_types_must_work() {
                 arity: ColumnArity::Required,
             },
 
-            default: Some(format!("nextval(\"{}\".\"User_serial_col_seq\"::regclass)", SCHEMA)),
+            default: Some(DefaultValue::Value(format!(
+                "nextval(\"{}\".\"User_serial_col_seq\"::regclass)",
+                SCHEMA
+            ))),
             auto_increment: true,
+            comment: None,
         },
         Column {
             name: "time_col".into(),
@@ -445,6 +487,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "time_with_zone_col".into(),
@@ -456,6 +499,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "timestamp_col".into(),
@@ -467,6 +511,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "timestamp_with_zone_col".into(),
@@ -478,6 +523,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "tsquery_col".into(),
@@ -489,6 +535,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "tsvector_col".into(),
@@ -500,6 +547,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "txid_col".into(),
@@ -511,6 +559,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "json_col".into(),
@@ -522,6 +571,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "jsonb_col".into(),
@@ -533,6 +583,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "uuid_col".into(),
@@ -544,6 +595,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            comment: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -567,6 +619,8 @@ async fn all_postgres_column_types_must_work() {
                 },),
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -607,6 +661,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_cascade".into(),
@@ -617,6 +672,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_restrict".into(),
@@ -627,6 +683,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_default".into(),
@@ -637,6 +694,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_null".into(),
@@ -647,6 +705,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "id".into(),
@@ -658,6 +717,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -702,6 +762,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -718,16 +780,40 @@ async fn postgres_enums_must_work() {
     let schema = inspector.describe(SCHEMA).await.expect("describing");
     let got_enum = schema.get_enum("mood").expect("get enum");
 
-    let values: HashSet<String> = ["happy".into(), "ok".into(), "sad".into()].iter().cloned().collect();
     assert_eq!(
         got_enum,
         &Enum {
             name: "mood".into(),
-            values,
+            values: vec!["sad".into(), "ok".into(), "happy".into()],
         }
     );
 }
 
+#[tokio::test]
+async fn postgres_enum_columns_must_work() {
+    let sql = format!(
+        "CREATE TYPE \"{0}\".\"Mood\" AS ENUM ('sad', 'ok', 'happy');
+         CREATE TABLE \"{0}\".\"User\" (
+            id SERIAL PRIMARY KEY,
+            mood \"{0}\".\"Mood\" NOT NULL DEFAULT 'ok',
+            moods \"{0}\".\"Mood\"[]
+         );",
+        SCHEMA
+    );
+    let inspector = get_postgres_describer(&sql, "postgres_enum_columns_must_work").await;
+
+    let schema = inspector.describe(SCHEMA).await.expect("describing");
+    let table = schema.get_table("User").expect("get User table");
+    let mood = table.column("mood").expect("get mood column");
+    let moods = table.column("moods").expect("get moods column");
+
+    assert_eq!(mood.tpe.family, ColumnTypeFamily::Enum("Mood".to_owned()));
+    assert_eq!(mood.tpe.arity, ColumnArity::Required);
+    assert_eq!(mood.default.as_ref().and_then(DefaultValue::as_value), Some("ok"));
+    assert_eq!(moods.tpe.family, ColumnTypeFamily::Enum("Mood".to_owned()));
+    assert_eq!(moods.tpe.arity, ColumnArity::List);
+}
+
 #[tokio::test]
 async fn postgres_sequences_must_work() {
 
@@ -745,3 +831,74 @@ async fn postgres_sequences_must_work() {
         },
     );
 }
+
+#[tokio::test]
+async fn postgres_check_constraints_must_work() {
+    let sql = format!(
+        "CREATE TABLE \"{0}\".\"Product\" (
+            id SERIAL PRIMARY KEY,
+            price INTEGER NOT NULL,
+            CONSTRAINT \"positive_price\" CHECK (price >= 0)
+         );",
+        SCHEMA
+    );
+    let inspector = get_postgres_describer(&sql, "postgres_check_constraints_must_work").await;
+
+    let schema = inspector.describe(SCHEMA).await.expect("describing");
+    let table = schema.get_table("Product").expect("get Product table");
+
+    assert_eq!(
+        table.check_constraints,
+        vec![CheckConstraint {
+            name: "positive_price".into(),
+            expression: "(price >= 0)".into(),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn postgres_views_must_be_described() {
+    let sql = format!(
+        "CREATE TABLE \"{0}\".\"User\" (
+            id SERIAL PRIMARY KEY,
+            name TEXT NOT NULL
+         );
+         CREATE VIEW \"{0}\".\"UserName\" AS SELECT id, name FROM \"{0}\".\"User\";",
+        SCHEMA
+    );
+    let inspector = get_postgres_describer(&sql, "postgres_views_must_be_described").await;
+
+    let schema = inspector.describe(SCHEMA).await.expect("describing");
+
+    assert!(schema.get_table("UserName").is_none());
+
+    let view = schema.get_view("UserName").expect("get UserName view");
+
+    assert_eq!(view.column("id").unwrap().tpe.family, ColumnTypeFamily::Int);
+    assert_eq!(view.column("name").unwrap().tpe.family, ColumnTypeFamily::String);
+    assert!(view.definition.is_some());
+}
+
+#[tokio::test]
+async fn postgres_comments_must_be_described() {
+    let sql = format!(
+        "CREATE TABLE \"{0}\".\"User\" (
+            id SERIAL PRIMARY KEY,
+            name TEXT NOT NULL
+         );
+         COMMENT ON TABLE \"{0}\".\"User\" IS 'The users of the app.';
+         COMMENT ON COLUMN \"{0}\".\"User\".\"name\" IS 'The user''s full name.';",
+        SCHEMA
+    );
+    let inspector = get_postgres_describer(&sql, "postgres_comments_must_be_described").await;
+
+    let schema = inspector.describe(SCHEMA).await.expect("describing");
+    let table = schema.get_table("User").expect("get User table");
+
+    assert_eq!(table.comment.as_ref().map(String::as_str), Some("The users of the app."));
+    assert_eq!(
+        table.column_bang("name").comment.as_ref().map(String::as_str),
+        Some("The user's full name.")
+    );
+    assert_eq!(table.column_bang("id").comment, None);
+}
@@ -6,7 +6,6 @@ use log::{debug, LevelFilter};
 use pretty_assertions::assert_eq;
 use quaint::connector::{Queryable, Sqlite as SqliteDatabaseClient};
 use sql_schema_describer::*;
-use std::collections::HashSet;
 use std::fs::File;
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -17,9 +16,6 @@ const SCHEMA: &str = "DatabaseInspectorTest";
 
 #[test]
 fn database_schema_is_serializable() {
-    let mut enum_values = HashSet::new();
-    enum_values.insert("option1".to_string());
-    enum_values.insert("option2".to_string());
     let schema = SqlSchema {
         tables: vec![
             Table {
@@ -34,6 +30,7 @@ fn database_schema_is_serializable() {
                         },
                         default: None,
                         auto_increment: true,
+                        comment: None,
                     },
                     Column {
                         name: "column2".to_string(),
@@ -42,8 +39,9 @@ fn database_schema_is_serializable() {
                             family: ColumnTypeFamily::String,
                             arity: ColumnArity::Nullable,
                         },
-                        default: Some("default value".to_string()),
+                        default: Some(DefaultValue::Value("default value".to_string())),
                         auto_increment: false,
+                        comment: None,
                     },
                     Column {
                         name: "column3".to_string(),
@@ -54,6 +52,7 @@ fn database_schema_is_serializable() {
                         },
                         default: None,
                         auto_increment: false,
+                        comment: None,
                     },
                 ],
                 indices: vec![Index {
@@ -72,6 +71,8 @@ fn database_schema_is_serializable() {
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::NoAction,
                 }],
+                check_constraints: vec![],
+                comment: None,
             },
             Table {
                 name: "table2".to_string(),
@@ -84,6 +85,7 @@ fn database_schema_is_serializable() {
                     },
                     default: None,
                     auto_increment: true,
+                    comment: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -91,17 +93,20 @@ fn database_schema_is_serializable() {
                     sequence: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
             },
         ],
         enums: vec![Enum {
             name: "enum1".to_string(),
-            values: enum_values,
+            values: vec!["option1".to_string(), "option2".to_string()],
         }],
         sequences: vec![Sequence {
             name: "sequence1".to_string(),
             initial_value: 1,
             allocation_size: 32,
         }],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -129,13 +134,17 @@ fn database_schema_without_primary_key_is_serializable() {
                 },
                 default: None,
                 auto_increment: false,
+                comment: None,
             }],
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-without-primary-key.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -155,6 +164,7 @@ fn database_schema_is_serializable_for_every_column_type_family() {
     let mut columns: Vec<Column> = vec![
         ColumnTypeFamily::Int,
         ColumnTypeFamily::Float,
+        ColumnTypeFamily::Decimal,
         ColumnTypeFamily::Boolean,
         ColumnTypeFamily::String,
         ColumnTypeFamily::DateTime,
@@ -177,6 +187,7 @@ fn database_schema_is_serializable_for_every_column_type_family() {
         },
         default: None,
         auto_increment: false,
+        comment: None,
     })
     .collect();
     let schema = SqlSchema {
@@ -186,9 +197,12 @@ fn database_schema_is_serializable_for_every_column_type_family() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-type-families.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -217,6 +231,7 @@ fn database_schema_is_serializable_for_every_column_arity() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         })
         .collect();
     let schema = SqlSchema {
@@ -226,9 +241,12 @@ fn database_schema_is_serializable_for_every_column_arity() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-arities.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -258,6 +276,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     },
                     auto_increment: false,
                     default: None,
+                    comment: None,
                 },
                 Column {
                     name: "column2".to_string(),
@@ -268,6 +287,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     },
                     auto_increment: false,
                     default: None,
+                    comment: None,
                 },
                 Column {
                     name: "column3".to_string(),
@@ -278,6 +298,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     },
                     auto_increment: false,
                     default: None,
+                    comment: None,
                 },
                 Column {
                     name: "column4".to_string(),
@@ -288,6 +309,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     },
                     auto_increment: false,
                     default: None,
+                    comment: None,
                 },
                 Column {
                     name: "column5".to_string(),
@@ -298,6 +320,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     },
                     auto_increment: false,
                     default: None,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -339,9 +362,12 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
             ],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-foreign-key-actions.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -32,6 +32,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -42,6 +43,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "real_col".to_string(),
@@ -52,6 +54,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            comment: None,
         },
         Column {
             name: "primary_col".to_string(),
@@ -62,6 +65,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: true,
+            comment: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -77,6 +81,8 @@ async fn sqlite_column_types_must_work() {
                 sequence: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -115,6 +121,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -125,6 +132,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -135,6 +143,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_default".to_string(),
@@ -145,6 +154,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -155,6 +165,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    comment: None,
                 },
                 Column {
                     name: "id".to_string(),
@@ -165,6 +176,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: true,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -209,6 +221,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -242,3 +256,78 @@ async fn sqlite_text_primary_keys_must_be_inferred_on_table_and_not_as_separate_
         }
     );
 }
+
+#[tokio::test]
+async fn sqlite_enum_check_constraints_must_be_described_as_enums() {
+    let sql = format!(
+        r#"CREATE TABLE "{0}"."User" (
+            "id" INTEGER PRIMARY KEY,
+            "mood" TEXT NOT NULL CHECK ("mood" IN ('sad', 'ok', 'it''s complicated')),
+            "name" TEXT NOT NULL
+        )"#,
+        SCHEMA
+    );
+    let inspector = get_sqlite_describer(&sql, "sqlite_enum_check_constraints_must_be_described_as_enums").await;
+    let schema = inspector.describe(SCHEMA).await.expect("describing");
+    let table = schema.get_table("User").expect("couldn't get User table");
+
+    assert_eq!(
+        table.column("mood").unwrap().tpe.family,
+        ColumnTypeFamily::Enum("User_mood".to_owned())
+    );
+    assert_eq!(table.column("name").unwrap().tpe.family, ColumnTypeFamily::String);
+    assert_eq!(
+        schema.enums,
+        vec![Enum {
+            name: "User_mood".to_owned(),
+            values: vec!["sad".to_owned(), "ok".to_owned(), "it's complicated".to_owned()],
+        }]
+    );
+}
+
+#[tokio::test]
+async fn sqlite_named_check_constraints_must_be_described() {
+    let sql = format!(
+        r#"CREATE TABLE "{0}"."Product" (
+            "id" INTEGER PRIMARY KEY,
+            "mood" TEXT NOT NULL CHECK ("mood" IN ('sad', 'ok')),
+            "price" INTEGER NOT NULL,
+            CONSTRAINT "positive_price" CHECK ("price" >= 0 AND ("price" < 1000 OR "mood" = ')'))
+        )"#,
+        SCHEMA
+    );
+    let inspector = get_sqlite_describer(&sql, "sqlite_named_check_constraints_must_be_described").await;
+    let schema = inspector.describe(SCHEMA).await.expect("describing");
+    let table = schema.get_table("Product").expect("couldn't get Product table");
+
+    assert_eq!(
+        table.check_constraints,
+        vec![CheckConstraint {
+            name: "positive_price".to_owned(),
+            expression: r#""price" >= 0 AND ("price" < 1000 OR "mood" = ')')"#.to_owned(),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn sqlite_views_must_be_described() {
+    let sql = format!(
+        r#"CREATE TABLE "{0}"."User" (
+            "id" INTEGER PRIMARY KEY,
+            "name" TEXT NOT NULL
+        );
+        CREATE VIEW "{0}"."UserName" AS SELECT "id", "name" FROM "User";"#,
+        SCHEMA
+    );
+    let inspector = get_sqlite_describer(&sql, "sqlite_views_must_be_described").await;
+    let schema = inspector.describe(SCHEMA).await.expect("describing");
+
+    assert!(schema.get_table("UserName").is_none());
+
+    let view = schema.get_view("UserName").expect("couldn't get UserName view");
+    let column_names: Vec<&str> = view.columns.iter().map(|column| column.name.as_str()).collect();
+
+    assert_eq!(column_names, vec!["id", "name"]);
+    assert_eq!(view.column("name").unwrap().tpe.family, ColumnTypeFamily::String);
+    assert!(view.definition.as_ref().unwrap().contains("SELECT"));
+}
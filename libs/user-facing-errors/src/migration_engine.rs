@@ -27,6 +27,15 @@ struct MigrationRollback {
     pub database_error: String,
 }
 
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P3003",
+    message = "Timed out after ${timeout_secs} seconds trying to acquire the migration lock. Another migration engine is probably applying, rolling back or resetting migrations on the same database."
+)]
+pub struct MigrationLockTimeout {
+    pub timeout_secs: u64,
+}
+
 // Tests
 
 #[cfg(test)]
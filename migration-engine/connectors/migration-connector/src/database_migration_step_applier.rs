@@ -12,6 +12,11 @@ pub trait DatabaseMigrationStepApplier<T>: Send + Sync + 'static {
     /// Returns true to signal to the caller that there are more steps to unapply.
     async fn unapply_step(&self, database_migration: &T, step: usize) -> ConnectorResult<bool>;
 
+    /// Checks against the current state of the database whether the step has already been applied. This
+    /// is used to find where to resume an interrupted migration. Returns `None` if there is no such step,
+    /// or if it can't be determined whether it was applied.
+    async fn step_is_applied(&self, database_migration: &T, step: usize) -> ConnectorResult<Option<bool>>;
+
     /// Render steps for the CLI. Each step will contain the raw field.
     fn render_steps_pretty(&self, database_migration: &T) -> ConnectorResult<Vec<serde_json::Value>>;
 }
@@ -19,6 +19,8 @@ where
 pub struct DestructiveChangeDiagnostics {
     pub errors: Vec<MigrationError>,
     pub warnings: Vec<MigrationWarning>,
+    /// The number of rows affected by the destructive steps of the migration, as estimated by the checker.
+    pub affected_rows: Vec<AffectedRowsEstimate>,
 }
 
 impl DestructiveChangeDiagnostics {
@@ -26,6 +28,7 @@ impl DestructiveChangeDiagnostics {
         DestructiveChangeDiagnostics {
             errors: Vec::new(),
             warnings: Vec::new(),
+            affected_rows: Vec::new(),
         }
     }
 
@@ -56,6 +59,16 @@ pub struct MigrationError {
     pub field: Option<String>,
 }
 
+/// The estimated number of rows affected by dropping or altering a table or column. For columns, only
+/// the rows with a non-null value in the column are counted.
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AffectedRowsEstimate {
+    pub table: String,
+    pub column: Option<String>,
+    pub rows: i64,
+}
+
 /// An implementor of [DestructiveChangesChecker](trait.DestructiveChangesChecker.html) that performs no check.
 pub struct EmptyDestructiveChangesChecker<T> {
     database_migration: PhantomData<T>,
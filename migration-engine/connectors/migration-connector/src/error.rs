@@ -76,4 +76,7 @@ pub enum ErrorKind {
 
     #[error("Unique constraint violation.")]
     UniqueConstraintViolation { field_name: String },
+
+    #[error("Timed out after {} seconds trying to acquire the migration lock.", timeout_secs)]
+    MigrationLockTimeout { timeout_secs: u64 },
 }
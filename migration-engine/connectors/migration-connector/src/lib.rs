@@ -43,6 +43,17 @@ pub trait MigrationConnector: Send + Sync + 'static {
     /// Drop all database state.
     async fn reset(&self) -> ConnectorResult<()>;
 
+    /// Acquire the lock guarding the migrations on the database, waiting for it to be released by other
+    /// migration engines if necessary. The default implementation does not lock anything.
+    async fn acquire_lock(&self) -> ConnectorResult<()> {
+        Ok(())
+    }
+
+    /// Release the lock acquired with `acquire_lock`.
+    async fn release_lock(&self) -> ConnectorResult<()> {
+        Ok(())
+    }
+
     /// See [MigrationPersistence](trait.MigrationPersistence.html).
     fn migration_persistence(&self) -> Arc<dyn MigrationPersistence>;
 
@@ -71,6 +82,9 @@ pub trait MigrationConnector: Send + Sync + 'static {
 
 pub trait DatabaseMigrationMarker: Debug + Send + Sync {
     fn serialize(&self) -> serde_json::Value;
+
+    /// The number of steps the migration applier goes through to apply the migration.
+    fn step_count(&self) -> usize;
 }
 
 /// Shorthand for a [Result](https://doc.rust-lang.org/std/result/enum.Result.html) where the error
@@ -10,6 +10,14 @@ where
     async fn apply(&self, migration: &Migration, database_migration: &T) -> ConnectorResult<()>;
 
     async fn unapply(&self, migration: &Migration, database_migration: &T) -> ConnectorResult<()>;
+
+    /// Resume an interrupted migration, starting with the step at index `from_step`.
+    async fn resume(&self, migration: &Migration, database_migration: &T, from_step: usize) -> ConnectorResult<()>;
+
+    /// Roll back an interrupted migration. Since the migration was only partially applied, its own
+    /// rollback steps can't be used: `rollback_migration` must take the database from its current state
+    /// back to the state before the interrupted migration.
+    async fn roll_back_interrupted(&self, migration: &Migration, rollback_migration: &T) -> ConnectorResult<()>;
 }
 
 pub struct MigrationApplierImpl<T>
@@ -31,7 +39,7 @@ where
         migration_updates.status = MigrationStatus::MigrationInProgress;
         self.migration_persistence.update(&migration_updates).await?;
 
-        let apply_result = self.go_forward(&mut migration_updates, database_migration).await;
+        let apply_result = self.go_forward(&mut migration_updates, database_migration, 0).await;
 
         match apply_result {
             Ok(()) => {
@@ -70,6 +78,66 @@ where
             }
         }
     }
+
+    async fn resume(&self, migration: &Migration, database_migration: &T, from_step: usize) -> ConnectorResult<()> {
+        let mut migration_updates = migration.update_params();
+        migration_updates.status = MigrationStatus::MigrationInProgress;
+        migration_updates.applied = from_step;
+        migration_updates.errors = Vec::new();
+        self.migration_persistence.update(&migration_updates).await?;
+
+        let apply_result = self
+            .go_forward(&mut migration_updates, database_migration, from_step)
+            .await;
+
+        match apply_result {
+            Ok(()) => {
+                migration_updates.mark_as_finished();
+                self.migration_persistence.update(&migration_updates).await?;
+                Ok(())
+            }
+            Err(err) => {
+                migration_updates.status = MigrationStatus::MigrationFailure;
+                migration_updates.errors = vec![format!("{:?}", err)];
+                self.migration_persistence.update(&migration_updates).await?;
+                Err(err)
+            }
+        }
+    }
+
+    async fn roll_back_interrupted(&self, migration: &Migration, rollback_migration: &T) -> ConnectorResult<()> {
+        let mut migration_updates = migration.update_params();
+        migration_updates.status = MigrationStatus::RollingBack;
+        migration_updates.rolled_back = 0;
+        migration_updates.errors = Vec::new();
+        self.migration_persistence.update(&migration_updates).await?;
+
+        let mut step = 0;
+        let rollback_result: ConnectorResult<()> = async {
+            while self.step_applier.apply_step(&rollback_migration, step).await? {
+                step += 1;
+                migration_updates.rolled_back += 1;
+                self.migration_persistence.update(&migration_updates).await?;
+            }
+
+            Ok(())
+        }
+        .await;
+
+        match rollback_result {
+            Ok(()) => {
+                migration_updates.status = MigrationStatus::RollbackSuccess;
+                self.migration_persistence.update(&migration_updates).await?;
+                Ok(())
+            }
+            Err(err) => {
+                migration_updates.status = MigrationStatus::RollbackFailure;
+                migration_updates.errors = vec![format!("{:?}", err)];
+                self.migration_persistence.update(&migration_updates).await?;
+                Err(err)
+            }
+        }
+    }
 }
 
 impl<T> MigrationApplierImpl<T>
@@ -80,8 +148,9 @@ where
         &self,
         migration_updates: &mut MigrationUpdateParams,
         database_migration: &T,
+        from_step: usize,
     ) -> ConnectorResult<()> {
-        let mut step = 0;
+        let mut step = from_step;
         while self.step_applier.apply_step(&database_migration, step).await? {
             step += 1;
             migration_updates.applied += 1;
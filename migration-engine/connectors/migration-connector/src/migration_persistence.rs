@@ -73,6 +73,16 @@ pub trait MigrationPersistence: Send + Sync + 'static {
     /// into the database.
     async fn update(&self, params: &MigrationUpdateParams) -> Result<(), ConnectorError>;
 
+    /// Replace the persisted migrations with a revision between `first_revision` and `last_revision`
+    /// (inclusive) by the `squashed` migration. The squashed migration is persisted with its revision and
+    /// status as provided, so it can take the place of the migrations it replaces in the history.
+    async fn squash(
+        &self,
+        first_revision: usize,
+        last_revision: usize,
+        squashed: Migration,
+    ) -> Result<Migration, ConnectorError>;
+
     /// Returns whether the migration with the provided migration id has already been successfully applied.
     ///
     /// The default impl will load all migrations and scan for the provided migration id. Implementors are encouraged to implement this more efficiently.
@@ -281,6 +291,15 @@ impl MigrationPersistence for EmptyMigrationPersistence {
         unimplemented!("Not allowed on a EmptyMigrationPersistence")
     }
 
+    async fn squash(
+        &self,
+        _first_revision: usize,
+        _last_revision: usize,
+        _squashed: Migration,
+    ) -> Result<Migration, ConnectorError> {
+        unimplemented!("Not allowed on a EmptyMigrationPersistence")
+    }
+
     async fn current_datamodel_ast(&self) -> Result<datamodel::ast::SchemaAst, ConnectorError> {
         Ok(datamodel::ast::SchemaAst { tops: Vec::new() })
     }
@@ -1,7 +1,8 @@
 use migration_connector::{ConnectorError, ErrorKind};
 use quaint::error::Error as QuaintError;
+use std::time::Duration;
 use thiserror::Error;
-use user_facing_errors::quaint::render_quaint_error;
+use user_facing_errors::{migration_engine::MigrationLockTimeout, quaint::render_quaint_error, KnownError};
 
 pub type SqlResult<T> = Result<T, SqlError>;
 
@@ -65,6 +66,9 @@ pub enum SqlError {
         #[source]
         cause: QuaintError,
     },
+
+    #[error("Timed out trying to acquire the migration lock")]
+    MigrationLockTimeout { timeout: Duration },
 }
 
 impl SqlError {
@@ -125,6 +129,14 @@ impl SqlError {
                     },
                 }
             }
+            SqlError::MigrationLockTimeout { timeout } => {
+                let timeout_secs = timeout.as_secs();
+
+                ConnectorError {
+                    user_facing_error: KnownError::new(MigrationLockTimeout { timeout_secs }).ok(),
+                    kind: ErrorKind::MigrationLockTimeout { timeout_secs },
+                }
+            }
             error => ConnectorError::from_kind(ErrorKind::QueryError(error.into())),
         }
     }
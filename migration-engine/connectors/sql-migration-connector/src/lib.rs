@@ -6,6 +6,7 @@ mod sql_database_migration_inferrer;
 mod sql_database_step_applier;
 mod sql_destructive_changes_checker;
 mod sql_migration;
+mod sql_migration_lock;
 mod sql_migration_persistence;
 mod sql_renderer;
 mod sql_schema_calculator;
@@ -28,6 +29,10 @@ use std::{fs, path::PathBuf, sync::Arc, time::Duration};
 
 const CONNECTION_TIMEOUT: Duration = Duration::from_secs(10);
 
+/// How long to wait for the migration lock held by another migration engine, unless overridden with the
+/// `MIGRATION_LOCK_TIMEOUT` environment variable (in seconds).
+const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(60);
+
 pub struct SqlMigrationConnector {
     pub connection_info: ConnectionInfo,
     pub schema_name: String,
@@ -37,6 +42,7 @@ pub struct SqlMigrationConnector {
     pub database_migration_step_applier: Arc<dyn DatabaseMigrationStepApplier<SqlMigration>>,
     pub destructive_changes_checker: Arc<dyn DestructiveChangesChecker<SqlMigration>>,
     pub database_describer: Arc<dyn SqlSchemaDescriberBackend + Send + Sync + 'static>,
+    pub lock_timeout: Duration,
 }
 
 impl SqlMigrationConnector {
@@ -100,6 +106,7 @@ impl SqlMigrationConnector {
             connection_info: connection_info.clone(),
             schema_name: schema_name.clone(),
             conn: Arc::clone(&conn),
+            describer: Arc::clone(&describer),
         });
 
         let destructive_changes_checker = Arc::new(SqlDestructiveChangesChecker {
@@ -118,6 +125,7 @@ impl SqlMigrationConnector {
             database_migration_step_applier,
             destructive_changes_checker,
             database_describer: Arc::clone(&describer),
+            lock_timeout: lock_timeout_from_env(),
         })
     }
 
@@ -199,6 +207,14 @@ impl MigrationConnector for SqlMigrationConnector {
         Ok(())
     }
 
+    async fn acquire_lock(&self) -> ConnectorResult<()> {
+        catch(&self.connection_info, self.acquire_lock_impl()).await
+    }
+
+    async fn release_lock(&self) -> ConnectorResult<()> {
+        catch(&self.connection_info, self.release_lock_impl()).await
+    }
+
     fn migration_persistence(&self) -> Arc<dyn MigrationPersistence> {
         Arc::clone(&self.migration_persistence)
     }
@@ -230,6 +246,14 @@ pub(crate) async fn catch<O>(
     }
 }
 
+fn lock_timeout_from_env() -> Duration {
+    std::env::var("MIGRATION_LOCK_TIMEOUT")
+        .ok()
+        .and_then(|timeout| timeout.parse().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_LOCK_TIMEOUT)
+}
+
 fn validate_database_str(database_str: &str, provider: &str) -> ConnectorResult<()> {
     let scheme = database_str.split(":").next();
 
@@ -1,4 +1,4 @@
-use crate::sql_schema_calculator::SqlSchemaCalculator;
+use crate::sql_schema_calculator::{FieldExtensions, ModelExtensions, SqlSchemaCalculator};
 use crate::sql_schema_differ::{SqlSchemaDiff, SqlSchemaDiffer};
 use crate::*;
 use datamodel::*;
@@ -24,12 +24,15 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer {
         let fut = async {
             let current_database_schema: SqlSchema = self.describe(&self.schema_name).await?;
             let expected_database_schema = SqlSchemaCalculator::calculate(next)?;
-            infer(
+            let mut migration = infer(
                 &current_database_schema,
                 &expected_database_schema,
                 &self.schema_name,
                 self.sql_family(),
-            )
+            )?;
+            mark_online_index_creations(&mut migration, next);
+
+            Ok(migration)
         };
 
         catch(&self.connection_info, fut).await
@@ -44,12 +47,15 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer {
         let result: SqlResult<SqlMigration> = (|| {
             let current_database_schema: SqlSchema = SqlSchemaCalculator::calculate(previous)?;
             let expected_database_schema = SqlSchemaCalculator::calculate(next)?;
-            infer(
+            let mut migration = infer(
                 &current_database_schema,
                 &expected_database_schema,
                 &self.schema_name,
                 self.sql_family(),
-            )
+            )?;
+            mark_online_index_creations(&mut migration, next);
+
+            Ok(migration)
         })();
 
         result.map_err(|sql_error| sql_error.into_connector_error(&self.connection_info))
@@ -72,6 +78,19 @@ fn infer(
     schema_name: &str,
     sql_family: SqlFamily,
 ) -> SqlResult<SqlMigration> {
+    let adjusted_expected_database_schema;
+    let expected_database_schema = match sql_family {
+        SqlFamily::Sqlite => {
+            adjusted_expected_database_schema = inline_enums(&text_columns_as_strings(expected_database_schema));
+            &adjusted_expected_database_schema
+        }
+        SqlFamily::Mysql => {
+            adjusted_expected_database_schema = inline_enums(&uuid_columns_as_char(expected_database_schema));
+            &adjusted_expected_database_schema
+        }
+        SqlFamily::Postgres => expected_database_schema,
+    };
+
     let (original_steps, corrected_steps) = infer_database_migration_steps_and_fix(
         &current_database_schema,
         &expected_database_schema,
@@ -80,7 +99,7 @@ fn infer(
     )?;
     let (_, rollback) = infer_database_migration_steps_and_fix(
         &expected_database_schema,
-        &current_database_schema,
+        &raw_types_of_native_columns_only(current_database_schema, expected_database_schema),
         &schema_name,
         sql_family,
     )?;
@@ -93,28 +112,141 @@ fn infer(
     })
 }
 
+/// SQLite has no JSON and UUID types. Json and Uuid columns are stored as TEXT, and described as
+/// string columns, so we expect them as such to avoid migrating them again on every migration.
+fn text_columns_as_strings(schema: &SqlSchema) -> SqlSchema {
+    let mut schema = schema.clone();
+
+    for column in schema.tables.iter_mut().flat_map(|table| table.columns.iter_mut()) {
+        if column.tpe.family == ColumnTypeFamily::Json || column.tpe.family == ColumnTypeFamily::Uuid {
+            column.tpe.family = ColumnTypeFamily::String;
+        }
+    }
+
+    schema
+}
+
+/// MySQL has no UUID type. Uuid columns without a native type are stored as CHAR(36), and described as
+/// string columns, so we expect them with that raw type to compare them by their raw type.
+fn uuid_columns_as_char(schema: &SqlSchema) -> SqlSchema {
+    let mut schema = schema.clone();
+
+    for column in schema.tables.iter_mut().flat_map(|table| table.columns.iter_mut()) {
+        if column.tpe.family == ColumnTypeFamily::Uuid && column.tpe.raw.is_empty() {
+            column.tpe.raw = "char(36)".to_owned();
+        }
+    }
+
+    schema
+}
+
+/// MySQL and SQLite have no enum types, enums are declared on each column using them. These enums are
+/// described with a name derived from the column, so we expect them as such.
+fn inline_enums(schema: &SqlSchema) -> SqlSchema {
+    let mut inlined = schema.clone();
+    inlined.enums = Vec::new();
+
+    for table in inlined.tables.iter_mut() {
+        for column in table.columns.iter_mut() {
+            let values = match schema.column_enum(column) {
+                Some(inum) => inum.values.clone(),
+                None => continue,
+            };
+            let name = inline_enum_name(&table.name, &column.name);
+
+            column.tpe.family = ColumnTypeFamily::Enum(name.clone());
+            inlined.enums.push(Enum { name, values });
+        }
+    }
+
+    inlined
+}
+
+/// The renderers use the raw type of columns with a native type. Described columns always have a
+/// raw type, so we only keep it where the expected column has a native type, and let all other
+/// columns be rolled back to the types we render by default.
+fn raw_types_of_native_columns_only(current: &SqlSchema, expected: &SqlSchema) -> SqlSchema {
+    let mut schema = current.clone();
+
+    for table in schema.tables.iter_mut() {
+        let expected_table = expected.get_table(&table.name);
+
+        for column in table.columns.iter_mut() {
+            let has_native_type = expected_table
+                .and_then(|expected_table| expected_table.columns.iter().find(|col| col.name == column.name))
+                .map(|expected_column| !expected_column.tpe.raw.is_empty())
+                .unwrap_or(false);
+
+            if !has_native_type {
+                column.tpe.raw = String::new();
+            }
+        }
+    }
+
+    schema
+}
+
+/// Flag the index creations on existing tables that the datamodel asks to be performed online, i.e.
+/// without locking writes on the table while the index is built.
+fn mark_online_index_creations(migration: &mut SqlMigration, next: &Datamodel) {
+    let online_indexes: Vec<(&str, Vec<String>)> = next
+        .models()
+        .flat_map(|model| {
+            model.indexes.iter().filter(|index| index.online).map(move |index| {
+                let columns = index
+                    .fields
+                    .iter()
+                    .filter_map(|field_name| model.find_field(field_name))
+                    .map(|field| field.db_name())
+                    .collect();
+
+                (model.db_name(), columns)
+            })
+        })
+        .collect();
+
+    let before = &migration.before;
+
+    for step in migration
+        .original_steps
+        .iter_mut()
+        .chain(migration.corrected_steps.iter_mut())
+    {
+        if let SqlMigrationStep::CreateIndex(create_index) = step {
+            create_index.online = before.has_table(&create_index.table)
+                && online_indexes
+                    .iter()
+                    .any(|(table, columns)| *table == create_index.table && *columns == create_index.index.columns);
+        }
+    }
+}
+
 fn infer_database_migration_steps_and_fix(
     from: &SqlSchema,
     to: &SqlSchema,
     schema_name: &str,
     sql_family: SqlFamily,
 ) -> SqlResult<(Vec<SqlMigrationStep>, Vec<SqlMigrationStep>)> {
-    let diff: SqlSchemaDiff = SqlSchemaDiffer::diff(&from, &to);
+    let diff: SqlSchemaDiff = SqlSchemaDiffer::diff(&from, &to, sql_family);
     let is_sqlite = sql_family == SqlFamily::Sqlite;
 
     let corrected_steps = if is_sqlite {
         fix_stupid_sqlite(diff, &from, &to, &schema_name)?
     } else {
-        fix_id_column_type_change(&from, &to, schema_name, diff.into_steps())?
+        fix_id_column_type_change(&from, &to, schema_name, sql_family, diff.into_steps())?
     };
 
-    Ok((SqlSchemaDiffer::diff(&from, &to).into_steps(), corrected_steps))
+    Ok((
+        SqlSchemaDiffer::diff(&from, &to, sql_family).into_steps(),
+        corrected_steps,
+    ))
 }
 
 fn fix_id_column_type_change(
     from: &SqlSchema,
     to: &SqlSchema,
     _schema_name: &str,
+    sql_family: SqlFamily,
     steps: Vec<SqlMigrationStep>,
 ) -> SqlResult<Vec<SqlMigrationStep>> {
     let has_id_type_change = steps
@@ -156,7 +288,12 @@ fn fix_id_column_type_change(
             .map(|t| t.name.clone())
             .collect();
         radical_steps.push(SqlMigrationStep::DropTables(DropTables { names: tables_to_drop }));
-        let diff_from_empty: SqlSchemaDiff = SqlSchemaDiffer::diff(&SqlSchema::empty(), &to);
+        // The enums are not dropped with the tables.
+        let schema_without_tables = SqlSchema {
+            enums: from.enums.clone(),
+            ..SqlSchema::empty()
+        };
+        let diff_from_empty: SqlSchemaDiff = SqlSchemaDiffer::diff(&schema_without_tables, &to, sql_family);
         let mut steps_from_empty = diff_from_empty.into_steps();
         radical_steps.append(&mut steps_from_empty);
 
@@ -220,6 +357,8 @@ fn needs_fix(alter_table: &AlterTable) -> bool {
         TableChange::DropColumn(_) => true,
         TableChange::AlterColumn(_) => true,
         TableChange::DropForeignKey(_) => true,
+        // SQLite has no `ALTER TABLE` for constraints.
+        TableChange::AddCheckConstraint(_) | TableChange::DropCheckConstraint(_) => true,
     });
 
     change_that_does_not_work_on_sqlite.is_some()
@@ -289,6 +428,7 @@ fn fix(current: &Table, next: &Table, schema_name: &str) -> Vec<SqlMigrationStep
                 SqlMigrationStep::CreateIndex(CreateIndex {
                     table: next.name.clone(),
                     index: index.clone(),
+                    online: false,
                 })
             })
             .collect(),
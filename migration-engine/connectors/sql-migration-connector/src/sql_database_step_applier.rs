@@ -1,6 +1,9 @@
-use crate::*;
+use crate::{
+    sql_schema_differ::{column_can_be_altered_in_place, identity_sequence},
+    *,
+};
 use quaint::prelude::Queryable;
-use sql_renderer::SqlRenderer;
+use sql_renderer::{render_enum_values, SqlRenderer};
 use sql_schema_describer::*;
 use std::sync::Arc;
 use tracing_futures::Instrument;
@@ -9,26 +12,52 @@ pub struct SqlDatabaseStepApplier {
     pub connection_info: ConnectionInfo,
     pub schema_name: String,
     pub conn: Arc<dyn Queryable + Send + Sync + 'static>,
+    pub describer: Arc<dyn SqlSchemaDescriberBackend + Send + Sync + 'static>,
 }
 
 #[async_trait::async_trait]
 impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier {
     async fn apply_step(&self, database_migration: &SqlMigration, index: usize) -> ConnectorResult<bool> {
         self.catch(
-            self.apply_next_step(&database_migration.corrected_steps, index, self.renderer().as_ref())
-                .instrument(tracing::debug_span!("ApplySqlStep", index)),
+            self.apply_next_step(
+                &database_migration.corrected_steps,
+                index,
+                self.renderer().as_ref(),
+                &database_migration.before,
+                &database_migration.after,
+            )
+            .instrument(tracing::debug_span!("ApplySqlStep", index)),
         )
         .await
     }
 
     async fn unapply_step(&self, database_migration: &SqlMigration, index: usize) -> ConnectorResult<bool> {
         self.catch(
-            self.apply_next_step(&database_migration.rollback, index, self.renderer().as_ref())
-                .instrument(tracing::debug_span!("UnapplySqlStep", index)),
+            self.apply_next_step(
+                &database_migration.rollback,
+                index,
+                self.renderer().as_ref(),
+                &database_migration.after,
+                &database_migration.before,
+            )
+            .instrument(tracing::debug_span!("UnapplySqlStep", index)),
         )
         .await
     }
 
+    async fn step_is_applied(&self, database_migration: &SqlMigration, index: usize) -> ConnectorResult<Option<bool>> {
+        let step = match database_migration.corrected_steps.get(index) {
+            Some(step) => step,
+            None => return Ok(None),
+        };
+
+        let schema = self
+            .catch(async { Ok(self.describer.describe(&self.schema_name).await?) })
+            .await?;
+
+        Ok(step_is_applied(step, &schema))
+    }
+
     fn render_steps_pretty(&self, database_migration: &SqlMigration) -> ConnectorResult<Vec<serde_json::Value>> {
         Ok(
             render_steps_pretty(&database_migration, self.renderer().as_ref(), &self.schema_name)?
@@ -52,6 +81,8 @@ impl SqlDatabaseStepApplier {
         steps: &[SqlMigrationStep],
         index: usize,
         renderer: &(dyn SqlRenderer + Send + Sync),
+        current_schema: &SqlSchema,
+        next_schema: &SqlSchema,
     ) -> SqlResult<bool> {
         let has_this_one = steps.get(index).is_some();
         if !has_this_one {
@@ -61,12 +92,24 @@ impl SqlDatabaseStepApplier {
         let step = &steps[index];
         tracing::debug!(?step);
 
-        if let Some(sql_string) = render_raw_sql(&step, renderer, &self.schema_name)
+        if let Some(sql_string) = render_raw_sql(&step, renderer, &self.schema_name, current_schema, next_schema)
             .map_err(|err: std::fmt::Error| SqlError::Generic(format!("IO error: {}", err)))?
         {
             tracing::debug!(index, %sql_string);
 
-            let result = self.conn.query_raw(&sql_string, &[]).await;
+            let result = if self.step_runs_in_transaction(step) {
+                self.run_in_transaction(&sql_string).await
+            } else {
+                self.conn
+                    .query_raw(&sql_string, &[])
+                    .await
+                    .map(drop)
+                    .map_err(SqlError::from)
+            };
+
+            if let (Err(_), SqlMigrationStep::CreateIndex(create_index)) = (&result, step) {
+                self.drop_invalid_online_index(create_index).await;
+            }
 
             // TODO: this does not evaluate the results of SQLites PRAGMA foreign_key_check
             result?;
@@ -78,6 +121,82 @@ impl SqlDatabaseStepApplier {
         Ok(has_more)
     }
 
+    /// DDL is transactional on Postgres, so each step is run in its own transaction there. This way, an
+    /// interrupted migration never leaves a half-applied step behind.
+    fn step_runs_in_transaction(&self, step: &SqlMigrationStep) -> bool {
+        match (self.sql_family(), step) {
+            (SqlFamily::Postgres, SqlMigrationStep::CreateIndex(CreateIndex { online: true, .. })) => false,
+            (SqlFamily::Postgres, _) => true,
+            _ => false,
+        }
+    }
+
+    async fn run_in_transaction(&self, sql_string: &str) -> SqlResult<()> {
+        self.conn.query_raw("BEGIN", &[]).await?;
+
+        match self.conn.query_raw(sql_string, &[]).await {
+            Ok(_) => {
+                self.conn.query_raw("COMMIT", &[]).await?;
+                Ok(())
+            }
+            Err(err) => {
+                self.conn.query_raw("ROLLBACK", &[]).await.ok();
+                Err(err.into())
+            }
+        }
+    }
+
+    /// A failed `CREATE INDEX CONCURRENTLY` leaves an invalid index behind on Postgres. It has to
+    /// be dropped, otherwise retrying the migration would fail because the index name is taken. Valid
+    /// indexes, e.g. one that already existed under that name, are left alone.
+    async fn drop_invalid_online_index(&self, create_index: &CreateIndex) {
+        match self.sql_family() {
+            SqlFamily::Postgres if create_index.online => (),
+            _ => return,
+        }
+
+        let sql = "SELECT idx.indisvalid AS is_valid
+            FROM pg_class cls
+            JOIN pg_index idx ON idx.indexrelid = cls.oid
+            JOIN pg_namespace ns ON ns.oid = cls.relnamespace
+            WHERE ns.nspname = $1 AND cls.relname = $2";
+
+        let is_valid = match self
+            .conn
+            .query_raw(
+                sql,
+                &[
+                    self.schema_name.as_str().into(),
+                    create_index.index.name.as_str().into(),
+                ],
+            )
+            .await
+        {
+            Ok(rows) => rows
+                .into_iter()
+                .next()
+                .and_then(|row| row.get("is_valid").and_then(|is_valid| is_valid.as_bool())),
+            Err(err) => {
+                tracing::warn!(index = %create_index.index.name, %err, "Could not check whether the index is valid.");
+                return;
+            }
+        };
+
+        if is_valid != Some(false) {
+            return;
+        }
+
+        let drop_index = format!(
+            "DROP INDEX IF EXISTS {}",
+            self.renderer()
+                .quote_with_schema(&self.schema_name, &create_index.index.name)
+        );
+
+        if let Err(err) = self.conn.query_raw(&drop_index, &[]).await {
+            tracing::warn!(index = %create_index.index.name, %err, "Could not drop the invalid index.");
+        }
+    }
+
     fn sql_family(&self) -> SqlFamily {
         self.connection_info.sql_family()
     }
@@ -87,6 +206,95 @@ impl SqlDatabaseStepApplier {
     }
 }
 
+/// Check whether the effect of the step can be observed in the described schema. Returns `None` when
+/// this can't be determined, e.g. for raw SQL steps.
+fn step_is_applied(step: &SqlMigrationStep, schema: &SqlSchema) -> Option<bool> {
+    let has_index = |table: &str, index_name: &str| {
+        schema
+            .get_table(table)
+            .map(|table| table.indices.iter().any(|index| index.name == index_name))
+            .unwrap_or(false)
+    };
+
+    match step {
+        SqlMigrationStep::CreateTable(CreateTable { table }) => Some(schema.has_table(&table.name)),
+        SqlMigrationStep::DropTable(DropTable { name }) => Some(!schema.has_table(name)),
+        SqlMigrationStep::DropTables(DropTables { names }) => Some(names.iter().all(|name| !schema.has_table(name))),
+        SqlMigrationStep::RenameTable { name, new_name } => Some(!schema.has_table(name) && schema.has_table(new_name)),
+        SqlMigrationStep::AddForeignKey(AddForeignKey { table, foreign_key }) => schema.get_table(table).map(|table| {
+            table
+                .foreign_keys
+                .iter()
+                .any(|fk| fk.columns == foreign_key.columns && fk.referenced_table == foreign_key.referenced_table)
+        }),
+        SqlMigrationStep::CreateIndex(CreateIndex { table, index, .. }) => Some(has_index(table, &index.name)),
+        SqlMigrationStep::DropIndex(DropIndex { table, name }) => Some(!has_index(table, name)),
+        SqlMigrationStep::AlterIndex(AlterIndex {
+            table,
+            index_name,
+            index_new_name,
+        }) => Some(!has_index(table, index_name) && has_index(table, index_new_name)),
+        SqlMigrationStep::AlterTable(AlterTable { table, changes }) => {
+            let described_table = schema.get_table(&table.name)?;
+            let mut all_changes_applied = true;
+
+            for change in changes {
+                match change {
+                    TableChange::AddColumn(AddColumn { column }) => {
+                        all_changes_applied &= described_table.has_column(&column.name)
+                    }
+                    TableChange::DropColumn(DropColumn { name }) => {
+                        all_changes_applied &= !described_table.has_column(name)
+                    }
+                    TableChange::AddCheckConstraint(AddCheckConstraint { check_constraint }) => {
+                        all_changes_applied &= described_table.has_check_constraint(&check_constraint.name)
+                    }
+                    TableChange::DropCheckConstraint(DropCheckConstraint { name }) => {
+                        // Constraints whose expression changed are dropped and added again.
+                        let is_recreated = changes.iter().any(|change| match change {
+                            TableChange::AddCheckConstraint(AddCheckConstraint { check_constraint }) => {
+                                &check_constraint.name == name
+                            }
+                            _ => false,
+                        });
+
+                        all_changes_applied &= is_recreated || !described_table.has_check_constraint(name)
+                    }
+                    // Altered columns and dropped foreign keys can't be told apart reliably from the
+                    // description of the table.
+                    TableChange::AlterColumn(_) | TableChange::DropForeignKey(_) => return None,
+                }
+            }
+
+            Some(all_changes_applied)
+        }
+        SqlMigrationStep::CreateEnum(CreateEnum { name, .. }) => Some(schema.get_enum(name).is_some()),
+        SqlMigrationStep::DropEnum(DropEnum { name }) => Some(schema.get_enum(name).is_none()),
+        SqlMigrationStep::AlterEnum(AlterEnum {
+            name,
+            created_variants,
+            dropped_variants,
+        }) => schema.get_enum(name).map(|inum| {
+            created_variants.iter().all(|variant| inum.values.contains(variant))
+                && dropped_variants.iter().all(|variant| !inum.values.contains(variant))
+        }),
+        SqlMigrationStep::AlterComment(AlterComment { table, column, comment }) => {
+            let described_table = schema.get_table(table)?;
+
+            match column {
+                Some(column) => described_table
+                    .column(column)
+                    .map(|described_column| &described_column.comment == comment),
+                None => Some(&described_table.comment == comment),
+            }
+        }
+        SqlMigrationStep::AlterSequence(AlterSequence { table, next, .. }) => schema
+            .get_table(table)
+            .map(|described_table| identity_sequence(described_table) == next.as_ref()),
+        SqlMigrationStep::RawSql { .. } => None,
+    }
+}
+
 fn render_steps_pretty(
     database_migration: &SqlMigration,
     renderer: &(dyn SqlRenderer + Send + Sync),
@@ -95,8 +303,14 @@ fn render_steps_pretty(
     let mut steps = Vec::with_capacity(database_migration.corrected_steps.len());
 
     for step in &database_migration.corrected_steps {
-        if let Some(sql) = render_raw_sql(&step, renderer, schema_name)
-            .map_err(|err: std::fmt::Error| ConnectorError::from_kind(ErrorKind::Generic(err.into())))?
+        if let Some(sql) = render_raw_sql(
+            &step,
+            renderer,
+            schema_name,
+            &database_migration.before,
+            &database_migration.after,
+        )
+        .map_err(|err: std::fmt::Error| ConnectorError::from_kind(ErrorKind::Generic(err.into())))?
         {
             steps.push(PrettySqlMigrationStep {
                 step: step.clone(),
@@ -108,10 +322,14 @@ fn render_steps_pretty(
     Ok(steps)
 }
 
+/// The `current_schema` is the schema the step is applied to, the `next_schema` the schema the
+/// migration leads to.
 fn render_raw_sql(
     step: &SqlMigrationStep,
     renderer: &(dyn SqlRenderer + Send + Sync),
     schema_name: &str,
+    current_schema: &SqlSchema,
+    next_schema: &SqlSchema,
 ) -> std::result::Result<Option<String>, std::fmt::Error> {
     use itertools::Itertools;
     use std::fmt::Write as _;
@@ -129,7 +347,7 @@ fn render_raw_sql(
 
             let mut columns = table.columns.iter().peekable();
             while let Some(column) = columns.next() {
-                let col_sql = renderer.render_column(&schema_name, &table, &column, false);
+                let col_sql = renderer.render_column(&schema_name, &table, &column, false, next_schema);
 
                 write!(
                     create_table,
@@ -151,6 +369,15 @@ fn render_raw_sql(
                 write!(create_table, ",\n    PRIMARY KEY ({})", column_names.join(","))?;
             }
 
+            for check_constraint in &table.check_constraints {
+                write!(
+                    create_table,
+                    ",\n    CONSTRAINT {} CHECK ({})",
+                    renderer.quote(&check_constraint.name),
+                    check_constraint.expression
+                )?;
+            }
+
             if sql_family == SqlFamily::Sqlite && !table.foreign_keys.is_empty() {
                 write!(create_table, ",")?;
 
@@ -169,6 +396,11 @@ fn render_raw_sql(
 
             write!(create_table, "\n) {}", create_table_suffix(sql_family))?;
 
+            // Comments of created tables get their own steps on Postgres.
+            if let (SqlFamily::Mysql, Some(comment)) = (sql_family, &table.comment) {
+                write!(create_table, " COMMENT = {}", renderer.render_string_literal(comment))?;
+            }
+
             Ok(Some(create_table))
         }
         SqlMigrationStep::DropTable(DropTable { name }) => Ok(Some(format!(
@@ -230,7 +462,7 @@ fn render_raw_sql(
             for change in changes.clone() {
                 match change {
                     TableChange::AddColumn(AddColumn { column }) => {
-                        let col_sql = renderer.render_column(&schema_name, &table, &column, true);
+                        let col_sql = renderer.render_column(&schema_name, &table, &column, true, next_schema);
                         lines.push(format!("ADD COLUMN {}", col_sql));
                     }
                     TableChange::DropColumn(DropColumn { name }) => {
@@ -238,10 +470,21 @@ fn render_raw_sql(
                         lines.push(format!("DROP COLUMN {}", name));
                     }
                     TableChange::AlterColumn(AlterColumn { name, column }) => {
-                        let name = renderer.quote(&name);
-                        lines.push(format!("DROP COLUMN {}", name));
-                        let col_sql = renderer.render_column(&schema_name, &table, &column, true);
-                        lines.push(format!("ADD COLUMN {}", col_sql));
+                        let alter_in_place = current_schema
+                            .get_table(&table.name)
+                            .and_then(|previous_table| previous_table.column(&name))
+                            .filter(|previous_column| column_can_be_altered_in_place(previous_column, &column))
+                            .and_then(|_| renderer.render_alter_column(&schema_name, &table, &column, next_schema));
+
+                        match alter_in_place {
+                            Some(clauses) => lines.extend(clauses),
+                            None => {
+                                let name = renderer.quote(&name);
+                                lines.push(format!("DROP COLUMN {}", name));
+                                let col_sql = renderer.render_column(&schema_name, &table, &column, true, next_schema);
+                                lines.push(format!("ADD COLUMN {}", col_sql));
+                            }
+                        }
                     }
                     TableChange::DropForeignKey(DropForeignKey { constraint_name }) => match sql_family {
                         SqlFamily::Mysql => {
@@ -250,6 +493,17 @@ fn render_raw_sql(
                         }
                         _ => (),
                     },
+                    TableChange::AddCheckConstraint(AddCheckConstraint { check_constraint }) => {
+                        lines.push(format!(
+                            "ADD CONSTRAINT {} CHECK ({})",
+                            renderer.quote(&check_constraint.name),
+                            check_constraint.expression
+                        ));
+                    }
+                    TableChange::DropCheckConstraint(DropCheckConstraint { name }) => match sql_family {
+                        SqlFamily::Mysql => lines.push(format!("DROP CHECK {}", renderer.quote(&name))),
+                        _ => lines.push(format!("DROP CONSTRAINT {}", renderer.quote(&name))),
+                    },
                 };
             }
             Ok(Some(format!(
@@ -258,12 +512,17 @@ fn render_raw_sql(
                 lines.join(",\n")
             )))
         }
-        SqlMigrationStep::CreateIndex(CreateIndex { table, index }) => {
+        SqlMigrationStep::CreateIndex(CreateIndex { table, index, online }) => {
             let Index { name, columns, tpe } = index;
             let index_type = match tpe {
                 IndexType::Unique => "UNIQUE",
                 IndexType::Normal => "",
             };
+            let (concurrently, lock_clause) = match (sql_family, online) {
+                (SqlFamily::Postgres, true) => (" CONCURRENTLY", ""),
+                (SqlFamily::Mysql, true) => ("", " ALGORITHM=INPLACE LOCK=NONE"),
+                _ => ("", ""),
+            };
             let index_name = match sql_family {
                 SqlFamily::Sqlite => renderer.quote_with_schema(&schema_name, &name),
                 _ => renderer.quote(&name),
@@ -274,11 +533,13 @@ fn render_raw_sql(
             };
             let columns: Vec<String> = columns.iter().map(|c| renderer.quote(c)).collect();
             Ok(Some(format!(
-                "CREATE {} INDEX {} ON {}({})",
+                "CREATE {} INDEX{} {} ON {}({}){}",
                 index_type,
+                concurrently,
                 index_name,
                 table_reference,
-                columns.join(",")
+                columns.join(","),
+                lock_clause
             )))
         }
         SqlMigrationStep::DropIndex(DropIndex { table, name }) => match sql_family {
@@ -310,10 +571,284 @@ fn render_raw_sql(
             ))),
             SqlFamily::Sqlite => unimplemented!("Index renaming on SQLite."),
         },
+        SqlMigrationStep::CreateEnum(CreateEnum { name, variants }) => match sql_family {
+            SqlFamily::Postgres => Ok(Some(format!(
+                "CREATE TYPE {} AS ENUM ({})",
+                renderer.quote_with_schema(&schema_name, name),
+                render_enum_values(variants),
+            ))),
+            SqlFamily::Mysql | SqlFamily::Sqlite => Ok(None),
+        },
+        SqlMigrationStep::DropEnum(DropEnum { name }) => match sql_family {
+            SqlFamily::Postgres => Ok(Some(format!(
+                "DROP TYPE {}",
+                renderer.quote_with_schema(&schema_name, name)
+            ))),
+            SqlFamily::Mysql | SqlFamily::Sqlite => Ok(None),
+        },
+        SqlMigrationStep::AlterEnum(AlterEnum { name, .. }) => match sql_family {
+            SqlFamily::Postgres => {
+                render_postgres_alter_enum(name, renderer, &schema_name, current_schema, next_schema)
+            }
+            SqlFamily::Mysql | SqlFamily::Sqlite => Ok(None),
+        },
+        SqlMigrationStep::AlterComment(AlterComment { table, column, comment }) => render_alter_comment(
+            table,
+            column.as_ref(),
+            comment.as_ref(),
+            renderer,
+            &schema_name,
+            next_schema,
+        ),
+        SqlMigrationStep::AlterSequence(alter_sequence) => match sql_family {
+            SqlFamily::Postgres => render_postgres_alter_sequence(alter_sequence, renderer, &schema_name),
+            SqlFamily::Mysql | SqlFamily::Sqlite => Ok(None),
+        },
         SqlMigrationStep::RawSql { raw } => Ok(Some(raw.to_owned())),
     }
 }
 
+/// MySQL has no statement for column comments: the column is redefined with its new comment.
+fn render_alter_comment(
+    table_name: &str,
+    column_name: Option<&String>,
+    comment: Option<&String>,
+    renderer: &(dyn SqlRenderer + Send + Sync),
+    schema_name: &str,
+    next_schema: &SqlSchema,
+) -> std::result::Result<Option<String>, std::fmt::Error> {
+    let table_reference = renderer.quote_with_schema(schema_name, table_name);
+
+    match (renderer.sql_family(), column_name) {
+        (SqlFamily::Postgres, None) => Ok(Some(format!(
+            "COMMENT ON TABLE {} IS {}",
+            table_reference,
+            comment
+                .map(|comment| renderer.render_string_literal(comment))
+                .unwrap_or_else(|| "NULL".to_owned())
+        ))),
+        (SqlFamily::Postgres, Some(column_name)) => Ok(Some(format!(
+            "COMMENT ON COLUMN {}.{} IS {}",
+            table_reference,
+            renderer.quote(column_name),
+            comment
+                .map(|comment| renderer.render_string_literal(comment))
+                .unwrap_or_else(|| "NULL".to_owned())
+        ))),
+        (SqlFamily::Mysql, None) => Ok(Some(format!(
+            "ALTER TABLE {} COMMENT = {}",
+            table_reference,
+            renderer.render_string_literal(comment.map(String::as_str).unwrap_or(""))
+        ))),
+        (SqlFamily::Mysql, Some(column_name)) => {
+            let table = match next_schema.get_table(table_name) {
+                Some(table) => table,
+                None => return Ok(None),
+            };
+            let column = match table.column(column_name) {
+                Some(column) => column,
+                None => return Ok(None),
+            };
+
+            Ok(Some(format!(
+                "ALTER TABLE {} MODIFY COLUMN {}",
+                table_reference,
+                renderer.render_column(schema_name, table, column, false, next_schema)
+            )))
+        }
+        (SqlFamily::Sqlite, _) => Ok(None),
+    }
+}
+
+/// Values can't be removed from a Postgres enum, and `ALTER TYPE ... ADD VALUE` can't run in a
+/// transaction before Postgres 12. The type is therefore recreated with the new values, and the
+/// columns using it are converted. This happens in a single `DO` block, so the step is atomic.
+fn render_postgres_alter_enum(
+    enum_name: &str,
+    renderer: &(dyn SqlRenderer + Send + Sync),
+    schema_name: &str,
+    current_schema: &SqlSchema,
+    next_schema: &SqlSchema,
+) -> std::result::Result<Option<String>, std::fmt::Error> {
+    use std::fmt::Write as _;
+
+    let next_enum = match next_schema.get_enum(enum_name) {
+        Some(next_enum) => next_enum,
+        None => return Ok(None),
+    };
+    let enum_type = renderer.quote_with_schema(schema_name, enum_name);
+    let old_enum_name = format!("{}_old", enum_name);
+
+    let mut alter_enum = String::with_capacity(300);
+
+    writeln!(alter_enum, "DO $$\nBEGIN")?;
+    writeln!(
+        alter_enum,
+        "ALTER TYPE {} RENAME TO {};",
+        enum_type,
+        renderer.quote(&old_enum_name)
+    )?;
+    writeln!(
+        alter_enum,
+        "CREATE TYPE {} AS ENUM ({});",
+        enum_type,
+        render_enum_values(&next_enum.values)
+    )?;
+
+    for table in &current_schema.tables {
+        let table_name = renderer.quote_with_schema(schema_name, &table.name);
+
+        for column in table
+            .columns
+            .iter()
+            .filter(|column| column.tpe.family == ColumnTypeFamily::Enum(enum_name.to_owned()))
+        {
+            let column_name = renderer.quote(&column.name);
+            let array = if column.tpe.arity == ColumnArity::List {
+                "[]"
+            } else {
+                ""
+            };
+
+            // Defaults can't be cast to the new type automatically.
+            if column.default.is_some() {
+                writeln!(
+                    alter_enum,
+                    "ALTER TABLE {} ALTER COLUMN {} DROP DEFAULT;",
+                    table_name, column_name
+                )?;
+            }
+
+            writeln!(
+                alter_enum,
+                "ALTER TABLE {table} ALTER COLUMN {column} TYPE {tpe}{array} USING ({column}::text{array}::{tpe}{array});",
+                table = table_name,
+                column = column_name,
+                tpe = enum_type,
+                array = array,
+            )?;
+
+            let next_default = next_schema
+                .get_table(&table.name)
+                .and_then(|next_table| next_table.column(&column.name))
+                .filter(|next_column| next_column.tpe.family == column.tpe.family)
+                .and_then(|next_column| next_column.default.as_ref())
+                .and_then(DefaultValue::as_value)
+                .filter(|default| next_enum.values.iter().any(|value| value == *default));
+
+            if let Some(default) = next_default {
+                writeln!(
+                    alter_enum,
+                    "ALTER TABLE {} ALTER COLUMN {} SET DEFAULT {};",
+                    table_name,
+                    column_name,
+                    render_enum_values(&[default.to_owned()])
+                )?;
+            }
+        }
+    }
+
+    writeln!(
+        alter_enum,
+        "DROP TYPE {};",
+        renderer.quote_with_schema(schema_name, &old_enum_name)
+    )?;
+    write!(alter_enum, "END $$;")?;
+
+    Ok(Some(alter_enum))
+}
+
+/// Ids with a sequence are identity columns, the others are `SERIAL`. A column switching between the two drops
+/// its way of generating values and gets the other one, a `SERIAL` column continues after the highest id. The
+/// start value of a kept sequence is only used when it restarts, the values generated so far stay taken. This
+/// happens in a single `DO` block, so the step is atomic.
+fn render_postgres_alter_sequence(
+    alter_sequence: &AlterSequence,
+    renderer: &(dyn SqlRenderer + Send + Sync),
+    schema_name: &str,
+) -> std::result::Result<Option<String>, std::fmt::Error> {
+    use std::fmt::Write as _;
+
+    let table_name = renderer.quote_with_schema(schema_name, &alter_sequence.table);
+    let column_name = renderer.quote(&alter_sequence.column);
+    let serial_sequence = renderer.quote_with_schema(
+        schema_name,
+        &format!("{}_{}_seq", alter_sequence.table, alter_sequence.column),
+    );
+
+    let mut sql = String::with_capacity(300);
+
+    writeln!(sql, "DO $$\nBEGIN")?;
+
+    match (&alter_sequence.previous, &alter_sequence.next) {
+        (Some(previous), Some(next)) => {
+            if previous.name != next.name {
+                writeln!(
+                    sql,
+                    "ALTER SEQUENCE {} RENAME TO {};",
+                    renderer.quote_with_schema(schema_name, &previous.name),
+                    renderer.quote(&next.name)
+                )?;
+            }
+
+            writeln!(
+                sql,
+                "ALTER TABLE {} ALTER COLUMN {} SET GENERATED BY DEFAULT SET START WITH {} SET INCREMENT BY {};",
+                table_name, column_name, next.initial_value, next.allocation_size
+            )?;
+        }
+        (None, Some(next)) => {
+            writeln!(
+                sql,
+                "ALTER TABLE {} ALTER COLUMN {} DROP IDENTITY IF EXISTS;",
+                table_name, column_name
+            )?;
+            writeln!(
+                sql,
+                "ALTER TABLE {} ALTER COLUMN {} DROP DEFAULT;",
+                table_name, column_name
+            )?;
+            writeln!(sql, "DROP SEQUENCE IF EXISTS {};", serial_sequence)?;
+            writeln!(
+                sql,
+                "ALTER TABLE {} ALTER COLUMN {} ADD GENERATED BY DEFAULT AS IDENTITY (SEQUENCE NAME {} START WITH {} INCREMENT BY {});",
+                table_name,
+                column_name,
+                renderer.quote_with_schema(schema_name, &next.name),
+                next.initial_value,
+                next.allocation_size
+            )?;
+        }
+        (Some(_), None) => {
+            writeln!(
+                sql,
+                "ALTER TABLE {} ALTER COLUMN {} DROP IDENTITY IF EXISTS;",
+                table_name, column_name
+            )?;
+            writeln!(
+                sql,
+                "CREATE SEQUENCE {} OWNED BY {}.{};",
+                serial_sequence, table_name, column_name
+            )?;
+            writeln!(
+                sql,
+                "ALTER TABLE {} ALTER COLUMN {} SET DEFAULT nextval('{}');",
+                table_name, column_name, serial_sequence
+            )?;
+            writeln!(
+                sql,
+                "PERFORM setval('{}', coalesce(max({}), 0) + 1, false) FROM {};",
+                serial_sequence, column_name, table_name
+            )?;
+        }
+        (None, None) => return Ok(None),
+    }
+
+    write!(sql, "END $$;")?;
+
+    Ok(Some(sql))
+}
+
 fn create_table_suffix(sql_family: SqlFamily) -> &'static str {
     match sql_family {
         SqlFamily::Sqlite => "",
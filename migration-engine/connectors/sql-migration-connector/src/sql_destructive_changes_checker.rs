@@ -1,10 +1,10 @@
 use crate::{
-    AlterColumn, ConnectionInfo, DropColumn, DropTable, DropTables, SqlError, SqlMigration, SqlMigrationStep,
-    SqlResult, TableChange,
+    sql_schema_differ::column_can_be_altered_in_place, AlterColumn, AlterEnum, ConnectionInfo, DropColumn, DropTable,
+    DropTables, SqlError, SqlFamily, SqlMigration, SqlMigrationStep, SqlResult, TableChange,
 };
 use migration_connector::*;
 use quaint::{ast::*, prelude::Queryable};
-use sql_schema_describer::SqlSchemaDescriberBackend;
+use sql_schema_describer::{ColumnArity, ColumnTypeFamily, Enum, SqlSchema, SqlSchemaDescriberBackend};
 use std::sync::Arc;
 
 pub struct SqlDestructiveChangesChecker {
@@ -29,6 +29,12 @@ impl SqlDestructiveChangesChecker {
             SqlError::Generic("No count was returned when checking for existing rows in dropped table.".to_owned())
         })?;
 
+        diagnostics.affected_rows.push(AffectedRowsEstimate {
+            table: table_name.to_owned(),
+            column: None,
+            rows: rows_count,
+        });
+
         if rows_count > 0 {
             diagnostics.add_warning(MigrationWarning {
                 description: format!(
@@ -42,6 +48,39 @@ impl SqlDestructiveChangesChecker {
         Ok(())
     }
 
+    async fn count_rows(&self, query: &str, params: &[ParameterizedValue<'_>]) -> SqlResult<i64> {
+        self.database
+            .query_raw(query, params)
+            .await
+            .map_err(SqlError::from)
+            .and_then(|result_set| {
+                result_set
+                    .first()
+                    .as_ref()
+                    .and_then(|row| row.at(0))
+                    .and_then(|count| count.as_i64())
+                    .ok_or_else(|| SqlError::Generic("Unexpected result set shape when counting rows.".to_owned()))
+            })
+    }
+
+    /// Quotes an identifier in the raw queries of the checks.
+    fn quote(&self, name: &str) -> String {
+        match self.connection_info.sql_family() {
+            SqlFamily::Mysql => format!("`{}`", name.replace('`', "``")),
+            SqlFamily::Postgres | SqlFamily::Sqlite => format!("\"{}\"", name.replace('"', "\"\"")),
+        }
+    }
+
+    fn placeholders(&self, count: usize) -> String {
+        (1..=count)
+            .map(|idx| match self.connection_info.sql_family() {
+                SqlFamily::Postgres => format!("${}", idx),
+                SqlFamily::Mysql | SqlFamily::Sqlite => "?".to_owned(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
     async fn count_values_in_column(&self, column_name: &str, table: &sql_schema_describer::Table) -> SqlResult<i64> {
         let query = Select::from_table((self.schema_name.as_str(), table.name.as_str()))
             .value(count(quaint::ast::Column::new(column_name)))
@@ -75,6 +114,12 @@ impl SqlDestructiveChangesChecker {
     ) -> SqlResult<()> {
         let values_count = self.count_values_in_column(&drop_column.name, table).await?;
 
+        diagnostics.affected_rows.push(AffectedRowsEstimate {
+            table: table.name.clone(),
+            column: Some(drop_column.name.clone()),
+            rows: values_count,
+        });
+
         if values_count > 0 {
             diagnostics.add_warning(MigrationWarning {
                 description: format!(
@@ -89,13 +134,13 @@ impl SqlDestructiveChangesChecker {
         Ok(())
     }
 
-    /// Emit a warning when we alter a column that contains non-null values. We will implement
-    /// non-destructive alter column for a subset of changes in the future, but at the moment all
-    /// alter columns are destructive.
+    /// Emit a warning when we alter a column that contains non-null values. Only the columns that
+    /// can be altered in place keep their data, all other alter columns are destructive.
     async fn check_alter_column(
         &self,
         alter_column: &AlterColumn,
         previous_table: &sql_schema_describer::Table,
+        next_schema: &SqlSchema,
         diagnostics: &mut DestructiveChangeDiagnostics,
     ) -> SqlResult<()> {
         let values_count = self.count_values_in_column(&alter_column.name, previous_table).await?;
@@ -103,7 +148,20 @@ impl SqlDestructiveChangesChecker {
             .column(&alter_column.name)
             .expect("unsupported column renaming");
 
-        if values_count > 0 {
+        diagnostics.affected_rows.push(AffectedRowsEstimate {
+            table: previous_table.name.clone(),
+            column: Some(alter_column.name.clone()),
+            rows: values_count,
+        });
+
+        let altered_in_place = column_can_be_altered_in_place(previous_column, &alter_column.column);
+
+        if values_count > 0 && altered_in_place {
+            if let Some(next_enum) = next_schema.column_enum(&alter_column.column) {
+                self.check_values_are_enum_variants(alter_column, previous_table, next_enum, diagnostics)
+                    .await?;
+            }
+        } else if values_count > 0 {
             diagnostics.add_warning(MigrationWarning {
                 description: format!(
                                  "You are about to alter the column `{column_name}` on the `{table_name}` table, which still contains {values_count} non-null values. The data in that column will be lost.",
@@ -127,6 +185,116 @@ impl SqlDestructiveChangesChecker {
         Ok(())
     }
 
+    /// Emit a warning when a column converted to an enum in place contains values that are not variants
+    /// of the enum. The conversion would fail on these values.
+    async fn check_values_are_enum_variants(
+        &self,
+        alter_column: &AlterColumn,
+        previous_table: &sql_schema_describer::Table,
+        next_enum: &Enum,
+        diagnostics: &mut DestructiveChangeDiagnostics,
+    ) -> SqlResult<()> {
+        // SQLite stores enums as text, any value can be converted.
+        if alter_column.column.tpe.arity == ColumnArity::List
+            || next_enum.values.is_empty()
+            || self.connection_info.sql_family() == SqlFamily::Sqlite
+        {
+            return Ok(());
+        }
+
+        // Postgres enum columns are compared as text, the values may not be variants of their current enum.
+        let column = match self.connection_info.sql_family() {
+            SqlFamily::Postgres => format!("{}::text", self.quote(&alter_column.name)),
+            SqlFamily::Mysql | SqlFamily::Sqlite => self.quote(&alter_column.name),
+        };
+        let query = format!(
+            "SELECT COUNT(*) FROM {}.{} WHERE {column} IS NOT NULL AND {column} NOT IN ({})",
+            self.quote(&self.schema_name),
+            self.quote(&previous_table.name),
+            self.placeholders(next_enum.values.len()),
+            column = column,
+        );
+        let params: Vec<ParameterizedValue> = next_enum.values.iter().map(|value| value.as_str().into()).collect();
+
+        let rows_count = self.count_rows(&query, &params).await?;
+
+        if rows_count > 0 {
+            diagnostics.add_warning(MigrationWarning {
+                description: format!(
+                    "You are about to convert the column `{column_name}` on the `{table_name}` table to an enum, but {rows_count} rows contain values that are not variants of the enum. The migration will fail.",
+                    column_name = alter_column.name,
+                    table_name = &previous_table.name,
+                    rows_count = rows_count,
+                ),
+            })
+        }
+
+        Ok(())
+    }
+
+    /// Emit a warning when we remove values from an enum that are still used in a column. Only
+    /// Postgres has `AlterEnum` steps, on the other databases this is an altered column.
+    async fn check_alter_enum(
+        &self,
+        alter_enum: &AlterEnum,
+        before: &SqlSchema,
+        diagnostics: &mut DestructiveChangeDiagnostics,
+    ) -> SqlResult<()> {
+        if alter_enum.dropped_variants.is_empty() {
+            return Ok(());
+        }
+
+        let placeholders = self.placeholders(alter_enum.dropped_variants.len());
+        let params: Vec<ParameterizedValue> = alter_enum
+            .dropped_variants
+            .iter()
+            .map(|variant| variant.as_str().into())
+            .collect();
+        let family = ColumnTypeFamily::Enum(alter_enum.name.clone());
+
+        for table in &before.tables {
+            for column in table.columns.iter().filter(|column| column.tpe.family == family) {
+                let condition = match column.tpe.arity {
+                    ColumnArity::List => format!(
+                        "{}::text[] && ARRAY[{}]::text[]",
+                        self.quote(&column.name),
+                        placeholders
+                    ),
+                    _ => format!("{}::text IN ({})", self.quote(&column.name), placeholders),
+                };
+                let query = format!(
+                    "SELECT COUNT(*) FROM {}.{} WHERE {}",
+                    self.quote(&self.schema_name),
+                    self.quote(&table.name),
+                    condition
+                );
+
+                let rows_count = self.count_rows(&query, &params).await?;
+
+                diagnostics.affected_rows.push(AffectedRowsEstimate {
+                    table: table.name.clone(),
+                    column: Some(column.name.clone()),
+                    rows: rows_count,
+                });
+
+                if rows_count > 0 {
+                    diagnostics.add_warning(MigrationWarning {
+                        description: format!(
+                            "You are about to remove the values [{values}] from the enum `{enum_name}`, which are still used in {rows_count} rows of the column `{column_name}` on the `{table_name}` table.",
+                            values = alter_enum.dropped_variants.join(", "),
+                            enum_name = alter_enum.name,
+                            rows_count = rows_count,
+                            column_name = column.name,
+                            table_name = table.name,
+                        ),
+                    })
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     async fn check_impl(&self, database_migration: &SqlMigration) -> SqlResult<DestructiveChangeDiagnostics> {
         let mut diagnostics = DestructiveChangeDiagnostics::new();
 
@@ -145,8 +313,13 @@ impl SqlDestructiveChangesChecker {
                                         .await?
                                 }
                                 TableChange::AlterColumn(ref alter_column) => {
-                                    self.check_alter_column(alter_column, before_table, &mut diagnostics)
-                                        .await?
+                                    self.check_alter_column(
+                                        alter_column,
+                                        before_table,
+                                        &database_migration.after,
+                                        &mut diagnostics,
+                                    )
+                                    .await?
                                 }
                                 _ => (),
                             }
@@ -163,6 +336,10 @@ impl SqlDestructiveChangesChecker {
                         self.check_table_drop(name, &mut diagnostics).await?;
                     }
                 }
+                SqlMigrationStep::AlterEnum(alter_enum) => {
+                    self.check_alter_enum(alter_enum, &database_migration.before, &mut diagnostics)
+                        .await?;
+                }
                 // do nothing
                 _ => (),
             }
@@ -30,6 +30,10 @@ impl DatabaseMigrationMarker for SqlMigration {
     fn serialize(&self) -> serde_json::Value {
         serde_json::to_value(self).unwrap()
     }
+
+    fn step_count(&self) -> usize {
+        self.corrected_steps.len()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -44,6 +48,11 @@ pub enum SqlMigrationStep {
     CreateIndex(CreateIndex),
     DropIndex(DropIndex),
     AlterIndex(AlterIndex),
+    CreateEnum(CreateEnum),
+    DropEnum(DropEnum),
+    AlterEnum(AlterEnum),
+    AlterComment(AlterComment),
+    AlterSequence(AlterSequence),
 }
 
 /// A helper struct to serialize an [SqlMigrationStep](/sql-migration/enum.SqlMigrationStep.html)
@@ -84,6 +93,8 @@ pub enum TableChange {
     /// This is actually producing SQL only on MySQL, where we have to drop the foreign key
     /// constraint before any column that is part of it.
     DropForeignKey(DropForeignKey),
+    AddCheckConstraint(AddCheckConstraint),
+    DropCheckConstraint(DropCheckConstraint),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -113,10 +124,24 @@ pub struct DropForeignKey {
     pub constraint_name: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AddCheckConstraint {
+    pub check_constraint: CheckConstraint,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DropCheckConstraint {
+    pub name: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateIndex {
     pub table: String,
     pub index: Index,
+    /// Create the index without blocking writes on the table (`CONCURRENTLY` on Postgres,
+    /// `ALGORITHM=INPLACE LOCK=NONE` on MySQL). Such steps must not run inside a transaction.
+    #[serde(default)]
+    pub online: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -131,3 +156,43 @@ pub struct AlterIndex {
     pub index_name: String,
     pub index_new_name: String,
 }
+
+/// Enum steps are only produced on Postgres. On MySQL and SQLite, enums are part of the column
+/// definition.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateEnum {
+    pub name: String,
+    pub variants: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DropEnum {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AlterEnum {
+    pub name: String,
+    pub created_variants: Vec<String>,
+    pub dropped_variants: Vec<String>,
+}
+
+/// Sets the comment of a table, or of one of its columns. A `None` comment removes it. Comment steps are
+/// not produced on SQLite, which has no comments.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AlterComment {
+    pub table: String,
+    pub column: Option<String>,
+    pub comment: Option<String>,
+}
+
+/// Changes the sequence of the autoincrementing id column of a table. Sequence steps are only produced on
+/// Postgres, where ids with a sequence are identity columns and the others are `SERIAL`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AlterSequence {
+    pub table: String,
+    pub column: String,
+    /// The sequence of the identity column. `None` for a `SERIAL` column.
+    pub previous: Option<Sequence>,
+    pub next: Option<Sequence>,
+}
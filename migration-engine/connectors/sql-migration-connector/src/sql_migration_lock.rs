@@ -0,0 +1,155 @@
+//! Locking of the migrations on a database, so that concurrent migration engines (e.g. several
+//! application instances starting at the same time) do not apply the same migrations simultaneously.
+//!
+//! - On Postgres, we take a session-level advisory lock keyed on the schema name.
+//! - On MySQL, we take a named lock with `GET_LOCK()`. Lock names are limited to 64 characters there, so
+//!   the lock is named after the SHA1 hash of the lock name.
+//! - On SQLite, we create a lock file next to the database file. The lock file is not removed if the
+//!   engine crashes while holding the lock, in which case it has to be deleted manually.
+
+use crate::{SqlError, SqlMigrationConnector, SqlResult};
+use quaint::{ast::ParameterizedValue, prelude::ConnectionInfo};
+use std::{
+    fs::OpenOptions,
+    io,
+    time::{Duration, Instant},
+};
+
+/// How long to wait between two attempts at taking the lock, when the database can't wait for us.
+const LOCK_POLLING_INTERVAL: Duration = Duration::from_millis(200);
+
+impl SqlMigrationConnector {
+    pub(crate) async fn acquire_lock_impl(&self) -> SqlResult<()> {
+        match &self.connection_info {
+            ConnectionInfo::Postgres(_) => self.poll_lock(move || self.try_acquire_postgres_lock()).await,
+            ConnectionInfo::Mysql(_) => self.acquire_mysql_lock().await,
+            ConnectionInfo::Sqlite { file_path, .. } => {
+                let lock_file = lock_file_path(file_path);
+                let lock_file = lock_file.as_str();
+
+                self.poll_lock(move || async move { try_create_lock_file(lock_file) })
+                    .await
+            }
+        }
+    }
+
+    pub(crate) async fn release_lock_impl(&self) -> SqlResult<()> {
+        match &self.connection_info {
+            ConnectionInfo::Postgres(_) => {
+                self.database
+                    .query_raw(
+                        "SELECT pg_advisory_unlock(hashtext($1))",
+                        &[ParameterizedValue::from(self.lock_name().as_str())],
+                    )
+                    .await?;
+            }
+            ConnectionInfo::Mysql(_) => {
+                self.database
+                    .query_raw(
+                        "SELECT RELEASE_LOCK(SHA1(?))",
+                        &[ParameterizedValue::from(self.lock_name().as_str())],
+                    )
+                    .await?;
+            }
+            ConnectionInfo::Sqlite { file_path, .. } => match std::fs::remove_file(lock_file_path(file_path)) {
+                Ok(()) => (),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+                Err(err) => {
+                    return Err(SqlError::Generic(format!(
+                        "Could not remove the migration lock file: {}",
+                        err
+                    )))
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Call `try_lock` until it succeeds, or the lock timeout is exceeded.
+    async fn poll_lock<F, Fut>(&self, try_lock: F) -> SqlResult<()>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = SqlResult<bool>>,
+    {
+        let start = Instant::now();
+
+        while !try_lock().await? {
+            if start.elapsed() >= self.lock_timeout {
+                return Err(SqlError::MigrationLockTimeout {
+                    timeout: self.lock_timeout,
+                });
+            }
+
+            tracing::debug!("The migration lock is held by another migration engine. Waiting.");
+            tokio::time::delay_for(LOCK_POLLING_INTERVAL).await;
+        }
+
+        Ok(())
+    }
+
+    async fn try_acquire_postgres_lock(&self) -> SqlResult<bool> {
+        let result_set = self
+            .database
+            .query_raw(
+                "SELECT pg_try_advisory_lock(hashtext($1))::int",
+                &[ParameterizedValue::from(self.lock_name().as_str())],
+            )
+            .await?;
+
+        let lock_acquired = result_set
+            .first()
+            .and_then(|row| row.at(0).and_then(|value| value.as_i64()))
+            .ok_or_else(|| SqlError::Generic("Unexpected result when acquiring the migration lock.".to_owned()))?;
+
+        Ok(lock_acquired == 1)
+    }
+
+    async fn acquire_mysql_lock(&self) -> SqlResult<()> {
+        let result_set = self
+            .database
+            .query_raw(
+                "SELECT GET_LOCK(SHA1(?), ?)",
+                &[
+                    ParameterizedValue::from(self.lock_name().as_str()),
+                    ParameterizedValue::from(self.lock_timeout.as_secs() as i64),
+                ],
+            )
+            .await?;
+
+        // GET_LOCK returns 1 when the lock was acquired, and 0 when it timed out.
+        match result_set
+            .first()
+            .and_then(|row| row.at(0).and_then(|value| value.as_i64()))
+        {
+            Some(1) => Ok(()),
+            Some(0) => Err(SqlError::MigrationLockTimeout {
+                timeout: self.lock_timeout,
+            }),
+            _ => Err(SqlError::Generic(
+                "Unexpected result when acquiring the migration lock.".to_owned(),
+            )),
+        }
+    }
+
+    fn lock_name(&self) -> String {
+        format!("prisma_migrate_{}", self.schema_name)
+    }
+}
+
+fn lock_file_path(database_file_path: &str) -> String {
+    format!("{}.migration-lock", database_file_path)
+}
+
+/// Returns whether the lock file was created. Creation fails if the file already exists, which means
+/// the lock is held.
+fn try_create_lock_file(lock_file_path: &str) -> SqlResult<bool> {
+    match OpenOptions::new().write(true).create_new(true).open(lock_file_path) {
+        Ok(_) => Ok(true),
+        Err(err) if err.kind() == io::ErrorKind::AlreadyExists => Ok(false),
+        Err(err) => Err(SqlError::Generic(format!(
+            "Could not create the migration lock file: {}",
+            err
+        ))),
+    }
+}
@@ -200,6 +200,32 @@ impl MigrationPersistence for SqlMigrationPersistence {
         })
         .await
     }
+
+    async fn squash(
+        &self,
+        first_revision: usize,
+        last_revision: usize,
+        squashed: Migration,
+    ) -> Result<Migration, ConnectorError> {
+        self.catch(async move {
+            self.connection.query_raw("BEGIN", &[]).await?;
+
+            match self
+                .replace_squashed_migrations(first_revision, last_revision, &squashed)
+                .await
+            {
+                Ok(()) => {
+                    self.connection.query_raw("COMMIT", &[]).await?;
+                    Ok(squashed)
+                }
+                Err(err) => {
+                    self.connection.query_raw("ROLLBACK", &[]).await.ok();
+                    Err(err)
+                }
+            }
+        })
+        .await
+    }
 }
 
 fn migration_table_setup_sqlite(t: &mut barrel::Table) {
@@ -233,6 +259,51 @@ fn migration_table_setup(
 }
 
 impl SqlMigrationPersistence {
+    /// Replaces the migrations in the revision range with the squashed migration. Callers are
+    /// responsible for running this in a transaction.
+    async fn replace_squashed_migrations(
+        &self,
+        first_revision: usize,
+        last_revision: usize,
+        squashed: &Migration,
+    ) -> Result<(), super::SqlError> {
+        let delete = Delete::from_table(self.table()).so_that(
+            REVISION_COLUMN
+                .greater_than_or_equals(first_revision)
+                .and(REVISION_COLUMN.less_than_or_equals(last_revision)),
+        );
+
+        self.connection.query(delete.into()).await?;
+
+        let finished_at_value = match squashed.finished_at {
+            Some(x) => self.convert_datetime(x),
+            None => ParameterizedValue::Null,
+        };
+
+        let insert = Insert::single_into(self.table())
+            .value(REVISION_COLUMN, squashed.revision)
+            .value(DATAMODEL_COLUMN, squashed.datamodel_string.as_str())
+            .value(NAME_COLUMN, squashed.name.as_str())
+            .value(STATUS_COLUMN, squashed.status.code())
+            .value(APPLIED_COLUMN, squashed.applied)
+            .value(ROLLED_BACK_COLUMN, squashed.rolled_back)
+            .value(
+                DATAMODEL_STEPS_COLUMN,
+                serde_json::to_string(&squashed.datamodel_steps).unwrap(),
+            )
+            .value(
+                DATABASE_MIGRATION_COLUMN,
+                serde_json::to_string(&squashed.database_migration).unwrap(),
+            )
+            .value(ERRORS_COLUMN, serde_json::to_string(&squashed.errors).unwrap())
+            .value(STARTED_AT_COLUMN, self.convert_datetime(squashed.started_at))
+            .value(FINISHED_AT_COLUMN, finished_at_value);
+
+        self.connection.query(insert.into()).await?;
+
+        Ok(())
+    }
+
     fn table(&self) -> Table {
         match self.connection_info.sql_family() {
             SqlFamily::Sqlite => {
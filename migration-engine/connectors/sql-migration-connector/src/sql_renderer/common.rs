@@ -8,10 +8,22 @@ pub fn render_nullability(column: &Column) -> &'static str {
     }
 }
 
+/// Database generated defaults are rendered verbatim, in the parentheses all databases accept around
+/// expressions in `DEFAULT`.
 pub fn render_default(column: &Column) -> String {
     match &column.default {
-        Some(value) => match column.tpe.family {
-            ColumnTypeFamily::String | ColumnTypeFamily::DateTime => format!(
+        Some(DefaultValue::DbGenerated(expression)) => format!("DEFAULT ({})", expression),
+        // UUIDs stored as BINARY(16) on MySQL default to the bytes of the UUID.
+        Some(DefaultValue::Value(value))
+            if column.tpe.family == ColumnTypeFamily::Uuid && column.tpe.raw == "binary(16)" =>
+        {
+            format!("DEFAULT X'{}'", value.replace('-', ""))
+        }
+        Some(DefaultValue::Value(value)) => match column.tpe.family {
+            ColumnTypeFamily::String
+            | ColumnTypeFamily::DateTime
+            | ColumnTypeFamily::Enum(_)
+            | ColumnTypeFamily::Uuid => format!(
                 "DEFAULT '{}'",
                 // TODO: remove once sql-schema-describer does unescaping, and perform escaping again here.
                 value
@@ -26,6 +38,23 @@ pub fn render_default(column: &Column) -> String {
     }
 }
 
+/// Enum columns are rendered from their enum, even when they have a raw type.
+pub fn is_enum(column_type: &ColumnType) -> bool {
+    match column_type.family {
+        ColumnTypeFamily::Enum(_) => true,
+        _ => false,
+    }
+}
+
+/// Renders enum values as a list of string literals, e.g. `'A', 'B'`.
+pub fn render_enum_values(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|value| format!("'{}'", value.replace('\'', "''")))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 pub fn render_on_delete(on_delete: &ForeignKeyAction) -> &'static str {
     match on_delete {
         ForeignKeyAction::NoAction => "",
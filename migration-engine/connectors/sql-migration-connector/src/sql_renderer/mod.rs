@@ -10,6 +10,8 @@ use mysql_renderer::MySqlRenderer;
 use postgres_renderer::PostgresRenderer;
 use sqlite_renderer::SqliteRenderer;
 
+pub(crate) use common::render_enum_values;
+
 use std::fmt::Write as _;
 
 pub trait SqlRenderer {
@@ -29,12 +31,36 @@ pub trait SqlRenderer {
 
     fn quote(&self, name: &str) -> String;
 
-    fn render_column(&self, schema_name: &str, table: &Table, column: &Column, add_fk_prefix: bool) -> String;
+    /// The `next_schema` is the schema the column is part of, used to look up the values of enum columns.
+    fn render_column(
+        &self,
+        schema_name: &str,
+        table: &Table,
+        column: &Column,
+        add_fk_prefix: bool,
+        next_schema: &SqlSchema,
+    ) -> String;
 
     fn render_column_type(&self, t: &ColumnType) -> String;
 
+    /// Renders the `ALTER TABLE` clauses converting an existing column to `column` in place, keeping its
+    /// data. Returns `None` when the database can't alter columns, and the table has to be recreated.
+    fn render_alter_column(
+        &self,
+        _schema_name: &str,
+        _table: &Table,
+        _column: &Column,
+        _next_schema: &SqlSchema,
+    ) -> Option<Vec<String>> {
+        None
+    }
+
     fn render_references(&self, schema_name: &str, foreign_key: &ForeignKey) -> String;
 
+    fn render_string_literal(&self, value: &str) -> String {
+        format!("'{}'", value.replace('\'', "''"))
+    }
+
     fn sql_family(&self) -> SqlFamily;
 }
 
@@ -20,36 +20,78 @@ impl super::SqlRenderer for MySqlRenderer {
         format!("`{}`", name)
     }
 
-    fn render_column(&self, _schema_name: &str, table: &Table, column: &Column, _add_fk_prefix: bool) -> String {
+    fn render_column(
+        &self,
+        _schema_name: &str,
+        table: &Table,
+        column: &Column,
+        _add_fk_prefix: bool,
+        next_schema: &SqlSchema,
+    ) -> String {
         let column_name = self.quote(&column.name);
-        let tpe_str = self.render_column_type(&column.tpe);
+        let tpe_str = match next_schema.column_enum(column) {
+            Some(inum) => format!("ENUM({})", render_enum_values(&inum.values)),
+            None => self.render_column_type(&column.tpe),
+        };
         let nullability_str = render_nullability(&column);
         let default_str = render_default(&column);
         let foreign_key = table.foreign_key_for_column(&column.name);
         let auto_increment_str = if column.auto_increment { "AUTO_INCREMENT" } else { "" };
+        let comment_str = column
+            .comment
+            .as_ref()
+            .map(|comment| format!(" COMMENT {}", self.render_string_literal(comment)))
+            .unwrap_or_default();
 
         match foreign_key {
-            Some(_) => format!("{} {} {} {}", column_name, tpe_str, nullability_str, default_str),
+            Some(_) => format!(
+                "{} {} {} {}{}",
+                column_name, tpe_str, nullability_str, default_str, comment_str
+            ),
             None => format!(
-                "{} {} {} {} {}",
-                column_name, tpe_str, nullability_str, default_str, auto_increment_str
+                "{} {} {} {} {}{}",
+                column_name, tpe_str, nullability_str, default_str, auto_increment_str, comment_str
             ),
         }
     }
 
     fn render_column_type(&self, t: &ColumnType) -> String {
+        // Native types are rendered as they were specified.
+        if !t.raw.is_empty() && !is_enum(t) {
+            return t.raw.clone();
+        }
+
         match &t.family {
+            ColumnTypeFamily::Binary => format!("longblob"),
             ColumnTypeFamily::Boolean => format!("boolean"),
             ColumnTypeFamily::DateTime => format!("datetime(3)"),
             ColumnTypeFamily::Float => format!("Decimal(65,30)"),
+            ColumnTypeFamily::Decimal => format!("decimal(65,30)"),
             ColumnTypeFamily::Int => format!("int"),
+            ColumnTypeFamily::Json => format!("json"),
             // we use varchar right now as mediumtext doesn't allow default values
             // a bigger length would not allow to use such a column as primary key
             ColumnTypeFamily::String => format!("varchar{}", VARCHAR_LENGTH_PREFIX),
+            ColumnTypeFamily::Uuid => format!("char(36)"),
+            // Enum columns are rendered with their values when the enum is known.
+            ColumnTypeFamily::Enum(_) => format!("varchar{}", VARCHAR_LENGTH_PREFIX),
             x => unimplemented!("{:?} not handled yet", x),
         }
     }
 
+    fn render_alter_column(
+        &self,
+        schema_name: &str,
+        table: &Table,
+        column: &Column,
+        next_schema: &SqlSchema,
+    ) -> Option<Vec<String>> {
+        Some(vec![format!(
+            "MODIFY COLUMN {}",
+            self.render_column(schema_name, table, column, true, next_schema)
+        )])
+    }
+
     fn render_references(&self, schema_name: &str, foreign_key: &ForeignKey) -> String {
         use itertools::Itertools;
 
@@ -67,4 +109,9 @@ impl super::SqlRenderer for MySqlRenderer {
             render_on_delete(&foreign_key.on_delete_action)
         )
     }
+
+    /// Backslashes are escape characters in MySQL string literals.
+    fn render_string_literal(&self, value: &str) -> String {
+        format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''"))
+    }
 }
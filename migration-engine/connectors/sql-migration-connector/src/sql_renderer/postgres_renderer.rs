@@ -17,15 +17,38 @@ impl super::SqlRenderer for PostgresRenderer {
         format!("\"{}\"", name)
     }
 
-    fn render_column(&self, _schema_name: &str, _table: &Table, column: &Column, _add_fk_prefix: bool) -> String {
+    fn render_column(
+        &self,
+        schema_name: &str,
+        table: &Table,
+        column: &Column,
+        _add_fk_prefix: bool,
+        _next_schema: &SqlSchema,
+    ) -> String {
         let column_name = self.quote(&column.name);
-        let tpe_str = self.render_column_type(&column.tpe);
+        let tpe_str = render_column_type_in_schema(self, schema_name, column);
         let nullability_str = render_nullability(&column);
         let default_str = render_default(&column);
         let is_serial = column.auto_increment;
 
-        if is_serial {
-            format!("{} SERIAL", column_name)
+        if let (true, Some(sequence)) = (is_serial, identity_sequence(table, column)) {
+            // Identity columns take the options of their sequence, unlike `SERIAL` ones.
+            format!(
+                "{} {} GENERATED BY DEFAULT AS IDENTITY (SEQUENCE NAME {} START WITH {} INCREMENT BY {}) NOT NULL",
+                column_name,
+                tpe_str,
+                self.quote_with_schema(schema_name, &sequence.name),
+                sequence.initial_value,
+                sequence.allocation_size,
+            )
+        } else if is_serial {
+            let serial = match column.tpe.raw.as_str() {
+                "int2" => "SMALLSERIAL",
+                "int8" => "BIGSERIAL",
+                _ => "SERIAL",
+            };
+
+            format!("{} {}", column_name, serial)
         } else {
             format!("{} {} {} {}", column_name, tpe_str, nullability_str, default_str)
         }
@@ -38,16 +61,70 @@ impl super::SqlRenderer for PostgresRenderer {
             _ => "",
         };
 
+        // Native types are rendered as they were specified. Described array types have a leading underscore.
+        if !t.raw.is_empty() && !is_enum(t) {
+            let raw = if t.arity == ColumnArity::List {
+                t.raw.trim_start_matches('_')
+            } else {
+                t.raw.as_str()
+            };
+
+            return format!("{} {}", raw, array);
+        }
+
         match &t.family {
+            ColumnTypeFamily::Binary => format!("bytea {}", array),
             ColumnTypeFamily::Boolean => format!("boolean {}", array),
             ColumnTypeFamily::DateTime => format!("timestamp(3) {}", array),
             ColumnTypeFamily::Float => format!("Decimal(65,30) {}", array),
+            ColumnTypeFamily::Decimal => format!("numeric(65,30) {}", array),
             ColumnTypeFamily::Int => format!("integer {}", array),
+            ColumnTypeFamily::Json => format!("jsonb {}", array),
             ColumnTypeFamily::String => format!("text {}", array),
+            ColumnTypeFamily::Uuid => format!("uuid {}", array),
+            ColumnTypeFamily::Enum(enum_name) => format!("{} {}", self.quote(enum_name), array),
             x => unimplemented!("{:?} not handled yet", x),
         }
     }
 
+    /// The default is dropped first, as it may not be castable to the new type.
+    fn render_alter_column(
+        &self,
+        schema_name: &str,
+        _table: &Table,
+        column: &Column,
+        _next_schema: &SqlSchema,
+    ) -> Option<Vec<String>> {
+        let column_name = self.quote(&column.name);
+        let tpe_str = render_column_type_in_schema(self, schema_name, column);
+        let tpe_str = tpe_str.trim();
+        let nullability_str = if column.is_required() { "SET" } else { "DROP" };
+
+        // Enums can only be cast from text, e.g. when the column had another enum type.
+        let text_cast = match (&column.tpe.family, &column.tpe.arity) {
+            (ColumnTypeFamily::Enum(_), ColumnArity::List) => "::text[]",
+            (ColumnTypeFamily::Enum(_), _) => "::text",
+            _ => "",
+        };
+
+        let mut clauses = vec![
+            format!("ALTER COLUMN {} DROP DEFAULT", column_name),
+            format!(
+                "ALTER COLUMN {name} TYPE {tpe} USING {name}{text_cast}::{tpe}",
+                name = column_name,
+                text_cast = text_cast,
+                tpe = tpe_str
+            ),
+            format!("ALTER COLUMN {} {} NOT NULL", column_name, nullability_str),
+        ];
+
+        if column.default.is_some() {
+            clauses.push(format!("ALTER COLUMN {} SET {}", column_name, render_default(column)));
+        }
+
+        Some(clauses)
+    }
+
     fn render_references(&self, schema_name: &str, foreign_key: &ForeignKey) -> String {
         use itertools::Itertools;
 
@@ -66,3 +143,25 @@ impl super::SqlRenderer for PostgresRenderer {
         )
     }
 }
+
+/// The enum types are created in the schema of the tables.
+fn render_column_type_in_schema(renderer: &PostgresRenderer, schema_name: &str, column: &Column) -> String {
+    use super::SqlRenderer;
+
+    match &column.tpe.family {
+        ColumnTypeFamily::Enum(enum_name) if column.tpe.arity == ColumnArity::List => {
+            format!("{}[]", renderer.quote_with_schema(schema_name, enum_name))
+        }
+        ColumnTypeFamily::Enum(enum_name) => renderer.quote_with_schema(schema_name, enum_name),
+        _ => renderer.render_column_type(&column.tpe),
+    }
+}
+
+/// The sequence declared for the primary key column with `@sequence`. Without one, the column is `SERIAL`.
+fn identity_sequence<'a>(table: &'a Table, column: &Column) -> Option<&'a Sequence> {
+    table
+        .primary_key
+        .as_ref()
+        .filter(|pk| pk.is_single_primary_key(&column.name))
+        .and_then(|pk| pk.sequence.as_ref())
+}
@@ -18,7 +18,14 @@ impl super::SqlRenderer for SqliteRenderer {
         write!(buf, r#""{}""#, name)
     }
 
-    fn render_column(&self, _schema_name: &str, _table: &Table, column: &Column, _add_fk_prefix: bool) -> String {
+    fn render_column(
+        &self,
+        _schema_name: &str,
+        _table: &Table,
+        column: &Column,
+        _add_fk_prefix: bool,
+        next_schema: &SqlSchema,
+    ) -> String {
         let column_name = self.quote(&column.name);
         let tpe_str = self.render_column_type(&column.tpe);
         let nullability_str = render_nullability(&column);
@@ -28,20 +35,36 @@ impl super::SqlRenderer for SqliteRenderer {
         } else {
             ""
         };
+        // SQLite has no enum type, the values are enforced with a check constraint. The describer
+        // reads enums back from this exact constraint.
+        let check_str = match next_schema.column_enum(column) {
+            Some(inum) => format!("CHECK ({} IN ({}))", column_name, render_enum_values(&inum.values)),
+            None => String::new(),
+        };
 
         format!(
-            "{} {} {} {} {}",
-            column_name, tpe_str, nullability_str, default_str, auto_increment_str
+            "{} {} {} {} {} {}",
+            column_name, tpe_str, nullability_str, default_str, auto_increment_str, check_str
         )
     }
 
     fn render_column_type(&self, t: &ColumnType) -> String {
+        // Native types are rendered as they were specified.
+        if !t.raw.is_empty() && !is_enum(t) {
+            return t.raw.clone();
+        }
+
         match &t.family {
+            ColumnTypeFamily::Binary => format!("BLOB"),
             ColumnTypeFamily::Boolean => format!("BOOLEAN"),
             ColumnTypeFamily::DateTime => format!("DATE"),
             ColumnTypeFamily::Float => format!("REAL"),
+            ColumnTypeFamily::Decimal => format!("DECIMAL(65,30)"),
             ColumnTypeFamily::Int => format!("INTEGER"),
+            ColumnTypeFamily::Json => format!("TEXT"),
             ColumnTypeFamily::String => format!("TEXT"),
+            ColumnTypeFamily::Uuid => format!("TEXT"),
+            ColumnTypeFamily::Enum(_) => format!("TEXT"),
             x => unimplemented!("{:?} not handled yet", x),
         }
     }
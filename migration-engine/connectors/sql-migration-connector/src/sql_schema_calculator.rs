@@ -2,7 +2,6 @@ use crate::SqlResult;
 use chrono::*;
 use datamodel::common::*;
 use datamodel::*;
-use itertools::Itertools;
 use prisma_models::{DatamodelConverter, TempManifestationHolder, TempRelationHolder};
 use sql_schema_describer as sql;
 use sql_schema_describer::ColumnArity;
@@ -31,44 +30,73 @@ impl<'a> SqlSchemaCalculator<'a> {
             table.columns.sort_unstable_by_key(|col| col.name.clone());
         }
 
-        let enums = Vec::new();
-        let sequences = Vec::new();
+        let enums = self.calculate_enums();
+        let sequences = tables
+            .iter()
+            .filter_map(|table| table.primary_key.as_ref().and_then(|pk| pk.sequence.clone()))
+            .collect();
 
         Ok(sql::SqlSchema {
             tables,
             enums,
             sequences,
+            views: vec![],
         })
     }
 
+    fn calculate_enums(&self) -> Vec<sql::Enum> {
+        self.data_model
+            .enums()
+            .map(|inum| sql::Enum {
+                name: inum.db_name().to_owned(),
+                values: inum.values.clone(),
+            })
+            .collect()
+    }
+
     fn calculate_model_tables(&self) -> SqlResult<Vec<ModelTable>> {
         self.data_model
             .models()
+            // Views are managed by the user, the migration engine leaves them alone.
+            .filter(|model| !model.is_view)
             .map(|model| {
+                if let Some(schema) = &model.schema {
+                    return Err(crate::SqlError::Generic(format!(
+                        "The model `{model_name}` is in the schema `{schema}`. Migrations only manage the tables of the schema of the connection.",
+                        model_name = model.name,
+                        schema = schema,
+                    )));
+                }
+
                 let columns = model
                     .fields()
                     .flat_map(|f| match &f.field_type {
-                        FieldType::Base(_) | FieldType::Enum(_) => Some(sql::Column {
-                            name: f.db_name(),
-                            tpe: column_type(f),
-                            default: f.migration_value_new(&self.data_model),
-                            auto_increment: {
-                                match f.id_info {
-                                    Some(IdInfo {
-                                        strategy: IdStrategy::Auto,
-                                        sequence: _,
-                                    }) if column_type(f).family == sql::ColumnTypeFamily::Int => true,
-                                    _ => false,
-                                }
-                            },
-                        }),
+                        FieldType::Base(_) | FieldType::ConnectorSpecific(_) | FieldType::Enum(_) => {
+                            Some(sql::Column {
+                                name: f.db_name(),
+                                tpe: column_type(f, &self.data_model),
+                                default: f.migration_value_new(&self.data_model),
+                                auto_increment: {
+                                    match f.id_info {
+                                        Some(IdInfo {
+                                            strategy: IdStrategy::Auto,
+                                            sequence: _,
+                                        }) if column_type(f, &self.data_model).family == sql::ColumnTypeFamily::Int => {
+                                            true
+                                        }
+                                        _ => false,
+                                    }
+                                },
+                                comment: f.documentation.clone(),
+                            })
+                        }
                         _ => None,
                     })
                     .collect();
 
                 let primary_key = sql::PrimaryKey {
                     columns: id_fields(model).map(|field| field.db_name()).collect(),
-                    sequence: None,
+                    sequence: id_sequence(model),
                 };
 
                 let single_field_indexes = model.fields().filter_map(|f| {
@@ -90,17 +118,22 @@ impl<'a> SqlSchemaCalculator<'a> {
                         .map(|field_name| model.find_field(field_name).expect("Unknown field in index directive."))
                         .collect();
 
+                    // The model index definition uses the model field names, but the SQL Index
+                    // wants the column names. Relation fields can have multiple columns.
+                    let columns: Vec<String> = referenced_fields
+                        .iter()
+                        .flat_map(|field| match field.field_type {
+                            FieldType::Relation(_) => self.data_model.relation_columns(field),
+                            _ => vec![field.db_name()],
+                        })
+                        .collect();
+
                     sql::Index {
-                        name: index_definition.name.clone().unwrap_or_else(|| {
-                            format!(
-                                "{}.{}",
-                                &model.db_name(),
-                                referenced_fields.iter().map(|field| field.db_name()).join("_")
-                            )
-                        }),
-                        // The model index definition uses the model field names, but the SQL Index
-                        // wants the column names.
-                        columns: referenced_fields.iter().map(|field| field.db_name()).collect(),
+                        name: index_definition
+                            .name
+                            .clone()
+                            .unwrap_or_else(|| format!("{}.{}", &model.db_name(), columns.join("_"))),
+                        columns,
                         tpe: if index_definition.tpe == IndexType::Unique {
                             sql::IndexType::Unique
                         } else {
@@ -115,6 +148,18 @@ impl<'a> SqlSchemaCalculator<'a> {
                     indices: single_field_indexes.chain(multiple_field_indexes).collect(),
                     primary_key: Some(primary_key),
                     foreign_keys: Vec::new(),
+                    check_constraints: model
+                        .check_constraints
+                        .iter()
+                        .map(|check| sql::CheckConstraint {
+                            name: check
+                                .name
+                                .clone()
+                                .unwrap_or_else(|| default_check_constraint_name(&model.db_name(), &check.expression)),
+                            expression: check.expression.clone(),
+                        })
+                        .collect(),
+                    comment: model.documentation.clone(),
                 };
 
                 Ok(ModelTable {
@@ -133,7 +178,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                 match &relation.manifestation {
                     TempManifestationHolder::Inline {
                         in_table_of_model,
-                        column: column_name,
+                        columns: column_names,
                         referenced_fields,
                     } if in_table_of_model == &model_table.model.name => {
                         let (model, related_model) = if model_table.model == relation.model_a {
@@ -142,18 +187,19 @@ impl<'a> SqlSchemaCalculator<'a> {
                             (&relation.model_b, &relation.model_a)
                         };
 
-                        let field = model.fields().find(|f| &f.db_name() == column_name).unwrap();
+                        // Both fields of a self relation are on the model, the one holding the columns is inlined.
+                        let field = vec![&relation.field_a, &relation.field_b]
+                            .into_iter()
+                            .find(|f| &self.data_model.relation_columns(f) == column_names)
+                            .unwrap();
 
                         let referenced_fields: Vec<&Field> = if referenced_fields.is_empty() {
                             id_fields(related_model).collect()
                         } else {
-                            let fields: Vec<_> = related_model
-                                .fields()
-                                .filter(|field| {
-                                    referenced_fields
-                                        .iter()
-                                        .any(|referenced| referenced.as_str() == field.name)
-                                })
+                            // In the order of the references, the foreign key columns are in the same order.
+                            let fields: Vec<_> = referenced_fields
+                                .iter()
+                                .filter_map(|referenced| related_model.find_field(referenced))
                                 .collect();
 
                             if fields.len() != referenced_fields.len() {
@@ -167,36 +213,35 @@ impl<'a> SqlSchemaCalculator<'a> {
                             fields
                         };
 
-                        let columns: Vec<sql::Column> = if referenced_fields.len() == 1 {
-                            let referenced_field = referenced_fields.iter().next().unwrap();
+                        if column_names.len() != referenced_fields.len() {
+                            return Err(crate::SqlError::Generic(format!(
+                                "The relation field `{field_name}` on `{model_name}` has {columns} columns, but references {fields} fields",
+                                field_name = field.name,
+                                model_name = model.name,
+                                columns = column_names.len(),
+                                fields = referenced_fields.len(),
+                            )));
+                        }
 
-                            vec![sql::Column {
+                        let columns: Vec<sql::Column> = column_names
+                            .iter()
+                            .zip(referenced_fields.iter())
+                            .map(|(column_name, referenced_field)| sql::Column {
                                 name: column_name.clone(),
-                                tpe: column_type_for_scalar_type(
-                                    scalar_type_for_field(referenced_field),
-                                    column_arity(&field),
-                                ),
+                                tpe: column_type_with_arity(referenced_field, column_arity(&field), &self.data_model),
                                 default: None,
                                 auto_increment: false,
-                            }]
-                        } else {
-                            referenced_fields
-                                .iter()
-                                .map(|referenced_field| sql::Column {
-                                    name: format!("{}_{}", column_name, referenced_field.db_name()),
-                                    tpe: column_type_for_scalar_type(
-                                        scalar_type_for_field(referenced_field),
-                                        column_arity(&field),
-                                    ),
-                                    default: None,
-                                    auto_increment: false,
-                                })
-                                .collect()
-                        };
+                                // Compound foreign key columns are not documented by a single field.
+                                comment: match column_names.len() {
+                                    1 => field.documentation.clone(),
+                                    _ => None,
+                                },
+                            })
+                            .collect();
 
                         let foreign_key = sql::ForeignKey {
                             constraint_name: None,
-                            columns: columns.iter().map(|col| col.name.to_owned()).collect(),
+                            columns: column_names.clone(),
                             referenced_table: related_model.db_name().to_owned(),
                             referenced_columns: referenced_fields
                                 .iter()
@@ -208,11 +253,20 @@ impl<'a> SqlSchemaCalculator<'a> {
                             },
                         };
 
-                        model_table.table.columns.extend(columns);
-                        model_table.table.foreign_keys.push(foreign_key);
+                        // Compound foreign keys often share columns with the primary key, e.g. a tenant id.
+                        for column in columns {
+                            if !model_table.table.has_column(&column.name) {
+                                model_table.table.columns.push(column);
+                            }
+                        }
+
+                        // Foreign keys can't reference views.
+                        if !related_model.is_view {
+                            model_table.table.foreign_keys.push(foreign_key);
+                        }
 
                         if relation.is_one_to_one() {
-                            add_one_to_one_relation_unique_index(&mut model_table.table, column_name)
+                            add_one_to_one_relation_unique_index(&mut model_table.table, column_names)
                         }
                     }
                     _ => {}
@@ -228,10 +282,12 @@ impl<'a> SqlSchemaCalculator<'a> {
         for relation in self.calculate_relations().iter() {
             match &relation.manifestation {
                 TempManifestationHolder::Table => {
-                    let a_columns = relation_table_columns(&relation.model_a, relation.model_a_column());
-                    let mut b_columns = relation_table_columns(&relation.model_b, relation.model_b_column());
+                    let a_columns =
+                        relation_table_columns(&relation.model_a, relation.model_a_column(), &self.data_model);
+                    let mut b_columns =
+                        relation_table_columns(&relation.model_b, relation.model_b_column(), &self.data_model);
 
-                    let foreign_keys = vec![
+                    let mut foreign_keys = vec![
                         sql::ForeignKey {
                             constraint_name: None,
                             columns: a_columns.iter().map(|col| col.name.clone()).collect(),
@@ -248,6 +304,14 @@ impl<'a> SqlSchemaCalculator<'a> {
                         },
                     ];
 
+                    // Foreign keys can't reference views.
+                    foreign_keys.retain(|foreign_key| {
+                        !self
+                            .data_model
+                            .models()
+                            .any(|model| model.is_view && model.db_name() == foreign_key.referenced_table)
+                    });
+
                     let mut columns = a_columns;
                     columns.append(&mut b_columns);
 
@@ -264,6 +328,8 @@ impl<'a> SqlSchemaCalculator<'a> {
                         indices: vec![index],
                         primary_key: None,
                         foreign_keys,
+                        check_constraints: vec![],
+                        comment: None,
                     };
                     result.push(table);
                 }
@@ -278,14 +344,19 @@ impl<'a> SqlSchemaCalculator<'a> {
     }
 }
 
-fn relation_table_columns(referenced_model: &Model, reference_field_name: String) -> Vec<sql::Column> {
+fn relation_table_columns(
+    referenced_model: &Model,
+    reference_field_name: String,
+    datamodel: &Datamodel,
+) -> Vec<sql::Column> {
     if referenced_model.id_fields.is_empty() {
         let id_field = referenced_model.fields().find(|field| field.is_id()).unwrap();
         vec![sql::Column {
             name: reference_field_name,
-            tpe: column_type(id_field),
+            tpe: column_type(id_field, datamodel),
             default: None,
             auto_increment: false,
+            comment: None,
         }]
     } else {
         id_fields(referenced_model)
@@ -295,9 +366,10 @@ fn relation_table_columns(referenced_model: &Model, reference_field_name: String
                     reference_field_name = reference_field_name,
                     referenced_column_name = referenced_field.db_name()
                 ),
-                tpe: column_type(referenced_field),
+                tpe: column_type(referenced_field, datamodel),
                 default: None,
                 auto_increment: false,
+                comment: None,
             })
             .collect()
     }
@@ -319,6 +391,16 @@ impl ModelExtensions for Model {
     }
 }
 
+pub trait EnumExtensions {
+    fn db_name(&self) -> &str;
+}
+
+impl EnumExtensions for Enum {
+    fn db_name(&self) -> &str {
+        self.database_name.as_ref().unwrap_or_else(|| &self.name)
+    }
+}
+
 pub trait FieldExtensions {
     fn is_id(&self) -> bool;
 
@@ -328,7 +410,7 @@ pub trait FieldExtensions {
 
     fn migration_value(&self, datamodel: &Datamodel) -> ScalarValue;
 
-    fn migration_value_new(&self, datamodel: &Datamodel) -> Option<String>;
+    fn migration_value_new(&self, datamodel: &Datamodel) -> Option<sql::DefaultValue>;
 }
 
 impl FieldExtensions for Field {
@@ -351,12 +433,24 @@ impl FieldExtensions for Field {
             .unwrap_or_else(|| default_migration_value(&self.field_type, datamodel))
     }
 
-    fn migration_value_new(&self, datamodel: &Datamodel) -> Option<String> {
+    fn migration_value_new(&self, datamodel: &Datamodel) -> Option<sql::DefaultValue> {
+        // The database computes these, also for id fields.
+        if let Some(expression) = self.default_value.as_ref().and_then(|df| df.db_generated_expression()) {
+            return Some(sql::DefaultValue::DbGenerated(expression.to_owned()));
+        }
+
         let value = match (&self.default_value, self.arity) {
             (Some(df), _) => match df {
                 dml::DefaultValue::Single(s) => s.clone(),
                 dml::DefaultValue::Expression(_) => default_migration_value(&self.field_type, datamodel),
             },
+            // MySQL does not allow default values on JSON and BLOB columns.
+            (None, FieldArity::Required)
+                if self.field_type.scalar_type() == Some(ScalarType::Json)
+                    || self.field_type.scalar_type() == Some(ScalarType::Bytes) =>
+            {
+                return None
+            }
             // This is a temporary hack until we can report impossible unexecutable migrations.
             (None, FieldArity::Required) => default_migration_value(&self.field_type, datamodel),
             (None, _) => return None,
@@ -389,18 +483,22 @@ impl FieldExtensions for Field {
         if self.is_id() {
             None
         } else {
-            Some(result)
+            Some(sql::DefaultValue::Value(result))
         }
     }
 }
 
 fn default_migration_value(field_type: &FieldType, datamodel: &Datamodel) -> ScalarValue {
     match field_type {
+        FieldType::ConnectorSpecific(sft) => default_migration_value(&FieldType::Base(sft.prisma_type()), datamodel),
         FieldType::Base(ScalarType::Boolean) => ScalarValue::Boolean(false),
         FieldType::Base(ScalarType::Int) => ScalarValue::Int(0),
         FieldType::Base(ScalarType::Float) => ScalarValue::Float(0.0),
         FieldType::Base(ScalarType::String) => ScalarValue::String("".to_string()),
-        FieldType::Base(ScalarType::Decimal) => ScalarValue::Decimal(0.0),
+        FieldType::Base(ScalarType::Decimal) => ScalarValue::Decimal(0.into()),
+        FieldType::Base(ScalarType::Json) => ScalarValue::String("{}".to_string()),
+        FieldType::Base(ScalarType::Bytes) => ScalarValue::String("".to_string()),
+        FieldType::Base(ScalarType::Uuid) => ScalarValue::String("00000000-0000-0000-0000-000000000000".to_string()),
         FieldType::Base(ScalarType::DateTime) => {
             let naive = NaiveDateTime::from_timestamp(0, 0);
             let datetime: DateTime<Utc> = DateTime::from_utc(naive, Utc);
@@ -420,14 +518,33 @@ fn default_migration_value(field_type: &FieldType, datamodel: &Datamodel) -> Sca
     }
 }
 
-fn column_type(field: &Field) -> sql::ColumnType {
-    column_type_for_scalar_type(scalar_type_for_field(field), column_arity(field))
+fn column_type(field: &Field, datamodel: &Datamodel) -> sql::ColumnType {
+    column_type_with_arity(field, column_arity(field), datamodel)
+}
+
+/// Native types are carried over as the raw type, so they are rendered exactly as specified.
+fn column_type_with_arity(field: &Field, column_arity: ColumnArity, datamodel: &Datamodel) -> sql::ColumnType {
+    if let FieldType::Enum(enum_name) = &field.field_type {
+        let inum = datamodel
+            .find_enum(enum_name)
+            .expect(&format!("Enum {} was not present in the Datamodel.", enum_name));
+
+        return sql::ColumnType::pure(sql::ColumnTypeFamily::Enum(inum.db_name().to_owned()), column_arity);
+    }
+
+    let mut column_type = column_type_for_scalar_type(&scalar_type_for_field(field), column_arity);
+
+    if let FieldType::ConnectorSpecific(sft) = &field.field_type {
+        column_type.raw = sft.datasource_type().to_string();
+    }
+
+    column_type
 }
 
-fn scalar_type_for_field(field: &Field) -> &ScalarType {
+fn scalar_type_for_field(field: &Field) -> ScalarType {
     match &field.field_type {
-        FieldType::Base(ref scalar) => &scalar,
-        FieldType::Enum(_) => &ScalarType::String,
+        FieldType::Base(scalar) => *scalar,
+        FieldType::ConnectorSpecific(sft) => sft.prisma_type(),
         x => panic!(format!(
             "This field type is not suported here. Field type is {:?} on field {}",
             x, field.name
@@ -450,20 +567,49 @@ fn column_type_for_scalar_type(scalar_type: &ScalarType, column_arity: ColumnAri
         ScalarType::Boolean => sql::ColumnType::pure(sql::ColumnTypeFamily::Boolean, column_arity),
         ScalarType::String => sql::ColumnType::pure(sql::ColumnTypeFamily::String, column_arity),
         ScalarType::DateTime => sql::ColumnType::pure(sql::ColumnTypeFamily::DateTime, column_arity),
-        ScalarType::Decimal => unimplemented!(),
+        ScalarType::Decimal => sql::ColumnType::pure(sql::ColumnTypeFamily::Decimal, column_arity),
+        ScalarType::Json => sql::ColumnType::pure(sql::ColumnTypeFamily::Json, column_arity),
+        ScalarType::Bytes => sql::ColumnType::pure(sql::ColumnTypeFamily::Binary, column_arity),
+        ScalarType::Uuid => sql::ColumnType::pure(sql::ColumnTypeFamily::Uuid, column_arity),
     }
 }
 
-fn add_one_to_one_relation_unique_index(table: &mut sql::Table, column_name: &str) {
+fn add_one_to_one_relation_unique_index(table: &mut sql::Table, column_names: &[String]) {
     let index = sql::Index {
-        name: format!("{}_{}", table.name, column_name),
-        columns: vec![column_name.to_string()],
+        name: format!("{}_{}", table.name, column_names.join("_")),
+        columns: column_names.to_vec(),
         tpe: sql::IndexType::Unique,
     };
 
     table.indices.push(index);
 }
 
+/// Unnamed check constraints get a name derived from their expression. Check constraints are
+/// matched by name when diffing, so changing the expression of an unnamed constraint replaces it.
+fn default_check_constraint_name(table_name: &str, expression: &str) -> String {
+    // FNV-1a, because the name has to be stable across compiler versions and platforms.
+    let hash = expression.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+
+    format!("{}_{:08x}_check", table_name, hash as u32)
+}
+
+/// The sequence declared with `@sequence` on the autoincrementing id of the model.
+fn id_sequence(model: &Model) -> Option<sql::Sequence> {
+    model
+        .fields()
+        .filter_map(|field| field.id_info.as_ref())
+        .filter(|id_info| id_info.strategy == IdStrategy::Auto)
+        .filter_map(|id_info| id_info.sequence.as_ref())
+        .map(|sequence| sql::Sequence {
+            name: sequence.name.clone(),
+            initial_value: sequence.initial_value as u32,
+            allocation_size: sequence.allocation_size as u32,
+        })
+        .next()
+}
+
 fn id_fields(model: &Model) -> impl Iterator<Item = &Field> {
     // Single-id models
     model
@@ -6,12 +6,15 @@ use log::debug;
 use sql_schema_describer::*;
 use table::TableDiffer;
 
+pub(crate) use column::column_can_be_altered_in_place;
+
 const MIGRATION_TABLE_NAME: &str = "_Migration";
 
 #[derive(Debug)]
 pub struct SqlSchemaDiffer<'a> {
     previous: &'a SqlSchema,
     next: &'a SqlSchema,
+    sql_family: SqlFamily,
 }
 
 #[derive(Debug, Clone)]
@@ -23,17 +26,28 @@ pub struct SqlSchemaDiff {
     pub create_indexes: Vec<CreateIndex>,
     pub drop_indexes: Vec<DropIndex>,
     pub alter_indexes: Vec<AlterIndex>,
+    pub create_enums: Vec<CreateEnum>,
+    pub drop_enums: Vec<DropEnum>,
+    pub alter_enums: Vec<AlterEnum>,
+    pub alter_comments: Vec<AlterComment>,
+    pub alter_sequences: Vec<AlterSequence>,
 }
 
 impl SqlSchemaDiff {
     pub fn into_steps(self) -> Vec<SqlMigrationStep> {
-        wrap_as_step(self.drop_indexes, SqlMigrationStep::DropIndex)
+        // Order matters: enums must exist before the columns using them are created.
+        wrap_as_step(self.create_enums, SqlMigrationStep::CreateEnum)
+            .chain(wrap_as_step(self.alter_enums, SqlMigrationStep::AlterEnum))
+            .chain(wrap_as_step(self.drop_indexes, SqlMigrationStep::DropIndex))
             // Order matters: we must create tables before `alter_table`s because we could
             // be adding foreign keys to the new tables there.
             .chain(wrap_as_step(self.create_tables, SqlMigrationStep::CreateTable))
             // Order matters: we must run `alter table`s before `drop`s because we want to
             // drop foreign keys before the tables they are pointing to.
             .chain(wrap_as_step(self.alter_tables, SqlMigrationStep::AlterTable))
+            .chain(wrap_as_step(self.alter_sequences, SqlMigrationStep::AlterSequence))
+            // Order matters: the tables and columns must exist before they can be commented.
+            .chain(wrap_as_step(self.alter_comments, SqlMigrationStep::AlterComment))
             // Order matters: we must create indexes after ALTER TABLEs because the indexes can be on fields that
             // are dropped/created there.
             .chain(wrap_as_step(self.create_indexes, SqlMigrationStep::CreateIndex))
@@ -42,27 +56,39 @@ impl SqlSchemaDiff {
             .chain(wrap_as_step(self.add_foreign_keys, SqlMigrationStep::AddForeignKey))
             .chain(wrap_as_step(self.drop_tables, SqlMigrationStep::DropTable))
             .chain(wrap_as_step(self.alter_indexes, SqlMigrationStep::AlterIndex))
+            // Order matters: enums can only be dropped once no column uses them anymore.
+            .chain(wrap_as_step(self.drop_enums, SqlMigrationStep::DropEnum))
             .collect()
     }
 }
 
 impl<'schema> SqlSchemaDiffer<'schema> {
-    pub fn diff(previous: &SqlSchema, next: &SqlSchema) -> SqlSchemaDiff {
-        let differ = SqlSchemaDiffer { previous, next };
+    pub fn diff(previous: &SqlSchema, next: &SqlSchema, sql_family: SqlFamily) -> SqlSchemaDiff {
+        let differ = SqlSchemaDiffer {
+            previous,
+            next,
+            sql_family,
+        };
         differ.diff_internal()
     }
 
     fn diff_internal(&self) -> SqlSchemaDiff {
         let alter_indexes: Vec<_> = self.alter_indexes();
+        let alter_tables = self.alter_tables();
 
         SqlSchemaDiff {
             add_foreign_keys: self.add_foreign_keys(),
             drop_tables: self.drop_tables(),
             create_tables: self.create_tables(),
-            alter_tables: self.alter_tables(),
+            alter_comments: self.alter_comments(&alter_tables),
+            alter_sequences: self.alter_sequences(&alter_tables),
+            alter_tables,
             create_indexes: self.create_indexes(&alter_indexes),
             drop_indexes: self.drop_indexes(&alter_indexes).collect(),
             alter_indexes,
+            create_enums: self.create_enums(),
+            drop_enums: self.drop_enums(),
+            alter_enums: self.alter_enums(),
         }
     }
 
@@ -107,9 +133,11 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                 };
 
                 let changes: Vec<TableChange> = Self::drop_foreign_keys(&differ)
+                    .chain(Self::drop_check_constraints(&differ))
                     .chain(Self::drop_columns(&differ))
                     .chain(Self::add_columns(&differ))
-                    .chain(Self::alter_columns(&differ))
+                    .chain(self.alter_columns(&differ))
+                    .chain(Self::add_check_constraints(&differ))
                     .collect();
 
                 if !changes.is_empty() {
@@ -143,7 +171,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         })
     }
 
-    fn alter_columns<'a>(table_differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
+    fn alter_columns<'a>(&'a self, table_differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
         table_differ.column_pairs().filter_map(move |column_differ| {
             let previous_fk = table_differ
                 .previous
@@ -151,7 +179,10 @@ impl<'schema> SqlSchemaDiffer<'schema> {
 
             let next_fk = table_differ.next.foreign_key_for_column(&column_differ.next.name);
 
-            if column_differ.differs_in_something() || foreign_key_changed(previous_fk, next_fk) {
+            if column_differ.differs_in_something()
+                || foreign_key_changed(previous_fk, next_fk)
+                || self.inline_enum_changed(column_differ.previous, column_differ.next)
+            {
                 let change = AlterColumn {
                     name: column_differ.previous.name.clone(),
                     column: column_differ.next.clone(),
@@ -180,6 +211,26 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             })
     }
 
+    fn add_check_constraints<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
+        differ.created_check_constraints().map(|check_constraint| {
+            let change = AddCheckConstraint {
+                check_constraint: check_constraint.clone(),
+            };
+
+            TableChange::AddCheckConstraint(change)
+        })
+    }
+
+    fn drop_check_constraints<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
+        differ.dropped_check_constraints().map(|check_constraint| {
+            let change = DropCheckConstraint {
+                name: check_constraint.name.clone(),
+            };
+
+            TableChange::DropCheckConstraint(change)
+        })
+    }
+
     fn create_indexes(&self, alter_indexes: &[AlterIndex]) -> Vec<CreateIndex> {
         let mut result = Vec::new();
         for next_table in &self.next.tables {
@@ -195,6 +246,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                     let create = CreateIndex {
                         table: next_table.name.clone(),
                         index: index.clone(),
+                        online: false,
                     };
                     result.push(create);
                 }
@@ -274,6 +326,192 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         alter_indexes
     }
 
+    fn create_enums(&self) -> Vec<CreateEnum> {
+        if !self.has_enum_types() {
+            return Vec::new();
+        }
+
+        self.next
+            .enums
+            .iter()
+            .filter(|next_enum| self.previous.get_enum(&next_enum.name).is_none())
+            .map(|next_enum| CreateEnum {
+                name: next_enum.name.clone(),
+                variants: next_enum.values.clone(),
+            })
+            .collect()
+    }
+
+    fn drop_enums(&self) -> Vec<DropEnum> {
+        if !self.has_enum_types() {
+            return Vec::new();
+        }
+
+        self.previous
+            .enums
+            .iter()
+            .filter(|previous_enum| self.next.get_enum(&previous_enum.name).is_none())
+            .map(|previous_enum| DropEnum {
+                name: previous_enum.name.clone(),
+            })
+            .collect()
+    }
+
+    fn alter_enums(&self) -> Vec<AlterEnum> {
+        if !self.has_enum_types() {
+            return Vec::new();
+        }
+
+        self.previous
+            .enums
+            .iter()
+            .filter_map(|previous_enum| {
+                let next_enum = self.next.get_enum(&previous_enum.name)?;
+
+                if previous_enum.values == next_enum.values {
+                    return None;
+                }
+
+                Some(AlterEnum {
+                    name: next_enum.name.clone(),
+                    created_variants: next_enum
+                        .values
+                        .iter()
+                        .filter(|value| !previous_enum.values.contains(value))
+                        .cloned()
+                        .collect(),
+                    dropped_variants: previous_enum
+                        .values
+                        .iter()
+                        .filter(|value| !next_enum.values.contains(value))
+                        .cloned()
+                        .collect(),
+                })
+            })
+            .collect()
+    }
+
+    /// Comments are part of the table and column definitions on MySQL, so created tables and added or
+    /// altered columns only need separate comment steps on Postgres. SQLite has no comments.
+    fn alter_comments(&self, alter_tables: &[AlterTable]) -> Vec<AlterComment> {
+        let comments_are_inline = match self.sql_family {
+            SqlFamily::Sqlite => return Vec::new(),
+            SqlFamily::Mysql => true,
+            SqlFamily::Postgres => false,
+        };
+
+        let mut result = Vec::new();
+
+        if !comments_are_inline {
+            for table in self.created_tables() {
+                if table.comment.is_some() {
+                    result.push(table_comment(table));
+                }
+
+                result.extend(
+                    table
+                        .columns
+                        .iter()
+                        .filter(|column| column.comment.is_some())
+                        .map(|column| column_comment(table, column)),
+                );
+            }
+        }
+
+        for differ in self.table_pairs() {
+            if differ.previous.comment != differ.next.comment {
+                result.push(table_comment(differ.next));
+            }
+
+            let recreated_columns: Vec<&str> = alter_tables
+                .iter()
+                .filter(|alter_table| alter_table.table.name == differ.next.name)
+                .flat_map(|alter_table| alter_table.changes.iter())
+                .filter_map(|change| match change {
+                    TableChange::AddColumn(AddColumn { column }) => Some(column.name.as_str()),
+                    TableChange::AlterColumn(AlterColumn { column, .. }) => Some(column.name.as_str()),
+                    _ => None,
+                })
+                .collect();
+
+            for column in &differ.next.columns {
+                let comment_changed = if recreated_columns.contains(&column.name.as_str()) {
+                    !comments_are_inline && column.comment.is_some()
+                } else {
+                    differ
+                        .previous
+                        .column(&column.name)
+                        .map(|previous_column| previous_column.comment != column.comment)
+                        .unwrap_or(false)
+                };
+
+                if comment_changed {
+                    result.push(column_comment(differ.next, column));
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Only Postgres ids are generated by sequences that can be declared. Columns that are recreated get
+    /// their sequence with the new column.
+    fn alter_sequences(&self, alter_tables: &[AlterTable]) -> Vec<AlterSequence> {
+        if self.sql_family != SqlFamily::Postgres {
+            return Vec::new();
+        }
+
+        self.table_pairs()
+            .filter_map(|differ| {
+                let column = autoincrement_id_column(differ.next)?;
+
+                if autoincrement_id_column(differ.previous)?.name != column.name {
+                    return None;
+                }
+
+                let is_recreated = alter_tables
+                    .iter()
+                    .filter(|alter_table| alter_table.table.name == differ.next.name)
+                    .flat_map(|alter_table| alter_table.changes.iter())
+                    .any(|change| match change {
+                        TableChange::AlterColumn(AlterColumn { name, .. }) => name == &column.name,
+                        _ => false,
+                    });
+
+                let previous = identity_sequence(differ.previous);
+                let next = identity_sequence(differ.next);
+
+                if is_recreated || previous == next {
+                    return None;
+                }
+
+                Some(AlterSequence {
+                    table: differ.next.name.clone(),
+                    column: column.name.clone(),
+                    previous: previous.cloned(),
+                    next: next.cloned(),
+                })
+            })
+            .collect()
+    }
+
+    /// Only Postgres has enum types. On MySQL and SQLite, enums are declared inline on their
+    /// column, so changing their values is a change of the column.
+    fn has_enum_types(&self) -> bool {
+        self.sql_family == SqlFamily::Postgres
+    }
+
+    fn inline_enum_changed(&self, previous: &Column, next: &Column) -> bool {
+        if self.has_enum_types() {
+            return false;
+        }
+
+        match (self.previous.column_enum(previous), self.next.column_enum(next)) {
+            (Some(previous_enum), Some(next_enum)) => previous_enum.values != next_enum.values,
+            _ => false,
+        }
+    }
+
     fn created_tables<'a>(&'a self) -> impl Iterator<Item = &'a Table> + 'a {
         self.next.tables.iter().filter(move |next_table| {
             !self.previous.has_table(&next_table.name) && next_table.name != MIGRATION_TABLE_NAME
@@ -305,6 +543,42 @@ fn push_foreign_keys_from_created_tables<'a>(
     }
 }
 
+/// The autoincrementing column of a single column primary key.
+fn autoincrement_id_column(table: &Table) -> Option<&Column> {
+    match table.primary_key.as_ref()?.columns.as_slice() {
+        [column_name] => table.column(column_name).filter(|column| column.auto_increment),
+        _ => None,
+    }
+}
+
+/// The sequence of an autoincrementing id column created as identity column. The sequence Postgres creates
+/// for a `SERIAL` column is not declared with `@sequence`, so it does not count.
+pub(crate) fn identity_sequence(table: &Table) -> Option<&Sequence> {
+    let column = autoincrement_id_column(table)?;
+
+    table.primary_key.as_ref()?.sequence.as_ref().filter(|sequence| {
+        sequence.name != format!("{}_{}_seq", table.name, column.name)
+            || sequence.initial_value != 1
+            || sequence.allocation_size != 1
+    })
+}
+
+fn table_comment(table: &Table) -> AlterComment {
+    AlterComment {
+        table: table.name.clone(),
+        column: None,
+        comment: table.comment.clone(),
+    }
+}
+
+fn column_comment(table: &Table, column: &Column) -> AlterComment {
+    AlterComment {
+        table: table.name.clone(),
+        column: Some(column.name.clone()),
+        comment: column.comment.clone(),
+    }
+}
+
 /// Compare two [ForeignKey](/sql-schema-describer/struct.ForeignKey.html)s and return whether a
 /// migration needs to be applied.
 fn foreign_key_changed(previous: Option<&ForeignKey>, next: Option<&ForeignKey>) -> bool {
@@ -1,4 +1,4 @@
-use sql_schema_describer::{Column, ColumnTypeFamily};
+use sql_schema_describer::{Column, ColumnArity, ColumnTypeFamily, DefaultValue};
 
 #[derive(Debug)]
 pub(crate) struct ColumnDiffer<'a> {
@@ -9,13 +9,24 @@ pub(crate) struct ColumnDiffer<'a> {
 impl<'a> ColumnDiffer<'a> {
     pub(crate) fn differs_in_something(&self) -> bool {
         self.previous.name != self.next.name
-            // TODO: compare the whole type
-            // || self.previous.tpe != self.next.tpe
-            || self.previous.tpe.family != self.next.tpe.family
+            || !self.types_match()
             || self.previous.tpe.arity != self.next.tpe.arity
             || !self.defaults_match()
     }
 
+    /// Columns with a native type are compared by their raw type. Their family may not match the
+    /// family of the field type they are used with, e.g. `xml` columns for `String` fields, or the
+    /// `Decimal(65,30)` columns of `Float` fields, which are described as decimal columns.
+    fn types_match(&self) -> bool {
+        if self.previous.tpe.raw.is_empty() || self.next.tpe.raw.is_empty() {
+            return self.previous.tpe.family == self.next.tpe.family
+                || is_float_stored_as_decimal(self.previous, self.next)
+                || is_float_stored_as_decimal(self.next, self.previous);
+        }
+
+        native_types_match(&self.previous.tpe.raw, &self.next.tpe.raw)
+    }
+
     /// There are workarounds to cope with current migration and introspection limitations.
     ///
     /// - Since the values we set and introspect for timestamps are stringly typed, matching exactly the default value strings does not work on any database. Therefore we consider all datetime defaults as the same.
@@ -23,19 +34,29 @@ impl<'a> ColumnDiffer<'a> {
     /// - Postgres autoincrement fields get inferred with a default, which we want to ignore.
     ///
     /// - We bail on a number of cases that are too complex to deal with right now or underspecified, like strings containing escaped characters.
+    ///
+    /// - Databases rewrite the expressions of database generated defaults, e.g. Postgres turns `now() at time zone 'utc'` into `timezone('utc'::text, now())`, so they are compared after `normalize_expression`.
     fn defaults_match(&self) -> bool {
         if self.previous.auto_increment {
             return true;
         }
 
-        debug_assert_eq!(self.previous.tpe.family, self.next.tpe.family);
+        match (self.previous.db_generated_default(), self.next.db_generated_default()) {
+            (Some(previous), Some(next)) => return normalize_expression(previous) == normalize_expression(next),
+            (Some(_), None) | (None, Some(_)) => return false,
+            (None, None) => (),
+        }
 
-        let previous_value: Option<&str> = self.previous.default.as_ref().map(String::as_str);
-        let next_value: Option<&str> = self.next.default.as_ref().map(String::as_str);
+        debug_assert!(self.types_match());
+
+        let previous_value = self.previous.default.as_ref().and_then(DefaultValue::as_value);
+        let next_value = self.next.default.as_ref().and_then(DefaultValue::as_value);
 
         match self.previous.tpe.family {
-            ColumnTypeFamily::String => string_defaults_match(previous_value, next_value),
-            ColumnTypeFamily::Float => float_default(previous_value) == float_default(next_value),
+            ColumnTypeFamily::String | ColumnTypeFamily::Enum(_) => string_defaults_match(previous_value, next_value),
+            ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => {
+                float_default(previous_value) == float_default(next_value)
+            }
             ColumnTypeFamily::Int => int_default(previous_value) == int_default(next_value),
             ColumnTypeFamily::Boolean => bool_default(previous_value) == bool_default(next_value),
             _ => true,
@@ -43,6 +64,167 @@ impl<'a> ColumnDiffer<'a> {
     }
 }
 
+/// Whether the databases can convert the column to the next column in place, keeping its data, instead of
+/// dropping and recreating it. This is the case for changes between text and enum columns. Values that are
+/// not part of the next enum make the conversion fail, they are reported by the destructive changes checker.
+pub(crate) fn column_can_be_altered_in_place(previous: &Column, next: &Column) -> bool {
+    let is_list = |column: &Column| column.tpe.arity == ColumnArity::List;
+
+    if is_list(previous) != is_list(next) || previous.auto_increment != next.auto_increment {
+        return false;
+    }
+
+    match (&previous.tpe.family, &next.tpe.family) {
+        (ColumnTypeFamily::String, ColumnTypeFamily::Enum(_))
+        | (ColumnTypeFamily::Enum(_), ColumnTypeFamily::String)
+        | (ColumnTypeFamily::Enum(_), ColumnTypeFamily::Enum(_)) => true,
+        _ => false,
+    }
+}
+
+/// Whether `float` is the column of a `Float` field and `decimal` the described `Decimal(65,30)` column
+/// it is stored in on Postgres and MySQL. Postgres does not report the arguments.
+fn is_float_stored_as_decimal(float: &Column, decimal: &Column) -> bool {
+    let raw = normalize_native_type(&decimal.tpe.raw);
+    let arguments = &raw[native_type_name(&raw).len()..];
+
+    float.tpe.family == ColumnTypeFamily::Float
+        && float.tpe.raw.is_empty()
+        && decimal.tpe.family == ColumnTypeFamily::Decimal
+        && !raw.is_empty()
+        && (arguments.is_empty() || arguments == "(65,30)")
+}
+
+/// The databases do not report the arguments of every type, e.g. `numeric` without a precision, so
+/// arguments are only compared when both types have them.
+fn native_types_match(previous: &str, next: &str) -> bool {
+    let previous = normalize_native_type(previous);
+    let next = normalize_native_type(next);
+
+    if previous.contains('(') && next.contains('(') {
+        previous == next
+    } else {
+        native_type_name(&previous) == native_type_name(&next)
+    }
+}
+
+/// Postgres reports array types with a leading underscore, e.g. `_int4`.
+fn normalize_native_type(raw: &str) -> String {
+    raw.trim().trim_start_matches('_').to_lowercase().replace(' ', "")
+}
+
+fn native_type_name(native_type: &str) -> &str {
+    native_type.split('(').next().unwrap_or(native_type)
+}
+
+/// Undoes the rewrites of the databases on the expressions of defaults and check constraints, so the
+/// expressions can be compared: Postgres turns `x at time zone 'utc'` into `timezone('utc'::text, x)`
+/// and adds casts and parentheses, MySQL adds charset introducers and escapes quotes. Case, whitespace,
+/// identifier quotes and outer parentheses are ignored too, outside of string literals.
+pub(super) fn normalize_expression(expression: &str) -> String {
+    let expression = expression.trim().replace("\\'", "'").replace("_utf8mb4'", "'");
+    let mut expression = map_outside_literals(&expression, |c| c.to_lowercase().collect());
+
+    if let Some(idx) = expression.find(" at time zone ") {
+        expression = format!(
+            "timezone({}, {})",
+            &expression[idx + " at time zone ".len()..],
+            &expression[..idx]
+        );
+    }
+
+    let mut normalized = map_outside_literals(&expression, |c| {
+        if c.is_whitespace() || c == '"' || c == '`' {
+            String::new()
+        } else {
+            c.to_string()
+        }
+    });
+
+    // Casts, e.g. `::text` or `::"Color"[]`.
+    while let Some(idx) = normalized.find("::") {
+        let cast_len = normalized[idx + 2..]
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '"' || c == '[' || c == ']'))
+            .unwrap_or(normalized.len() - idx - 2);
+
+        normalized.replace_range(idx..idx + 2 + cast_len, "");
+    }
+
+    // Parentheses around a single operand, e.g. `(price)::numeric >= (0)::numeric`.
+    while let Some((open, close)) = find_parenthesized_operand(&normalized) {
+        normalized.replace_range(close..close + 1, "");
+        normalized.replace_range(open..open + 1, "");
+    }
+
+    while is_wrapped_in_parentheses(&normalized) {
+        normalized = normalized[1..normalized.len() - 1].to_owned();
+    }
+
+    normalized
+}
+
+/// Maps the characters of the expression outside of its string literals, which are kept as they are.
+fn map_outside_literals(expression: &str, f: impl Fn(char) -> String) -> String {
+    let mut in_literal = false;
+    let mut mapped = String::with_capacity(expression.len());
+
+    for c in expression.chars() {
+        if c == '\'' {
+            in_literal = !in_literal;
+            mapped.push(c);
+        } else if in_literal {
+            mapped.push(c);
+        } else {
+            mapped.push_str(&f(c));
+        }
+    }
+
+    mapped
+}
+
+/// The positions of parentheses around a column name or a literal, that are not the parentheses of a
+/// function call.
+fn find_parenthesized_operand(expression: &str) -> Option<(usize, usize)> {
+    let is_operand_char = |c: char| c.is_alphanumeric() || c == '_' || c == '.' || c == '\'';
+
+    expression
+        .char_indices()
+        .filter(|(_, c)| *c == '(')
+        .find_map(|(open, _)| {
+            let is_call = expression[..open].chars().last().map(is_operand_char).unwrap_or(false);
+            let len = expression[open + 1..].find(|c: char| !is_operand_char(c))?;
+            let close = open + 1 + len;
+
+            if !is_call && len > 0 && expression[close..].starts_with(')') {
+                Some((open, close))
+            } else {
+                None
+            }
+        })
+}
+
+fn is_wrapped_in_parentheses(expression: &str) -> bool {
+    if !expression.starts_with('(') || !expression.ends_with(')') {
+        return false;
+    }
+
+    let mut depth = 0;
+
+    for (idx, c) in expression.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => (),
+        }
+
+        if depth == 0 {
+            return idx == expression.len() - 1;
+        }
+    }
+
+    false
+}
+
 fn float_default(s: Option<&str>) -> Option<f64> {
     s.and_then(|s| s.parse().ok())
 }
@@ -87,22 +269,25 @@ mod tests {
         let col_a = Column {
             name: "A".to_owned(),
             tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
-            default: Some("abc".to_owned()),
+            default: Some(DefaultValue::Value("abc".to_owned())),
             auto_increment: false,
+            comment: None,
         };
 
         let col_b = Column {
             name: "A".to_owned(),
             tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
-            default: Some(r##""abc""##.to_owned()),
+            default: Some(DefaultValue::Value(r##""abc""##.to_owned())),
             auto_increment: false,
+            comment: None,
         };
 
         let col_c = Column {
             name: "A".to_owned(),
             tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
-            default: Some(r##"'abc'"##.to_owned()),
+            default: Some(DefaultValue::Value(r##"'abc'"##.to_owned())),
             auto_increment: false,
+            comment: None,
         };
 
         assert!(ColumnDiffer {
@@ -124,20 +309,138 @@ mod tests {
         .defaults_match());
     }
 
+    #[test]
+    fn native_types_are_compared_by_their_raw_type() {
+        let described = Column {
+            name: "A".to_owned(),
+            tpe: ColumnType {
+                raw: "varchar(255)".to_owned(),
+                family: ColumnTypeFamily::String,
+                arity: ColumnArity::Required,
+            },
+            default: None,
+            auto_increment: false,
+            comment: None,
+        };
+
+        let same = Column {
+            tpe: ColumnType {
+                raw: "VARCHAR(255)".to_owned(),
+                ..described.tpe.clone()
+            },
+            ..described.clone()
+        };
+
+        let longer = Column {
+            tpe: ColumnType {
+                raw: "varchar(300)".to_owned(),
+                ..described.tpe.clone()
+            },
+            ..described.clone()
+        };
+
+        let uuid = Column {
+            tpe: ColumnType {
+                raw: "uuid".to_owned(),
+                family: ColumnTypeFamily::Uuid,
+                arity: ColumnArity::Required,
+            },
+            ..described.clone()
+        };
+
+        let uuid_for_string_field = Column {
+            tpe: ColumnType {
+                raw: "uuid".to_owned(),
+                ..described.tpe.clone()
+            },
+            ..described.clone()
+        };
+
+        assert!(!ColumnDiffer {
+            previous: &described,
+            next: &same,
+        }
+        .differs_in_something());
+
+        assert!(ColumnDiffer {
+            previous: &described,
+            next: &longer,
+        }
+        .differs_in_something());
+
+        assert!(!ColumnDiffer {
+            previous: &uuid,
+            next: &uuid_for_string_field,
+        }
+        .differs_in_something());
+    }
+
+    #[test]
+    fn float_fields_match_the_decimal_columns_they_are_stored_in() {
+        let described = |raw: &str| Column {
+            name: "A".to_owned(),
+            tpe: ColumnType {
+                raw: raw.to_owned(),
+                family: ColumnTypeFamily::Decimal,
+                arity: ColumnArity::Required,
+            },
+            default: None,
+            auto_increment: false,
+            comment: None,
+        };
+
+        let float = Column {
+            tpe: ColumnType::pure(ColumnTypeFamily::Float, ColumnArity::Required),
+            ..described("")
+        };
+
+        for raw in &["numeric", "decimal(65,30)"] {
+            assert!(!ColumnDiffer {
+                previous: &described(raw),
+                next: &float,
+            }
+            .differs_in_something());
+        }
+
+        assert!(ColumnDiffer {
+            previous: &described("decimal(10,2)"),
+            next: &float,
+        }
+        .differs_in_something());
+
+        assert!(ColumnDiffer {
+            previous: &Column {
+                tpe: ColumnType {
+                    raw: "real".to_owned(),
+                    family: ColumnTypeFamily::Float,
+                    arity: ColumnArity::Required,
+                },
+                ..described("")
+            },
+            next: &Column {
+                tpe: ColumnType::pure(ColumnTypeFamily::Decimal, ColumnArity::Required),
+                ..described("")
+            },
+        }
+        .differs_in_something());
+    }
+
     #[test]
     fn datetime_defaults_match() {
         let col_a = Column {
             name: "A".to_owned(),
             tpe: ColumnType::pure(ColumnTypeFamily::DateTime, ColumnArity::Required),
-            default: Some("2019-09-01T08:00:00Z".to_owned()),
+            default: Some(DefaultValue::Value("2019-09-01T08:00:00Z".to_owned())),
             auto_increment: false,
+            comment: None,
         };
 
         let col_b = Column {
             name: "A".to_owned(),
             tpe: ColumnType::pure(ColumnTypeFamily::DateTime, ColumnArity::Required),
-            default: Some("2019-09-01 18:00:00 UTC".to_owned()),
+            default: Some(DefaultValue::Value("2019-09-01 18:00:00 UTC".to_owned())),
             auto_increment: false,
+            comment: None,
         };
 
         assert!(ColumnDiffer {
@@ -152,15 +455,17 @@ mod tests {
         let col_a = Column {
             name: "A".to_owned(),
             tpe: ColumnType::pure(ColumnTypeFamily::Float, ColumnArity::Required),
-            default: Some("0.33".to_owned()),
+            default: Some(DefaultValue::Value("0.33".to_owned())),
             auto_increment: false,
+            comment: None,
         };
 
         let col_b = Column {
             name: "A".to_owned(),
             tpe: ColumnType::pure(ColumnTypeFamily::Float, ColumnArity::Required),
-            default: Some("0.33000".to_owned()),
+            default: Some(DefaultValue::Value("0.33000".to_owned())),
             auto_increment: false,
+            comment: None,
         };
 
         assert!(ColumnDiffer {
@@ -172,14 +477,133 @@ mod tests {
         let col_c = Column {
             name: "A".to_owned(),
             tpe: ColumnType::pure(ColumnTypeFamily::Float, ColumnArity::Required),
-            default: Some("0.34".to_owned()),
+            default: Some(DefaultValue::Value("0.34".to_owned())),
+            auto_increment: false,
+            comment: None,
+        };
+
+        assert!(!ColumnDiffer {
+            previous: &col_a,
+            next: &col_c,
+        }
+        .defaults_match());
+    }
+
+    #[test]
+    fn db_generated_defaults_are_compared_without_the_rewrites_of_the_database() {
+        let col_a = Column {
+            name: "A".to_owned(),
+            tpe: ColumnType::pure(ColumnTypeFamily::DateTime, ColumnArity::Required),
+            default: Some(DefaultValue::DbGenerated("now() at time zone 'utc'".to_owned())),
+            auto_increment: false,
+            comment: None,
+        };
+
+        let col_b = Column {
+            default: Some(DefaultValue::DbGenerated("timezone('utc'::text, now())".to_owned())),
+            ..col_a.clone()
+        };
+
+        let col_c = Column {
+            default: None,
+            ..col_a.clone()
+        };
+
+        let col_d = Column {
+            default: Some(DefaultValue::DbGenerated("now() at time zone 'cet'".to_owned())),
+            ..col_a.clone()
+        };
+
+        assert!(ColumnDiffer {
+            previous: &col_a,
+            next: &col_b,
+        }
+        .defaults_match());
+
+        assert!(!ColumnDiffer {
+            previous: &col_b,
+            next: &col_c,
+        }
+        .defaults_match());
+
+        assert!(!ColumnDiffer {
+            previous: &col_b,
+            next: &col_d,
+        }
+        .defaults_match());
+    }
+
+    #[test]
+    fn db_generated_defaults_differing_in_the_case_of_a_literal_do_not_match() {
+        let col_a = Column {
+            name: "A".to_owned(),
+            tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
+            default: Some(DefaultValue::DbGenerated("CONCAT('Id-', uuid())".to_owned())),
             auto_increment: false,
+            comment: None,
+        };
+
+        let col_b = Column {
+            default: Some(DefaultValue::DbGenerated("concat('id-', uuid())".to_owned())),
+            ..col_a.clone()
         };
 
+        let col_c = Column {
+            default: Some(DefaultValue::DbGenerated("concat('Id-',UUID())".to_owned())),
+            ..col_a.clone()
+        };
+
+        assert!(ColumnDiffer {
+            previous: &col_a,
+            next: &col_b,
+        }
+        .differs_in_something());
+
         assert!(!ColumnDiffer {
             previous: &col_a,
             next: &col_c,
         }
+        .differs_in_something());
+    }
+
+    #[test]
+    fn values_in_parentheses_are_not_db_generated() {
+        let col_a = Column {
+            name: "A".to_owned(),
+            tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
+            default: Some(DefaultValue::Value("(none)".to_owned())),
+            auto_increment: false,
+            comment: None,
+        };
+
+        let col_b = Column {
+            default: Some(DefaultValue::DbGenerated("none".to_owned())),
+            ..col_a.clone()
+        };
+
+        assert!(col_a.db_generated_default().is_none());
+        assert!(!ColumnDiffer {
+            previous: &col_a,
+            next: &col_b,
+        }
         .defaults_match());
     }
+
+    #[test]
+    fn expressions_are_normalized() {
+        assert_eq!(normalize_expression("(gen_random_uuid())"), "gen_random_uuid()");
+        assert_eq!(normalize_expression("(now()) + (1)"), "(now())+1");
+        assert_eq!(normalize_expression("'A'::\"Color\""), "'A'");
+        assert_eq!(
+            normalize_expression("UPPER(name) <> 'Big Apple'"),
+            "upper(name)<>'Big Apple'"
+        );
+        assert_eq!(normalize_expression("((price)::numeric >= (0)::numeric)"), "price>=0");
+        assert_eq!(normalize_expression("(`price` >= 0)"), "price>=0");
+        assert_eq!(normalize_expression("length(name) > 0"), "length(name)>0");
+        assert_eq!(
+            normalize_expression("concat(_utf8mb4\\'a\\',uuid())"),
+            "concat('a',uuid())"
+        );
+    }
 }
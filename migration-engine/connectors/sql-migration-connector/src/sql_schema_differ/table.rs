@@ -1,5 +1,5 @@
-use super::column::ColumnDiffer;
-use sql_schema_describer::{Column, ForeignKey, Index, Table};
+use super::column::{normalize_expression, ColumnDiffer};
+use sql_schema_describer::{CheckConstraint, Column, ForeignKey, Index, Table};
 
 pub(crate) struct TableDiffer<'schema> {
     pub(crate) previous: &'schema Table,
@@ -49,6 +49,28 @@ impl<'schema> TableDiffer<'schema> {
         })
     }
 
+    /// Check constraints are matched by name and expression. A constraint whose expression changed is
+    /// both dropped and created.
+    pub(crate) fn created_check_constraints(&self) -> impl Iterator<Item = &CheckConstraint> {
+        self.next.check_constraints.iter().filter(move |next_check| {
+            !self
+                .previous
+                .check_constraints
+                .iter()
+                .any(|previous_check| check_constraints_match(previous_check, next_check))
+        })
+    }
+
+    pub(crate) fn dropped_check_constraints(&self) -> impl Iterator<Item = &CheckConstraint> {
+        self.previous.check_constraints.iter().filter(move |previous_check| {
+            !self
+                .next
+                .check_constraints
+                .iter()
+                .any(|next_check| check_constraints_match(previous_check, next_check))
+        })
+    }
+
     pub(crate) fn index_pairs<'a>(&'a self) -> impl Iterator<Item = (&'schema Index, &'schema Index)> + 'a {
         self.previous.indices.iter().filter_map(move |previous_index| {
             self.next
@@ -86,3 +108,8 @@ fn columns_match(a: &Column, b: &Column) -> bool {
 fn indexes_are_equivalent(first: &Index, second: &Index) -> bool {
     first.columns == second.columns && first.tpe == second.tpe
 }
+
+/// Databases rewrite the expressions of check constraints, so they are compared normalized.
+fn check_constraints_match(previous: &CheckConstraint, next: &CheckConstraint) -> bool {
+    previous.name == next.name && normalize_expression(&previous.expression) == normalize_expression(&next.expression)
+}
@@ -50,7 +50,9 @@ pub trait GenericApi: Send + Sync + 'static {
     async fn infer_migration_steps(&self, input: &InferMigrationStepsInput) -> CoreResult<MigrationStepsResultOutput>;
     async fn list_migrations(&self, input: &serde_json::Value) -> CoreResult<Vec<ListMigrationsOutput>>;
     async fn migration_progress(&self, input: &MigrationProgressInput) -> CoreResult<MigrationProgressOutput>;
+    async fn recover_migration(&self, input: &RecoverMigrationInput) -> CoreResult<RecoverMigrationOutput>;
     async fn reset(&self, input: &serde_json::Value) -> CoreResult<serde_json::Value>;
+    async fn squash_migrations(&self, input: &SquashMigrationsInput) -> CoreResult<ListMigrationsOutput>;
     async fn unapply_migration(&self, input: &UnapplyMigrationInput) -> CoreResult<UnapplyMigrationOutput>;
     fn migration_persistence(&self) -> Arc<dyn MigrationPersistence>;
     fn connector_type(&self) -> &'static str;
@@ -118,12 +120,27 @@ where
             .await
     }
 
+    async fn recover_migration(&self, input: &RecoverMigrationInput) -> CoreResult<RecoverMigrationOutput> {
+        self.handle_command::<RecoverMigrationCommand>(input)
+            .instrument(tracing::info_span!("RecoverMigration"))
+            .await
+    }
+
     async fn reset(&self, input: &serde_json::Value) -> CoreResult<serde_json::Value> {
         self.handle_command::<ResetCommand>(input)
             .instrument(tracing::info_span!("Reset"))
             .await
     }
 
+    async fn squash_migrations(&self, input: &SquashMigrationsInput) -> CoreResult<ListMigrationsOutput> {
+        self.handle_command::<SquashMigrationsCommand>(input)
+            .instrument(tracing::info_span!(
+                "SquashMigrations",
+                migration_id = input.migration_id.as_str()
+            ))
+            .await
+    }
+
     async fn unapply_migration(&self, input: &UnapplyMigrationInput) -> CoreResult<UnapplyMigrationOutput> {
         self.handle_command::<UnapplyMigrationCommand>(input)
             .instrument(tracing::info_span!("UnapplyMigration"))
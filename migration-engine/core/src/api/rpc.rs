@@ -24,6 +24,8 @@ enum RpcCommand {
     Reset,
     CalculateDatamodel,
     CalculateDatabaseSteps,
+    SquashMigrations,
+    RecoverMigration,
 }
 
 impl RpcCommand {
@@ -37,6 +39,8 @@ impl RpcCommand {
             RpcCommand::Reset => "reset",
             RpcCommand::CalculateDatamodel => "calculateDatamodel",
             RpcCommand::CalculateDatabaseSteps => "calculateDatabaseSteps",
+            RpcCommand::SquashMigrations => "squashMigrations",
+            RpcCommand::RecoverMigration => "recoverMigration",
         }
     }
 }
@@ -50,6 +54,8 @@ static AVAILABLE_COMMANDS: &[RpcCommand] = &[
     RpcCommand::Reset,
     RpcCommand::CalculateDatamodel,
     RpcCommand::CalculateDatabaseSteps,
+    RpcCommand::SquashMigrations,
+    RpcCommand::RecoverMigration,
 ];
 
 impl RpcApi {
@@ -163,6 +169,14 @@ impl RpcApi {
                 let input: CalculateDatabaseStepsInput = params.clone().parse()?;
                 render(executor.calculate_database_steps(&input).await?)
             }
+            RpcCommand::SquashMigrations => {
+                let input: SquashMigrationsInput = params.clone().parse()?;
+                render(executor.squash_migrations(&input).await?)
+            }
+            RpcCommand::RecoverMigration => {
+                let input: RecoverMigrationInput = params.clone().parse()?;
+                render(executor.recover_migration(&input).await?)
+            }
         }
     }
 }
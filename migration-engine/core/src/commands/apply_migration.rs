@@ -25,12 +25,18 @@ impl<'a> MigrationCommand for ApplyMigrationCommand<'a> {
         let connector = engine.connector();
         let migration_persistence = connector.migration_persistence();
 
-        match migration_persistence.last().await? {
-            Some(ref last_migration) if last_migration.is_watch_migration() && !cmd.input.is_watch_migration() => {
-                cmd.handle_transition_out_of_watch_mode(&engine).await
-            }
-            _ => cmd.handle_normal_migration(&engine).await,
-        }
+        engine
+            .with_migration_lock(async {
+                match migration_persistence.last().await? {
+                    Some(ref last_migration)
+                        if last_migration.is_watch_migration() && !cmd.input.is_watch_migration() =>
+                    {
+                        cmd.handle_transition_out_of_watch_mode(&engine).await
+                    }
+                    _ => cmd.handle_normal_migration(&engine).await,
+                }
+            })
+            .await
     }
 }
 
@@ -132,6 +138,10 @@ impl<'a> ApplyMigrationCommand<'a> {
             .await?;
 
         match (diagnostics.has_warnings(), self.input.force.unwrap_or(false)) {
+            // Nothing is written to the database or the migrations table in a dry run.
+            _ if self.input.dry_run.unwrap_or(false) => {
+                tracing::info!("The dryRun flag was passed, the migration will not be applied.")
+            }
             // We have no warnings, or the force flag is passed.
             (false, _) | (true, true) => {
                 tracing::debug!("Applying the migration");
@@ -148,7 +158,11 @@ impl<'a> ApplyMigrationCommand<'a> {
             (true, false) => tracing::info!("The force flag was not passed, the migration will not be applied."),
         }
 
-        let DestructiveChangeDiagnostics { warnings, errors } = diagnostics;
+        let DestructiveChangeDiagnostics {
+            warnings,
+            errors,
+            affected_rows,
+        } = diagnostics;
 
         Ok(MigrationStepsResultOutput {
             datamodel: datamodel::render_datamodel_to_string(&next_datamodel).unwrap(),
@@ -157,6 +171,7 @@ impl<'a> ApplyMigrationCommand<'a> {
             errors,
             warnings,
             general_errors: Vec::new(),
+            affected_rows,
         })
     }
 }
@@ -167,6 +182,8 @@ pub struct ApplyMigrationInput {
     pub migration_id: String,
     pub steps: Vec<MigrationStep>,
     pub force: Option<bool>,
+    /// Infer, check and render the migration without applying it or recording it in the migrations table.
+    pub dry_run: Option<bool>,
 }
 
 impl IsWatchMigration for ApplyMigrationInput {
@@ -47,7 +47,11 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
             .infer(&assumed_datamodel, &next_datamodel, &model_migration_steps)
             .await?;
 
-        let DestructiveChangeDiagnostics { warnings, errors: _ } = connector
+        let DestructiveChangeDiagnostics {
+            warnings,
+            errors: _,
+            affected_rows,
+        } = connector
             .destructive_changes_checker()
             .check(&database_migration)
             .await?;
@@ -92,6 +96,7 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
             errors: vec![],
             warnings,
             general_errors: vec![],
+            affected_rows,
         })
     }
 }
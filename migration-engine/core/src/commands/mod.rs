@@ -5,7 +5,9 @@ mod command;
 mod infer_migration_steps;
 mod list_migrations;
 mod migration_progress;
+mod recover_migration;
 mod reset;
+mod squash_migrations;
 mod unapply_migration;
 
 pub use apply_migration::*;
@@ -15,10 +17,12 @@ pub use command::*;
 pub use infer_migration_steps::*;
 pub use list_migrations::*;
 pub use migration_progress::*;
+pub use recover_migration::*;
 pub use reset::*;
+pub use squash_migrations::*;
 pub use unapply_migration::*;
 
-use migration_connector::{MigrationError, MigrationStep, MigrationWarning};
+use migration_connector::{AffectedRowsEstimate, MigrationError, MigrationStep, MigrationWarning};
 use serde::Serialize;
 
 #[derive(Debug, Serialize)]
@@ -39,4 +43,5 @@ pub struct MigrationStepsResultOutput {
     pub warnings: Vec<MigrationWarning>,
     pub errors: Vec<MigrationError>,
     pub general_errors: Vec<String>,
+    pub affected_rows: Vec<AffectedRowsEstimate>,
 }
@@ -0,0 +1,182 @@
+//! The RecoverMigration RPC method.
+
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use datamodel::{ast::SchemaAst, Datamodel};
+use migration_connector::*;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+pub struct RecoverMigrationCommand<'a> {
+    input: &'a RecoverMigrationInput,
+}
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for RecoverMigrationCommand<'a> {
+    type Input = RecoverMigrationInput;
+    type Output = RecoverMigrationOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let cmd = RecoverMigrationCommand { input };
+        debug!(?cmd.input);
+
+        engine
+            .with_migration_lock(async {
+                let migration_persistence = engine.connector().migration_persistence();
+
+                let interrupted_migration = migration_persistence
+                    .load_all()
+                    .await?
+                    .pop()
+                    .filter(|migration| is_interrupted(migration.status))
+                    .ok_or_else(|| CommandError::Input {
+                        error: "There is no interrupted migration to recover.".to_owned(),
+                    })?;
+
+                let resumed_from_step = match cmd.input.strategy {
+                    RecoveryStrategy::Resume => Some(cmd.resume(engine, &interrupted_migration).await?),
+                    RecoveryStrategy::Rollback => {
+                        cmd.roll_back(engine, &interrupted_migration).await?;
+                        None
+                    }
+                };
+
+                let recovered_migration = migration_persistence
+                    .by_name(&interrupted_migration.name)
+                    .await?
+                    .unwrap_or(interrupted_migration);
+
+                Ok(RecoverMigrationOutput {
+                    migration_id: recovered_migration.name,
+                    status: recovered_migration.status,
+                    resumed_from_step,
+                })
+            })
+            .await
+    }
+}
+
+impl<'a> RecoverMigrationCommand<'a> {
+    /// Resume the migration from the first step that did not take effect on the database. Returns the
+    /// index of that step.
+    async fn resume<C, D>(&self, engine: &MigrationEngine<C, D>, migration: &Migration) -> CommandResult<usize>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        match migration.status {
+            MigrationStatus::MigrationInProgress | MigrationStatus::MigrationFailure => (),
+            status => {
+                return Err(CommandError::Input {
+                    error: format!(
+                        "The migration `{}` was being rolled back ({}), it can not be resumed.",
+                        migration.name,
+                        status.code()
+                    ),
+                })
+            }
+        }
+
+        let connector = engine.connector();
+        let database_migration = connector.deserialize_database_migration(migration.database_migration.clone());
+        let step_applier = connector.database_migration_step_applier();
+
+        // The steps before `applied` are known to have been applied. The step after them may have
+        // taken effect without the progress being recorded, so we check against the database.
+        let mut from_step = migration.applied;
+
+        while let Some(true) = step_applier.step_is_applied(&database_migration, from_step).await? {
+            from_step += 1;
+        }
+
+        tracing::info!(
+            "Resuming the migration `{}` from step {}.",
+            migration.name.as_str(),
+            from_step
+        );
+
+        connector
+            .migration_applier()
+            .resume(migration, &database_migration, from_step)
+            .await?;
+
+        Ok(from_step)
+    }
+
+    /// Bring the database back to the state of the last successful migration, based on the current
+    /// description of the database.
+    async fn roll_back<C, D>(&self, engine: &MigrationEngine<C, D>, migration: &Migration) -> CommandResult<()>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let last_successful_migration = connector.migration_persistence().last().await?;
+
+        let previous_datamodel_ast = last_successful_migration
+            .as_ref()
+            .map(|m| m.datamodel_ast())
+            .unwrap_or_else(SchemaAst::empty);
+        let previous_datamodel = last_successful_migration
+            .as_ref()
+            .map(|m| m.datamodel())
+            .unwrap_or_else(Datamodel::empty);
+
+        let datamodel_steps = engine
+            .datamodel_migration_steps_inferrer()
+            .infer(&migration.datamodel_ast(), &previous_datamodel_ast);
+
+        let rollback_migration = connector
+            .database_migration_inferrer()
+            .infer(&migration.datamodel(), &previous_datamodel, &datamodel_steps)
+            .await?;
+
+        tracing::info!("Rolling back the migration `{}`.", migration.name.as_str());
+
+        connector
+            .migration_applier()
+            .roll_back_interrupted(migration, &rollback_migration)
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn is_interrupted(status: MigrationStatus) -> bool {
+    match status {
+        MigrationStatus::MigrationInProgress
+        | MigrationStatus::MigrationFailure
+        | MigrationStatus::RollingBack
+        | MigrationStatus::RollbackFailure => true,
+        MigrationStatus::Pending | MigrationStatus::MigrationSuccess | MigrationStatus::RollbackSuccess => false,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoverMigrationInput {
+    pub strategy: RecoveryStrategy,
+}
+
+/// How to recover from an interrupted migration.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum RecoveryStrategy {
+    /// Apply the steps that did not take effect yet.
+    Resume,
+    /// Bring the database back to the state before the migration.
+    Rollback,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoverMigrationOutput {
+    pub migration_id: String,
+    pub status: MigrationStatus,
+    /// The index of the first step that was applied when resuming the migration.
+    pub resumed_from_step: Option<usize>,
+}
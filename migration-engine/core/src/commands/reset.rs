@@ -13,11 +13,15 @@ impl<'a> MigrationCommand for ResetCommand {
     async fn execute<C, D>(_input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
     where
         C: MigrationConnector<DatabaseMigration = D>,
-        D: DatabaseMigrationMarker + 'static,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
     {
-        engine.reset().await?;
-        engine.init().await?;
+        engine
+            .with_migration_lock(async {
+                engine.reset().await?;
+                engine.init().await?;
 
-        Ok(json!({}))
+                Ok(json!({}))
+            })
+            .await
     }
 }
@@ -0,0 +1,154 @@
+//! The SquashMigrations RPC method.
+
+use super::list_migrations::{convert_migration_to_list_migration_steps_output, ListMigrationsOutput};
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use datamodel::{ast::SchemaAst, Datamodel};
+use migration_connector::*;
+use serde::Deserialize;
+use tracing::debug;
+
+pub struct SquashMigrationsCommand<'a> {
+    input: &'a SquashMigrationsInput,
+}
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for SquashMigrationsCommand<'a> {
+    type Input = SquashMigrationsInput;
+    type Output = ListMigrationsOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let cmd = SquashMigrationsCommand { input };
+        debug!(?cmd.input);
+
+        // The migrations history is read and rewritten under the lock, so that no migration can be applied in
+        // between.
+        let (squashed, squashed_count) = engine.with_migration_lock(cmd.squash(engine)).await?;
+
+        tracing::info!(
+            "Squashed {count} migrations into `{migration_id}`.",
+            count = squashed_count,
+            migration_id = squashed.name.as_str(),
+        );
+
+        convert_migration_to_list_migration_steps_output(&engine, squashed)
+    }
+}
+
+impl<'a> SquashMigrationsCommand<'a> {
+    /// Replaces the migrations in the range with the squashed migration. Returns the squashed migration and
+    /// the number of migrations it replaces.
+    async fn squash<C, D>(&self, engine: &MigrationEngine<C, D>) -> CommandResult<(Migration, usize)>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let migration_persistence = connector.migration_persistence();
+        let migrations = migration_persistence.load_all().await?;
+
+        let (first, last) = self.find_range(&migrations)?;
+        let squashed_migrations = &migrations[first..=last];
+
+        if let Some(migration) = squashed_migrations.iter().find(|m| !m.status.is_success()) {
+            return Err(CommandError::Input {
+                error: format!(
+                    "The migration `{}` has not been applied successfully and can not be squashed.",
+                    migration.name
+                ),
+            });
+        }
+
+        let migration_id_is_taken = migrations
+            .iter()
+            .enumerate()
+            .any(|(idx, m)| (idx < first || idx > last) && m.name == self.input.migration_id);
+
+        if migration_id_is_taken {
+            return Err(CommandError::Input {
+                error: format!(
+                    "Invariant violation: a migration with id `{}` already exists.",
+                    self.input.migration_id
+                ),
+            });
+        }
+
+        // The squashed migration starts from the state before the first squashed migration. When the range
+        // starts with the first migration, this is the empty datamodel, and the database steps are calculated
+        // from an empty schema.
+        let (previous_datamodel_ast, previous_datamodel) = match first.checked_sub(1).map(|idx| &migrations[idx]) {
+            Some(previous_migration) => (previous_migration.datamodel_ast(), previous_migration.datamodel()),
+            None => (SchemaAst::empty(), Datamodel::empty()),
+        };
+
+        let final_migration = &squashed_migrations[squashed_migrations.len() - 1];
+        let next_datamodel_ast = final_migration.datamodel_ast();
+        let next_datamodel = final_migration.datamodel();
+
+        let datamodel_steps = engine
+            .datamodel_migration_steps_inferrer()
+            .infer(&previous_datamodel_ast, &next_datamodel_ast);
+
+        let database_migration = connector
+            .database_migration_inferrer()
+            .infer_from_datamodels(&previous_datamodel, &next_datamodel, &datamodel_steps)
+            .await?;
+
+        let mut squashed = Migration::new(self.input.migration_id.clone());
+        squashed.revision = final_migration.revision;
+        squashed.status = MigrationStatus::MigrationSuccess;
+        squashed.applied = database_migration.step_count();
+        squashed.datamodel_string = final_migration.datamodel_string.clone();
+        squashed.datamodel_steps = datamodel_steps;
+        squashed.database_migration = database_migration.serialize();
+        squashed.started_at = squashed_migrations[0].started_at;
+        squashed.finished_at = final_migration.finished_at;
+
+        let squashed = migration_persistence
+            .squash(squashed_migrations[0].revision, final_migration.revision, squashed)
+            .await?;
+
+        Ok((squashed, squashed_migrations.len()))
+    }
+
+    /// Returns the indexes of the first and last migrations to squash in the migrations history.
+    fn find_range(&self, migrations: &[Migration]) -> CommandResult<(usize, usize)> {
+        let find_migration = |migration_id: &str| {
+            migrations
+                .iter()
+                .position(|migration| migration.name == migration_id)
+                .ok_or_else(|| CommandError::Input {
+                    error: format!("Could not find the migration `{}` to squash.", migration_id),
+                })
+        };
+
+        let first = find_migration(&self.input.from_migration_id)?;
+        let last = find_migration(&self.input.to_migration_id)?;
+
+        if first > last {
+            return Err(CommandError::Input {
+                error: format!(
+                    "The migration `{}` was applied after `{}`.",
+                    self.input.from_migration_id, self.input.to_migration_id
+                ),
+            });
+        }
+
+        Ok((first, last))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsInput {
+    /// The id of the migration replacing the squashed migrations.
+    pub migration_id: String,
+    /// The id of the first migration to squash.
+    pub from_migration_id: String,
+    /// The id of the last migration to squash. The squashed migration ends in this migration's datamodel.
+    pub to_migration_id: String,
+}
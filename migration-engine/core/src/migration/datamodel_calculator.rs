@@ -171,6 +171,7 @@ fn apply_create_field(datamodel: &mut ast::SchemaAst, step: &steps::CreateField)
         span: new_span(),
         directives: Vec::new(),
         default_value: None,
+        is_commented_out: false,
     };
     model.fields.push(field);
 
@@ -192,6 +193,7 @@ fn apply_create_model(datamodel: &mut ast::SchemaAst, step: &steps::CreateModel)
         span: new_span(),
         fields: vec![],
         directives: vec![],
+        is_commented_out: false,
     };
 
     datamodel.tops.push(ast::Top::Model(model));
@@ -479,6 +481,7 @@ fn apply_create_type_alias(
         arity: step.arity.into(),
         directives: vec![],
         field_type: new_ident(step.r#type.clone()),
+        is_commented_out: false,
     };
 
     datamodel.tops.push(ast::Top::Type(type_alias));
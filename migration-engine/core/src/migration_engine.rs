@@ -42,6 +42,23 @@ where
         Ok(())
     }
 
+    /// Run the future while holding the connector's migration lock, so that other migration engines
+    /// cannot modify the migrations on the same database at the same time.
+    pub async fn with_migration_lock<O>(
+        &self,
+        fut: impl std::future::Future<Output = CommandResult<O>>,
+    ) -> CommandResult<O> {
+        self.connector().acquire_lock().await?;
+
+        let result = fut.await;
+        let release_result = self.connector().release_lock().await;
+
+        let output = result?;
+        release_result?;
+
+        Ok(output)
+    }
+
     pub fn connector(&self) -> &C {
         &self.connector
     }
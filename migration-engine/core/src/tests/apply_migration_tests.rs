@@ -2,6 +2,7 @@
 
 use super::test_harness::*;
 use migration_connector::*;
+use sql_migration_connector::SqlMigrationConnector;
 
 #[test_each_connector]
 async fn single_watch_migrations_must_work(api: &TestApi) {
@@ -174,3 +175,103 @@ async fn applying_an_already_applied_migration_must_return_an_error(api: &TestAp
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn dry_runs_must_not_touch_the_database(api: &TestApi) -> TestResult {
+    use quaint::ast::*;
+
+    let dm1 = r#"
+        model Test {
+            id String @id
+            name String
+        }
+    "#;
+
+    let original_schema = api.infer_and_apply(dm1).await.sql_schema;
+
+    let insert = Insert::single_into(api.render_table_name("Test"))
+        .value("id", "abc")
+        .value("name", "george");
+    api.database().execute(insert.into()).await?;
+
+    let dm2 = r#"
+        model Test {
+            id String @id
+        }
+    "#;
+
+    let steps = api.infer(dm2.to_owned()).send().await?.datamodel_steps;
+    let migrations_count = api.migration_persistence().load_all().await?.len();
+
+    let output = api
+        .apply()
+        .steps(Some(steps))
+        .force(Some(true))
+        .dry_run(Some(true))
+        .send()
+        .await?;
+
+    assert!(!output.database_steps.as_array().unwrap().is_empty());
+    assert_eq!(output.warnings.len(), 1);
+    assert_eq!(
+        output.affected_rows,
+        &[AffectedRowsEstimate {
+            table: "Test".to_owned(),
+            column: Some("name".to_owned()),
+            rows: 1,
+        }]
+    );
+
+    assert_eq!(api.describe_database().await?, original_schema);
+    assert_eq!(api.migration_persistence().load_all().await?.len(), migrations_count);
+
+    Ok(())
+}
+
+#[test_one_connector(connector = "sqlite")]
+async fn the_migration_lock_must_be_released_after_applying_a_migration(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Test {
+            id Int @id
+        }
+    "#;
+
+    api.infer_and_apply(dm).await;
+
+    let file_path = match api.connection_info().unwrap() {
+        quaint::prelude::ConnectionInfo::Sqlite { file_path, .. } => file_path,
+        _ => unreachable!(),
+    };
+
+    assert!(!std::path::Path::new(&format!("{}.migration-lock", file_path)).exists());
+
+    // Applying another migration must not wait for the lock.
+    api.infer_and_apply("").await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn the_migration_lock_must_time_out_when_held_by_another_engine_on_mysql() {
+    // Long enough that the lock name would exceed the 64 characters limit of MySQL lock names.
+    let url = mysql_url("the_migration_lock_must_time_out_on_mysql_with_a_long_db_name");
+    create_mysql_database(&url.parse().unwrap()).await.unwrap();
+
+    let first_engine = SqlMigrationConnector::new(&url, "mysql").await.unwrap();
+    let mut second_engine = SqlMigrationConnector::new(&url, "mysql").await.unwrap();
+    second_engine.lock_timeout = std::time::Duration::from_secs(1);
+
+    first_engine.acquire_lock().await.unwrap();
+
+    let err = second_engine.acquire_lock().await.unwrap_err();
+
+    match err.kind {
+        ErrorKind::MigrationLockTimeout { timeout_secs } => assert_eq!(timeout_secs, 1),
+        other => panic!("Expected a lock timeout, got {:?}", other),
+    }
+
+    // The lock can be taken once the first engine releases it.
+    first_engine.release_lock().await.unwrap();
+    second_engine.acquire_lock().await.unwrap();
+    second_engine.release_lock().await.unwrap();
+}
@@ -343,6 +343,7 @@ async fn command_errors_must_return_an_unknown_error(api: &TestApi) {
             model: "abcd".to_owned(),
         })],
         force: Some(true),
+        dry_run: None,
     };
 
     let error = api.execute_command::<ApplyMigrationCommand>(&input).await.unwrap_err();
@@ -398,6 +399,7 @@ async fn unique_constraint_errors_in_migrations_must_return_a_known_error(api: &
         migration_id: "the-migration".to_owned(),
         steps: steps,
         force: Some(true),
+        dry_run: None,
     };
 
     let error = api.execute_command::<ApplyMigrationCommand>(&input).await.unwrap_err();
@@ -343,3 +343,212 @@ async fn string_columns_do_not_get_arbitrarily_migrated(api: &TestApi) -> TestRe
 
     Ok(())
 }
+
+#[test_one_connector(connector = "postgres")]
+async fn removing_enum_values_that_are_in_use_should_warn(api: &TestApi) {
+    let dm = r#"
+        model Test {
+            id String @id @default(cuid())
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+            SAD
+        }
+    "#;
+    let original_database_schema = api.infer_and_apply(&dm).await.sql_schema;
+
+    let insert = format!(
+        r#"INSERT INTO "{}"."Test" ("id", "mood") VALUES ('a', 'HAPPY'), ('b', 'SAD')"#,
+        api.schema_name()
+    );
+    api.database().query_raw(&insert, &[]).await.unwrap();
+
+    let dm = r#"
+        model Test {
+            id String @id @default(cuid())
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+        }
+    "#;
+
+    let InferAndApplyOutput {
+        migration_output,
+        sql_schema: final_database_schema,
+    } = api.infer_and_apply(&dm).await;
+
+    assert_eq!(original_database_schema, final_database_schema);
+
+    assert_eq!(
+        migration_output.warnings,
+        &[MigrationWarning {
+            description: "You are about to remove the values [SAD] from the enum `Mood`, which are still used in 1 rows of the column `mood` on the `Test` table.".to_owned(),
+        }]
+    );
+}
+
+#[test_each_connector]
+async fn float_columns_with_data_are_not_altered(api: &TestApi) -> TestResult {
+    // `Float` fields are stored as `Decimal(65,30)` on Postgres and MySQL, which are described as decimal columns.
+    let dm = r#"
+        model Test {
+            id String @id
+            value Float?
+            price Decimal?
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?;
+
+    let insert = Insert::single_into(api.render_table_name("Test"))
+        .value("id", "the-id")
+        .value("value", 3.5)
+        .value("price", 2.25);
+
+    api.database().execute(insert.into()).await?;
+
+    let output = api.infer_apply(dm).send().await?;
+
+    assert_eq!(output.database_steps.as_array().map(Vec::len), Some(0));
+    assert!(output.warnings.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn converting_string_columns_to_enums_keeps_the_data(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id String @id
+            mood String?
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?;
+
+    let insert = Insert::single_into(api.render_table_name("Test"))
+        .value("id", "the-id")
+        .value("mood", "HAPPY");
+    api.database().query(insert.into()).await?;
+
+    let dm2 = r#"
+        model Test {
+            id String @id
+            mood Mood?
+        }
+
+        enum Mood {
+            HAPPY
+            SAD
+        }
+    "#;
+
+    let output = api.infer_apply(dm2).send().await?;
+
+    assert!(output.warnings.is_empty());
+
+    let select = Select::from_table(api.render_table_name("Test"))
+        .value(count(asterisk()))
+        .so_that("mood".equals("HAPPY"));
+    let result_set = api.database().query(select.into()).await?;
+    let values_count = result_set
+        .first()
+        .and_then(|row| row.at(0).and_then(|value| value.as_i64()));
+
+    assert_eq!(values_count, Some(1));
+
+    Ok(())
+}
+
+#[test_each_connector(ignore = "sqlite")]
+async fn converting_string_columns_with_other_values_to_enums_should_warn(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id String @id
+            mood String?
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?;
+
+    let insert = Insert::multi_into(api.render_table_name("Test"), vec!["id", "mood"])
+        .values(vec!["a", "HAPPY"])
+        .values(vec!["b", "ANGRY"]);
+    api.database().query(insert.into()).await?;
+
+    let dm2 = r#"
+        model Test {
+            id String @id
+            mood Mood?
+        }
+
+        enum Mood {
+            HAPPY
+            SAD
+        }
+    "#;
+
+    let output = api.infer_apply(dm2).send().await?;
+
+    assert_eq!(
+        output.warnings,
+        &[MigrationWarning {
+            description: "You are about to convert the column `mood` on the `Test` table to an enum, but 1 rows contain values that are not variants of the enum. The migration will fail.".to_owned(),
+        }]
+    );
+
+    Ok(())
+}
+
+#[test_one_connector(connector = "mysql")]
+async fn adding_a_value_to_an_inline_enum_keeps_the_data(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?;
+
+    let insert = Insert::single_into(api.render_table_name("Test"))
+        .value("id", "the-id")
+        .value("mood", "HAPPY");
+    api.database().query(insert.into()).await?;
+
+    let dm2 = r#"
+        model Test {
+            id String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+            SAD
+        }
+    "#;
+
+    let output = api.infer_apply(dm2).send().await?;
+
+    assert!(output.warnings.is_empty());
+
+    let select = Select::from_table(api.render_table_name("Test"))
+        .value(count(asterisk()))
+        .so_that("mood".equals("HAPPY"));
+    let result_set = api.database().query(select.into()).await?;
+    let values_count = result_set
+        .first()
+        .and_then(|row| row.at(0).and_then(|value| value.as_i64()));
+
+    assert_eq!(values_count, Some(1));
+
+    Ok(())
+}
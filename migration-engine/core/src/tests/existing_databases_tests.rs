@@ -341,8 +341,12 @@ async fn renaming_a_field_where_the_column_was_already_renamed_must_work(api: &T
     let final_result = api.infer_and_apply(&dm2).await.sql_schema;
 
     let final_column = final_result.table_bang("Blog").column_bang("new_title");
+    let float_family = match api.sql_family() {
+        SqlFamily::Sqlite => ColumnTypeFamily::Float,
+        _ => ColumnTypeFamily::Decimal,
+    };
 
-    assert_eq!(final_column.tpe.family, ColumnTypeFamily::Float);
+    assert_eq!(final_column.tpe.family, float_family);
     assert!(final_result.table_bang("Blog").column("title").is_none());
 }
 
@@ -396,6 +400,7 @@ async fn removing_a_default_from_a_non_nullable_foreign_key_column_must_warn(api
     let apply_input = ApplyMigrationInput {
         steps: result.datamodel_steps,
         force: Some(false),
+        dry_run: None,
         migration_id: "test-migration".into(),
     };
 
@@ -4,7 +4,7 @@ use crate::commands::{
 };
 use pretty_assertions::assert_eq;
 use quaint::prelude::SqlFamily;
-use sql_migration_connector::{AlterIndex, CreateIndex, DropIndex, SqlMigrationStep};
+use sql_migration_connector::{AlterEnum, AlterIndex, AlterSequence, CreateIndex, DropIndex, SqlMigrationStep};
 use sql_schema_describer::*;
 
 #[test_each_connector]
@@ -14,6 +14,7 @@ async fn adding_a_scalar_field_must_work(api: &TestApi) {
             id String @id @default(cuid())
             int Int
             float Float
+            decimal Decimal
             boolean Boolean
             string String
             dateTime DateTime
@@ -30,11 +31,24 @@ async fn adding_a_scalar_field_must_work(api: &TestApi) {
     table.columns.iter().for_each(|c| assert_eq!(c.is_required(), true));
 
     assert_eq!(table.column_bang("int").tpe.family, ColumnTypeFamily::Int);
-    assert_eq!(table.column_bang("float").tpe.family, ColumnTypeFamily::Float);
+    // `Float` fields are stored as `Decimal(65,30)` on Postgres and MySQL.
+    let float_family = match api.sql_family() {
+        SqlFamily::Sqlite => ColumnTypeFamily::Float,
+        _ => ColumnTypeFamily::Decimal,
+    };
+    assert_eq!(table.column_bang("float").tpe.family, float_family);
+    assert_eq!(table.column_bang("decimal").tpe.family, ColumnTypeFamily::Decimal);
     assert_eq!(table.column_bang("boolean").tpe.family, ColumnTypeFamily::Boolean);
     assert_eq!(table.column_bang("string").tpe.family, ColumnTypeFamily::String);
     assert_eq!(table.column_bang("dateTime").tpe.family, ColumnTypeFamily::DateTime);
-    assert_eq!(table.column_bang("enum").tpe.family, ColumnTypeFamily::String);
+    let expected_enum_name = match api.sql_family() {
+        SqlFamily::Postgres => "MyEnum",
+        _ => "Test_enum",
+    };
+    assert_eq!(
+        table.column_bang("enum").tpe.family,
+        ColumnTypeFamily::Enum(expected_enum_name.to_owned())
+    );
 }
 
 #[test_each_connector(ignore = "mysql_mariadb")]
@@ -108,14 +122,135 @@ async fn adding_an_id_field_of_type_int_with_autoincrement_must_work(api: &TestA
     match api.sql_family() {
         SqlFamily::Postgres => {
             let sequence = result.get_sequence("Test_myId_seq").expect("sequence must exist");
-            let default = column.default.as_ref().expect("Must have nextval default");
+            let default = column
+                .default
+                .as_ref()
+                .and_then(DefaultValue::as_value)
+                .expect("Must have nextval default");
             assert_eq!(default.contains(&sequence.name), true);
-            assert_eq!(default, &format!("nextval(\"{}\"::regclass)", sequence.name))
+            assert_eq!(default, format!("nextval(\"{}\"::regclass)", sequence.name))
         }
         _ => assert_eq!(column.auto_increment, true),
     }
 }
 
+#[test_one_connector(connector = "postgres")]
+async fn ids_with_a_sequence_must_keep_the_options_of_the_sequence(api: &TestApi) {
+    let dm = r#"
+        model Test {
+            id Int @id @default(autoincrement()) @sequence(name: "test_ids", initialValue: 100, allocationSize: 10)
+            text String
+        }
+    "#;
+
+    let result = api.infer_and_apply(&dm).await.sql_schema;
+    let table = result.table_bang("Test");
+
+    assert_eq!(table.column_bang("id").auto_increment, true);
+    assert_eq!(
+        table.primary_key.as_ref().and_then(|pk| pk.sequence.as_ref()),
+        Some(&Sequence {
+            name: "test_ids".to_string(),
+            initial_value: 100,
+            allocation_size: 10,
+        })
+    );
+}
+
+#[test_one_connector(connector = "postgres")]
+async fn changing_the_sequence_of_an_id_must_alter_the_sequence(api: &TestApi) {
+    let dm1 = r#"
+        model Test {
+            id Int @id @default(autoincrement()) @sequence(name: "test_ids", initialValue: 100, allocationSize: 10)
+            text String
+        }
+    "#;
+    api.infer_and_apply(&dm1).await;
+
+    let dm2 = r#"
+        model Test {
+            id Int @id @default(autoincrement()) @sequence(name: "test_ids_2", initialValue: 100, allocationSize: 5)
+            text String
+        }
+    "#;
+    let result = api.infer_and_apply(&dm2).await;
+    let next_sequence = Sequence {
+        name: "test_ids_2".to_string(),
+        initial_value: 100,
+        allocation_size: 5,
+    };
+
+    assert_eq!(
+        result.sql_migration(),
+        &[SqlMigrationStep::AlterSequence(AlterSequence {
+            table: "Test".to_string(),
+            column: "id".to_string(),
+            previous: Some(Sequence {
+                name: "test_ids".to_string(),
+                initial_value: 100,
+                allocation_size: 10,
+            }),
+            next: Some(next_sequence.clone()),
+        })]
+    );
+    let table = result.sql_schema.table_bang("Test");
+    assert_eq!(
+        table.primary_key.as_ref().and_then(|pk| pk.sequence.as_ref()),
+        Some(&next_sequence)
+    );
+
+    let result = api.infer_and_apply(&dm2).await;
+    assert!(result.sql_migration().is_empty());
+}
+
+#[test_one_connector(connector = "postgres")]
+async fn ids_must_switch_between_serial_and_identity_columns(api: &TestApi) {
+    let serial = r#"
+        model Test {
+            id Int @id @default(autoincrement())
+            text String
+        }
+    "#;
+    let identity = r#"
+        model Test {
+            id Int @id @default(autoincrement()) @sequence(name: "test_ids", initialValue: 100, allocationSize: 10)
+            text String
+        }
+    "#;
+    let serial_sequence = Sequence {
+        name: "Test_id_seq".to_string(),
+        initial_value: 1,
+        allocation_size: 1,
+    };
+    let sequence_of = |schema: &SqlSchema| {
+        schema
+            .table_bang("Test")
+            .primary_key
+            .as_ref()
+            .and_then(|pk| pk.sequence.clone())
+    };
+
+    api.infer_and_apply(&serial).await;
+
+    let result = api.infer_and_apply(&identity).await;
+    assert_eq!(result.sql_migration().len(), 1);
+    assert_eq!(
+        sequence_of(&result.sql_schema),
+        Some(Sequence {
+            name: "test_ids".to_string(),
+            initial_value: 100,
+            allocation_size: 10,
+        })
+    );
+    assert!(api.infer_and_apply(&identity).await.sql_migration().is_empty());
+
+    let result = api.infer_and_apply(&serial).await;
+    assert_eq!(result.sql_migration().len(), 1);
+    assert_eq!(sequence_of(&result.sql_schema), Some(serial_sequence));
+    assert!(result.sql_schema.table_bang("Test").column_bang("id").auto_increment);
+    assert!(api.infer_and_apply(&serial).await.sql_migration().is_empty());
+}
+
 #[test_each_connector]
 async fn removing_a_scalar_field_must_work(api: &TestApi) {
     let dm1 = r#"
@@ -1203,6 +1338,7 @@ async fn index_updates_with_rename_must_work(api: &TestApi) {
                     columns: vec!["field".into(), "id".into()],
                     tpe: IndexType::Unique,
                 },
+                online: false,
             }),
         ];
         let actual_steps = result.sql_migration();
@@ -1210,6 +1346,97 @@ async fn index_updates_with_rename_must_work(api: &TestApi) {
     }
 }
 
+#[test_each_connector]
+async fn online_indexes_on_existing_tables_must_work(api: &TestApi) {
+    let dm1 = r#"
+            model A {
+                id Int @id
+                field String
+            }
+        "#;
+    api.infer_and_apply(&dm1).await;
+
+    let dm2 = r#"
+            model A {
+                id Int @id
+                field String
+
+                @@index([field], name: "onlineIndex", online: true)
+            }
+        "#;
+    let result = api.infer_and_apply(&dm2).await;
+    let index = result
+        .sql_schema
+        .table_bang("A")
+        .indices
+        .iter()
+        .find(|i| i.name == "onlineIndex" && i.columns == &["field"]);
+    assert!(index.is_some());
+    assert_eq!(index.unwrap().tpe, IndexType::Normal);
+
+    if !api.is_sqlite() {
+        let expected_steps = vec![SqlMigrationStep::CreateIndex(CreateIndex {
+            table: "A".into(),
+            index: Index {
+                name: "onlineIndex".into(),
+                columns: vec!["field".into()],
+                tpe: IndexType::Normal,
+            },
+            online: true,
+        })];
+        let actual_steps = result.sql_migration();
+        assert_eq!(actual_steps, expected_steps);
+    }
+}
+
+#[test_one_connector(connector = "postgres")]
+async fn failed_online_indexes_must_not_drop_valid_indexes_of_the_same_name(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+            model A {
+                id Int @id
+                field String
+            }
+
+            model B {
+                id Int @id
+                field String
+
+                @@index([field], name: "onlineIndex")
+            }
+        "#;
+    api.infer_and_apply(&dm1).await;
+
+    // Index names are unique per schema on Postgres, so this one can not be created.
+    let dm2 = r#"
+            model A {
+                id Int @id
+                field String
+
+                @@index([field], name: "onlineIndex", online: true)
+            }
+
+            model B {
+                id Int @id
+                field String
+
+                @@index([field], name: "onlineIndex")
+            }
+        "#;
+    anyhow::ensure!(api.infer_apply(&dm2).send().await.is_err(), "The migration must fail");
+
+    let schema = api.describe_database().await?;
+    anyhow::ensure!(
+        schema
+            .table_bang("B")
+            .indices
+            .iter()
+            .any(|index| index.name == "onlineIndex"),
+        "The valid index must survive"
+    );
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn dropping_a_model_with_a_multi_field_unique_index_must_work(api: &TestApi) {
     let dm1 = r#"
@@ -1261,7 +1488,7 @@ async fn adding_a_scalar_list_for_a_modelwith_id_type_int_must_work(api: &TestAp
     assert_eq!(string_column.tpe.arity, ColumnArity::List);
 
     let enum_column = table_for_a.column_bang("enums");
-    assert_eq!(enum_column.tpe.family, ColumnTypeFamily::String);
+    assert_eq!(enum_column.tpe.family, ColumnTypeFamily::Enum("Status".to_owned()));
     assert_eq!(enum_column.tpe.arity, ColumnArity::List);
 }
 
@@ -1609,28 +1836,28 @@ async fn escaped_string_defaults_are_not_arbitrarily_migrated(api: &TestApi) ->
         table
             .column("name")
             .and_then(|c| c.default.as_ref())
-            .map(String::as_str),
+            .and_then(DefaultValue::as_value),
         Some(if api.is_mysql() { "ba\u{0}nana" } else { "ba\\0nana" })
     );
     assert_eq!(
         table
             .column("sideNames")
             .and_then(|c| c.default.as_ref())
-            .map(String::as_str),
+            .and_then(DefaultValue::as_value),
         Some(if api.is_mysql() { "top\ndown" } else { "top\\ndown" })
     );
     assert_eq!(
         table
             .column("contains")
             .and_then(|c| c.default.as_ref())
-            .map(String::as_str),
+            .and_then(DefaultValue::as_value),
         Some("potassium")
     );
     assert_eq!(
         table
             .column("seasonality")
             .and_then(|c| c.default.as_ref())
-            .map(String::as_str),
+            .and_then(DefaultValue::as_value),
         Some("summer")
     );
 
@@ -1660,6 +1887,7 @@ async fn created_at_does_not_get_arbitrarily_migrated(api: &TestApi) -> TestResu
             .column_bang("createdAt")
             .default
             .as_ref()
+            .and_then(DefaultValue::as_value)
             .unwrap()
             .contains("1970"),
         "createdAt default is set"
@@ -1844,6 +2072,70 @@ async fn relations_can_reference_multiple_fields_with_mappings(api: &TestApi) ->
     Ok(())
 }
 
+#[test_each_connector]
+async fn relations_can_reference_multiple_fields_with_mapped_columns(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model User {
+            tenant String
+            id     Int
+
+            @@id([tenant, id])
+        }
+
+        model Account {
+            id   Int @id
+            user User @map(["user_tenant", "user_id"])
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?;
+    let schema = api.describe_database().await?;
+
+    schema
+        .assert_table("Account")?
+        .assert_foreign_keys_count(1)?
+        .assert_fk_on_columns(&["user_tenant", "user_id"], |fk| {
+            fk.assert_references("User", &["tenant", "id"])
+        })?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn compound_foreign_keys_can_share_columns_with_the_primary_key(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model User {
+            tenant String
+            id     Int
+            posts  Post[]
+
+            @@id([tenant, id])
+        }
+
+        model Post {
+            tenant String
+            id     Int
+            author User @relation(references: [tenant, id]) @map(["tenant", "author_id"])
+
+            @@id([tenant, id])
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?;
+    let schema = api.describe_database().await?;
+
+    schema
+        .assert_table("Post")?
+        .assert_has_column("author_id")?
+        .assert_pk(|pk| pk.assert_columns(&["tenant", "id"]))?
+        .assert_foreign_keys_count(1)?
+        .assert_fk_on_columns(&["tenant", "author_id"], |fk| {
+            fk.assert_references("User", &["tenant", "id"])
+        })?;
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn foreign_keys_are_added_on_existing_tables(api: &TestApi) -> TestResult {
     let dm1 = r#"
@@ -2038,3 +2330,469 @@ async fn join_tables_between_models_with_mapped_compound_primary_keys_must_work(
 
     Ok(())
 }
+
+#[test_each_connector(ignore = "mysql_mariadb")]
+async fn json_fields_must_work(api: &TestApi) {
+    let dm = r#"
+            model A {
+                id Int @id
+                metadata Json
+                optionalMetadata Json?
+            }
+        "#;
+    let result = api.infer_and_apply(&dm).await;
+    let table = result.sql_schema.table_bang("A");
+
+    let expected_family = match api.sql_family() {
+        SqlFamily::Sqlite => ColumnTypeFamily::String,
+        _ => ColumnTypeFamily::Json,
+    };
+    assert_eq!(table.column_bang("metadata").tpe.family, expected_family);
+    assert_eq!(table.column_bang("optionalMetadata").tpe.family, expected_family);
+    assert_eq!(table.column_bang("optionalMetadata").is_required(), false);
+
+    // Applying the same datamodel again must not change the Json columns.
+    let result = api.infer_and_apply(&dm).await;
+    assert!(result.sql_migration().is_empty());
+}
+
+#[test_each_connector]
+async fn bytes_fields_must_work(api: &TestApi) {
+    let dm = r#"
+            model A {
+                id Int @id
+                thumbnail Bytes
+                signature Bytes?
+            }
+        "#;
+    let result = api.infer_and_apply(&dm).await;
+    let table = result.sql_schema.table_bang("A");
+
+    assert_eq!(table.column_bang("thumbnail").tpe.family, ColumnTypeFamily::Binary);
+    assert_eq!(table.column_bang("signature").tpe.family, ColumnTypeFamily::Binary);
+    assert_eq!(table.column_bang("signature").is_required(), false);
+
+    let result = api.infer_and_apply(&dm).await;
+    assert!(result.sql_migration().is_empty());
+}
+
+#[test_each_connector]
+async fn uuid_fields_must_work(api: &TestApi) {
+    let dm = r#"
+            model A {
+                id Uuid @id @default(uuid())
+                otherId Uuid?
+                bs B[]
+            }
+
+            model B {
+                id Int @id
+                a A
+            }
+        "#;
+    let result = api.infer_and_apply(&dm).await;
+    let table = result.sql_schema.table_bang("A");
+
+    // MySQL stores UUIDs as CHAR(36), which is not described as a UUID column.
+    let expected_family = match api.sql_family() {
+        SqlFamily::Sqlite | SqlFamily::Mysql => ColumnTypeFamily::String,
+        SqlFamily::Postgres => ColumnTypeFamily::Uuid,
+    };
+    assert_eq!(table.column_bang("id").tpe.family, expected_family);
+    assert_eq!(table.column_bang("otherId").tpe.family, expected_family);
+    assert_eq!(
+        result.sql_schema.table_bang("B").column_bang("a").tpe.family,
+        expected_family
+    );
+
+    // Applying the same datamodel again must not change the Uuid columns.
+    let result = api.infer_and_apply(&dm).await;
+    assert!(result.sql_migration().is_empty());
+}
+
+#[test_one_connector(connector = "mysql")]
+async fn binary_uuid_fields_must_work_on_mysql(api: &TestApi) {
+    let dm = r#"
+            datasource db {
+                provider = "mysql"
+                url = "mysql://localhost:3306"
+            }
+
+            model A {
+                id Int @id
+                externalId Uuid @db.BinaryUuid
+                otherId Uuid
+            }
+        "#;
+    let result = api.infer_and_apply(&dm).await;
+    let table = result.sql_schema.table_bang("A");
+
+    assert_eq!(table.column_bang("externalId").tpe.raw, "binary(16)");
+    assert_eq!(table.column_bang("otherId").tpe.raw, "char(36)");
+
+    // Applying the same datamodel again must not change the Uuid columns.
+    let result = api.infer_and_apply(&dm).await;
+    assert!(result.sql_migration().is_empty());
+}
+
+#[test_one_connector(connector = "postgres")]
+async fn native_types_must_work(api: &TestApi) {
+    let dm1 = r#"
+            datasource pg {
+                provider = "postgresql"
+                url = "postgresql://localhost:5432"
+            }
+
+            model A {
+                id Int @id
+                title String @pg.VarChar(100)
+                price Decimal @pg.Numeric(10, 2)
+                counter Int @pg.SmallInt
+            }
+        "#;
+    let result = api.infer_and_apply(&dm1).await;
+    let table = result.sql_schema.table_bang("A");
+
+    assert_eq!(table.column_bang("title").tpe.raw, "varchar(100)");
+    assert_eq!(table.column_bang("title").tpe.family, ColumnTypeFamily::String);
+    assert_eq!(table.column_bang("price").tpe.raw, "numeric(10,2)");
+    assert_eq!(table.column_bang("counter").tpe.raw, "int2");
+
+    // Applying the same datamodel again must not change the native columns.
+    let result = api.infer_and_apply(&dm1).await;
+    assert!(result.sql_migration().is_empty());
+
+    let dm2 = r#"
+            datasource pg {
+                provider = "postgresql"
+                url = "postgresql://localhost:5432"
+            }
+
+            model A {
+                id Int @id
+                title String @pg.VarChar(255)
+                price Decimal @pg.Numeric(10, 2)
+                counter Int
+            }
+        "#;
+    let result = api.infer_and_apply(&dm2).await;
+    let table = result.sql_schema.table_bang("A");
+
+    assert_eq!(table.column_bang("title").tpe.raw, "varchar(255)");
+    assert_eq!(table.column_bang("counter").tpe.raw, "int4");
+}
+
+#[test_each_connector]
+async fn enums_must_be_created_in_the_database(api: &TestApi) {
+    let dm = r#"
+        model Test {
+            id Int @id
+            mood Mood
+            optionalMood Mood?
+        }
+
+        enum Mood {
+            HAPPY
+            SAD
+        }
+    "#;
+    let result = api.infer_and_apply(&dm).await;
+
+    let (mood_enum, optional_mood_enum) = match api.sql_family() {
+        SqlFamily::Postgres => ("Mood", "Mood"),
+        _ => ("Test_mood", "Test_optionalMood"),
+    };
+    let table = result.sql_schema.table_bang("Test");
+    assert_eq!(
+        table.column_bang("mood").tpe.family,
+        ColumnTypeFamily::Enum(mood_enum.to_owned())
+    );
+    assert_eq!(
+        table.column_bang("optionalMood").tpe.family,
+        ColumnTypeFamily::Enum(optional_mood_enum.to_owned())
+    );
+    assert_eq!(
+        result.sql_schema.get_enum(mood_enum).map(|inum| inum.values.clone()),
+        Some(vec!["HAPPY".to_owned(), "SAD".to_owned()])
+    );
+
+    // Invalid values must be rejected by the database.
+    let insert = format!(
+        r#"INSERT INTO "{}"."Test" ("id", "mood") VALUES (1, 'ANGRY')"#,
+        api.schema_name()
+    );
+    let insert = match api.sql_family() {
+        SqlFamily::Mysql => insert.replace('"', "`"),
+        _ => insert,
+    };
+    assert!(api.database().query_raw(&insert, &[]).await.is_err());
+
+    // Applying the same datamodel again must not change the enum columns.
+    let result = api.infer_and_apply(&dm).await;
+    assert!(result.sql_migration().is_empty());
+}
+
+#[test_each_connector]
+async fn enum_values_can_be_added_and_removed(api: &TestApi) {
+    let dm1 = r#"
+        model Test {
+            id Int @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+            SAD
+        }
+    "#;
+    api.infer_and_apply(&dm1).await;
+
+    let dm2 = r#"
+        model Test {
+            id Int @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+            ANGRY
+        }
+    "#;
+    let result = api.infer_and_apply(&dm2).await;
+
+    let enum_name = match api.sql_family() {
+        SqlFamily::Postgres => "Mood",
+        _ => "Test_mood",
+    };
+    assert_eq!(
+        result.sql_schema.get_enum(enum_name).map(|inum| inum.values.clone()),
+        Some(vec!["HAPPY".to_owned(), "ANGRY".to_owned()])
+    );
+
+    if api.sql_family() == SqlFamily::Postgres {
+        assert_eq!(
+            result.sql_migration(),
+            &[SqlMigrationStep::AlterEnum(AlterEnum {
+                name: "Mood".to_owned(),
+                created_variants: vec!["ANGRY".to_owned()],
+                dropped_variants: vec!["SAD".to_owned()],
+            })]
+        );
+    }
+
+    let result = api.infer_and_apply(&dm2).await;
+    assert!(result.sql_migration().is_empty());
+}
+
+#[test_one_connector(connector = "postgres")]
+async fn enums_are_dropped_with_their_last_column(api: &TestApi) {
+    let dm1 = r#"
+        model Test {
+            id Int @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+            SAD
+        }
+    "#;
+    let result = api.infer_and_apply(&dm1).await;
+    assert!(result.sql_schema.get_enum("Mood").is_some());
+
+    let dm2 = r#"
+        model Test {
+            id Int @id
+        }
+    "#;
+    let result = api.infer_and_apply(&dm2).await;
+    assert!(result.sql_schema.get_enum("Mood").is_none());
+    assert!(!result.sql_schema.table_bang("Test").has_column("mood"));
+}
+
+#[test_each_connector]
+async fn db_generated_defaults_must_work(api: &TestApi) {
+    let dm = r#"
+        model Test {
+            id Int @id
+            score Int @default(dbgenerated("1 + 1"))
+        }
+    "#;
+    let result = api.infer_and_apply(&dm).await;
+    let column = result.sql_schema.table_bang("Test").column_bang("score");
+    assert!(column.db_generated_default().is_some());
+
+    // The database normalizes the expression, which must not cause a migration.
+    let result = api.infer_and_apply(&dm).await;
+    assert!(result.sql_migration().is_empty());
+
+    // Editing the expression must migrate the column.
+    let edited_dm = r#"
+        model Test {
+            id Int @id
+            score Int @default(dbgenerated("2 + 2"))
+        }
+    "#;
+    let result = api.infer_and_apply(&edited_dm).await;
+    assert!(!result.sql_migration().is_empty());
+    let column = result.sql_schema.table_bang("Test").column_bang("score");
+    assert!(column.db_generated_default().unwrap().contains('2'));
+
+    let dm2 = r#"
+        model Test {
+            id Int @id
+            score Int
+        }
+    "#;
+    let result = api.infer_and_apply(&dm2).await;
+    let column = result.sql_schema.table_bang("Test").column_bang("score");
+    assert!(column.db_generated_default().is_none());
+}
+
+// MySQL 5.7 parses check constraints but does not enforce or describe them.
+#[test_each_connector(ignore = "mysql")]
+async fn check_constraints_must_work(api: &TestApi) {
+    let dm1 = r#"
+        model Product {
+            id Int @id
+            price Int
+
+            @@check("price >= 0", name: "positive_price")
+        }
+    "#;
+    let result = api.infer_and_apply(&dm1).await;
+    let table = result.sql_schema.table_bang("Product");
+    assert!(table.has_check_constraint("positive_price"));
+
+    // The database normalizes the expression, which must not cause a migration.
+    let result = api.infer_and_apply(&dm1).await;
+    assert!(result.sql_migration().is_empty());
+
+    // Adding a column rebuilds the table on SQLite, which must keep the constraint.
+    let dm2 = r#"
+        model Product {
+            id Int @id
+            price Int
+            name String
+
+            @@check("price >= 0", name: "positive_price")
+        }
+    "#;
+    let result = api.infer_and_apply(&dm2).await;
+    let table = result.sql_schema.table_bang("Product");
+    assert!(table.has_column("name"));
+    assert!(table.has_check_constraint("positive_price"));
+
+    // Changing the expression recreates the constraint.
+    let edited_dm2 = r#"
+        model Product {
+            id Int @id
+            price Int
+            name String
+
+            @@check("price > 0", name: "positive_price")
+        }
+    "#;
+    let result = api.infer_and_apply(&edited_dm2).await;
+    assert!(!result.sql_migration().is_empty());
+    let table = result.sql_schema.table_bang("Product");
+    let check_constraint = table
+        .check_constraints
+        .iter()
+        .find(|check_constraint| check_constraint.name == "positive_price")
+        .unwrap();
+    assert!(!check_constraint.expression.contains(">="));
+
+    let dm3 = r#"
+        model Product {
+            id Int @id
+            price Int
+            name String
+        }
+    "#;
+    let result = api.infer_and_apply(&dm3).await;
+    assert!(result.sql_schema.table_bang("Product").check_constraints.is_empty());
+}
+
+#[test_each_connector]
+async fn view_models_must_not_create_tables(api: &TestApi) {
+    let dm = r#"
+        model User {
+            id Int @id
+            name String
+        }
+
+        model UserName {
+            id Int @id
+            name String
+
+            @@view
+        }
+    "#;
+    let result = api.infer_and_apply(&dm).await;
+    assert!(result.sql_schema.has_table("User"));
+    assert!(!result.sql_schema.has_table("UserName"));
+
+    let result = api.infer_and_apply(&dm).await;
+    assert!(result.sql_migration().is_empty());
+}
+
+#[test_each_connector]
+async fn models_of_other_schemas_must_be_rejected(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Invoice {
+            id Int @id
+
+            @@schema("billing")
+        }
+    "#;
+    anyhow::ensure!(api.infer_apply(&dm).send().await.is_err(), "The migration must fail");
+
+    let schema = api.describe_database().await?;
+    anyhow::ensure!(!schema.has_table("Invoice"), "No table must be created");
+
+    Ok(())
+}
+
+#[test_each_connector(ignore = "sqlite")]
+async fn doc_comments_must_be_written_as_database_comments(api: &TestApi) {
+    let dm1 = r#"
+        /// The users of the app.
+        model User {
+            id Int @id
+            /// The user's full name.
+            name String
+        }
+    "#;
+    let result = api.infer_and_apply(&dm1).await;
+    let table = result.sql_schema.table_bang("User");
+    assert_eq!(table.comment.as_ref().map(String::as_str), Some("The users of the app."));
+    assert_eq!(
+        table.column_bang("name").comment.as_ref().map(String::as_str),
+        Some("The user's full name.")
+    );
+
+    let result = api.infer_and_apply(&dm1).await;
+    assert!(result.sql_migration().is_empty());
+
+    let dm2 = r#"
+        model User {
+            id Int @id
+            /// How the user wants to be called.
+            name String
+            /// Where we send the newsletter.
+            email String
+        }
+    "#;
+    let result = api.infer_and_apply(&dm2).await;
+    let table = result.sql_schema.table_bang("User");
+    assert_eq!(table.comment, None);
+    assert_eq!(
+        table.column_bang("name").comment.as_ref().map(String::as_str),
+        Some("How the user wants to be called.")
+    );
+    assert_eq!(
+        table.column_bang("email").comment.as_ref().map(String::as_str),
+        Some("Where we send the newsletter.")
+    );
+}
@@ -0,0 +1,111 @@
+use super::test_harness::*;
+use crate::commands::{RecoverMigrationCommand, RecoverMigrationInput, RecoveryStrategy};
+use migration_connector::MigrationStatus;
+use pretty_assertions::assert_eq;
+use quaint::prelude::{Queryable, SqlFamily};
+
+/// Apply a migration creating a table and an index, then simulate a crash after the table was created:
+/// drop the index and mark the migration as still in progress.
+async fn setup_interrupted_migration(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.infer_and_apply(dm1).await;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+        }
+
+        model Dog {
+            id Int @id
+            name String
+
+            @@index([name], name: "dog_name_idx")
+        }
+    "#;
+
+    api.infer_apply(dm2)
+        .migration_id(Some("interrupted".to_owned()))
+        .send()
+        .await?;
+
+    let drop_index = match api.sql_family() {
+        SqlFamily::Mysql => format!("DROP INDEX `dog_name_idx` ON `{}`.`Dog`", api.schema_name()),
+        _ => format!(r#"DROP INDEX "{}"."dog_name_idx""#, api.schema_name()),
+    };
+
+    api.database().query_raw(&drop_index, &[]).await?;
+
+    let persistence = api.migration_persistence();
+    let migration = persistence.by_name("interrupted").await?.unwrap();
+    let mut params = migration.update_params();
+    params.status = MigrationStatus::MigrationInProgress;
+    params.applied = 0;
+    params.finished_at = None;
+    persistence.update(&params).await?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn resuming_an_interrupted_migration_must_apply_the_missing_steps(api: &TestApi) -> TestResult {
+    setup_interrupted_migration(api).await?;
+
+    let input = RecoverMigrationInput {
+        strategy: RecoveryStrategy::Resume,
+    };
+
+    let output = api.execute_command::<RecoverMigrationCommand>(&input).await.unwrap();
+
+    assert_eq!(output.migration_id, "interrupted");
+    assert_eq!(output.status, MigrationStatus::MigrationSuccess);
+    assert_eq!(output.resumed_from_step, Some(1));
+
+    let schema = api.describe_database().await?;
+    let dog = schema.table_bang("Dog");
+    assert!(dog.indices.iter().any(|index| index.name == "dog_name_idx"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn rolling_back_an_interrupted_migration_must_restore_the_previous_state(api: &TestApi) -> TestResult {
+    setup_interrupted_migration(api).await?;
+
+    let input = RecoverMigrationInput {
+        strategy: RecoveryStrategy::Rollback,
+    };
+
+    let output = api.execute_command::<RecoverMigrationCommand>(&input).await.unwrap();
+
+    assert_eq!(output.status, MigrationStatus::RollbackSuccess);
+    assert_eq!(output.resumed_from_step, None);
+
+    let schema = api.describe_database().await?;
+    assert!(schema.table("Dog").is_err());
+    assert!(schema.table("Cat").is_ok());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn recovering_without_an_interrupted_migration_must_fail(api: &TestApi) {
+    api.infer_and_apply("model Cat { id Int @id }").await;
+
+    let input = RecoverMigrationInput {
+        strategy: RecoveryStrategy::Resume,
+    };
+
+    let error = api
+        .execute_command::<RecoverMigrationCommand>(&input)
+        .await
+        .unwrap_err();
+
+    assert!(error
+        .message()
+        .contains("There is no interrupted migration to recover."));
+}
@@ -0,0 +1,145 @@
+use super::test_harness::*;
+use crate::commands::{SquashMigrationsCommand, SquashMigrationsInput};
+use migration_connector::MigrationStatus;
+use pretty_assertions::assert_eq;
+
+#[test_each_connector]
+async fn squashing_all_migrations_must_work(api: &TestApi) {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm1)
+        .migration_id(Some("mig01".to_owned()))
+        .send()
+        .await
+        .unwrap();
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm2)
+        .migration_id(Some("mig02".to_owned()))
+        .send()
+        .await
+        .unwrap();
+
+    let schema_before_squashing = api.describe_database().await.unwrap();
+
+    let input = SquashMigrationsInput {
+        migration_id: "squashed".to_owned(),
+        from_migration_id: "mig01".to_owned(),
+        to_migration_id: "mig02".to_owned(),
+    };
+
+    let output = api.execute_command::<SquashMigrationsCommand>(&input).await.unwrap();
+
+    assert_eq!(output.id, "squashed");
+    assert_eq!(output.status, MigrationStatus::MigrationSuccess);
+
+    let migrations = api.migration_persistence().load_all().await.unwrap();
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(migrations[0].name, "squashed");
+    assert_eq!(migrations[0].datamodel().find_model("Dog").is_some(), true);
+
+    // `applied` counts database steps, not datamodel steps.
+    let database_steps = migrations[0].database_migration["corrected_steps"].as_array().unwrap();
+    assert_eq!(migrations[0].applied, database_steps.len());
+
+    // The database itself is left untouched.
+    assert_eq!(api.describe_database().await.unwrap(), schema_before_squashing);
+
+    // Further migrations build on the squashed migration.
+    let dm3 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    let result = api.infer_and_apply(dm3).await.sql_schema;
+    assert!(result.table("Dog").is_err());
+    assert!(result.table_bang("Cat").column("name").is_some());
+}
+
+#[test_each_connector]
+async fn squashing_a_range_of_migrations_must_keep_the_surrounding_migrations(api: &TestApi) {
+    let dms = &[
+        "model Cat { id Int @id }",
+        "model Cat { id Int @id \n name String }",
+        "model Cat { id Int @id \n name String \n age Int }",
+        "model Cat { id Int @id \n age Int }",
+    ];
+
+    for (idx, dm) in dms.iter().enumerate() {
+        api.infer_apply(dm)
+            .migration_id(Some(format!("mig0{}", idx)))
+            .send()
+            .await
+            .unwrap();
+    }
+
+    let input = SquashMigrationsInput {
+        migration_id: "squashed".to_owned(),
+        from_migration_id: "mig01".to_owned(),
+        to_migration_id: "mig02".to_owned(),
+    };
+
+    api.execute_command::<SquashMigrationsCommand>(&input).await.unwrap();
+
+    let migration_names: Vec<String> = api
+        .migration_persistence()
+        .load_all()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|migration| migration.name)
+        .collect();
+
+    assert_eq!(migration_names, &["mig00", "squashed", "mig03"]);
+}
+
+#[test_each_connector]
+async fn squashing_migrations_in_the_wrong_order_must_fail(api: &TestApi) {
+    let dm1 = "model Cat { id Int @id }";
+    let dm2 = "model Cat { id Int @id \n name String }";
+
+    api.infer_apply(dm1)
+        .migration_id(Some("mig01".to_owned()))
+        .send()
+        .await
+        .unwrap();
+    api.infer_apply(dm2)
+        .migration_id(Some("mig02".to_owned()))
+        .send()
+        .await
+        .unwrap();
+
+    let input = SquashMigrationsInput {
+        migration_id: "squashed".to_owned(),
+        from_migration_id: "mig02".to_owned(),
+        to_migration_id: "mig01".to_owned(),
+    };
+
+    let error = api
+        .execute_command::<SquashMigrationsCommand>(&input)
+        .await
+        .unwrap_err();
+
+    assert!(error
+        .message()
+        .contains("The migration `mig02` was applied after `mig01`."));
+
+    let migrations = api.migration_persistence().load_all().await.unwrap();
+    assert_eq!(migrations.len(), 2);
+}
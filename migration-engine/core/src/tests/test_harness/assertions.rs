@@ -1,5 +1,5 @@
 use pretty_assertions::assert_eq;
-use sql_schema_describer::{Column, ForeignKey, PrimaryKey, SqlSchema, Table};
+use sql_schema_describer::{Column, DefaultValue, ForeignKey, PrimaryKey, SqlSchema, Table};
 
 type AssertionResult<T> = Result<T, anyhow::Error>;
 
@@ -89,7 +89,7 @@ pub struct ColumnAssertion<'a>(&'a Column);
 
 impl<'a> ColumnAssertion<'a> {
     pub fn assert_default(self, expected: Option<&str>) -> AssertionResult<Self> {
-        assert_eq!(self.0.default.as_ref().map(String::as_str), expected);
+        assert_eq!(self.0.default.as_ref().and_then(DefaultValue::as_value), expected);
 
         Ok(self)
     }
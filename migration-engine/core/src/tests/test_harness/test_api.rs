@@ -78,6 +78,7 @@ impl TestApi {
             migration_id: migration_id.into(),
             steps,
             force: None,
+            dry_run: None,
         };
 
         let migration_output = self.api.apply_migration(&input).await.expect("ApplyMigration failed");
@@ -139,6 +140,7 @@ impl TestApi {
             migration_id: None,
             steps: None,
             force: None,
+            dry_run: None,
         }
     }
 
@@ -11,6 +11,7 @@ pub struct Apply<'a> {
     pub(super) migration_id: Option<String>,
     pub(super) steps: Option<Vec<MigrationStep>>,
     pub(super) force: Option<bool>,
+    pub(super) dry_run: Option<bool>,
 }
 
 impl Apply<'_> {
@@ -29,6 +30,11 @@ impl Apply<'_> {
         self
     }
 
+    pub fn dry_run(mut self, dry_run: Option<bool>) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
     pub async fn send(self) -> Result<MigrationStepsResultOutput, anyhow::Error> {
         let migration_id = self.migration_id.unwrap_or_else(|| {
             format!(
@@ -40,6 +46,7 @@ impl Apply<'_> {
         let input = ApplyMigrationInput {
             migration_id,
             force: self.force,
+            dry_run: self.dry_run,
             steps: self.steps.unwrap_or_else(Vec::new),
         };
 
@@ -42,6 +42,7 @@ impl<'a> InferApply<'a> {
             migration_id,
             steps,
             force: self.force,
+            dry_run: None,
         };
 
         let migration_output = self.api.api.apply_migration(&input).await?;
@@ -0,0 +1,49 @@
+package queries
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class ViewQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  // The migration engine creates no table for views, so the view reads the rows of the Todo table.
+  val project = SchemaDsl.fromStringV11() {
+    """model Todo {
+      |  id    String @id @default(cuid())
+      |  title String
+      |}
+      |
+      |model TodoView {
+      |  id    String @id
+      |  title String
+      |
+      |  @@view
+      |  @@map("Todo")
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  "Reading a view" should "return its records by id and as a list" in {
+    val id = server
+      .query(s"""mutation { createTodo(data: {title: "Hello World!"}) { id } }""", project)
+      .pathAsString("data.createTodo.id")
+
+    val many = server.query("""{ todoViews { title } }""", project)
+    many.toString should equal("""{"data":{"todoViews":[{"title":"Hello World!"}]}}""")
+
+    val single = server.query(s"""{ todoView(where: {id: "$id"}) { id title } }""", project)
+    single.toString should equal(s"""{"data":{"todoView":{"id":"$id","title":"Hello World!"}}}""")
+  }
+
+  "Views" should "not be writable" in {
+    server.queryThatMustFail(
+      s"""mutation { createTodoView(data: {id: "a", title: "Nope"}) { id } }""",
+      project,
+      errorCode = 0
+    )
+  }
+}
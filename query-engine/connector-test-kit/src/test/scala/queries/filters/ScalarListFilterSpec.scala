@@ -0,0 +1,86 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.ScalarListsCapability
+import util._
+
+class ScalarListFilterSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(ScalarListsCapability)
+
+  val project: Project = ProjectDsl.fromString { """
+    |model Post {
+    |  id     String @id @default(cuid())
+    |  title  String
+    |  tags   String[]
+    |  scores Int[]
+    |  enums  Enum[]
+    |}
+    |
+    |enum Enum {
+    | A
+    | B
+    |}
+    |"""
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.truncateProjectTables(project)
+
+    createPost("first", """["rust", "sql"]""", "[1, 2]", "[A]")
+    createPost("second", """["rust"]""", "[2, 3]", "[A, B]")
+    createPost("third", "[]", "[]", "[]")
+  }
+
+  def createPost(title: String, tags: String, scores: String, enums: String): Unit = {
+    server.query(
+      s"""mutation{createPost(data:{
+         |title: "$title",
+         |tags: { set: $tags },
+         |scores: { set: $scores },
+         |enums: { set: $enums }}){id}}""".stripMargin,
+      project
+    )
+  }
+
+  "A scalar list filter" should "find the lists containing a value" in {
+    val res = server.query("""{posts(where: {tags_contains: "sql"}){title}}""", project)
+
+    res.toString() should be("""{"data":{"posts":[{"title":"first"}]}}""")
+  }
+
+  "A scalar list filter" should "find the lists containing every given value" in {
+    val res = server.query("""{posts(where: {scores_contains_every: [2, 3]}){title}}""", project)
+
+    res.toString() should be("""{"data":{"posts":[{"title":"second"}]}}""")
+  }
+
+  "A scalar list filter" should "find the lists containing some of the given values" in {
+    val res = server.query("""{posts(where: {scores_contains_some: [1, 3]}, orderBy: title_ASC){title}}""", project)
+
+    res.toString() should be("""{"data":{"posts":[{"title":"first"},{"title":"second"}]}}""")
+  }
+
+  "A scalar list filter" should "work on enum lists" in {
+    val res = server.query("""{posts(where: {enums_contains: B}){title}}""", project)
+
+    res.toString() should be("""{"data":{"posts":[{"title":"second"}]}}""")
+  }
+
+  "A scalar list filter" should "be combinable with other filters" in {
+    val res = server.query("""{posts(where: {OR: [{tags_contains: "sql"}, {title: "third"}]}, orderBy: title_ASC){title}}""", project)
+
+    res.toString() should be("""{"data":{"posts":[{"title":"first"},{"title":"third"}]}}""")
+  }
+
+  "A scalar list filter" should "return nothing if no list matches" in {
+    val res = server.query("""{posts(where: {tags_contains: "scala"}){title}}""", project)
+
+    res.toString() should be("""{"data":{"posts":[]}}""")
+  }
+}
@@ -0,0 +1,77 @@
+package queries.filters.nonEmbedded
+
+import org.scalatest._
+import util.ConnectorCapability.JoinRelationLinksCapability
+import util._
+
+class CompoundRelationFilterSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(JoinRelationLinksCapability)
+
+  // The foreign key of Post consists of the columns `user_firstName` and `user_lastName`, it does not hold the id of the user.
+  val project = SchemaDsl.fromStringV11() {
+    """model User {
+      |  id        String    @id @default(cuid())
+      |  firstName String
+      |  lastName  String
+      |  posts     Post[]
+      |
+      |  @@unique([firstName, lastName])
+      |}
+      |
+      |model Post {
+      |  id       String    @id @default(cuid())
+      |  title    String
+      |  user     User?     @relation(references: [firstName, lastName])
+      |  comments Comment[]
+      |}
+      |
+      |model Comment {
+      |  id   String @id @default(cuid())
+      |  text String
+      |  post Post?
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    server.query(
+      """mutation {
+        |  createUser(data: {
+        |    firstName: "Ada"
+        |    lastName: "Lovelace"
+        |    posts: { create: [{ title: "engine", comments: { create: [{ text: "nice" }] } }] }
+        |  }){ id }
+        |}""".stripMargin,
+      project
+    )
+    server.query("""mutation { createUser(data: { firstName: "Ada", lastName: "Byron" }){ id } }""", project)
+    server.query("""mutation { createPost(data: { title: "orphan" }){ id } }""", project)
+  }
+
+  "A to-one relation filter" should "join over all foreign key columns" in {
+    server.query("""{ posts(where: { user: { lastName: "Lovelace" } }) { title } }""", project).toString should be(
+      """{"data":{"posts":[{"title":"engine"}]}}""")
+
+    server.query("""{ posts(where: { user: null }) { title } }""", project).toString should be(
+      """{"data":{"posts":[{"title":"orphan"}]}}""")
+  }
+
+  "A to-many relation filter" should "join over all foreign key columns" in {
+    server.query("""{ users(where: { posts_some: { title: "engine" } }) { lastName } }""", project).toString should be(
+      """{"data":{"users":[{"lastName":"Lovelace"}]}}""")
+
+    server.query("""{ users(where: { posts_none: { title: "engine" } }) { lastName } }""", project).toString should be(
+      """{"data":{"users":[{"lastName":"Byron"}]}}""")
+  }
+
+  "A nested relation filter" should "join over all foreign key columns" in {
+    server.query("""{ users(where: { posts_some: { comments_some: { text: "nice" } } }) { lastName } }""", project).toString should be(
+      """{"data":{"users":[{"lastName":"Lovelace"}]}}""")
+
+    server.query("""{ comments(where: { post: { user: { lastName: "Lovelace" } } }) { text } }""", project).toString should be(
+      """{"data":{"comments":[{"text":"nice"}]}}""")
+  }
+}
@@ -16,4 +16,18 @@ class UUIDFilterSpec extends FlatSpec with Matchers with ApiSpecBase {
     database.setup(project)
     server.query("""query {users(where: { id: "a3f7bcd1-3ae7-4706-913a-9cfe5ed7e7b6" }) {id}}""", project).toString should be("""{"data":{"users":[]}}""")
   }
+
+  "Using a Uuid field in where clause" should "work" in {
+    val project: Project = ProjectDsl.fromString { """
+                                                      |model User {
+                                                      |  id   Uuid   @id @default(uuid())
+                                                      |  name String
+                                                      |}""".stripMargin }
+    database.setup(project)
+
+    val id = server.query("""mutation {createUser(data: { name: "Alice" }) {id}}""", project).pathAsString("data.createUser.id")
+
+    server.query(s"""query {users(where: { id: "$id" }) {name}}""", project).toString should be("""{"data":{"users":[{"name":"Alice"}]}}""")
+    server.query(s"""query {users(where: { id_in: ["$id"] }) {name}}""", project).toString should be("""{"data":{"users":[{"name":"Alice"}]}}""")
+  }
 }
@@ -0,0 +1,54 @@
+package writes
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class DbGeneratedDefaultSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = ProjectDsl.fromString {
+    """
+      |model Todo {
+      |  id    String @id @default(cuid())
+      |  title String
+      |  score Int    @default(dbgenerated("1 + 1"))
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  "Creating an item without a value for a field with a database generated default" should "let the database fill it in" in {
+    val result = server.query(
+      """
+        |mutation {
+        |  createTodo(data: { title: "the title" }){
+        |    title
+        |    score
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.pathAsLong("data.createTodo.score") should equal(2)
+  }
+
+  "Creating an item with a value for a field with a database generated default" should "use the value" in {
+    val result = server.query(
+      """
+        |mutation {
+        |  createTodo(data: { title: "the title", score: 10 }){
+        |    title
+        |    score
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.pathAsLong("data.createTodo.score") should equal(10)
+  }
+}
@@ -98,6 +98,26 @@ class UpdateManyListSpec extends FlatSpec with Matchers with ApiSpecBase {
     }
   }
 
+  "The updateMany Mutation" should "push to Lists of Strings" in {
+    test { project =>
+      server
+        .query("""mutation a {updateManyMyObjects(data:{strings: { set: ["Alpha"] }}){count}}""", project)
+        .pathAsLong("data.updateManyMyObjects.count") should be(1)
+      server
+        .query("""mutation a {updateManyMyObjects(data:{strings: { push: ["Beta","Gamma"] }}){count}}""", project)
+        .pathAsLong("data.updateManyMyObjects.count") should be(1)
+      server.query("""{myObjects{strings}}""", project).toString should be("""{"data":{"myObjects":[{"strings":["Alpha","Beta","Gamma"]}]}}""")
+    }
+  }
+
+  "The updateMany Mutation" should "push to empty Lists of Enums" in {
+    test { project =>
+      server.query("""mutation a {updateManyMyObjects(data:{enums: { push: [B] }}){count}}""", project).pathAsLong("data.updateManyMyObjects.count") should be(
+        1)
+      server.query("""{myObjects{enums}}""", project).toString should be("""{"data":{"myObjects":[{"enums":["B"]}]}}""")
+    }
+  }
+
   def test(fn: Project => Unit) = {
     testDataModels.testV11 { project =>
       server.query("""mutation{createMyObject(data:{name: "Test"}){name}}""", project)
@@ -0,0 +1,86 @@
+package writes.nonEmbedded.nestedMutations
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.JoinRelationLinksCapability
+import util._
+
+class NestedMutationsOverCompoundRelationSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(JoinRelationLinksCapability)
+
+  // The foreign key of Post consists of the columns `user_firstName` and `user_lastName`, it does not hold the id of the user.
+  val project = SchemaDsl.fromStringV11() {
+    """model User {
+      |  id        String @id @default(cuid())
+      |  firstName String
+      |  lastName  String
+      |  posts     Post[]
+      |
+      |  @@unique([firstName, lastName])
+      |}
+      |
+      |model Post {
+      |  id    String @id @default(cuid())
+      |  title String
+      |  user  User?  @relation(references: [firstName, lastName])
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = {
+    super.beforeEach()
+    database.truncateProjectTables(project)
+  }
+
+  "A nested create" should "write all foreign key columns" in {
+    server.query(
+      """mutation {
+        |  createUser(data: {
+        |    firstName: "Ada"
+        |    lastName: "Lovelace"
+        |    posts: { create: [{ title: "first" }, { title: "second" }] }
+        |  }){ id }
+        |}""".stripMargin,
+      project
+    )
+
+    server.query("""{ users { firstName posts(orderBy: title_ASC) { title } } }""", project).toString should be(
+      """{"data":{"users":[{"firstName":"Ada","posts":[{"title":"first"},{"title":"second"}]}]}}""")
+
+    server.query("""{ posts(orderBy: title_ASC) { title user { lastName } } }""", project).toString should be(
+      """{"data":{"posts":[{"title":"first","user":{"lastName":"Lovelace"}},{"title":"second","user":{"lastName":"Lovelace"}}]}}""")
+  }
+
+  "A nested connect and disconnect" should "write all foreign key columns" in {
+    val userId = server
+      .query("""mutation { createUser(data: { firstName: "Grace", lastName: "Hopper" }){ id } }""", project)
+      .pathAsString("data.createUser.id")
+
+    val postId = server
+      .query(s"""mutation { createPost(data: { title: "cobol", user: { connect: { id: "$userId" } } }){ id } }""", project)
+      .pathAsString("data.createPost.id")
+
+    server.query(s"""{ post(where: { id: "$postId" }) { user { firstName } } }""", project).toString should be(
+      """{"data":{"post":{"user":{"firstName":"Grace"}}}}""")
+
+    server.query(
+      s"""mutation { updatePost(where: { id: "$postId" }, data: { user: { disconnect: true } }){ id } }""",
+      project
+    )
+
+    server.query(s"""{ post(where: { id: "$postId" }) { user { firstName } } }""", project).toString should be(
+      """{"data":{"post":{"user":null}}}""")
+
+    server.query(
+      s"""mutation { updateUser(where: { id: "$userId" }, data: { posts: { connect: [{ id: "$postId" }] } }){ id } }""",
+      project
+    )
+
+    server.query(s"""{ user(where: { id: "$userId" }) { posts { title } } }""", project).toString should be(
+      """{"data":{"user":{"posts":[{"title":"cobol"}]}}}""")
+  }
+}
@@ -0,0 +1,32 @@
+use super::Filter;
+use crate::compare::JsonCompare;
+use prisma_models::{PrismaValue, ScalarField};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonFilter {
+    pub field: Arc<ScalarField>,
+    pub condition: JsonCondition,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonCondition {
+    /// The value at the given path in the document equals the given JSON value.
+    /// Path segments are object keys, or array indexes when they are numeric.
+    PathEquals { path: Vec<String>, value: PrismaValue },
+}
+
+impl JsonCompare for Arc<ScalarField> {
+    fn path_equals<T>(&self, path: Vec<String>, value: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(JsonFilter {
+            field: Arc::clone(self),
+            condition: JsonCondition::PathEquals {
+                path,
+                value: value.into(),
+            },
+        })
+    }
+}
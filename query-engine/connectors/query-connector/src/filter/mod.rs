@@ -4,6 +4,7 @@
 //! [ScalarCompare](/query-connector/trait.ScalarCompare.html) and
 //! [RelationCompare](/query-connector/trait.RelationCompare.html).
 
+mod json;
 mod list;
 mod relation;
 mod scalar;
@@ -11,6 +12,7 @@ mod scalar;
 use prisma_models::prelude::*;
 use std::fmt;
 
+pub use json::*;
 pub use list::*;
 pub use relation::*;
 pub use scalar::*;
@@ -22,6 +24,7 @@ pub enum Filter {
     Not(Vec<Filter>),
     Scalar(ScalarFilter),
     ScalarList(ScalarListFilter),
+    Json(JsonFilter),
     OneRelationIsNull(OneRelationIsNullFilter),
     Relation(RelationFilter),
     NodeSubscription,
@@ -77,6 +80,12 @@ impl From<ScalarListFilter> for Filter {
     }
 }
 
+impl From<JsonFilter> for Filter {
+    fn from(sf: JsonFilter) -> Self {
+        Filter::Json(sf)
+    }
+}
+
 impl From<OneRelationIsNullFilter> for Filter {
     fn from(sf: OneRelationIsNullFilter) -> Self {
         Filter::OneRelationIsNull(sf)
@@ -110,6 +119,7 @@ pub fn test_data_model() -> InternalDataModelRef {
             behaviour: None,
             default_value: None,
             internal_enum: None,
+            native_type: None,
         }),
         FieldTemplate::Scalar(ScalarFieldTemplate {
             name: "name".to_owned(),
@@ -123,6 +133,7 @@ pub fn test_data_model() -> InternalDataModelRef {
             behaviour: None,
             default_value: None,
             internal_enum: None,
+            native_type: None,
         }),
         FieldTemplate::Relation(RelationFieldTemplate {
             name: "sites".to_owned(),
@@ -150,21 +161,26 @@ pub fn test_data_model() -> InternalDataModelRef {
         behaviour: None,
         default_value: None,
         internal_enum: None,
+        native_type: None,
     })];
 
     let model_templates = vec![
         ModelTemplate {
             name: "User".to_owned(),
             is_embedded: false,
+            is_view: false,
             fields: user_field_templates,
             manifestation: None,
+            schema: None,
             indexes: vec![],
         },
         ModelTemplate {
             name: "Site".to_owned(),
             is_embedded: false,
+            is_view: false,
             fields: site_field_templates,
             manifestation: None,
+            schema: None,
             indexes: vec![],
         },
     ];
@@ -1,4 +1,5 @@
 use prisma_models::*;
+use std::collections::BTreeMap;
 
 pub struct WriteArgs {
     args: PrismaArgs,
@@ -12,4 +13,8 @@ impl WriteArgs {
     pub fn non_list_args(&self) -> &PrismaArgs {
         &self.args
     }
+
+    pub fn list_pushes(&self) -> &BTreeMap<String, PrismaValue> {
+        &self.args.list_pushes
+    }
 }
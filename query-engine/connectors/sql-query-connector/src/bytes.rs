@@ -0,0 +1,245 @@
+//! quaint has no binary values: bytea and BLOB columns can neither be bound as
+//! parameters nor read from the result rows. This covers Bytes fields, and Uuid
+//! fields stored as BINARY(16) on MySQL, which can also be ids. Binary values are
+//! written and compared as hex literals decoded by the database, and the binary
+//! columns are selected as hex strings, all as raw expressions in the query AST.
+//! The hex strings are decoded in `row`.
+use crate::{error::SqlError, raw_expression::RawExpression, QueryExt};
+use connector_interface::filter::{Filter, ScalarCondition};
+use datamodel::FieldArity;
+use prisma_models::prelude::*;
+use quaint::{
+    ast::{Aliasable, Column, DatabaseValue, Id, Insert, ParameterizedValue},
+    error::Error as QueryError,
+    prelude::SqlFamily,
+};
+use std::io;
+
+/// The datasource type of Uuid fields stored in 16 bytes.
+const BINARY_UUID_TYPE: &str = "binary(16)";
+
+/// Returns true if the values of the field are stored in a binary column.
+pub(crate) fn is_binary(field: &ScalarField) -> bool {
+    match field.type_identifier {
+        TypeIdentifier::Bytes => true,
+        TypeIdentifier::UUID => field.native_type.as_ref().map(String::as_str) == Some(BINARY_UUID_TYPE),
+        _ => false,
+    }
+}
+
+/// Returns true if the ids of the model are stored in a binary column, as are the foreign keys and relation
+/// tables referencing them.
+pub(crate) fn has_binary_ids(model: &ModelRef) -> bool {
+    is_binary(&model.fields().id())
+}
+
+/// Returns true if the arguments contain binary values, that are written as raw expressions.
+pub fn has_bytes(model: &ModelRef, args: &PrismaArgs) -> bool {
+    let fields = model.fields();
+
+    args.args.iter().any(|(name, value)| match fields.find_from_all(name) {
+        Ok(Field::Scalar(sf)) => is_binary(sf) && !value.is_null(),
+        Ok(Field::Relation(rf)) => has_binary_ids(&rf.related_model()) && !value.is_null(),
+        Err(_) => false,
+    })
+}
+
+/// The value of the field in the query AST. Values of binary columns are hex literals, nulls are bound as regular
+/// parameters.
+pub(crate) fn field_value(field: &ScalarField, value: PrismaValue) -> DatabaseValue<'static> {
+    if !is_binary(field) {
+        return value.into();
+    }
+
+    match value {
+        PrismaValue::Bytes(bytes) => literal(&bytes),
+        PrismaValue::Uuid(uuid) | PrismaValue::GraphqlId(GraphqlId::UUID(uuid)) => literal(uuid.as_bytes()),
+        value => value.into(),
+    }
+}
+
+/// The id of a record of the model in the query AST.
+pub(crate) fn id_value(model: &ModelRef, id: impl Into<PrismaValue>) -> DatabaseValue<'static> {
+    field_value(&model.fields().id(), id.into())
+}
+
+pub(crate) fn id_values<T>(model: &ModelRef, ids: impl IntoIterator<Item = T>) -> Vec<DatabaseValue<'static>>
+where
+    T: Into<PrismaValue>,
+{
+    let id_field = model.fields().id();
+
+    ids.into_iter().map(|id| field_value(&id_field, id.into())).collect()
+}
+
+fn literal(bytes: &[u8]) -> DatabaseValue<'static> {
+    RawExpression::BinaryLiteral(encode_hex(bytes)).value(Vec::new())
+}
+
+/// Inserts a record with binary values, returning the id generated by the database. quaint can not render the
+/// literals, so the insert is rendered with them and run as a raw query.
+pub async fn insert(conn: &dyn QueryExt, sql_family: SqlFamily, insert: Insert<'_>) -> Result<Option<Id>, QueryError> {
+    let (sql, params) = crate::raw_expression::build(sql_family, insert.into());
+
+    let generated_id = match sql_family {
+        // The insert returns the id column.
+        SqlFamily::Postgres => conn.query_raw(&sql, &params).await?.into_iter().next(),
+        SqlFamily::Mysql => {
+            conn.execute_raw(&sql, &params).await?;
+            conn.query_raw("SELECT LAST_INSERT_ID()", &[]).await?.into_iter().next()
+        }
+        SqlFamily::Sqlite => {
+            conn.execute_raw(&sql, &params).await?;
+            conn.query_raw("SELECT last_insert_rowid()", &[])
+                .await?
+                .into_iter()
+                .next()
+        }
+    };
+
+    Ok(generated_id
+        .and_then(|row| row.into_iter().next())
+        .and_then(|value| match value {
+            ParameterizedValue::Integer(i) => Some(Id::Int(i as usize)),
+            ParameterizedValue::Text(s) => Some(Id::String(s.into_owned())),
+            ParameterizedValue::Uuid(u) => Some(Id::UUID(u)),
+            _ => None,
+        }))
+}
+
+/// The values and types to select for the selected fields: binary columns are selected as hex strings under their
+/// own name.
+pub fn selection(selected_fields: &SelectedFields) -> (Vec<DatabaseValue<'static>>, Vec<(TypeIdentifier, FieldArity)>) {
+    let values = selected_fields
+        .columns()
+        .enumerate()
+        .map(|(position, column)| {
+            // Scalar fields come first in the selected columns, followed by the foreign keys.
+            let name = column.name.to_string();
+            let binary = match selected_fields.scalar.get(position) {
+                Some(selected) => is_binary(&selected.field),
+                None => selected_fields.relation.iter().any(|selected| {
+                    selected.field.db_name() == name && has_binary_ids(&selected.field.related_model())
+                }),
+            };
+
+            if binary {
+                hex(column, &name)
+            } else {
+                column.into()
+            }
+        })
+        .collect();
+
+    (values, selected_fields.types().collect())
+}
+
+/// A column holding ids of the model, selected under the given name.
+pub(crate) fn id_selection(model: &ModelRef, column: Column<'static>, name: &str) -> DatabaseValue<'static> {
+    if has_binary_ids(model) {
+        hex(column, name)
+    } else {
+        column.alias(name.to_owned()).into()
+    }
+}
+
+fn hex(column: Column<'static>, name: &str) -> DatabaseValue<'static> {
+    RawExpression::Hex.value(vec![column.into(), Column::from(name.to_owned()).into()])
+}
+
+/// Returns an error for the filters on binary columns that can not be rendered: the values are only compared as a
+/// whole.
+pub fn check_filter(filter: &Filter) -> crate::Result<()> {
+    match filter {
+        Filter::And(filters) | Filter::Or(filters) | Filter::Not(filters) => filters.iter().try_for_each(check_filter),
+        Filter::Relation(relation_filter) => check_filter(&relation_filter.nested_filter),
+        Filter::Scalar(scalar_filter) if is_binary(&scalar_filter.field) => match scalar_filter.condition {
+            ScalarCondition::Contains(_)
+            | ScalarCondition::NotContains(_)
+            | ScalarCondition::StartsWith(_)
+            | ScalarCondition::NotStartsWith(_)
+            | ScalarCondition::EndsWith(_)
+            | ScalarCondition::NotEndsWith(_) => {
+                let message = format!(
+                    "The field `{}` is stored in a binary column, which only supports equality and comparison filters.",
+                    scalar_filter.field.name
+                );
+
+                Err(SqlError::QueryError(
+                    io::Error::new(io::ErrorKind::InvalidInput, message).into(),
+                ))
+            }
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Decodes the hex strings binary columns are read as. The databases return upper or lower case digits.
+pub(crate) fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|idx| hex.get(idx..idx + 2).and_then(|byte| u8::from_str_radix(byte, 16).ok()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raw_expression::build;
+    use quaint::ast::{Comparable, Select};
+    use uuid::Uuid;
+
+    #[test]
+    fn binary_values_round_trip_through_hex() {
+        let bytes = vec![0xff, 0x00, 0xfe, 0x80, 0x7f];
+        let select = Select::from_table("Test").value(literal(&bytes));
+
+        assert_eq!(
+            build(SqlFamily::Postgres, select.clone().into()).0,
+            r#"SELECT decode('ff00fe807f', 'hex') FROM "Test""#
+        );
+        assert_eq!(
+            build(SqlFamily::Mysql, select.into()).0,
+            "SELECT X'ff00fe807f' FROM `Test`"
+        );
+        assert_eq!(decode_hex(&encode_hex(&bytes)), Some(bytes.clone()));
+        assert_eq!(decode_hex("FF00FE807F"), Some(bytes));
+    }
+
+    #[test]
+    fn binary_uuids_are_compared_to_their_bytes() {
+        let uuid = Uuid::parse_str("6f2e8a4c-0b1d-4e3f-9a7b-5c6d7e8f9a0b").unwrap();
+        let condition = Column::from("id").in_selection(vec![literal(uuid.as_bytes())]);
+        let select = Select::from_table("Test").so_that(condition);
+
+        assert_eq!(
+            build(SqlFamily::Mysql, select.into()).0,
+            "SELECT `Test`.* FROM `Test` WHERE `id` IN (X'6f2e8a4c0b1d4e3f9a7b5c6d7e8f9a0b')"
+        );
+    }
+
+    #[test]
+    fn binary_columns_are_selected_as_hex_strings() {
+        let select = Select::from_table("Test").value(hex(Column::from("data").table("Test"), "data"));
+
+        assert_eq!(
+            build(SqlFamily::Sqlite, select.into()).0,
+            "SELECT CASE WHEN `Test`.`data` IS NULL THEN NULL ELSE hex(`Test`.`data`) END AS `data` FROM `Test`"
+        );
+    }
+
+    #[test]
+    fn invalid_hex_strings_are_not_decoded() {
+        assert_eq!(decode_hex("abc"), None);
+        assert_eq!(decode_hex("zz"), None);
+    }
+}
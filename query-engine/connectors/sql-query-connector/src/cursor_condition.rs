@@ -1,3 +1,4 @@
+use crate::bytes;
 use connector_interface::QueryArguments;
 use prisma_models::*;
 use quaint::ast::*;
@@ -26,6 +27,7 @@ pub fn build(query_arguments: &QueryArguments, model: ModelRef) -> ConditionTree
 
             let cursor_for = |cursor_type: CursorType, id: GraphqlId| {
                 let model_id = model.fields().id();
+                let id = bytes::id_value(&model, id);
                 let where_condition = model_id.as_column().equals(id.clone());
 
                 let select_query = Select::from_table(model.as_table())
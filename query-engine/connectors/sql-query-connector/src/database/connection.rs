@@ -65,7 +65,10 @@ where
         filter: &'b Filter,
         selected_fields: &'b SelectedFields,
     ) -> connector::IO<'b, Option<SingleRecord>> {
-        IO::new(self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await }))
+        IO::new(self.catch(async move {
+            let sql_family = self.connection_info.sql_family();
+            read::get_single_record(&self.inner, sql_family, model, filter, selected_fields).await
+        }))
     }
 
     fn get_many_records<'b>(
@@ -74,11 +77,10 @@ where
         query_arguments: QueryArguments,
         selected_fields: &'b SelectedFields,
     ) -> connector::IO<'b, ManyRecords> {
-        IO::new(
-            self.catch(
-                async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await },
-            ),
-        )
+        IO::new(self.catch(async move {
+            let sql_family = self.connection_info.sql_family();
+            read::get_many_records(&self.inner, sql_family, model, query_arguments, selected_fields).await
+        }))
     }
 
     fn get_related_records<'b>(
@@ -91,6 +93,7 @@ where
         IO::new(self.catch(async move {
             read::get_related_records::<T>(
                 &self.inner,
+                self.connection_info.sql_family(),
                 from_field,
                 from_record_ids,
                 query_arguments,
@@ -101,7 +104,10 @@ where
     }
 
     fn count_by_model<'b>(&'b self, model: &'b ModelRef, query_arguments: QueryArguments) -> connector::IO<'b, usize> {
-        IO::new(self.catch(async move { read::count_by_model(&self.inner, model, query_arguments).await }))
+        IO::new(self.catch(async move {
+            let sql_family = self.connection_info.sql_family();
+            read::count_by_model(&self.inner, sql_family, model, query_arguments).await
+        }))
     }
 }
 
@@ -111,7 +117,10 @@ where
     T: ManyRelatedRecordsQueryBuilder + Send + Sync + 'static,
 {
     fn create_record<'a>(&'a self, model: &'a ModelRef, args: WriteArgs) -> connector::IO<GraphqlId> {
-        IO::new(self.catch(async move { write::create_record(&self.inner, model, args).await }))
+        IO::new(self.catch(async move {
+            let sql_family = self.connection_info.sql_family();
+            write::create_record(&self.inner, sql_family, model, args).await
+        }))
     }
 
     fn update_records<'a>(
@@ -120,11 +129,17 @@ where
         where_: Filter,
         args: WriteArgs,
     ) -> connector::IO<Vec<GraphqlId>> {
-        IO::new(self.catch(async move { write::update_records(&self.inner, model, where_, args).await }))
+        IO::new(self.catch(async move {
+            let sql_family = self.connection_info.sql_family();
+            write::update_records(&self.inner, sql_family, model, where_, args).await
+        }))
     }
 
     fn delete_records<'a>(&'a self, model: &'a ModelRef, where_: Filter) -> connector::IO<usize> {
-        IO::new(self.catch(async move { write::delete_records(&self.inner, model, where_).await }))
+        IO::new(self.catch(async move {
+            let sql_family = self.connection_info.sql_family();
+            write::delete_records(&self.inner, sql_family, model, where_).await
+        }))
     }
 
     fn connect<'a>(
@@ -133,7 +148,10 @@ where
         parent_id: &'a GraphqlId,
         child_ids: &'a [GraphqlId],
     ) -> connector::IO<()> {
-        IO::new(self.catch(async move { write::connect(&self.inner, field, parent_id, child_ids).await }))
+        IO::new(self.catch(async move {
+            let sql_family = self.connection_info.sql_family();
+            write::connect(&self.inner, sql_family, field, parent_id, child_ids).await
+        }))
     }
 
     fn disconnect<'a>(
@@ -142,6 +160,9 @@ where
         parent_id: &'a GraphqlId,
         child_ids: &'a [GraphqlId],
     ) -> connector::IO<()> {
-        IO::new(self.catch(async move { write::disconnect(&self.inner, field, parent_id, child_ids).await }))
+        IO::new(self.catch(async move {
+            let sql_family = self.connection_info.sql_family();
+            write::disconnect(&self.inner, sql_family, field, parent_id, child_ids).await
+        }))
     }
 }
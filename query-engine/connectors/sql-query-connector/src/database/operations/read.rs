@@ -1,24 +1,28 @@
 use crate::{
+    bytes,
     query_builder::read::{self, ManyRelatedRecordsBaseQuery, ManyRelatedRecordsQueryBuilder},
     QueryExt, SqlError,
 };
 
 use connector_interface::*;
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, prelude::SqlFamily};
 use std::convert::TryFrom;
 
 pub async fn get_single_record(
     conn: &dyn QueryExt,
+    sql_family: SqlFamily,
     model: &ModelRef,
     filter: &Filter,
     selected_fields: &SelectedFields,
 ) -> crate::Result<Option<SingleRecord>> {
-    let query = read::get_records(&model, selected_fields.columns(), filter);
+    bytes::check_filter(filter)?;
+
+    let (columns, idents) = bytes::selection(selected_fields);
+    let query = read::get_records(&model, columns.into_iter(), filter);
     let field_names = selected_fields.names().map(String::from).collect();
-    let idents: Vec<_> = selected_fields.types().collect();
 
-    let record = (match conn.find(query, idents.as_slice()).await {
+    let record = (match conn.find(sql_family, query, idents.as_slice()).await {
         Ok(result) => Ok(Some(result)),
         Err(_e @ SqlError::RecordNotFoundForWhere(_)) => Ok(None),
         Err(_e @ SqlError::RecordDoesNotExist) => Ok(None),
@@ -32,16 +36,19 @@ pub async fn get_single_record(
 
 pub async fn get_many_records(
     conn: &dyn QueryExt,
+    sql_family: SqlFamily,
     model: &ModelRef,
     query_arguments: QueryArguments,
     selected_fields: &SelectedFields,
 ) -> crate::Result<ManyRecords> {
     let field_names = selected_fields.names().map(String::from).collect();
-    let idents: Vec<_> = selected_fields.types().collect();
-    let query = read::get_records(model, selected_fields.columns(), query_arguments);
+    let (columns, idents) = bytes::selection(selected_fields);
+    check_arguments(&query_arguments)?;
+
+    let query = read::get_records(model, columns.into_iter(), query_arguments);
 
     let records = conn
-        .filter(query.into(), idents.as_slice())
+        .filter(sql_family, query.into(), idents.as_slice())
         .await?
         .into_iter()
         .map(Record::from)
@@ -52,6 +59,7 @@ pub async fn get_many_records(
 
 pub async fn get_related_records<T>(
     conn: &dyn QueryExt,
+    sql_family: SqlFamily,
     from_field: &RelationFieldRef,
     from_record_ids: &[GraphqlId],
     query_arguments: QueryArguments,
@@ -60,7 +68,7 @@ pub async fn get_related_records<T>(
 where
     T: ManyRelatedRecordsQueryBuilder,
 {
-    let mut idents: Vec<_> = selected_fields.types().collect();
+    let (mut columns, mut idents) = bytes::selection(selected_fields);
     idents.push(from_field.related_field().type_identifier_with_arity());
     idents.push(from_field.type_identifier_with_arity());
 
@@ -68,16 +76,28 @@ where
     field_names.push(from_field.related_field().name.clone());
     field_names.push(from_field.name.clone());
 
-    let can_skip_joins = from_field.relation_is_inlined_in_child() && !query_arguments.is_with_pagination();
+    check_arguments(&query_arguments)?;
+
+    let can_skip_joins = from_field.relation_is_inlined_in_child()
+        && !from_field.relation().references_non_id_fields()
+        && !query_arguments.is_with_pagination();
 
-    let mut columns: Vec<_> = selected_fields.columns().collect();
-    columns.push(from_field.opposite_column(true).alias(SelectedFields::RELATED_MODEL_ALIAS));
-    columns.push(from_field.relation_column(true).alias(SelectedFields::PARENT_MODEL_ALIAS));
+    columns.push(bytes::id_selection(
+        &from_field.related_model(),
+        from_field.opposite_column(true),
+        SelectedFields::RELATED_MODEL_ALIAS,
+    ));
+    columns.push(bytes::id_selection(
+        &from_field.model(),
+        from_field.relation_column(true),
+        SelectedFields::PARENT_MODEL_ALIAS,
+    ));
 
     let query = if can_skip_joins {
         let model = from_field.related_model();
+        let parent_ids = bytes::id_values(&from_field.model(), from_record_ids);
         let select = read::get_records(&model, columns.into_iter(), query_arguments)
-            .and_where(from_field.relation_column(true).in_selection(from_record_ids.to_owned()));
+            .and_where(from_field.relation_column(true).in_selection(parent_ids));
 
         Query::from(select)
     } else {
@@ -92,7 +112,7 @@ where
     };
 
     let records: crate::Result<Vec<Record>> = conn
-        .filter(query, idents.as_slice())
+        .filter(sql_family, query, idents.as_slice())
         .await?
         .into_iter()
         .map(|mut row| {
@@ -117,11 +137,21 @@ where
 
 pub async fn count_by_model(
     conn: &dyn QueryExt,
+    sql_family: SqlFamily,
     model: &ModelRef,
     query_arguments: QueryArguments,
 ) -> crate::Result<usize> {
+    check_arguments(&query_arguments)?;
+
     let query = read::count_by_model(model, query_arguments);
-    let result = conn.find_int(query).await? as usize;
+    let result = conn.find_int(sql_family, query).await? as usize;
 
     Ok(result)
 }
+
+fn check_arguments(query_arguments: &QueryArguments) -> crate::Result<()> {
+    match query_arguments.filter {
+        Some(ref filter) => bytes::check_filter(filter),
+        None => Ok(()),
+    }
+}
@@ -1,12 +1,25 @@
-use crate::{error::SqlError, query_builder::write, QueryExt};
+use crate::{bytes, error::SqlError, query_builder::write, scalar_list, QueryExt};
 use connector_interface::*;
 use prisma_models::*;
-use quaint::error::Error as QueryError;
+use quaint::{error::Error as QueryError, prelude::SqlFamily};
 
-pub async fn create_record(conn: &dyn QueryExt, model: &ModelRef, args: WriteArgs) -> crate::Result<GraphqlId> {
+pub async fn create_record(
+    conn: &dyn QueryExt,
+    sql_family: SqlFamily,
+    model: &ModelRef,
+    args: WriteArgs,
+) -> crate::Result<GraphqlId> {
+    let has_bytes = bytes::has_bytes(model, args.non_list_args());
     let (insert, returned_id) = write::create_record(model, args.non_list_args().clone());
 
-    let last_id = match conn.insert(insert).await {
+    // Binary values can not be bound as parameters, records with binary values are inserted with a raw query.
+    let result = if has_bytes {
+        bytes::insert(conn, sql_family, insert).await
+    } else {
+        conn.insert(insert).await
+    };
+
+    let last_id = match result {
         Ok(id) => id,
         Err(QueryError::UniqueConstraintViolation { field_name }) => {
             if field_name == "PRIMARY" {
@@ -43,30 +56,41 @@ pub async fn create_record(conn: &dyn QueryExt, model: &ModelRef, args: WriteArg
 
 pub async fn update_records(
     conn: &dyn QueryExt,
+    sql_family: SqlFamily,
     model: &ModelRef,
     where_: Filter,
     args: WriteArgs,
 ) -> crate::Result<Vec<GraphqlId>> {
-    let ids = conn.filter_ids(model, where_.clone()).await?;
+    bytes::check_filter(&where_)?;
+
+    let ids = conn.filter_ids(sql_family, model, where_).await?;
 
     if ids.len() == 0 {
         return Ok(vec![]);
     }
 
-    let updates = {
+    {
         let ids: Vec<&GraphqlId> = ids.iter().map(|id| &*id).collect();
-        write::update_many(model, ids.as_slice(), args.non_list_args())?
-    };
 
-    for update in updates {
-        conn.update(update).await?;
+        for update in write::update_many(model, ids.as_slice(), args.non_list_args())? {
+            conn.execute_query(sql_family, update.into()).await?;
+        }
+
+        scalar_list::push_to_lists(conn, model, ids.as_slice(), args.list_pushes()).await?;
     }
 
     Ok(ids)
 }
 
-pub async fn delete_records(conn: &dyn QueryExt, model: &ModelRef, where_: Filter) -> crate::Result<usize> {
-    let ids = conn.filter_ids(model, where_.clone()).await?;
+pub async fn delete_records(
+    conn: &dyn QueryExt,
+    sql_family: SqlFamily,
+    model: &ModelRef,
+    where_: Filter,
+) -> crate::Result<usize> {
+    bytes::check_filter(&where_)?;
+
+    let ids = conn.filter_ids(sql_family, model, where_).await?;
     let ids: Vec<&GraphqlId> = ids.iter().map(|id| &*id).collect();
     let count = ids.len();
 
@@ -75,7 +99,7 @@ pub async fn delete_records(conn: &dyn QueryExt, model: &ModelRef, where_: Filte
     }
 
     for delete in write::delete_many(model, ids.as_slice()) {
-        conn.delete(delete).await?;
+        conn.execute_query(sql_family, delete.into()).await?;
     }
 
     Ok(count)
@@ -83,24 +107,26 @@ pub async fn delete_records(conn: &dyn QueryExt, model: &ModelRef, where_: Filte
 
 pub async fn connect(
     conn: &dyn QueryExt,
+    sql_family: SqlFamily,
     field: &RelationFieldRef,
     parent_id: &GraphqlId,
     child_ids: &[GraphqlId],
 ) -> crate::Result<()> {
     let query = write::create_relation_table_records(field, parent_id, child_ids);
 
-    conn.execute(query).await?;
+    conn.execute_query(sql_family, query).await?;
     Ok(())
 }
 
 pub async fn disconnect(
     conn: &dyn QueryExt,
+    sql_family: SqlFamily,
     field: &RelationFieldRef,
     parent_id: &GraphqlId,
     child_ids: &[GraphqlId],
 ) -> crate::Result<()> {
     let query = write::delete_relation_table_records(field, parent_id, child_ids);
 
-    conn.execute(query).await?;
+    conn.execute_query(sql_family, query).await?;
     Ok(())
 }
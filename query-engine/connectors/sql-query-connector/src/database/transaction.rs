@@ -56,7 +56,10 @@ where
         filter: &'b Filter,
         selected_fields: &'b SelectedFields,
     ) -> connector::IO<'b, Option<SingleRecord>> {
-        IO::new(self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await }))
+        IO::new(self.catch(async move {
+            let sql_family = self.connection_info.sql_family();
+            read::get_single_record(&self.inner, sql_family, model, filter, selected_fields).await
+        }))
     }
 
     fn get_many_records<'b>(
@@ -65,11 +68,10 @@ where
         query_arguments: QueryArguments,
         selected_fields: &'b SelectedFields,
     ) -> connector::IO<'b, ManyRecords> {
-        IO::new(
-            self.catch(
-                async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await },
-            ),
-        )
+        IO::new(self.catch(async move {
+            let sql_family = self.connection_info.sql_family();
+            read::get_many_records(&self.inner, sql_family, model, query_arguments, selected_fields).await
+        }))
     }
 
     fn get_related_records<'b>(
@@ -82,6 +84,7 @@ where
         IO::new(self.catch(async move {
             read::get_related_records::<T>(
                 &self.inner,
+                self.connection_info.sql_family(),
                 from_field,
                 from_record_ids,
                 query_arguments,
@@ -92,7 +95,10 @@ where
     }
 
     fn count_by_model<'b>(&'b self, model: &'b ModelRef, query_arguments: QueryArguments) -> connector::IO<'b, usize> {
-        IO::new(self.catch(async move { read::count_by_model(&self.inner, model, query_arguments).await }))
+        IO::new(self.catch(async move {
+            let sql_family = self.connection_info.sql_family();
+            read::count_by_model(&self.inner, sql_family, model, query_arguments).await
+        }))
     }
 }
 
@@ -101,7 +107,10 @@ where
     T: ManyRelatedRecordsQueryBuilder + Send + Sync + 'static,
 {
     fn create_record<'b>(&'b self, model: &'b ModelRef, args: WriteArgs) -> connector::IO<GraphqlId> {
-        IO::new(self.catch(async move { write::create_record(&self.inner, model, args).await }))
+        IO::new(self.catch(async move {
+            let sql_family = self.connection_info.sql_family();
+            write::create_record(&self.inner, sql_family, model, args).await
+        }))
     }
 
     fn update_records<'b>(
@@ -110,11 +119,17 @@ where
         where_: Filter,
         args: WriteArgs,
     ) -> connector::IO<Vec<GraphqlId>> {
-        IO::new(self.catch(async move { write::update_records(&self.inner, model, where_, args).await }))
+        IO::new(self.catch(async move {
+            let sql_family = self.connection_info.sql_family();
+            write::update_records(&self.inner, sql_family, model, where_, args).await
+        }))
     }
 
     fn delete_records<'b>(&'b self, model: &'b ModelRef, where_: Filter) -> connector::IO<usize> {
-        IO::new(self.catch(async move { write::delete_records(&self.inner, model, where_).await }))
+        IO::new(self.catch(async move {
+            let sql_family = self.connection_info.sql_family();
+            write::delete_records(&self.inner, sql_family, model, where_).await
+        }))
     }
 
     fn connect<'b>(
@@ -123,7 +138,10 @@ where
         parent_id: &'b GraphqlId,
         child_ids: &'b [GraphqlId],
     ) -> connector::IO<()> {
-        IO::new(self.catch(async move { write::connect(&self.inner, field, parent_id, child_ids).await }))
+        IO::new(self.catch(async move {
+            let sql_family = self.connection_info.sql_family();
+            write::connect(&self.inner, sql_family, field, parent_id, child_ids).await
+        }))
     }
 
     fn disconnect<'b>(
@@ -132,6 +150,9 @@ where
         parent_id: &'b GraphqlId,
         child_ids: &'b [GraphqlId],
     ) -> connector::IO<()> {
-        IO::new(self.catch(async move { write::disconnect(&self.inner, field, parent_id, child_ids).await }))
+        IO::new(self.catch(async move {
+            let sql_family = self.connection_info.sql_family();
+            write::disconnect(&self.inner, sql_family, field, parent_id, child_ids).await
+        }))
     }
 }
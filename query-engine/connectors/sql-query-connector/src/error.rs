@@ -12,6 +12,9 @@ pub enum SqlError {
     #[fail(display = "Null constraint failed: {}", field_name)]
     NullConstraintViolation { field_name: String },
 
+    #[fail(display = "Check constraint failed: {}", constraint_name)]
+    CheckConstraintViolation { constraint_name: String },
+
     #[fail(display = "Record does not exist.")]
     RecordDoesNotExist,
 
@@ -77,6 +80,15 @@ impl SqlError {
             SqlError::NullConstraintViolation { field_name } => {
                 ConnectorError::from_kind(ErrorKind::NullConstraintViolation { field_name })
             }
+            SqlError::CheckConstraintViolation { constraint_name } => ConnectorError {
+                user_facing_error: user_facing_errors::KnownError::new(
+                    user_facing_errors::query_engine::CheckConstraintViolation {
+                        constraint_name: constraint_name.clone(),
+                    },
+                )
+                .ok(),
+                kind: ErrorKind::CheckConstraintViolation { constraint_name },
+            },
             SqlError::RecordDoesNotExist => ConnectorError::from_kind(ErrorKind::RecordDoesNotExist),
             SqlError::ColumnDoesNotExist => ConnectorError::from_kind(ErrorKind::ColumnDoesNotExist),
             SqlError::ConnectionError(e) => ConnectorError {
@@ -116,7 +128,10 @@ impl SqlError {
 impl From<quaint::error::Error> for SqlError {
     fn from(e: quaint::error::Error) -> Self {
         match e {
-            quaint::error::Error::QueryError(e) => Self::QueryError(e),
+            quaint::error::Error::QueryError(e) => match violated_check_constraint(&e.to_string()) {
+                Some(constraint_name) => Self::CheckConstraintViolation { constraint_name },
+                None => Self::QueryError(e),
+            },
             quaint::error::Error::IoError(_) => Self::ConnectionError(e),
             quaint::error::Error::NotFound => Self::RecordDoesNotExist,
             quaint::error::Error::UniqueConstraintViolation { field_name } => {
@@ -147,6 +162,30 @@ impl From<quaint::error::Error> for SqlError {
     }
 }
 
+/// quaint does not have a dedicated error for check constraint violations, so we find the
+/// constraint name in the database error message.
+///
+/// - Postgres: `new row for relation "Product" violates check constraint "positive_price"`
+/// - MySQL: `Check constraint 'positive_price' is violated.`
+/// - SQLite: `CHECK constraint failed: positive_price`
+fn violated_check_constraint(message: &str) -> Option<String> {
+    let between = |start: &str, end: &str| -> Option<String> {
+        let from = message.find(start)? + start.len();
+        let to = message[from..].find(end)? + from;
+
+        Some(message[from..to].to_owned())
+    };
+
+    between("violates check constraint \"", "\"")
+        .or_else(|| between("Check constraint '", "' is violated"))
+        .or_else(|| {
+            let start = "CHECK constraint failed: ";
+            let from = message.find(start)? + start.len();
+
+            Some(message[from..].trim().to_owned())
+        })
+}
+
 impl From<DomainError> for SqlError {
     fn from(e: DomainError) -> SqlError {
         SqlError::DomainError(e)
@@ -177,3 +216,45 @@ impl From<FromUtf8Error> for SqlError {
         SqlError::ColumnReadFailure(e.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn postgres_check_constraint_violations_are_recognized() {
+        let message = r#"db error: ERROR: new row for relation "Product" violates check constraint "positive_price""#;
+
+        assert_eq!(
+            violated_check_constraint(message).as_ref().map(String::as_str),
+            Some("positive_price")
+        );
+    }
+
+    #[test]
+    fn mysql_check_constraint_violations_are_recognized() {
+        let message = "Server error: `ERROR HY000 (3819): Check constraint 'positive_price' is violated.'";
+
+        assert_eq!(
+            violated_check_constraint(message).as_ref().map(String::as_str),
+            Some("positive_price")
+        );
+    }
+
+    #[test]
+    fn sqlite_check_constraint_violations_are_recognized() {
+        let message = "CHECK constraint failed: positive_price";
+
+        assert_eq!(
+            violated_check_constraint(message).as_ref().map(String::as_str),
+            Some("positive_price")
+        );
+    }
+
+    #[test]
+    fn other_errors_are_not_check_constraint_violations() {
+        let message = r#"db error: ERROR: null value in column "price" violates not-null constraint"#;
+
+        assert_eq!(violated_check_constraint(message), None);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::{bytes, json_filter, scalar_list};
 use connector_interface::filter::*;
 use prisma_models::prelude::*;
 use quaint::ast::*;
@@ -130,6 +131,8 @@ impl AliasedCondition for Filter {
                 }
             }
             Filter::Empty => ConditionTree::NoCondition,
+            Filter::ScalarList(filter) => filter.aliased_cond(alias),
+            Filter::Json(filter) => filter.aliased_cond(alias),
             _ => unimplemented!(),
         }
     }
@@ -143,30 +146,34 @@ impl AliasedCondition for ScalarFilter {
             None => self.field.as_column(),
         };
 
+        let field = self.field;
+        let value = |value: PrismaValue| bytes::field_value(&field, value);
+        let values = |values: Vec<PrismaValue>| values.into_iter().map(value).collect::<Vec<DatabaseValue>>();
+
         let condition = match self.condition {
             ScalarCondition::Equals(PrismaValue::Null) => column.is_null(),
             ScalarCondition::NotEquals(PrismaValue::Null) => column.is_not_null(),
-            ScalarCondition::Equals(value) => column.equals(value),
-            ScalarCondition::NotEquals(value) => column.not_equals(value),
+            ScalarCondition::Equals(v) => column.equals(value(v)),
+            ScalarCondition::NotEquals(v) => column.not_equals(value(v)),
             ScalarCondition::Contains(value) => column.like(format!("{}", value)),
             ScalarCondition::NotContains(value) => column.not_like(format!("{}", value)),
             ScalarCondition::StartsWith(value) => column.begins_with(format!("{}", value)),
             ScalarCondition::NotStartsWith(value) => column.not_begins_with(format!("{}", value)),
             ScalarCondition::EndsWith(value) => column.ends_into(format!("{}", value)),
             ScalarCondition::NotEndsWith(value) => column.not_ends_into(format!("{}", value)),
-            ScalarCondition::LessThan(value) => column.less_than(value),
-            ScalarCondition::LessThanOrEquals(value) => column.less_than_or_equals(value),
-            ScalarCondition::GreaterThan(value) => column.greater_than(value),
-            ScalarCondition::GreaterThanOrEquals(value) => column.greater_than_or_equals(value),
+            ScalarCondition::LessThan(v) => column.less_than(value(v)),
+            ScalarCondition::LessThanOrEquals(v) => column.less_than_or_equals(value(v)),
+            ScalarCondition::GreaterThan(v) => column.greater_than(value(v)),
+            ScalarCondition::GreaterThanOrEquals(v) => column.greater_than_or_equals(value(v)),
             // We need to preserve the split first semantic for protobuf
-            ScalarCondition::In(Some(values)) => match values.split_first() {
+            ScalarCondition::In(Some(vs)) => match vs.split_first() {
                 Some((PrismaValue::Null, tail)) if tail.is_empty() => column.is_null(),
-                _ => column.in_selection(values),
+                _ => column.in_selection(values(vs)),
             },
             // We need to preserve the split first semantic for protobuf
-            ScalarCondition::NotIn(Some(values)) => match values.split_first() {
+            ScalarCondition::NotIn(Some(vs)) => match vs.split_first() {
                 Some((PrismaValue::Null, tail)) if tail.is_empty() => column.is_not_null(),
-                _ => column.not_in_selection(values),
+                _ => column.not_in_selection(values(vs)),
             },
             ScalarCondition::In(None) => column.is_null(),
             ScalarCondition::NotIn(None) => column.is_not_null(),
@@ -176,16 +183,44 @@ impl AliasedCondition for ScalarFilter {
     }
 }
 
-impl AliasedCondition for RelationFilter {
-    /// Conversion from a `RelationFilter` to a query condition tree. Aliased when in a nested `SELECT`.
+impl AliasedCondition for ScalarListFilter {
+    /// Conversion from a `ScalarListFilter` to a query condition tree. Aliased when in a nested `SELECT`.
     fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
-        let id = self.field.model().fields().id().as_column();
+        let column = match alias {
+            Some(ref alias) => self.field.as_column().table(alias.to_string(None)),
+            None => self.field.as_column(),
+        };
 
+        scalar_list::list_condition(column, self.condition)
+    }
+}
+
+impl AliasedCondition for JsonFilter {
+    /// Conversion from a `JsonFilter` to a query condition tree. Aliased when in a nested `SELECT`.
+    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
         let column = match alias {
-            Some(ref alias) => id.table(alias.dec().to_string(None)),
-            None => id,
+            Some(ref alias) => self.field.as_column().table(alias.to_string(None)),
+            None => self.field.as_column(),
         };
 
+        json_filter::json_condition(column, self.condition)
+    }
+}
+
+impl AliasedCondition for RelationFilter {
+    /// Conversion from a `RelationFilter` to a query condition tree. Aliased when in a nested `SELECT`.
+    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+        let columns: Vec<Column<'static>> = self
+            .field
+            .relation_columns()
+            .into_iter()
+            .map(|(_, model_column)| match alias {
+                Some(ref alias) => model_column.table(alias.dec().to_string(None)),
+                None => model_column,
+            })
+            .collect();
+
+        let column = columns_value(columns);
         let condition = self.condition.clone();
         let sub_select = self.aliased_sel(alias.map(|a| a.inc(AliasMode::Table)));
 
@@ -207,8 +242,19 @@ impl AliasedSelect for RelationFilter {
         let condition = self.condition.clone();
         let relation = self.field.relation();
 
-        let this_column = self.field.relation_column(false).table(alias.to_string(None));
-        let other_column = self.field.opposite_column(false).table(alias.to_string(None));
+        let this_columns: Vec<Column<'static>> = self
+            .field
+            .relation_columns()
+            .into_iter()
+            .map(|(relation_column, _)| relation_column.table(alias.to_string(None)))
+            .collect();
+
+        let (other_columns, other_model_columns): (Vec<Column<'static>>, Vec<Column<'static>>) = self
+            .field
+            .opposite_columns()
+            .into_iter()
+            .map(|(relation_column, model_column)| (relation_column.table(alias.to_string(None)), model_column))
+            .unzip();
 
         // Normalize filter tree
         let compacted = match *self.nested_filter {
@@ -230,7 +276,10 @@ impl AliasedSelect for RelationFilter {
         };
 
         match compacted {
-            Filter::Relation(filter) => {
+            // The nested sub-select can be compared with the relation table directly if it selects the values
+            // the relation table holds, otherwise it is applied to the joined related model below.
+            Filter::Relation(filter) if related_model_columns(&filter) == other_model_columns => {
+                let other_column = columns_value(other_columns);
                 let sub_condition = filter.condition.clone();
                 let sub_select = filter.aliased_sel(Some(alias.inc(AliasMode::Table)));
 
@@ -244,32 +293,33 @@ impl AliasedSelect for RelationFilter {
 
                 let conditions = tree.invert_if(condition.invert_of_subselect());
 
-                Select::from_table(relation.as_table().alias(alias.to_string(None)))
-                    .column(this_column)
+                let table = relation.as_table().alias(alias.to_string(None));
+
+                this_columns
+                    .into_iter()
+                    .fold(Select::from_table(table), |acc, column| acc.column(column))
                     .so_that(conditions)
             }
             nested_filter => {
                 let tree = nested_filter.aliased_cond(Some(alias.flip(AliasMode::Join)));
 
-                let id_column = self
-                    .field
-                    .related_model()
-                    .fields()
-                    .id()
-                    .as_column()
-                    .table(alias.to_string(Some(AliasMode::Join)));
+                let join_condition = self.field.related_join_condition(
+                    Some(alias.to_string(Some(AliasMode::Join))),
+                    Some(alias.to_string(None)),
+                );
 
                 let join = self
                     .field
                     .related_model()
                     .as_table()
                     .alias(alias.to_string(Some(AliasMode::Join)))
-                    .on(id_column.equals(other_column));
+                    .on(join_condition);
 
                 let table = relation.as_table().alias(alias.to_string(Some(AliasMode::Table)));
 
-                Select::from_table(table)
-                    .column(this_column)
+                this_columns
+                    .into_iter()
+                    .fold(Select::from_table(table), |acc, column| acc.column(column))
                     .inner_join(join)
                     .so_that(tree.invert_if(condition.invert_of_subselect()))
             }
@@ -277,19 +327,48 @@ impl AliasedSelect for RelationFilter {
     }
 }
 
+/// The columns of the related model whose values the sub-select of a nested relation filter selects.
+fn related_model_columns(filter: &RelationFilter) -> Vec<Column<'static>> {
+    filter
+        .field
+        .relation_columns()
+        .into_iter()
+        .map(|(_, model_column)| model_column)
+        .collect()
+}
+
 impl AliasedCondition for OneRelationIsNullFilter {
     /// Conversion from a `OneRelationIsNullFilter` to a query condition tree. Aliased when in a nested `SELECT`.
     fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
         let alias = alias.map(|a| a.to_string(None));
 
-        let condition = if self.field.relation_is_inlined_in_parent() {
-            self.field.as_column().opt_table(alias.clone()).is_null()
+        let references_non_id_fields = self.field.relation().references_non_id_fields();
+
+        let condition = if self.field.relation_is_inlined_in_parent() && !references_non_id_fields {
+            ConditionTree::single(self.field.as_column().opt_table(alias.clone()).is_null())
+        } else if self.field.relation_is_inlined_in_parent() {
+            // A relation over several foreign key columns is null when any of them is.
+            self.field
+                .opposite_columns()
+                .into_iter()
+                .map(|(relation_column, _)| relation_column.opt_table(alias.clone()).is_null())
+                .fold(ConditionTree::NoCondition, |acc, is_null| match acc {
+                    ConditionTree::NoCondition => is_null.into(),
+                    acc => acc.or(is_null),
+                })
         } else {
             let relation = self.field.relation();
-
-            let column = relation
-                .column_for_relation_side(self.field.relation_side)
-                .opt_table(alias.clone());
+            let (columns, model_columns): (Vec<Column<'static>>, Vec<Column<'static>>) = self
+                .field
+                .relation_columns()
+                .into_iter()
+                .map(|(relation_column, model_column)| {
+                    (
+                        relation_column.opt_table(alias.clone()),
+                        model_column.opt_table(alias.clone()),
+                    )
+                })
+                .unzip();
 
             let table = Table::from(relation.as_table());
             let relation_table = match alias {
@@ -297,15 +376,21 @@ impl AliasedCondition for OneRelationIsNullFilter {
                 None => table,
             };
 
-            let select = Select::from_table(relation_table)
-                .column(column.clone())
-                .so_that(column.is_not_null());
+            let not_null = columns
+                .iter()
+                .fold(ConditionTree::NoCondition, |acc, column| match acc {
+                    ConditionTree::NoCondition => column.clone().is_not_null().into(),
+                    acc => acc.and(column.clone().is_not_null()),
+                });
 
-            let id_column = self.field.model().fields().id().as_column().opt_table(alias.clone());
+            let select = columns
+                .into_iter()
+                .fold(Select::from_table(relation_table), |acc, column| acc.column(column))
+                .so_that(not_null);
 
-            id_column.not_in_selection(select)
+            columns_value(model_columns).not_in_selection(select).into()
         };
 
-        ConditionTree::single(condition)
+        condition
     }
 }
@@ -0,0 +1,72 @@
+//! The JSON operators are not part of the query AST, so the Json path filters
+//! are rendered as raw expressions (see `raw_expression`).
+use crate::raw_expression::{JsonPathSegment, RawExpression};
+use connector_interface::filter::JsonCondition;
+use quaint::ast::{Column, ConditionTree, DatabaseValue};
+
+/// The condition matching the records whose document in the given column satisfies the Json filter.
+pub(crate) fn json_condition(column: Column<'static>, condition: JsonCondition) -> ConditionTree<'static> {
+    match condition {
+        JsonCondition::PathEquals { path, value } => {
+            let segments = path.iter().map(|segment| segment_kind(segment)).collect();
+            let mut args: Vec<DatabaseValue<'static>> = vec![column.into()];
+
+            args.extend(path.into_iter().map(DatabaseValue::from));
+            args.push(value.into());
+
+            RawExpression::JsonPathEquals(segments).condition(args)
+        }
+    }
+}
+
+/// Numeric path segments are array indexes.
+fn segment_kind(segment: &str) -> JsonPathSegment {
+    if !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit()) {
+        JsonPathSegment::Index
+    } else {
+        JsonPathSegment::Key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raw_expression;
+    use prisma_models::PrismaValue;
+    use quaint::{ast::Select, prelude::SqlFamily};
+
+    fn path_equals(sql_family: SqlFamily) -> (String, usize) {
+        let condition = JsonCondition::PathEquals {
+            path: vec!["tags".to_owned(), "0".to_owned()],
+            value: PrismaValue::String("\"a\"".to_owned()),
+        };
+        let select = Select::from_table("Test").so_that(json_condition(Column::from("doc"), condition));
+        let (sql, params) = raw_expression::build(sql_family, select.into());
+
+        (sql, params.len())
+    }
+
+    #[test]
+    fn postgres_paths_are_bound_as_text_arrays() {
+        assert_eq!(
+            path_equals(SqlFamily::Postgres),
+            (
+                r#"SELECT "Test".* FROM "Test" WHERE (CAST("doc" #> CAST(ARRAY[$1, $2] AS text[]) AS jsonb) = $3)"#
+                    .to_owned(),
+                3
+            )
+        );
+    }
+
+    #[test]
+    fn mysql_paths_are_built_with_quoted_keys_and_array_indexes() {
+        assert_eq!(
+            path_equals(SqlFamily::Mysql),
+            (
+                "SELECT `Test`.* FROM `Test` WHERE (JSON_EXTRACT(`doc`, CONCAT('$', '.', JSON_QUOTE(?), '[', ?, ']')) = CAST(? AS JSON))"
+                    .to_owned(),
+                3
+            )
+        );
+    }
+}
@@ -6,15 +6,19 @@
 //! - [DatabaseWriter](../query-connector/trait.DatabaseWriter.html) to write
 //!   data.
 
+mod bytes;
 mod cursor_condition;
 mod database;
 mod error;
 mod filter_conversion;
+mod json_filter;
 mod ordering;
 mod query_builder;
 mod query_ext;
+mod raw_expression;
 mod raw_query;
 mod row;
+mod scalar_list;
 
 use filter_conversion::*;
 use query_ext::QueryExt;
@@ -1,11 +1,11 @@
 use crate::{cursor_condition, filter_conversion::AliasedCondition};
 use connector_interface::{OrderDirections, QueryArguments, SkipAndLimit};
 use prisma_models::prelude::*;
-use quaint::ast::{Aliasable, Column, Comparable, ConditionTree, Joinable, Select};
+use quaint::ast::{Aliasable, ConditionTree, DatabaseValue, Joinable, Select};
 
 pub struct ManyRelatedRecordsBaseQuery<'a> {
     pub from_field: &'a RelationFieldRef,
-    pub columns: Vec<Column<'static>>,
+    pub columns: Vec<DatabaseValue<'static>>,
     pub from_record_ids: &'a [GraphqlId],
     pub query: Select<'a>,
     pub order_directions: OrderDirections,
@@ -20,7 +20,7 @@ impl<'a> ManyRelatedRecordsBaseQuery<'a> {
         from_field: &'a RelationFieldRef,
         from_record_ids: &'a [GraphqlId],
         query_arguments: QueryArguments,
-        columns: Vec<Column<'static>>,
+        columns: Vec<DatabaseValue<'static>>,
     ) -> ManyRelatedRecordsBaseQuery<'a> {
         let cursor = cursor_condition::build(&query_arguments, from_field.related_model());
         let window_limits = query_arguments.window_limits();
@@ -34,23 +34,34 @@ impl<'a> ManyRelatedRecordsBaseQuery<'a> {
 
         let select = Select::from_table(from_field.related_model().as_table());
 
-        let query = if from_field.relation_is_inlined_in_child() {
-            columns.iter().fold(select, |acc, col| acc.column(col.clone()))
+        let query = if from_field.relation_is_inlined_in_child() && !from_field.relation().references_non_id_fields() {
+            columns.iter().fold(select, |acc, col| acc.value(col.clone()))
+        } else if from_field.relation_is_inlined_in_child() {
+            // The foreign key does not hold the parent ids, they come from the joined parent table.
+            let join = from_field
+                .model()
+                .as_table()
+                .alias(Relation::TABLE_ALIAS)
+                .on(join_condition(
+                    from_field.relation_columns(),
+                    from_field.relation().as_table(),
+                    Relation::TABLE_ALIAS.into(),
+                ));
+
+            columns
+                .iter()
+                .fold(select, |acc, col| acc.value(col.clone()))
+                .inner_join(join)
         } else {
             let join = from_field
                 .relation()
                 .as_table()
                 .alias(Relation::TABLE_ALIAS)
-                .on(from_field
-                    .related_model()
-                    .fields()
-                    .id()
-                    .as_column()
-                    .equals(from_field.opposite_column(true)));
+                .on(from_field.related_join_condition(None, Some(Relation::TABLE_ALIAS.to_string())));
 
             columns
                 .iter()
-                .fold(select, |acc, col| acc.column(col.clone()))
+                .fold(select, |acc, col| acc.value(col.clone()))
                 .inner_join(join)
         };
 
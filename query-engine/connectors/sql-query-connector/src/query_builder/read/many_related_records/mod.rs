@@ -6,7 +6,7 @@ pub use base_query::*;
 pub use row_number::*;
 pub use union_all::*;
 
-use crate::ordering::Ordering;
+use crate::{bytes, ordering::Ordering};
 use prisma_models::*;
 use quaint::ast::{Comparable, Conjuctive, Query};
 
@@ -21,7 +21,7 @@ pub trait ManyRelatedRecordsQueryBuilder {
         let conditions = base
             .from_field
             .relation_column(true)
-            .in_selection(base.from_record_ids.to_owned())
+            .in_selection(bytes::id_values(&base.from_field.model(), base.from_record_ids))
             .and(base.condition)
             .and(base.cursor);
 
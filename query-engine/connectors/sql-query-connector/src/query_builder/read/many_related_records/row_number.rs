@@ -1,7 +1,7 @@
 use super::*;
-use crate::ordering::Ordering;
+use crate::{bytes, ordering::Ordering};
 use prisma_models::prelude::*;
-use quaint::ast::{row_number, Aliasable, Comparable, Conjuctive, Function, Select, Table};
+use quaint::ast::{row_number, Aliasable, Comparable, Conjuctive, DatabaseValue, Function, Select, Table};
 
 pub struct ManyRelatedRecordsWithRowNumber;
 
@@ -10,14 +10,14 @@ impl ManyRelatedRecordsQueryBuilder for ManyRelatedRecordsWithRowNumber {
         let conditions = base
             .from_field
             .relation_column(true)
-            .in_selection(base.from_record_ids.to_owned())
+            .in_selection(bytes::id_values(&base.from_field.model(), base.from_record_ids))
             .and(base.condition)
             .and(base.cursor);
 
         let mut base_query = base.query.so_that(conditions);
 
         if let Some(order_by) = &base.order_directions.primary_order_by {
-            let column = order_by.field.as_column();
+            let column = DatabaseValue::from(order_by.field.as_column());
 
             if !base.columns.contains(&column) {
                 base_query = base_query.column(order_by.field.as_column());
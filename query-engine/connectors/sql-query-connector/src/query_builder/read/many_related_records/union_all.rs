@@ -1,5 +1,5 @@
 use super::*;
-use crate::ordering::Ordering;
+use crate::{bytes, ordering::Ordering};
 use connector_interface::SkipAndLimit;
 use prisma_models::prelude::*;
 use quaint::ast::*;
@@ -34,7 +34,7 @@ impl ManyRelatedRecordsQueryBuilder for ManyRelatedRecordsWithUnionAll {
         let build_cond = |id| {
             let conditions = base_condition
                 .clone()
-                .and(from_field.relation_column(true).equals(id));
+                .and(from_field.relation_column(true).equals(bytes::id_value(&from_field.model(), id)));
 
             base_query.clone().so_that(conditions)
         };
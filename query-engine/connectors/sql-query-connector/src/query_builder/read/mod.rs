@@ -67,17 +67,21 @@ impl SelectDefinition for QueryArguments {
     }
 }
 
-pub fn get_records<T>(model: &ModelRef, columns: impl Iterator<Item = Column<'static>>, query: T) -> Select<'static>
+pub fn get_records<T>(
+    model: &ModelRef,
+    columns: impl Iterator<Item = DatabaseValue<'static>>,
+    query: T,
+) -> Select<'static>
 where
     T: SelectDefinition,
 {
-    columns.fold(query.into_select(model), |acc, col| acc.column(col))
+    columns.fold(query.into_select(model), |acc, col| acc.value(col))
 }
 
 pub fn count_by_model(model: &ModelRef, query_arguments: QueryArguments) -> Select<'static> {
     let id_field = model.fields().id();
 
-    let selected_fields = vec![id_field.as_column()];
+    let selected_fields = vec![id_field.as_column().into()];
 
     let base_query = get_records(model, selected_fields.into_iter(), query_arguments);
     let table = Table::from(base_query).alias("sub");
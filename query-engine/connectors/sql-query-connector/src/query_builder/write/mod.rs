@@ -1,4 +1,4 @@
-use crate::error::SqlError;
+use crate::{bytes, error::SqlError};
 use prisma_models::*;
 use quaint::ast::*;
 use std::convert::TryFrom;
@@ -18,19 +18,50 @@ pub fn create_record(model: &ModelRef, mut args: PrismaArgs) -> (Insert<'static>
         .filter(|field| args.has_arg_for(&field.name()))
         .collect();
 
-    let fields = fields
+    let values: Vec<(String, DatabaseValue<'static>)> = fields
         .iter()
-        .map(|field| (field.db_name(), args.take_field_value(field.name()).unwrap()));
+        .flat_map(|field| column_values(field, args.take_field_value(field.name()).unwrap()))
+        .collect();
 
     let base = Insert::single_into(model.as_table());
 
-    let insert = fields
+    let insert = values
         .into_iter()
-        .fold(base, |acc, (name, value)| acc.value(name.into_owned(), value));
+        .fold(base, |acc, (name, value)| acc.value(name, value));
 
     (Insert::from(insert).returning(vec![id_field.as_column()]), return_id)
 }
 
+/// The columns and values written for a field. A relation over a foreign key referencing other fields
+/// than the id is given the id of the related record, its columns get the referenced values of that record.
+fn column_values(field: &Field, value: PrismaValue) -> Vec<(String, DatabaseValue<'static>)> {
+    match field {
+        Field::Relation(rf) if rf.relation().references_non_id_fields() && rf.relation_is_inlined_in_parent() => {
+            let related_model = rf.related_model();
+            let id_column = related_model.fields().id().as_column();
+            let id = bytes::id_value(&related_model, value.clone());
+
+            rf.opposite_columns()
+                .into_iter()
+                .map(|(referencing, referenced)| {
+                    let value: DatabaseValue<'static> = if value.is_null() {
+                        value.clone().into()
+                    } else {
+                        Select::from_table(related_model.as_table())
+                            .column(referenced)
+                            .so_that(id_column.clone().equals(id.clone()))
+                            .into()
+                    };
+
+                    (referencing.name.into_owned(), value)
+                })
+                .collect()
+        }
+        Field::Scalar(sf) => vec![(sf.db_name().to_owned(), bytes::field_value(sf, value))],
+        Field::Relation(rf) => vec![(rf.db_name(), bytes::id_value(&rf.related_model(), value))],
+    }
+}
+
 pub fn create_relation_table_records(
     field: &RelationFieldRef,
     parent_id: &GraphqlId,
@@ -46,14 +77,17 @@ pub fn create_relation_table_records(
     };
 
     let generate_ids = relation.id_column().is_some();
+    let parent_id = bytes::id_value(&field.model(), parent_id);
     let insert = Insert::multi_into(relation.as_table(), columns);
     let insert: MultiRowInsert = child_ids
         .into_iter()
         .fold(insert, |insert, child_id| {
+            let child_id = bytes::id_value(&field.related_model(), child_id);
+
             if generate_ids {
-                insert.values((parent_id.clone(), child_id.clone(), cuid::cuid().unwrap()))
+                insert.values((parent_id.clone(), child_id, cuid::cuid().unwrap()))
             } else {
-                insert.values((parent_id.clone(), child_id.clone()))
+                insert.values((parent_id.clone(), child_id))
             }
         })
         .into();
@@ -70,8 +104,8 @@ pub fn delete_relation_table_records(
     let parent_column = field.relation_column(false);
     let child_column = field.opposite_column(false);
 
-    let parent_id_criteria = parent_column.equals(parent_id);
-    let child_id_criteria = child_column.in_selection(child_ids.to_owned());
+    let parent_id_criteria = parent_column.equals(bytes::id_value(&field.model(), parent_id));
+    let child_id_criteria = child_column.in_selection(bytes::id_values(&field.related_model(), child_ids));
 
     Delete::from_table(relation.as_table())
         .so_that(parent_id_criteria.and(child_id_criteria))
@@ -95,16 +129,17 @@ pub fn update_many(model: &ModelRef, ids: &[&GraphqlId], args: &PrismaArgs) -> c
             });
         }
 
-        query = query.set(field.db_name().to_string(), value.clone());
+        for (name, value) in column_values(&field, value.clone()) {
+            query = query.set(name, value);
+        }
     }
 
     let result: Vec<Update> = ids
         .chunks(PARAMETER_LIMIT)
         .into_iter()
         .map(|ids| {
-            query
-                .clone()
-                .so_that(fields.id().as_column().in_selection(ids.to_vec()))
+            let ids = bytes::id_values(model, ids.iter().cloned());
+            query.clone().so_that(fields.id().as_column().in_selection(ids))
         })
         .collect();
 
@@ -115,7 +150,8 @@ pub fn delete_many(model: &ModelRef, ids: &[&GraphqlId]) -> Vec<Delete<'static>>
     let mut deletes = Vec::new();
 
     for chunk in ids.chunks(PARAMETER_LIMIT).into_iter() {
-        let condition = model.fields().id().as_column().in_selection(chunk.to_vec());
+        let ids = bytes::id_values(model, chunk.iter().cloned());
+        let condition = model.fields().id().as_column().in_selection(ids);
         deletes.push(Delete::from_table(model.as_table()).so_that(condition));
     }
 
@@ -1,4 +1,4 @@
-use crate::{error::*, AliasedCondition, RawQuery, SqlRow, ToSqlRow};
+use crate::{bytes, error::*, raw_expression, AliasedCondition, RawQuery, SqlRow, ToSqlRow};
 use async_trait::async_trait;
 use connector_interface::filter::Filter;
 use datamodel::FieldArity;
@@ -7,6 +7,7 @@ use quaint::{
     ast::*,
     connector::{self, Queryable},
     pooled::PooledConnection,
+    prelude::SqlFamily,
 };
 use serde_json::{Map, Number, Value};
 use std::convert::TryFrom;
@@ -18,8 +19,15 @@ impl QueryExt for PooledConnection {}
 /// Basically represents a connection wrapper?
 #[async_trait]
 pub trait QueryExt: Queryable + Send + Sync {
-    async fn filter(&self, q: Query<'_>, idents: &[(TypeIdentifier, FieldArity)]) -> crate::Result<Vec<SqlRow>> {
-        let result_set = self.query(q).await?;
+    async fn filter(
+        &self,
+        sql_family: SqlFamily,
+        q: Query<'_>,
+        idents: &[(TypeIdentifier, FieldArity)],
+    ) -> crate::Result<Vec<SqlRow>> {
+        // The query is rendered here, as it can contain raw expressions quaint can not render.
+        let (sql, params) = raw_expression::build(sql_family, q);
+        let result_set = self.query_raw(sql.as_str(), params.as_slice()).await?;
         let mut sql_rows = Vec::new();
 
         for row in result_set {
@@ -29,6 +37,15 @@ pub trait QueryExt: Queryable + Send + Sync {
         Ok(sql_rows)
     }
 
+    /// Runs a write query, returning the number of affected rows.
+    async fn execute_query(&self, sql_family: SqlFamily, q: Query<'_>) -> crate::Result<u64> {
+        // The query is rendered here, as it can contain raw expressions quaint can not render.
+        let (sql, params) = raw_expression::build(sql_family, q);
+        let changes = self.execute_raw(sql.as_str(), params.as_slice()).await?;
+
+        Ok(changes)
+    }
+
     async fn raw_json(&self, q: RawQuery) -> crate::Result<Value> {
         if q.is_select() {
             let result_set = self.query_raw(q.0.as_str(), &[]).await?;
@@ -54,8 +71,13 @@ pub trait QueryExt: Queryable + Send + Sync {
     }
 
     /// Select one row from the database.
-    async fn find(&self, q: Select<'_>, idents: &[(TypeIdentifier, FieldArity)]) -> crate::Result<SqlRow> {
-        self.filter(q.limit(1).into(), idents)
+    async fn find(
+        &self,
+        sql_family: SqlFamily,
+        q: Select<'_>,
+        idents: &[(TypeIdentifier, FieldArity)],
+    ) -> crate::Result<SqlRow> {
+        self.filter(sql_family, q.limit(1).into(), idents)
             .await?
             .into_iter()
             .next()
@@ -63,10 +85,10 @@ pub trait QueryExt: Queryable + Send + Sync {
     }
 
     /// Read the first column from the first row as an integer.
-    async fn find_int(&self, q: Select<'_>) -> crate::Result<i64> {
+    async fn find_int(&self, sql_family: SqlFamily, q: Select<'_>) -> crate::Result<i64> {
         // UNWRAP: A dataset will always have at least one column, even if it contains no data.
         let id = self
-            .find(q, &[(TypeIdentifier::Int, FieldArity::Required)])
+            .find(sql_family, q, &[(TypeIdentifier::Int, FieldArity::Required)])
             .await?
             .values
             .into_iter()
@@ -77,17 +99,27 @@ pub trait QueryExt: Queryable + Send + Sync {
     }
 
     /// Read the all columns as an `GraphqlId`
-    async fn filter_ids(&self, model: &ModelRef, filter: Filter) -> crate::Result<Vec<GraphqlId>> {
+    async fn filter_ids(
+        &self,
+        sql_family: SqlFamily,
+        model: &ModelRef,
+        filter: Filter,
+    ) -> crate::Result<Vec<GraphqlId>> {
+        let id_field = model.fields().id();
         let select = Select::from_table(model.as_table())
-            .column(model.fields().id().as_column())
+            .value(bytes::id_selection(model, id_field.as_column(), id_field.db_name()))
             .so_that(filter.aliased_cond(None));
 
-        self.select_ids(select).await
+        self.select_ids(sql_family, select).await
     }
 
-    async fn select_ids(&self, select: Select<'_>) -> crate::Result<Vec<GraphqlId>> {
+    async fn select_ids(&self, sql_family: SqlFamily, select: Select<'_>) -> crate::Result<Vec<GraphqlId>> {
         let mut rows = self
-            .filter(select.into(), &[(TypeIdentifier::GraphQLID, FieldArity::Required)])
+            .filter(
+                sql_family,
+                select.into(),
+                &[(TypeIdentifier::GraphQLID, FieldArity::Required)],
+            )
             .await?;
         let mut result = Vec::new();
 
@@ -0,0 +1,337 @@
+//! Expressions that have no equivalent in the query AST of quaint, such as the
+//! PostgreSQL array operators, the JSON functions and binary literals. They are
+//! put into the AST as a row starting with a marker column that names the
+//! expression, followed by its arguments. This way quaint renders the columns
+//! with their aliases and binds the parameters in place, and `build` only has
+//! to replace the rendered rows with the expressions.
+use prisma_models::{prelude::*, sql_ext::schema_and_table};
+use quaint::{
+    ast::{Column, ConditionTree, DatabaseValue, Expression, ParameterizedValue, Query, Row},
+    prelude::SqlFamily,
+    visitor::{self, Visitor},
+};
+
+/// The marker columns are named with this prefix and the name of the expression.
+const MARKER_PREFIX: &str = "prisma_raw_";
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum RawExpression {
+    /// The array in the first argument contains all elements of the second one.
+    ArrayContains,
+    /// The arrays have elements in common.
+    ArrayOverlaps,
+    /// The array is null or has no elements.
+    ArrayIsEmpty,
+    /// The value at a path in the JSON document of the first argument equals the JSON value of the last argument.
+    /// The path segments are the arguments in between.
+    JsonPathEquals(Vec<JsonPathSegment>),
+    /// A binary value given by its hex digits, which are safe to render in the query.
+    BinaryLiteral(String),
+    /// The binary column in the first argument as a hex string, named after the second argument.
+    Hex,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum JsonPathSegment {
+    Key,
+    Index,
+}
+
+impl RawExpression {
+    fn name(&self) -> String {
+        match self {
+            RawExpression::ArrayContains => "array_contains".to_owned(),
+            RawExpression::ArrayOverlaps => "array_overlaps".to_owned(),
+            RawExpression::ArrayIsEmpty => "array_is_empty".to_owned(),
+            RawExpression::JsonPathEquals(segments) => {
+                segments
+                    .iter()
+                    .fold("json_path_equals".to_owned(), |name, segment| match segment {
+                        JsonPathSegment::Key => format!("{}_key", name),
+                        JsonPathSegment::Index => format!("{}_index", name),
+                    })
+            }
+            RawExpression::BinaryLiteral(hex) => format!("binary_literal_{}", hex),
+            RawExpression::Hex => "hex".to_owned(),
+        }
+    }
+
+    fn from_marker(marker: &str) -> Option<Self> {
+        if !marker.starts_with(MARKER_PREFIX) {
+            return None;
+        }
+
+        match &marker[MARKER_PREFIX.len()..] {
+            "array_contains" => Some(RawExpression::ArrayContains),
+            "array_overlaps" => Some(RawExpression::ArrayOverlaps),
+            "array_is_empty" => Some(RawExpression::ArrayIsEmpty),
+            "hex" => Some(RawExpression::Hex),
+            name if name.starts_with("json_path_equals") => {
+                let segments = name["json_path_equals".len()..]
+                    .split('_')
+                    .skip(1)
+                    .map(|segment| match segment {
+                        "key" => Some(JsonPathSegment::Key),
+                        "index" => Some(JsonPathSegment::Index),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+
+                Some(RawExpression::JsonPathEquals(segments))
+            }
+            name if name.starts_with("binary_literal_") => {
+                let hex = &name["binary_literal_".len()..];
+
+                if hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                    Some(RawExpression::BinaryLiteral(hex.to_owned()))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// The expression applied to the given arguments, as a value of the query AST.
+    pub(crate) fn value<'a>(self, args: Vec<DatabaseValue<'a>>) -> DatabaseValue<'a> {
+        let marker = Column::from(format!("{}{}", MARKER_PREFIX, self.name()));
+
+        args.into_iter()
+            .fold(Row::new().push(marker), |row, arg| row.push(arg))
+            .into()
+    }
+
+    /// The expression applied to the given arguments, as a condition of the query AST.
+    pub(crate) fn condition<'a>(self, args: Vec<DatabaseValue<'a>>) -> ConditionTree<'a> {
+        ConditionTree::single(Expression::Value(Box::new(self.value(args))))
+    }
+
+    /// Renders the expression with the rendered arguments, in place of the parenthesized row. MySQL and SQLite
+    /// bind the parameters by position, so the arguments must be used once and in order.
+    fn render(&self, sql_family: SqlFamily, args: &[String]) -> String {
+        match self {
+            RawExpression::ArrayContains => format!("({} @> {})", args[0], args[1]),
+            RawExpression::ArrayOverlaps => format!("({} && {})", args[0], args[1]),
+            RawExpression::ArrayIsEmpty => format!("(COALESCE(cardinality({}), 0) = 0)", args[0]),
+            RawExpression::JsonPathEquals(segments) => {
+                let (document, rest) = args.split_first().unwrap();
+                let (value, path) = rest.split_last().unwrap();
+
+                format!(
+                    "({})",
+                    render_json_path_equals(sql_family, document, segments, path, value)
+                )
+            }
+            RawExpression::BinaryLiteral(hex) => match sql_family {
+                SqlFamily::Postgres => format!("decode('{}', 'hex')", hex),
+                SqlFamily::Mysql | SqlFamily::Sqlite => format!("X'{}'", hex),
+            },
+            RawExpression::Hex => match sql_family {
+                SqlFamily::Postgres => format!("encode({}, 'hex') AS {}", args[0], args[1]),
+                SqlFamily::Mysql => format!("HEX({}) AS {}", args[0], args[1]),
+                // `hex(NULL)` is an empty string on SQLite.
+                SqlFamily::Sqlite => format!(
+                    "CASE WHEN {0} IS NULL THEN NULL ELSE hex({0}) END AS {1}",
+                    args[0], args[1]
+                ),
+            },
+        }
+    }
+}
+
+/// `json` columns are compared as `jsonb` on PostgreSQL, where the key order and whitespace do not matter. MySQL
+/// and SQLite take the path in their JSON path syntax, which is built with the keys quoted as JSON strings.
+fn render_json_path_equals(
+    sql_family: SqlFamily,
+    document: &str,
+    segments: &[JsonPathSegment],
+    path: &[String],
+    value: &str,
+) -> String {
+    let mysql_path = || {
+        segments
+            .iter()
+            .zip(path)
+            .map(|(segment, arg)| match segment {
+                JsonPathSegment::Key => format!(", '.', JSON_QUOTE({})", arg),
+                JsonPathSegment::Index => format!(", '[', {}, ']'", arg),
+            })
+            .collect::<String>()
+    };
+
+    let sqlite_path = || {
+        segments
+            .iter()
+            .zip(path)
+            .map(|(segment, arg)| match segment {
+                JsonPathSegment::Key => format!(" || '.' || json_quote({})", arg),
+                JsonPathSegment::Index => format!(" || '[' || {} || ']'", arg),
+            })
+            .collect::<String>()
+    };
+
+    match sql_family {
+        SqlFamily::Postgres => format!(
+            "CAST({} #> CAST(ARRAY[{}] AS text[]) AS jsonb) = {}",
+            document,
+            path.join(", "),
+            value
+        ),
+        SqlFamily::Mysql => format!(
+            "JSON_EXTRACT({}, CONCAT('$'{})) = CAST({} AS JSON)",
+            document,
+            mysql_path(),
+            value
+        ),
+        SqlFamily::Sqlite => format!(
+            "json_extract({}, '$'{}) = json_extract({}, '$')",
+            document,
+            sqlite_path(),
+            value
+        ),
+    }
+}
+
+/// Renders the query with the raw expressions in it.
+pub fn build<'a>(sql_family: SqlFamily, query: Query<'a>) -> (String, Vec<ParameterizedValue<'a>>) {
+    let (sql, params) = match sql_family {
+        SqlFamily::Postgres => visitor::Postgres::build(query),
+        SqlFamily::Mysql => visitor::Mysql::build(query),
+        SqlFamily::Sqlite => visitor::Sqlite::build(query),
+    };
+
+    (render_raw_expressions(sql_family, &sql), params)
+}
+
+/// Replaces the rows starting with a marker column in the rendered query with the raw expressions.
+fn render_raw_expressions(sql_family: SqlFamily, sql: &str) -> String {
+    let delimiter = match sql_family {
+        SqlFamily::Postgres => '"',
+        SqlFamily::Mysql | SqlFamily::Sqlite => '`',
+    };
+
+    let row_start = format!("({}{}", delimiter, MARKER_PREFIX);
+    let mut rendered = String::with_capacity(sql.len());
+    let mut rest = sql;
+
+    while let Some(position) = rest.find(&row_start) {
+        rendered.push_str(&rest[..position]);
+        rest = &rest[position..];
+
+        let row = &rest[..row_length(rest)];
+        let mut parts = split_row(&row[1..row.len() - 1]).into_iter();
+        let marker = parts.next().map(|marker| marker.trim_matches(delimiter));
+
+        match marker.and_then(RawExpression::from_marker) {
+            Some(expression) => {
+                let args: Vec<String> = parts.map(|arg| render_raw_expressions(sql_family, arg)).collect();
+
+                rendered.push_str(&expression.render(sql_family, &args));
+                rest = &rest[row.len()..];
+            }
+            // A row of regular columns that happens to start with the prefix.
+            None => {
+                rendered.push('(');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    rendered.push_str(rest);
+    rendered
+}
+
+/// The length of the parenthesized row at the start of the query, up to its closing parenthesis.
+fn row_length(sql: &str) -> usize {
+    let mut depth = 0;
+    let mut quote = None;
+
+    for (idx, c) in sql.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if q == c => quote = None,
+            (Some(_), _) => (),
+            (None, '"') | (None, '`') | (None, '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') if depth == 1 => return idx + 1,
+            (None, ')') => depth -= 1,
+            _ => (),
+        }
+    }
+
+    sql.len()
+}
+
+/// Splits the contents of a row on the commas that are not nested in parentheses or quotes.
+fn split_row(contents: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut quote = None;
+    let mut part_start = 0;
+
+    for (idx, c) in contents.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if q == c => quote = None,
+            (Some(_), _) => (),
+            (None, '"') | (None, '`') | (None, '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, ',') if depth == 0 => {
+                parts.push(contents[part_start..idx].trim());
+                part_start = idx + 1;
+            }
+            _ => (),
+        }
+    }
+
+    parts.push(contents[part_start..].trim());
+    parts
+}
+
+pub(crate) fn quoted_table(sql_family: SqlFamily, model: &ModelRef) -> String {
+    let (schema, table) = schema_and_table(model);
+
+    format!("{}.{}", quoted(sql_family, &schema), quoted(sql_family, &table))
+}
+
+pub(crate) fn placeholder(sql_family: SqlFamily, position: usize) -> String {
+    match sql_family {
+        SqlFamily::Postgres => format!("${}", position),
+        SqlFamily::Mysql | SqlFamily::Sqlite => "?".to_owned(),
+    }
+}
+
+pub(crate) fn quoted(sql_family: SqlFamily, identifier: &str) -> String {
+    match sql_family {
+        SqlFamily::Mysql => format!("`{}`", identifier.replace('`', "``")),
+        _ => format!("\"{}\"", identifier.replace('"', "\"\"")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quaint::ast::{Comparable, Select};
+
+    #[test]
+    fn raw_expressions_are_rendered_in_place_of_the_marker_rows() {
+        let column = Column::from("tags").table("t0");
+        let contains = RawExpression::ArrayContains.condition(vec![column.into(), DatabaseValue::from("a")]);
+        let condition = ConditionTree::and(contains, Column::from("id").equals(1));
+
+        let select = Select::from_table("Test").so_that(condition);
+        let (sql, params) = build(SqlFamily::Postgres, select.into());
+
+        assert_eq!(
+            sql,
+            r#"SELECT "Test".* FROM "Test" WHERE (("t0"."tags" @> $1) AND "id" = $2)"#
+        );
+        assert_eq!(params.len(), 2);
+    }
+
+    #[test]
+    fn rows_of_regular_columns_are_kept() {
+        let sql = "SELECT `a` FROM `b` WHERE (`prisma_raw_other`,`c`) IN (SELECT `d`, `e` FROM `f`)";
+
+        assert_eq!(render_raw_expressions(SqlFamily::Mysql, sql), sql);
+    }
+}
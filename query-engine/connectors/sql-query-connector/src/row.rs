@@ -1,4 +1,4 @@
-use crate::error::SqlError;
+use crate::{bytes, error::SqlError};
 use chrono::{DateTime, Utc};
 use datamodel::FieldArity;
 use prisma_models::{EnumValue, GraphqlId, PrismaValue, Record, TypeIdentifier};
@@ -101,8 +101,8 @@ fn row_value_to_prisma_value(
         },
         TypeIdentifier::Json => match p_value {
             ParameterizedValue::Null => PrismaValue::Null,
-            ParameterizedValue::Text(json) => PrismaValue::String(json.into()),
-            ParameterizedValue::Json(json) => PrismaValue::String(json.to_string()),
+            ParameterizedValue::Text(json) => PrismaValue::Json(json.into()),
+            ParameterizedValue::Json(json) => PrismaValue::Json(json.to_string()),
             _ => {
                 let error = io::Error::new(io::ErrorKind::InvalidData, "Json value not stored as text or json");
                 return Err(SqlError::ConversionError(error.into()));
@@ -158,6 +158,40 @@ fn row_value_to_prisma_value(
                 return Err(SqlError::ConversionError(error.into()));
             }
         },
+        // Binary columns are selected as hex strings, see `bytes`.
+        TypeIdentifier::Bytes => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Text(hex) => match bytes::decode_hex(&hex) {
+                Some(bytes) => PrismaValue::Bytes(bytes),
+                None => {
+                    let error = io::Error::new(io::ErrorKind::InvalidData, "Bytes value not selected as hex string");
+                    return Err(SqlError::ConversionError(error.into()));
+                }
+            },
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Bytes value not selected as hex string");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::Decimal => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Real(d) => PrismaValue::Decimal(d),
+            ParameterizedValue::Integer(i) => PrismaValue::Decimal(Decimal::from(i)),
+            ParameterizedValue::Text(s) => {
+                let decimal = s.parse::<Decimal>().map_err(|err| {
+                    SqlError::ConversionError(io::Error::new(io::ErrorKind::InvalidData, err.to_string()).into())
+                })?;
+
+                PrismaValue::Decimal(decimal)
+            }
+            _ => {
+                let error = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "Decimal value not stored as decimal, int or text",
+                );
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
         _ => PrismaValue::from(p_value),
     })
 }
@@ -0,0 +1,66 @@
+//! Scalar lists are stored in native array columns, which are only supported
+//! on PostgreSQL. The array operators are not part of the query AST: the list
+//! filters are rendered as raw expressions (see `raw_expression`), and pushes
+//! are run as raw queries after the regular column updates.
+use crate::{
+    raw_expression::{quoted, quoted_table, RawExpression},
+    QueryExt,
+};
+use connector_interface::filter::ScalarListCondition;
+use prisma_models::prelude::*;
+use quaint::{
+    ast::{Column, ConditionTree, ParameterizedValue},
+    prelude::SqlFamily,
+};
+use std::collections::BTreeMap;
+
+const PARAMETER_LIMIT: usize = 10000;
+
+/// Appends the given values to the scalar lists of the records with the given ids.
+pub async fn push_to_lists(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    ids: &[&GraphqlId],
+    list_pushes: &BTreeMap<String, PrismaValue>,
+) -> crate::Result<()> {
+    let fields = model.fields();
+
+    for (name, values) in list_pushes {
+        let field = fields.find_from_scalar(name)?;
+
+        for chunk in ids.chunks(PARAMETER_LIMIT) {
+            let id_placeholders: Vec<String> = (0..chunk.len()).map(|idx| format!("${}", idx + 2)).collect();
+
+            let sql = format!(
+                "UPDATE {table} SET {column} = array_cat({column}, $1) WHERE {id_column} IN ({ids})",
+                table = quoted_table(SqlFamily::Postgres, model),
+                column = quoted(SqlFamily::Postgres, field.db_name()),
+                id_column = quoted(SqlFamily::Postgres, fields.id().db_name()),
+                ids = id_placeholders.join(", "),
+            );
+
+            let mut params: Vec<ParameterizedValue> = vec![values.clone().into()];
+            params.extend(chunk.iter().map(|id| ParameterizedValue::from((*id).clone())));
+
+            conn.execute_raw(&sql, &params).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The condition matching the records whose list in the given column satisfies the list filter.
+pub(crate) fn list_condition(column: Column<'static>, condition: ScalarListCondition) -> ConditionTree<'static> {
+    match condition {
+        ScalarListCondition::Contains(value) => {
+            RawExpression::ArrayContains.condition(vec![column.into(), PrismaValue::List(Some(vec![value])).into()])
+        }
+        ScalarListCondition::ContainsEvery(values) => {
+            RawExpression::ArrayContains.condition(vec![column.into(), PrismaValue::List(Some(values)).into()])
+        }
+        ScalarListCondition::ContainsSome(values) => {
+            RawExpression::ArrayOverlaps.condition(vec![column.into(), PrismaValue::List(Some(values)).into()])
+        }
+        ScalarListCondition::ContainsNone => RawExpression::ArrayIsEmpty.condition(vec![column.into()]),
+    }
+}
@@ -175,12 +175,16 @@ impl QueryDocumentParser {
             (QueryValue::Null, _)                         => Ok(PrismaValue::Null),
             (QueryValue::String(s), ScalarType::String)   => Ok(PrismaValue::String(s)),
             (QueryValue::String(s), ScalarType::DateTime) => Self::parse_datetime(s.as_str()).map(PrismaValue::DateTime),
-            (QueryValue::String(_s), ScalarType::Json)     => unimplemented!(),
+            (QueryValue::String(s), ScalarType::Json)     => Self::parse_json(s.as_str()).map(|_| PrismaValue::Json(s)),
+            (QueryValue::String(s), ScalarType::Bytes)    => Self::parse_bytes(s.as_str()).map(PrismaValue::Bytes),
             (QueryValue::String(s), ScalarType::UUID)     => Self::parse_uuid(s.as_str()).map(PrismaValue::Uuid),
             (QueryValue::Int(i), ScalarType::Float)       => Ok(PrismaValue::Float(Decimal::from_f64(i as f64).expect("f64 is not a Decimal."))),
             (QueryValue::Int(i), ScalarType::Int)         => Ok(PrismaValue::Int(i)),
             (QueryValue::Float(f), ScalarType::Float)     => Ok(PrismaValue::Float(Decimal::from_f64(f).expect("f64 is not a Decimal."))),
             (QueryValue::Float(f), ScalarType::Int)       => Ok(PrismaValue::Int(f as i64)),
+            (QueryValue::String(s), ScalarType::Decimal)  => Self::parse_decimal(s.as_str()).map(PrismaValue::Decimal),
+            (QueryValue::Int(i), ScalarType::Decimal)     => Ok(PrismaValue::Decimal(Decimal::from(i))),
+            (QueryValue::Float(f), ScalarType::Decimal)   => Decimal::from_f64(f).map(PrismaValue::Decimal).ok_or_else(|| QueryParserError::ValueParseError(format!("Invalid Decimal: {}", f))),
             (QueryValue::Boolean(b), ScalarType::Boolean) => Ok(PrismaValue::Boolean(b)),
             (QueryValue::Enum(e), ScalarType::Enum(et))   => match et.value_for(e.as_str()) {
                                                                 Some(val) => Ok(PrismaValue::Enum(val.clone())),
@@ -196,6 +200,17 @@ impl QueryDocumentParser {
         }
     }
 
+    /// The datamodel has no UUID values: generated UUIDs are strings, which are parsed for UUID fields.
+    fn coerce_default_value(value: PrismaValue, input_type: &InputType) -> QueryParserResult<PrismaValue> {
+        match (value, input_type) {
+            (value, InputType::Opt(inner)) => Self::coerce_default_value(value, inner),
+            (PrismaValue::String(s), InputType::Scalar(ScalarType::UUID)) => {
+                Self::parse_uuid(s.as_str()).map(PrismaValue::Uuid)
+            }
+            (value, _) => Ok(value),
+        }
+    }
+
     pub fn parse_datetime(s: &str) -> QueryParserResult<DateTime<Utc>> {
         let fmt = "%Y-%m-%dT%H:%M:%S%.3f";
         Utc.datetime_from_str(s.trim_end_matches('Z'), fmt)
@@ -208,10 +223,21 @@ impl QueryDocumentParser {
             })
     }
 
+    /// Decimals are preferably sent as strings, which keeps them from losing precision in JSON.
+    pub fn parse_decimal(s: &str) -> QueryParserResult<Decimal> {
+        s.parse::<Decimal>()
+            .map_err(|err| QueryParserError::ValueParseError(format!("Invalid Decimal: {}", err)))
+    }
+
     pub fn parse_json(s: &str) -> QueryParserResult<serde_json::Value> {
         serde_json::from_str(s).map_err(|err| QueryParserError::ValueParseError(format!("Invalid json: {}", err)))
     }
 
+    /// Binary values are sent as base64 encoded strings.
+    pub fn parse_bytes(s: &str) -> QueryParserResult<Vec<u8>> {
+        base64::decode(s).map_err(|err| QueryParserError::ValueParseError(format!("Invalid base64: {}", err)))
+    }
+
     pub fn parse_uuid(s: &str) -> QueryParserResult<Uuid> {
         Uuid::parse_str(s).map_err(|err| QueryParserError::ValueParseError(format!("Invalid UUID: {}", err)))
     }
@@ -247,8 +273,14 @@ impl QueryDocumentParser {
                 let default_pair = field.default_value.clone().map(|def| (&field.name, def));
 
                 match default_pair {
+                    // Database generated defaults are left out, so the database fills them in.
+                    Some((_, ref dv)) if dv.is_db_generated() => None,
+
                     // If the input field has a default, add the default to the result.
-                    Some((k, dv)) => Some(Ok((k.clone(), ParsedInputValue::Single(dv.get().into())))),
+                    Some((k, dv)) => Some(
+                        Self::coerce_default_value(dv.get().into(), &field.field_type)
+                            .map(|value| (k.clone(), ParsedInputValue::Single(value))),
+                    ),
 
                     // Finally, if nothing is found, parse the input value with Null but disregard the result,
                     // except errors, which are propagated.
@@ -3,13 +3,16 @@ use crate::{
     schema_builder::compound_field_name,
     QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::{filter::Filter, RelationCompare, ScalarCompare};
+use connector::{filter::Filter, JsonCompare, RelationCompare, ScalarCompare, ScalarListCompare};
 use prisma_models::{Field, ModelRef, PrismaListValue, PrismaValue, RelationFieldRef, ScalarFieldRef};
 use std::{collections::BTreeMap, convert::TryFrom, convert::TryInto};
 
 lazy_static! {
     /// Filter operations in descending order of how they should be checked.
     static ref FILTER_OPERATIONS: Vec<FilterOp> = vec![
+        FilterOp::ContainsEvery,
+        FilterOp::ContainsSome,
+        FilterOp::PathEquals,
         FilterOp::NotIn,
         FilterOp::NotContains,
         FilterOp::NotStartsWith,
@@ -48,6 +51,9 @@ enum FilterOp {
     NotStartsWith,
     EndsWith,
     NotEndsWith,
+    ContainsEvery,
+    ContainsSome,
+    PathEquals,
     Some,
     None,
     Every,
@@ -83,6 +89,9 @@ impl FilterOp {
             FilterOp::NotStartsWith => "_not_starts_with",
             FilterOp::EndsWith => "_ends_with",
             FilterOp::NotEndsWith => "_not_ends_with",
+            FilterOp::ContainsEvery => "_contains_every",
+            FilterOp::ContainsSome => "_contains_some",
+            FilterOp::PathEquals => "_path_equals",
             FilterOp::Some => "_some",
             FilterOp::None => "_none",
             FilterOp::Every => "_every",
@@ -160,7 +169,16 @@ fn handle_scalar_field(
     value: ParsedInputValue,
     op: &FilterOp,
 ) -> QueryGraphBuilderResult<Filter> {
+    if *op == FilterOp::PathEquals {
+        return handle_json_path_filter(field, value);
+    }
+
     let value: PrismaValue = value.try_into()?;
+
+    if field.is_list {
+        return handle_scalar_list_field(field, value, op);
+    }
+
     Ok(match op {
         FilterOp::In => field.is_in(PrismaListValue::try_from(value)?),
         FilterOp::NotIn => field.not_in(PrismaListValue::try_from(value)?),
@@ -180,6 +198,41 @@ fn handle_scalar_field(
     })
 }
 
+fn handle_scalar_list_field(
+    field: &ScalarFieldRef,
+    value: PrismaValue,
+    op: &FilterOp,
+) -> QueryGraphBuilderResult<Filter> {
+    Ok(match op {
+        FilterOp::Contains => field.contains_element(value),
+        FilterOp::ContainsEvery => field.contains_every_element(PrismaListValue::try_from(value)?.unwrap_or_default()),
+        FilterOp::ContainsSome => field.contains_some_element(PrismaListValue::try_from(value)?.unwrap_or_default()),
+        _ => unreachable!(),
+    })
+}
+
+fn handle_json_path_filter(field: &ScalarFieldRef, value: ParsedInputValue) -> QueryGraphBuilderResult<Filter> {
+    let mut value: ParsedInputMap = value.try_into()?;
+    let segments: Vec<ParsedInputValue> = value.remove("path").unwrap().try_into()?;
+    let expected: PrismaValue = value.remove("equals").unwrap().try_into()?;
+
+    let path = segments
+        .into_iter()
+        .map(|segment| {
+            let segment: Option<String> = segment.try_into()?;
+
+            segment.ok_or_else(|| {
+                QueryGraphBuilderError::InputError(format!(
+                    "The path of a filter on `{}` can not contain null.",
+                    field.name
+                ))
+            })
+        })
+        .collect::<QueryGraphBuilderResult<Vec<String>>>()?;
+
+    Ok(field.path_equals(path, expected))
+}
+
 fn handle_relation_field(
     field: &RelationFieldRef,
     value: ParsedInputValue,
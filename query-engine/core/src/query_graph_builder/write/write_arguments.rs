@@ -20,10 +20,17 @@ impl WriteArguments {
                 match field {
                     Field::Scalar(sf) if sf.is_list => {
                         let vals: ParsedInputMap = v.try_into()?;
-                        let set_value: PrismaValue =
-                            vals.into_iter().find(|(k, _)| k == "set").unwrap().1.try_into()?;
 
-                        args.args.insert(sf.name.clone(), set_value)
+                        for (operation, value) in vals {
+                            let value: PrismaValue = value.try_into()?;
+
+                            match operation.as_str() {
+                                "set" => args.args.insert(sf.name.clone(), value),
+                                "push" if value.is_null() => (),
+                                "push" => args.args.push_to_list(sf.name.clone(), value),
+                                _ => unreachable!(),
+                            }
+                        }
                     }
 
                     Field::Scalar(sf) => {
@@ -5,7 +5,7 @@ use crate::{
 };
 use indexmap::IndexMap;
 use prisma_models::{EnumType, EnumValue, GraphqlId, PrismaValue};
-use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use std::{borrow::Borrow, collections::HashMap, convert::TryFrom};
 
 /// A grouping of items to their parent record.
@@ -328,6 +328,10 @@ fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaVal
             PrismaValue::Int(i.to_i64().expect("Unable to convert Decimal to i64."))
         }
 
+        (ScalarType::Decimal, PrismaValue::Decimal(d)) => PrismaValue::Decimal(d),
+        (ScalarType::Decimal, PrismaValue::Float(f)) => PrismaValue::Decimal(f),
+        (ScalarType::Decimal, PrismaValue::Int(i)) => PrismaValue::Decimal(Decimal::from(i)),
+
         (ScalarType::Enum(ref et), PrismaValue::Enum(ref ev)) => match et.value_for(&ev.name) {
             Some(_) => PrismaValue::Enum(ev.clone()),
             None => {
@@ -342,6 +346,9 @@ fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaVal
         (ScalarType::Boolean, PrismaValue::Boolean(b)) => PrismaValue::Boolean(b),
         (ScalarType::DateTime, PrismaValue::DateTime(dt)) => PrismaValue::DateTime(dt),
         (ScalarType::UUID, PrismaValue::Uuid(u)) => PrismaValue::Uuid(u),
+        (ScalarType::Json, PrismaValue::Json(j)) => PrismaValue::Json(j),
+        (ScalarType::Json, PrismaValue::String(s)) => PrismaValue::Json(s),
+        (ScalarType::Bytes, PrismaValue::Bytes(b)) => PrismaValue::Bytes(b),
 
         (st, pv) => {
             return Err(CoreError::SerializationError(format!(
@@ -321,6 +321,10 @@ impl InputType {
         InputType::Scalar(ScalarType::Float)
     }
 
+    pub fn decimal() -> InputType {
+        InputType::Scalar(ScalarType::Decimal)
+    }
+
     pub fn boolean() -> InputType {
         InputType::Scalar(ScalarType::Boolean)
     }
@@ -333,6 +337,10 @@ impl InputType {
         InputType::Scalar(ScalarType::Json)
     }
 
+    pub fn bytes() -> InputType {
+        InputType::Scalar(ScalarType::Bytes)
+    }
+
     pub fn uuid() -> InputType {
         InputType::Scalar(ScalarType::UUID)
     }
@@ -376,6 +384,10 @@ impl OutputType {
         OutputType::Scalar(ScalarType::Float)
     }
 
+    pub fn decimal() -> OutputType {
+        OutputType::Scalar(ScalarType::Decimal)
+    }
+
     pub fn boolean() -> OutputType {
         OutputType::Scalar(ScalarType::Boolean)
     }
@@ -388,6 +400,10 @@ impl OutputType {
         OutputType::Scalar(ScalarType::Json)
     }
 
+    pub fn bytes() -> OutputType {
+        OutputType::Scalar(ScalarType::Bytes)
+    }
+
     pub fn uuid() -> OutputType {
         OutputType::Scalar(ScalarType::UUID)
     }
@@ -430,10 +446,12 @@ pub enum ScalarType {
     String,
     Int,
     Float,
+    Decimal,
     Boolean,
     Enum(EnumTypeRef),
     DateTime,
     Json,
+    Bytes,
     UUID,
     ID,
 }
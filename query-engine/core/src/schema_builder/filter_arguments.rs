@@ -11,6 +11,8 @@ struct StaticFilterArguments {
     pub inclusion: Vec<FilterArgument>,
     pub alphanumeric: Vec<FilterArgument>,
     pub string: Vec<FilterArgument>,
+    pub scalar_list: Vec<FilterArgument>,
+    pub json: Vec<FilterArgument>,
     pub multi_relation: Vec<FilterArgument>,
     pub one_relation: Vec<FilterArgument>,
 }
@@ -43,6 +45,16 @@ lazy_static! {
             FilterArgument { suffix: "_not_ends_with",is_list: false } // All values not ending with the given string.
         ],
 
+        scalar_list: vec![
+            FilterArgument { suffix: "_contains",is_list: false }, // All lists containing the given value.
+            FilterArgument { suffix: "_contains_every",is_list: true }, // All lists containing every given value.
+            FilterArgument { suffix: "_contains_some",is_list: true } // All lists containing at least one of the given values.
+        ],
+
+        json: vec![
+            FilterArgument { suffix: "_path_equals",is_list: false } // All documents with the given value at the given path.
+        ],
+
         multi_relation: vec![
             FilterArgument { suffix: "_every",is_list: false }, // All records where all records in the relation satisfy the given condition.
             FilterArgument { suffix: "_some",is_list: false }, // All records that have at least one record in the relation satisfying the given condition.
@@ -59,19 +71,22 @@ pub fn get_field_filters<'a>(field: &ModelField) -> Vec<&'a FilterArgument> {
     if field.is_list() {
         match field.type_identifier() {
             TypeIdentifier::Relation => args.multi_relation.iter().collect(),
-            _ => vec![],
+            _ => args.scalar_list.iter().collect(),
         }
     } else {
         let filters = match field.type_identifier() {
-            TypeIdentifier::UUID => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
+            // UUIDs are not stored as text on every database, so they can not be matched as strings.
+            TypeIdentifier::UUID => vec![&args.base, &args.inclusion, &args.alphanumeric],
             TypeIdentifier::GraphQLID => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
             TypeIdentifier::String => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
             TypeIdentifier::Int => vec![&args.base, &args.inclusion, &args.alphanumeric],
             TypeIdentifier::Float => vec![&args.base, &args.inclusion, &args.alphanumeric],
+            TypeIdentifier::Decimal => vec![&args.base, &args.inclusion, &args.alphanumeric],
             TypeIdentifier::Boolean => vec![&args.base],
             TypeIdentifier::Enum => vec![&args.base, &args.inclusion],
             TypeIdentifier::DateTime => vec![&args.base, &args.inclusion, &args.alphanumeric],
-            TypeIdentifier::Json => vec![],
+            TypeIdentifier::Json => vec![&args.base, &args.json],
+            TypeIdentifier::Bytes => vec![&args.base],
             TypeIdentifier::Relation => vec![&args.one_relation],
         };
 
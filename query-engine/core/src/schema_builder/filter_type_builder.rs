@@ -129,7 +129,15 @@ impl<'a> FilterObjectTypeBuilder<'a> {
             .into_iter()
             .map(|arg| {
                 let field_name = format!("{}{}", field.name, arg.suffix);
-                let mapped = self.map_required_input_type(Arc::clone(&field));
+                let mapped = match self.map_required_input_type(Arc::clone(&field)) {
+                    // Scalar list filters compare list elements.
+                    InputType::List(element_type) if field.is_list => *element_type,
+                    // Json path filters compare the value at a path in the documents.
+                    InputType::Scalar(ScalarType::Json) if arg.suffix == "_path_equals" => {
+                        InputType::object(self.json_path_filter_object_type())
+                    }
+                    mapped => mapped,
+                };
 
                 if arg.is_list {
                     input_field(field_name, InputType::opt(InputType::list(mapped)), None)
@@ -140,6 +148,21 @@ impl<'a> FilterObjectTypeBuilder<'a> {
             .collect()
     }
 
+    /// The path and the expected value of a Json path filter.
+    fn json_path_filter_object_type(&self) -> InputObjectTypeRef {
+        let name = "JsonPathFilter".to_owned();
+        return_cached!(self.get_cache(), &name);
+
+        let input_object = Arc::new(init_input_object_type(name.clone()));
+        input_object.set_fields(vec![
+            input_field("path", InputType::list(InputType::string()), None),
+            input_field("equals", InputType::json(), None),
+        ]);
+
+        self.cache(name, Arc::clone(&input_object));
+        Arc::downgrade(&input_object)
+    }
+
     /// Maps relations to (filter) input fields.
     fn map_relation_filter_input_field(&self, field: RelationFieldRef) -> Vec<InputField> {
         let related_model = field.related_model();
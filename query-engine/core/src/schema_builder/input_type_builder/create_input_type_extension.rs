@@ -76,6 +76,8 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
             .relation()
             .into_iter()
             .filter(|rf| !rf.is_hidden)
+            // Views are read-only, they can't be written to through nested writes either.
+            .filter(|rf| !rf.related_model().is_view)
             .filter_map(|rf| {
                 let related_model = rf.related_model();
                 let related_field = rf.related_field();
@@ -11,11 +11,13 @@ pub trait InputBuilderExtensions {
             TypeIdentifier::String => InputType::string(),
             TypeIdentifier::Int => InputType::int(),
             TypeIdentifier::Float => InputType::float(),
+            TypeIdentifier::Decimal => InputType::decimal(),
             TypeIdentifier::Boolean => InputType::boolean(),
             TypeIdentifier::GraphQLID => InputType::id(),
             TypeIdentifier::UUID => InputType::uuid(),
             TypeIdentifier::DateTime => InputType::date_time(),
             TypeIdentifier::Json => InputType::json(),
+            TypeIdentifier::Bytes => InputType::bytes(),
             TypeIdentifier::Enum => self.map_enum_input_type(&field),
             TypeIdentifier::Relation => unreachable!(), // A scalar field can't be a relation.
         };
@@ -33,13 +33,46 @@ pub trait UpdateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> + Create
     }
 
     fn scalar_input_fields_for_update(&self, model: ModelRef) -> Vec<InputField> {
-        self.scalar_input_fields(
+        let (list_fields, non_list_fields): (Vec<ScalarFieldRef>, Vec<ScalarFieldRef>) =
+            model.fields().scalar().into_iter().partition(|f| f.is_list);
+
+        let mut fields = self.scalar_input_fields(
             model.name.clone(),
             "Update",
-            model.fields().scalar(),
+            non_list_fields,
             |f: ScalarFieldRef| self.map_optional_input_type(f),
             false,
-        )
+        );
+
+        let mut list_fields: Vec<InputField> = list_fields
+            .into_iter()
+            .map(|f| self.scalar_list_input_field_for_update(&model, f))
+            .collect();
+
+        fields.append(&mut list_fields);
+        fields
+    }
+
+    /// Builds the input field for a scalar list field, with its "<x>Update<y>Input" input object type.
+    /// The list can either be replaced ("set") or appended to ("push").
+    fn scalar_list_input_field_for_update(&self, model: &ModelRef, field: ScalarFieldRef) -> InputField {
+        let type_name = format!("{}Update{}Input", model.name, field.name);
+        let input_object = match self.get_cache().get(&type_name) {
+            Some(t) => t,
+            None => {
+                let fields = vec![
+                    input_field("set", self.map_optional_input_type(Arc::clone(&field)), None),
+                    input_field("push", self.map_optional_input_type(Arc::clone(&field)), None),
+                ];
+
+                let input_object = Arc::new(input_object_type(type_name.clone(), fields));
+                self.cache(type_name, Arc::clone(&input_object));
+                Arc::downgrade(&input_object)
+            }
+        };
+
+        let input_type = InputType::opt(InputType::object(input_object));
+        input_field(field.name.clone(), input_type, None)
     }
 
     /// For update input types only. Compute input fields for relational fields.
@@ -55,6 +88,7 @@ pub trait UpdateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> + Create
             .relation()
             .into_iter()
             .filter(|rf| !rf.is_hidden)
+            .filter(|rf| !rf.related_model().is_view)
             .filter_map(|rf| {
                 let related_model = rf.related_model();
                 let related_field = rf.related_field();
@@ -106,9 +106,11 @@ impl<'a> ObjectTypeBuilder<'a> {
             ModelField::Scalar(sf) => match sf.type_identifier {
                 TypeIdentifier::String => OutputType::string(),
                 TypeIdentifier::Float => OutputType::float(),
+                TypeIdentifier::Decimal => OutputType::decimal(),
                 TypeIdentifier::Boolean => OutputType::boolean(),
                 TypeIdentifier::Enum => Self::map_enum_field(sf).into(),
                 TypeIdentifier::Json => OutputType::json(),
+                TypeIdentifier::Bytes => OutputType::bytes(),
                 TypeIdentifier::DateTime => OutputType::date_time(),
                 TypeIdentifier::GraphQLID => OutputType::id(),
                 TypeIdentifier::UUID => OutputType::uuid(),
@@ -153,6 +153,7 @@ impl<'a> QuerySchemaBuilder<'a> {
         let non_embedded_models = self.non_embedded_models();
         let fields = non_embedded_models
             .into_iter()
+            .filter(|model| !model.is_view)
             .map(|model| {
                 let mut vec = vec![self.create_item_field(Arc::clone(&model))];
 
@@ -132,9 +132,23 @@ pub fn append_opt<T>(vec: &mut Vec<T>, opt: Option<T>) {
     opt.into_iter().for_each(|t| vec.push(t));
 }
 
+/// The name of an index, if it is a valid name in the schema. Introspected indexes are named like in
+/// the database, which often does not make a valid name (e.g. `User.firstName_lastName`).
+pub fn schema_index_name(index: &Index) -> Option<&str> {
+    index.name.as_ref().map(String::as_str).filter(|name| {
+        let mut chars = name.chars();
+
+        chars
+            .next()
+            .map(|c| c.is_ascii_alphabetic() || c == '_')
+            .unwrap_or(false)
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
 /// Computes a compound field name based on an index.
 pub fn compound_field_name(index: &Index) -> String {
-    index.name.clone().unwrap_or_else(|| {
+    schema_index_name(index).map(String::from).unwrap_or_else(|| {
         let index_fields = index.fields();
         let field_names: Vec<&str> = index_fields.iter().map(|sf| sf.name.as_ref()).collect();
 
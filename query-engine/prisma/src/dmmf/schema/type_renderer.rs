@@ -69,8 +69,10 @@ impl<'a> DMMFTypeRenderer<'a> {
                     ScalarType::Int => "Int",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
-                    ScalarType::Json => "DateTime",
+                    ScalarType::Json => "Json",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::ID => "ID",
                     ScalarType::UUID => "UUID",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
@@ -142,8 +144,10 @@ impl<'a> DMMFTypeRenderer<'a> {
                     ScalarType::Int => "Int",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
-                    ScalarType::Json => "DateTime",
+                    ScalarType::Json => "Json",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::ID => "ID",
                     ScalarType::UUID => "UUID",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
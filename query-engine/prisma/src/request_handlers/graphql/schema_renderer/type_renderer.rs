@@ -51,8 +51,10 @@ impl<'a> GqlTypeRenderer<'a> {
                     ScalarType::Int => "Int",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
-                    ScalarType::Json => "DateTime",
+                    ScalarType::Json => "Json",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::ID => "ID",
                     ScalarType::UUID => "UUID",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
@@ -92,8 +94,10 @@ impl<'a> GqlTypeRenderer<'a> {
                     ScalarType::Int => "Int",
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
+                    ScalarType::Decimal => "Decimal",
                     ScalarType::DateTime => "DateTime",
-                    ScalarType::Json => "DateTime",
+                    ScalarType::Json => "Json",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::ID => "ID",
                     ScalarType::UUID => "UUID",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.